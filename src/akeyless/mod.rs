@@ -0,0 +1,553 @@
+//! Akeyless signer integration
+//!
+//! Signs with an Ed25519 classic key held by Akeyless, through the public API or a
+//! customer gateway. Authentication uses an access ID with either an access key or a
+//! cloud identity; the resulting token is cached and renewed when Akeyless rejects
+//! it.
+
+use crate::http_client::{redacted_host, with_deadline, HttpClientConfig};
+use crate::interceptor::{IdentityInterceptor, RequestInterceptor};
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{SignedTransaction, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use serde_json::json;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Default Akeyless API endpoint
+pub const DEFAULT_API_URL: &str = "https://api.akeyless.io";
+
+/// Cloud whose workload identity authenticates to Akeyless
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloudProvider {
+    Aws,
+    Azure,
+    Gcp,
+}
+
+impl CloudProvider {
+    fn access_type(self) -> &'static str {
+        match self {
+            CloudProvider::Aws => "aws_iam",
+            CloudProvider::Azure => "azure_ad",
+            CloudProvider::Gcp => "gcp",
+        }
+    }
+}
+
+/// How the signer authenticates to Akeyless
+#[derive(Clone)]
+pub enum AkeylessAuth {
+    /// API key auth method
+    AccessKey {
+        access_id: String,
+        access_key: String,
+    },
+    /// Cloud identity auth method
+    ///
+    /// `cloud_id` is the base64 identity proof produced by Akeyless' cloud-id helper
+    /// on the workload.
+    CloudIdentity {
+        access_id: String,
+        provider: CloudProvider,
+        cloud_id: String,
+    },
+}
+
+impl AkeylessAuth {
+    fn access_id(&self) -> &str {
+        match self {
+            AkeylessAuth::AccessKey { access_id, .. }
+            | AkeylessAuth::CloudIdentity { access_id, .. } => access_id,
+        }
+    }
+
+    fn request_body(&self) -> serde_json::Value {
+        match self {
+            AkeylessAuth::AccessKey {
+                access_id,
+                access_key,
+            } => json!({
+                "access-type": "access_key",
+                "access-id": access_id,
+                "access-key": access_key,
+            }),
+            AkeylessAuth::CloudIdentity {
+                access_id,
+                provider,
+                cloud_id,
+            } => json!({
+                "access-type": provider.access_type(),
+                "access-id": access_id,
+                "cloud-id": cloud_id,
+            }),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct AuthResponse {
+    token: String,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    result: String,
+}
+
+/// Akeyless-based signer using an Ed25519 classic key
+#[derive(Clone)]
+pub struct AkeylessSigner {
+    client: Arc<reqwest::Client>,
+    api_url: String,
+    auth: AkeylessAuth,
+    key_name: String,
+    key_version: Option<u32>,
+    pubkey: Pubkey,
+    token: Arc<tokio::sync::Mutex<Option<String>>>,
+    interceptor: Arc<dyn RequestInterceptor>,
+    http_config: HttpClientConfig,
+}
+
+impl std::fmt::Debug for AkeylessSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AkeylessSigner")
+            .field("key_name", &self.key_name)
+            .field("pubkey", &self.pubkey)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AkeylessSigner {
+    /// Creates a new Akeyless signer
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` - Access ID and access key or cloud identity
+    /// * `key_name` - Full path of the classic key, e.g. `/solana/fee-payer`
+    /// * `pubkey` - Base58-encoded public key of the classic key
+    pub fn new(auth: AkeylessAuth, key_name: String, pubkey: String) -> Result<Self, SignerError> {
+        let pubkey = pubkey
+            .parse::<Pubkey>()
+            .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid public key: {e}")))?;
+
+        Ok(Self {
+            client: Arc::new(reqwest::Client::new()),
+            api_url: DEFAULT_API_URL.to_string(),
+            auth,
+            key_name,
+            key_version: None,
+            pubkey,
+            token: Arc::new(tokio::sync::Mutex::new(None)),
+            interceptor: Arc::new(IdentityInterceptor),
+            http_config: HttpClientConfig::default(),
+        })
+    }
+
+    /// Send requests to a gateway (e.g. `https://gateway.example.com:8081`) instead of the public API
+    pub fn with_api_url(mut self, api_url: String) -> Self {
+        self.api_url = api_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sign with a specific version of the key instead of the latest
+    pub fn with_key_version(mut self, version: u32) -> Self {
+        self.key_version = Some(version);
+        self
+    }
+
+    /// Set an interceptor applied to every outbound request
+    pub fn with_request_interceptor(
+        mut self,
+        interceptor: impl RequestInterceptor + 'static,
+    ) -> Self {
+        self.interceptor = Arc::new(interceptor);
+        self
+    }
+
+    /// Resolve `domain` to a pinned address instead of using system DNS
+    ///
+    /// The port of the request URL is used when present, so include it in the API
+    /// URL when pointing at a non-standard port.
+    pub fn with_resolve(mut self, domain: &str, addr: SocketAddr) -> Result<Self, SignerError> {
+        self.http_config.resolve.push((domain.to_string(), addr));
+        self.client = Arc::new(self.http_config.build()?);
+        Ok(self)
+    }
+
+    async fn post(
+        &self,
+        endpoint: &str,
+        body: &serde_json::Value,
+    ) -> Result<reqwest::Response, SignerError> {
+        let request = self
+            .client
+            .post(format!("{}/{endpoint}", self.api_url))
+            .json(body);
+
+        Ok(self.interceptor.intercept(request).send().await?)
+    }
+
+    async fn check_response(
+        response: reqwest::Response,
+        operation: &str,
+    ) -> Result<reqwest::Response, SignerError> {
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+        #[cfg(feature = "unsafe-debug")]
+        log::error!("Akeyless {operation} error - status: {status}, response: {error_text}");
+
+        #[cfg(not(feature = "unsafe-debug"))]
+        {
+            let _ = error_text;
+            log::error!("Akeyless {operation} error - status: {status}");
+        }
+
+        Err(SignerError::RemoteApiError(format!("API error {status}")))
+    }
+
+    /// Return the cached token, authenticating first if there is none
+    async fn token(&self) -> Result<String, SignerError> {
+        let mut token = self.token.lock().await;
+        if let Some(token) = token.as_ref() {
+            return Ok(token.clone());
+        }
+
+        let response = self.post("auth", &self.auth.request_body()).await?;
+        let auth: AuthResponse = Self::check_response(response, "auth").await?.json().await?;
+
+        *token = Some(auth.token.clone());
+        Ok(auth.token)
+    }
+
+    /// Post an authenticated request, re-authenticating once if the token was rejected
+    async fn post_authenticated(
+        &self,
+        endpoint: &str,
+        mut body: serde_json::Value,
+    ) -> Result<reqwest::Response, SignerError> {
+        for attempt in 0..2 {
+            body["token"] = json!(self.token().await?);
+            let response = self.post(endpoint, &body).await?;
+
+            // Tokens expire server-side; drop ours and authenticate again
+            if response.status() == reqwest::StatusCode::UNAUTHORIZED && attempt == 0 {
+                *self.token.lock().await = None;
+                continue;
+            }
+
+            return Self::check_response(response, endpoint).await;
+        }
+
+        unreachable!("the second attempt always returns")
+    }
+
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let mut body = json!({
+            "name": self.key_name,
+            "data": STANDARD.encode(message),
+        });
+        if let Some(version) = self.key_version {
+            body["version"] = json!(version);
+        }
+
+        let response: SignResponse = self
+            .post_authenticated("sign-data-with-classic-key", body)
+            .await?
+            .json()
+            .await
+            .map_err(|_| {
+                SignerError::SerializationError("Failed to parse Akeyless response".to_string())
+            })?;
+
+        let signature_bytes = STANDARD.decode(&response.result).map_err(|_| {
+            SignerError::SerializationError("Failed to decode signature".to_string())
+        })?;
+
+        let signature = Signature::try_from(signature_bytes.as_slice())
+            .map_err(|_| SignerError::SigningFailed("Invalid signature format".to_string()))?;
+
+        // Catches a key name that does not belong to the configured public key
+        if !TransactionUtil::verify_signature(&self.pubkey, message, &signature) {
+            return Err(SignerError::SigningFailed(format!(
+                "Akeyless key {} returned a signature that does not verify",
+                self.key_name
+            )));
+        }
+
+        Ok(signature)
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.pubkey, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.pubkey,
+        ))
+    }
+
+    /// Sign a transaction within the time remaining until `deadline`
+    ///
+    /// Returns `SignerError::Timeout` immediately if `deadline` has already passed,
+    /// or once it passes while the request is in flight.
+    pub async fn sign_transaction_by_deadline(
+        &self,
+        tx: &mut Transaction,
+        deadline: Instant,
+    ) -> Result<SignedTransaction, SignerError> {
+        with_deadline(deadline, self.sign_and_serialize(tx)).await
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for AkeylessSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        // Check that we can authenticate and see the key
+        self.post_authenticated("describe-item", json!({ "name": self.key_name }))
+            .await
+            .is_ok()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("akeyless", self.pubkey, self.latency_class())
+            .with_key_id(&self.key_name)
+            .with_endpoint(&self.api_url)
+    }
+
+    fn config_debug(&self) -> String {
+        let host = redacted_host(&self.api_url);
+        let auth = match &self.auth {
+            AkeylessAuth::AccessKey { .. } => "access_key",
+            AkeylessAuth::CloudIdentity { provider, .. } => provider.access_type(),
+        };
+
+        format!(
+            "backend=akeyless host={host} auth={auth} access_id={} key_name={} pubkey={}",
+            self.auth.access_id(),
+            self.key_name,
+            self.pubkey
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        reqwest::Url::parse(&self.api_url)
+            .map_err(|e| SignerError::ConfigError(format!("Invalid Akeyless API URL: {e}")))?;
+
+        if self.key_name.is_empty() {
+            return Err(SignerError::ConfigError(
+                "Akeyless key name must not be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn access_key() -> AkeylessAuth {
+        AkeylessAuth::AccessKey {
+            access_id: "p-abc123".to_string(),
+            access_key: "secret-access-key".to_string(),
+        }
+    }
+
+    async fn mock_auth(server: &MockServer, token: &str) {
+        Mock::given(method("POST"))
+            .and(path("/auth"))
+            .and(body_partial_json(json!({
+                "access-type": "access_key",
+                "access-id": "p-abc123",
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "token": token })))
+            .mount(server)
+            .await;
+    }
+
+    fn signer(server: &MockServer, keypair: &Keypair) -> AkeylessSigner {
+        AkeylessSigner::new(
+            access_key(),
+            "/solana/payer".to_string(),
+            keypair_pubkey(keypair).to_string(),
+        )
+        .unwrap()
+        .with_api_url(server.uri())
+    }
+
+    #[tokio::test]
+    async fn test_akeyless_sign() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signature = keypair_sign_message(&keypair, b"hello");
+        mock_auth(&server, "t-token").await;
+
+        Mock::given(method("POST"))
+            .and(path("/sign-data-with-classic-key"))
+            .and(body_partial_json(json!({
+                "name": "/solana/payer",
+                "data": STANDARD.encode(b"hello"),
+                "token": "t-token",
+                "version": 2,
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "result": STANDARD.encode(signature.as_ref()) })),
+            )
+            .mount(&server)
+            .await;
+
+        let signer = signer(&server, &keypair).with_key_version(2);
+        assert_eq!(signer.sign_message(b"hello").await.unwrap(), signature);
+        assert_eq!(signer.sign_message(b"hello").await.unwrap(), signature);
+
+        let auth_calls = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|request| request.url.path() == "/auth")
+            .count();
+        assert_eq!(auth_calls, 1);
+    }
+
+    #[tokio::test]
+    async fn test_akeyless_reauthenticates_on_401() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        mock_auth(&server, "t-fresh").await;
+
+        Mock::given(method("POST"))
+            .and(path("/describe-item"))
+            .and(body_partial_json(json!({ "token": "t-fresh" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/describe-item"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        let signer = signer(&server, &keypair);
+        *signer.token.lock().await = Some("t-expired".to_string());
+
+        assert!(signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_akeyless_sign_error() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        mock_auth(&server, "t-token").await;
+        Mock::given(method("POST"))
+            .and(path("/sign-data-with-classic-key"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+
+        let signer = signer(&server, &keypair);
+        let mut tx = create_test_transaction(&signer.pubkey());
+        assert!(matches!(
+            signer.sign_transaction(&mut tx).await,
+            Err(SignerError::RemoteApiError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_akeyless_rejects_signature_from_other_key() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signature = keypair_sign_message(&Keypair::new(), b"hello");
+        mock_auth(&server, "t-token").await;
+        Mock::given(method("POST"))
+            .and(path("/sign-data-with-classic-key"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "result": STANDARD.encode(signature.as_ref()) })),
+            )
+            .mount(&server)
+            .await;
+
+        assert!(matches!(
+            signer(&server, &keypair).sign_message(b"hello").await,
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_akeyless_cloud_identity_body() {
+        let auth = AkeylessAuth::CloudIdentity {
+            access_id: "p-cloud".to_string(),
+            provider: CloudProvider::Aws,
+            cloud_id: "Y2xvdWQtaWQ=".to_string(),
+        };
+
+        assert_eq!(
+            auth.request_body(),
+            json!({ "access-type": "aws_iam", "access-id": "p-cloud", "cloud-id": "Y2xvdWQtaWQ=" })
+        );
+    }
+
+    #[test]
+    fn test_akeyless_config_debug_redacts_secrets() {
+        let signer = AkeylessSigner::new(
+            access_key(),
+            "/solana/payer".to_string(),
+            Pubkey::new_unique().to_string(),
+        )
+        .unwrap();
+
+        let output = signer.config_debug();
+        assert!(output.contains("host=api.akeyless.io"));
+        assert!(output.contains("access_id=p-abc123"));
+        assert!(!output.contains("secret-access-key"));
+        assert!(signer.validate_config().is_ok());
+    }
+}
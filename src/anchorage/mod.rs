@@ -0,0 +1,489 @@
+//! Anchorage Digital custody signer integration
+//!
+//! Requests carry an API access key and an Ed25519 request signature over
+//! `timestamp + METHOD + path + body`. Each signature is a signing request that
+//! goes through the vault's approval policy, so it is polled until Anchorage
+//! completes or rejects it.
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{SignedTransaction, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use ed25519_dalek::Signer as _;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::{Duration, Instant};
+
+/// Default Anchorage API base URL
+const DEFAULT_API_BASE_URL: &str = "https://api.anchorage.com";
+
+/// Anchorage wraps every response body in `{"data": ...}`
+#[derive(Deserialize)]
+struct Envelope<T> {
+    data: T,
+}
+
+#[derive(Deserialize)]
+struct SigningRequest {
+    id: String,
+    status: String,
+    #[serde(default)]
+    signature: Option<String>,
+}
+
+/// Anchorage-based signer using a custody vault address through the Anchorage API
+#[derive(Clone)]
+pub struct AnchorageSigner {
+    api_key: String,
+    signing_key: ed25519_dalek::SigningKey,
+    vault_id: String,
+    public_key: Pubkey,
+    api_base_url: String,
+    poll_interval: Duration,
+    timeout: Duration,
+    client: reqwest::Client,
+}
+
+impl std::fmt::Debug for AnchorageSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnchorageSigner")
+            .field("public_key", &self.public_key)
+            .field("vault_id", &self.vault_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AnchorageSigner {
+    /// Create a new AnchorageSigner
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API access key
+    /// * `signing_secret` - Hex-encoded 32-byte Ed25519 secret registered with the API key
+    /// * `vault_id` - Vault holding the address
+    /// * `address` - Solana address to sign with (base58-encoded)
+    pub fn new(
+        api_key: String,
+        signing_secret: &str,
+        vault_id: String,
+        address: String,
+    ) -> Result<Self, SignerError> {
+        let secret: [u8; 32] = hex::decode(signing_secret.trim())
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| {
+                SignerError::InvalidPrivateKey(
+                    "Anchorage signing secret must be 32 hex-encoded bytes".to_string(),
+                )
+            })?;
+        let public_key = address
+            .parse::<Pubkey>()
+            .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid address: {e}")))?;
+
+        Ok(Self {
+            api_key,
+            signing_key: ed25519_dalek::SigningKey::from_bytes(&secret),
+            vault_id,
+            public_key,
+            api_base_url: DEFAULT_API_BASE_URL.to_string(),
+            poll_interval: Duration::from_secs(2),
+            timeout: Duration::from_secs(300),
+            client: reqwest::Client::new(),
+        })
+    }
+
+    /// Use a different API host, e.g. a sandbox environment
+    pub fn with_api_base_url(mut self, api_base_url: String) -> Self {
+        self.api_base_url = api_base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// How often to poll a pending signing request (default 2 seconds)
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// How long to wait for approval and signing (default 5 minutes)
+    ///
+    /// The request stays pending at Anchorage after `SignerError::Timeout` is
+    /// returned, and may still be approved later.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sign `timestamp + METHOD + path + body`, returning the hex signature
+    fn sign_request(&self, timestamp: &str, method: &str, path: &str, body: &str) -> String {
+        let content = format!("{timestamp}{method}{path}{body}");
+        hex::encode(self.signing_key.sign(content.as_bytes()).to_bytes())
+    }
+
+    /// Send a signed request and fail on non-success statuses
+    async fn send(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+        operation: &str,
+    ) -> Result<reqwest::Response, SignerError> {
+        let body = body.map(serde_json::to_string).transpose()?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| SignerError::Other(format!("System clock error: {e}")))?
+            .as_secs()
+            .to_string();
+        let signature = self.sign_request(
+            &timestamp,
+            method.as_str(),
+            path,
+            body.as_deref().unwrap_or_default(),
+        );
+
+        let mut request = self
+            .client
+            .request(method, format!("{}{path}", self.api_base_url))
+            .header("Api-Access-Key", &self.api_key)
+            .header("Api-Signature", signature)
+            .header("Api-Timestamp", timestamp);
+        if let Some(body) = body {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+
+        let response = request.send().await?;
+
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+        #[cfg(feature = "unsafe-debug")]
+        log::error!("Anchorage {operation} error - status: {status}, response: {error_text}");
+
+        #[cfg(not(feature = "unsafe-debug"))]
+        {
+            let _ = error_text;
+            log::error!("Anchorage {operation} error - status: {status}");
+        }
+
+        Err(SignerError::RemoteApiError(format!("API error {status}")))
+    }
+
+    /// Poll the signing request until it is completed, rejected, or times out
+    async fn wait_for_signature(&self, mut request: SigningRequest) -> Result<String, SignerError> {
+        let deadline = Instant::now() + self.timeout;
+
+        loop {
+            match request.status.as_str() {
+                "COMPLETED" => {
+                    return request.signature.ok_or_else(|| {
+                        SignerError::SigningFailed(format!(
+                            "Anchorage signing request {} completed without a signature",
+                            request.id
+                        ))
+                    })
+                }
+                "REJECTED" | "FAILED" | "EXPIRED" | "CANCELED" => {
+                    return Err(SignerError::SigningFailed(format!(
+                        "Anchorage signing request {} ended as {}",
+                        request.id, request.status
+                    )))
+                }
+                _ => {}
+            }
+
+            if Instant::now() + self.poll_interval > deadline {
+                return Err(SignerError::Timeout(format!(
+                    "Anchorage signing request {} still {} after {:?}",
+                    request.id, request.status, self.timeout
+                )));
+            }
+            tokio::time::sleep(self.poll_interval).await;
+
+            let envelope: Envelope<SigningRequest> = self
+                .send(
+                    reqwest::Method::GET,
+                    &format!("/v2/signing_requests/{}", request.id),
+                    None,
+                    "get signing request",
+                )
+                .await?
+                .json()
+                .await?;
+            request = envelope.data;
+        }
+    }
+
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let body = json!({
+            "vaultId": self.vault_id,
+            "networkId": "SOL",
+            "address": self.public_key.to_string(),
+            "payload": hex::encode(message),
+        });
+
+        let created: Envelope<SigningRequest> = self
+            .send(
+                reqwest::Method::POST,
+                "/v2/signing_requests",
+                Some(&body),
+                "create signing request",
+            )
+            .await?
+            .json()
+            .await?;
+        let request_id = created.data.id.clone();
+
+        let signature_hex = self.wait_for_signature(created.data).await?;
+        let signature_bytes = hex::decode(&signature_hex).map_err(|_| {
+            SignerError::SerializationError("Failed to decode signature".to_string())
+        })?;
+        let signature = Signature::try_from(signature_bytes.as_slice())
+            .map_err(|_| SignerError::SigningFailed("Invalid signature format".to_string()))?;
+
+        if !TransactionUtil::verify_signature(&self.public_key, message, &signature) {
+            return Err(SignerError::SigningFailed(format!(
+                "Anchorage signing request {request_id} returned a signature that does not verify"
+            )));
+        }
+
+        Ok(signature)
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for AnchorageSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Check that the API key is accepted and can read the vault
+    async fn is_available(&self) -> bool {
+        self.send(
+            reqwest::Method::GET,
+            &format!("/v2/vaults/{}", self.vault_id),
+            None,
+            "get vault",
+        )
+        .await
+        .is_ok()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("anchorage", self.public_key, self.latency_class())
+            .with_key_id(&self.vault_id)
+            .with_endpoint(&self.api_base_url)
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=anchorage api={} vault_id={} api_key=[REDACTED] pubkey={}",
+            self.api_base_url, self.vault_id, self.public_key
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        if self.api_key.is_empty() {
+            return Err(SignerError::ConfigError(
+                "Anchorage api_key must not be empty".to_string(),
+            ));
+        }
+
+        reqwest::Url::parse(&self.api_base_url)
+            .map(|_| ())
+            .map_err(|e| SignerError::ConfigError(format!("Invalid Anchorage API URL: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use ed25519_dalek::Verifier;
+    use wiremock::{
+        matchers::{body_partial_json, header, header_exists, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const TEST_SIGNING_SECRET: &str =
+        "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb";
+    const TEST_VAULT_ID: &str = "5b3f0e1d2c4a";
+
+    fn create_test_signer(mock_server: &MockServer, keypair: &Keypair) -> AnchorageSigner {
+        AnchorageSigner::new(
+            "access-key".to_string(),
+            TEST_SIGNING_SECRET,
+            TEST_VAULT_ID.to_string(),
+            keypair_pubkey(keypair).to_string(),
+        )
+        .unwrap()
+        .with_api_base_url(mock_server.uri())
+        .with_poll_interval(Duration::from_millis(10))
+    }
+
+    async fn mount_create(mock_server: &MockServer, message: &[u8], status: &str) {
+        Mock::given(method("POST"))
+            .and(path("/v2/signing_requests"))
+            .and(header("Api-Access-Key", "access-key"))
+            .and(header_exists("Api-Signature"))
+            .and(header_exists("Api-Timestamp"))
+            .and(body_partial_json(json!({
+                "vaultId": TEST_VAULT_ID,
+                "payload": hex::encode(message),
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "id": "sr-1", "status": status },
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[test]
+    fn test_anchorage_request_signature() {
+        let signer = AnchorageSigner::new(
+            "access-key".to_string(),
+            TEST_SIGNING_SECRET,
+            TEST_VAULT_ID.to_string(),
+            Pubkey::new_unique().to_string(),
+        )
+        .unwrap();
+
+        let signature = signer.sign_request("1718000000", "POST", "/v2/signing_requests", "{}");
+        let signature =
+            ed25519_dalek::Signature::from_slice(&hex::decode(signature).unwrap()).unwrap();
+
+        assert!(signer
+            .signing_key
+            .verifying_key()
+            .verify(b"1718000000POST/v2/signing_requests{}", &signature)
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_anchorage_sign_transaction_after_approval() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair);
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let message = tx.message_data();
+        let signature = keypair_sign_message(&keypair, &message);
+
+        mount_create(&mock_server, &message, "PENDING_APPROVAL").await;
+        Mock::given(method("GET"))
+            .and(path("/v2/signing_requests/sr-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "id": "sr-1", "status": "PENDING_APPROVAL" },
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/signing_requests/sr-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "id": "sr-1",
+                    "status": "COMPLETED",
+                    "signature": hex::encode(signature.as_ref()),
+                },
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let returned = signer.sign_transaction(&mut tx).await.unwrap().signature;
+        assert_eq!(returned, signature);
+        assert_eq!(tx.signatures[0], signature);
+    }
+
+    #[tokio::test]
+    async fn test_anchorage_rejected() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair);
+
+        mount_create(&mock_server, b"hello", "REJECTED").await;
+
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_anchorage_timeout() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer =
+            create_test_signer(&mock_server, &keypair).with_timeout(Duration::from_millis(50));
+
+        mount_create(&mock_server, b"hello", "PENDING_APPROVAL").await;
+        Mock::given(method("GET"))
+            .and(path("/v2/signing_requests/sr-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "id": "sr-1", "status": "PENDING_APPROVAL" },
+            })))
+            .mount(&mock_server)
+            .await;
+
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::Timeout(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_anchorage_is_available() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair);
+
+        Mock::given(method("GET"))
+            .and(path(format!("/v2/vaults/{TEST_VAULT_ID}")))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&mock_server)
+            .await;
+
+        assert!(!signer.is_available().await);
+        assert!(signer.config_debug().contains("api_key=[REDACTED]"));
+    }
+}
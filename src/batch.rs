@@ -0,0 +1,213 @@
+//! Concurrent signing with a cap on in-flight requests
+//!
+//! Issuing every request at once can trip the rate limits of remote backends
+//! such as Vault or Turnkey. The helpers here keep at most `max_in_flight`
+//! requests outstanding and return results in input order; the default
+//! [`SolanaSigner::sign_all_transactions`] uses [`DEFAULT_MAX_IN_FLIGHT`].
+
+use futures::stream::{FuturesOrdered, Stream, StreamExt};
+
+use crate::error::SignerError;
+use crate::sdk_adapter::Transaction;
+use crate::traits::{SignedTransaction, SolanaSigner};
+
+/// Requests the default [`SolanaSigner::sign_all_transactions`] keeps outstanding
+pub const DEFAULT_MAX_IN_FLIGHT: usize = 4;
+
+/// Sign `txs` with at most `max_in_flight` requests outstanding
+///
+/// A failure does not stop the other transactions. A `max_in_flight` of zero is
+/// treated as one.
+///
+/// # Returns
+///
+/// One result per transaction, in the same order as `txs`
+pub async fn sign_many<S: SolanaSigner + ?Sized>(
+    signer: &S,
+    txs: &mut [Transaction],
+    max_in_flight: usize,
+) -> Vec<Result<SignedTransaction, SignerError>> {
+    // A plain loop rather than `buffered`, so the future stays provably `Send`
+    // when used as the default `sign_all_transactions`
+    let mut results = Vec::with_capacity(txs.len());
+    let mut pending = FuturesOrdered::new();
+    for tx in txs.iter_mut() {
+        if pending.len() >= max_in_flight.max(1) {
+            results.extend(pending.next().await);
+        }
+        pending.push_back(signer.sign_transaction(tx));
+    }
+    while let Some(result) = pending.next().await {
+        results.push(result);
+    }
+    results
+}
+
+/// Sign `txs` one after another
+///
+/// For backends that can only serve one request at a time, such as a hardware
+/// device, or where concurrency would not help.
+///
+/// # Returns
+///
+/// One result per transaction, in the same order as `txs`
+pub async fn sign_sequentially<S: SolanaSigner + ?Sized>(
+    signer: &S,
+    txs: &mut [Transaction],
+) -> Vec<Result<SignedTransaction, SignerError>> {
+    let mut results = Vec::with_capacity(txs.len());
+    for tx in txs.iter_mut() {
+        results.push(signer.sign_transaction(tx).await);
+    }
+    results
+}
+
+/// Sign transactions from a stream with at most `max_in_flight` requests outstanding
+///
+/// New transactions are only pulled from `txs` as earlier ones complete, so a slow
+/// backend applies backpressure to the producer. Each signed transaction is
+/// yielded with its result, in input order.
+pub fn sign_stream<'a, S: SolanaSigner + ?Sized>(
+    signer: &'a S,
+    txs: impl Stream<Item = Transaction> + Send + 'a,
+    max_in_flight: usize,
+) -> impl Stream<Item = (Transaction, Result<SignedTransaction, SignerError>)> + Send + 'a {
+    txs.map(move |mut tx| async move {
+        let result = signer.sign_transaction(&mut tx).await;
+        (tx, result)
+    })
+    .buffered(max_in_flight.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{Pubkey, Signature};
+    use crate::test_util::create_test_transaction;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// Signer that records how many requests it is serving at once
+    struct SlowSigner {
+        pubkey: Pubkey,
+        in_flight: AtomicUsize,
+        max_seen: AtomicUsize,
+    }
+
+    impl SlowSigner {
+        fn new() -> Self {
+            Self {
+                pubkey: Pubkey::new_unique(),
+                in_flight: AtomicUsize::new(0),
+                max_seen: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl SolanaSigner for SlowSigner {
+        fn pubkey(&self) -> Pubkey {
+            self.pubkey
+        }
+
+        async fn sign_transaction(
+            &self,
+            tx: &mut Transaction,
+        ) -> Result<SignedTransaction, SignerError> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_seen.fetch_max(now, Ordering::SeqCst);
+            // Later transactions finish first, so ordering comes from `buffered`
+            let delay = 50 - tx.message.recent_blockhash.as_ref()[0] as u64 % 50;
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            let signature = Signature::from([tx.message.recent_blockhash.as_ref()[0]; 64]);
+            Ok(SignedTransaction::new(
+                String::new(),
+                signature,
+                self.pubkey,
+            ))
+        }
+
+        async fn sign_message(&self, _message: &[u8]) -> Result<Signature, SignerError> {
+            unimplemented!()
+        }
+
+        async fn sign_partial_transaction(
+            &self,
+            _tx: &mut Transaction,
+        ) -> Result<SignedTransaction, SignerError> {
+            unimplemented!()
+        }
+
+        async fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    fn numbered_transactions(signer: &SlowSigner, count: u8) -> Vec<Transaction> {
+        (0..count)
+            .map(|i| {
+                let mut tx = create_test_transaction(&signer.pubkey());
+                tx.message.recent_blockhash = crate::sdk_adapter::Hash::new_from_array([i; 32]);
+                tx
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_sign_many_bounds_concurrency() {
+        let signer = SlowSigner::new();
+        let mut txs = numbered_transactions(&signer, 10);
+
+        let results = sign_many(&signer, &mut txs, 3).await;
+
+        assert_eq!(signer.max_seen.load(Ordering::SeqCst), 3);
+        for (i, result) in results.into_iter().enumerate() {
+            assert_eq!(result.unwrap().signature, Signature::from([i as u8; 64]));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_sign_all_transactions_is_bounded() {
+        let signer = SlowSigner::new();
+        let mut txs = numbered_transactions(&signer, 10);
+
+        let results = signer.sign_all_transactions(&mut txs).await;
+
+        assert_eq!(
+            signer.max_seen.load(Ordering::SeqCst),
+            DEFAULT_MAX_IN_FLIGHT
+        );
+        assert!(results.iter().all(Result::is_ok));
+    }
+
+    #[tokio::test]
+    async fn test_sign_sequentially() {
+        let signer = SlowSigner::new();
+        let mut txs = numbered_transactions(&signer, 3);
+
+        let results = sign_sequentially(&signer, &mut txs).await;
+
+        assert_eq!(signer.max_seen.load(Ordering::SeqCst), 1);
+        for (i, result) in results.into_iter().enumerate() {
+            assert_eq!(result.unwrap().signature, Signature::from([i as u8; 64]));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_stream_preserves_order() {
+        let signer = SlowSigner::new();
+        let txs = numbered_transactions(&signer, 6);
+
+        let results: Vec<_> = sign_stream(&signer, futures::stream::iter(txs), 4)
+            .collect()
+            .await;
+
+        assert_eq!(signer.max_seen.load(Ordering::SeqCst), 4);
+        for (i, (tx, result)) in results.into_iter().enumerate() {
+            assert_eq!(tx.message.recent_blockhash.as_ref()[0], i as u8);
+            assert!(result.is_ok());
+        }
+    }
+}
@@ -0,0 +1,486 @@
+//! BitGo custody signer integration
+//!
+//! Transactions are signed through BitGo Express, which holds the user key share
+//! encrypted under the wallet passphrase and co-signs with BitGo. Express returns
+//! the signed transaction; the wallet's signature is taken from it, checked, and
+//! placed into the caller's transaction.
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use serde_json::json;
+
+/// Default BitGo Express URL
+const DEFAULT_EXPRESS_URL: &str = "http://localhost:3080";
+
+/// Signing flow of a BitGo wallet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BitGoWalletType {
+    /// Wallets whose user key is decrypted by Express (`signtx`)
+    #[default]
+    Multisig,
+    /// TSS (EdDSA MPC) wallets, signed through a transaction request (`signtxtss`)
+    Tss,
+}
+
+impl BitGoWalletType {
+    fn endpoint(self) -> &'static str {
+        match self {
+            BitGoWalletType::Multisig => "signtx",
+            BitGoWalletType::Tss => "signtxtss",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WalletResponse {
+    coin_specific: CoinSpecific,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CoinSpecific {
+    root_address: String,
+}
+
+/// Express returns Solana transactions base64-encoded, in a field named `txHex`
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignTxResponse {
+    tx_hex: String,
+}
+
+/// BitGo-based signer using a Solana wallet through BitGo Express
+#[derive(Clone)]
+pub struct BitGoSigner {
+    access_token: String,
+    wallet_id: String,
+    wallet_passphrase: String,
+    coin: String,
+    wallet_type: BitGoWalletType,
+    express_url: String,
+    client: reqwest::Client,
+    public_key: Pubkey,
+}
+
+impl std::fmt::Debug for BitGoSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BitGoSigner")
+            .field("public_key", &self.public_key)
+            .field("wallet_id", &self.wallet_id)
+            .field("coin", &self.coin)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BitGoSigner {
+    /// Create a new BitGoSigner (requires initialization)
+    ///
+    /// # Arguments
+    ///
+    /// * `access_token` - BitGo access token with spend permission on the wallet
+    /// * `wallet_id` - BitGo wallet ID
+    /// * `wallet_passphrase` - Passphrase decrypting the wallet's user key
+    pub fn new(access_token: String, wallet_id: String, wallet_passphrase: String) -> Self {
+        Self {
+            access_token,
+            wallet_id,
+            wallet_passphrase,
+            coin: "sol".to_string(),
+            wallet_type: BitGoWalletType::default(),
+            express_url: DEFAULT_EXPRESS_URL.to_string(),
+            client: reqwest::Client::new(),
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        }
+    }
+
+    /// Use a different coin, e.g. `tsol` for testnet wallets
+    pub fn with_coin(mut self, coin: String) -> Self {
+        self.coin = coin;
+        self
+    }
+
+    /// Use the signing flow of `wallet_type`
+    pub fn with_wallet_type(mut self, wallet_type: BitGoWalletType) -> Self {
+        self.wallet_type = wallet_type;
+        self
+    }
+
+    /// Use a BitGo Express instance at `express_url`
+    pub fn with_express_url(mut self, express_url: String) -> Self {
+        self.express_url = express_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Initialize the signer by fetching the wallet's root address
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        let wallet = self.fetch_wallet().await?;
+
+        self.public_key = wallet
+            .coin_specific
+            .root_address
+            .parse()
+            .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid root address: {e}")))?;
+        Ok(())
+    }
+
+    fn wallet_url(&self) -> String {
+        format!(
+            "{}/api/v2/{}/wallet/{}",
+            self.express_url, self.coin, self.wallet_id
+        )
+    }
+
+    /// Send a request with the access token and fail on non-success statuses
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+        operation: &str,
+    ) -> Result<reqwest::Response, SignerError> {
+        let response = request.bearer_auth(&self.access_token).send().await?;
+
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+        #[cfg(feature = "unsafe-debug")]
+        log::error!("BitGo {operation} error - status: {status}, response: {error_text}");
+
+        #[cfg(not(feature = "unsafe-debug"))]
+        {
+            let _ = error_text;
+            log::error!("BitGo {operation} error - status: {status}");
+        }
+
+        Err(SignerError::RemoteApiError(format!("API error {status}")))
+    }
+
+    async fn fetch_wallet(&self) -> Result<WalletResponse, SignerError> {
+        Ok(self
+            .send(self.client.get(self.wallet_url()), "get wallet")
+            .await?
+            .json()
+            .await?)
+    }
+
+    /// Have Express sign `transaction` and return the wallet's signature from the result
+    async fn sign_with_express(&self, transaction: &Transaction) -> Result<Signature, SignerError> {
+        let body = json!({
+            "walletPassphrase": self.wallet_passphrase,
+            "txPrebuild": { "txBase64": TransactionUtil::serialize_transaction(transaction)? },
+        });
+        let url = format!("{}/{}", self.wallet_url(), self.wallet_type.endpoint());
+
+        let response: SignTxResponse = self
+            .send(self.client.post(url).json(&body), "sign transaction")
+            .await?
+            .json()
+            .await
+            .map_err(|_| {
+                SignerError::SerializationError("Failed to parse BitGo response".to_string())
+            })?;
+
+        let bytes = STANDARD.decode(&response.tx_hex).map_err(|_| {
+            SignerError::SerializationError("Failed to decode signed transaction".to_string())
+        })?;
+        let signed: Transaction = bincode::deserialize(&bytes).map_err(|e| {
+            SignerError::SerializationError(format!("Invalid signed transaction: {e}"))
+        })?;
+
+        // The signature only means something for the exact message we asked to sign
+        if signed.message != transaction.message {
+            return Err(SignerError::SigningFailed(
+                "BitGo returned a different transaction than was submitted".to_string(),
+            ));
+        }
+
+        let index = TransactionUtil::get_signing_keypair_position(&signed, &self.public_key)?;
+        let signature = signed
+            .signatures
+            .get(index)
+            .copied()
+            .filter(|signature| {
+                TransactionUtil::verify_signature(
+                    &self.public_key,
+                    &transaction.message_data(),
+                    signature,
+                )
+            })
+            .ok_or_else(|| {
+                SignerError::SigningFailed(
+                    "BitGo did not return a valid wallet signature".to_string(),
+                )
+            })?;
+
+        Ok(signature)
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_with_express(transaction).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for BitGoSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, _message: &[u8]) -> Result<Signature, SignerError> {
+        Err(SignerError::SigningFailed(
+            "BitGo wallets cannot sign arbitrary messages".to_string(),
+        ))
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Check that Express is reachable and the token can read the wallet
+    async fn is_available(&self) -> bool {
+        self.fetch_wallet().await.is_ok()
+    }
+
+    /// Transactions only: BitGo builds and signs them itself, so there are no raw
+    /// message or versioned transaction signatures
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities {
+            supports_versioned_tx: false,
+            supports_message_sign: false,
+            ..SignerCapabilities::requiring_init()
+        }
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("bitgo", self.public_key, self.latency_class())
+            .with_key_id(&self.wallet_id)
+            .with_endpoint(&self.express_url)
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=bitgo express={} coin={} wallet_id={} wallet_type={:?} access_token=[REDACTED] pubkey={}",
+            self.express_url, self.coin, self.wallet_id, self.wallet_type, self.public_key
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        if self.access_token.is_empty() {
+            return Err(SignerError::ConfigError(
+                "BitGo access_token must not be empty".to_string(),
+            ));
+        }
+
+        if self.wallet_passphrase.is_empty() {
+            return Err(SignerError::ConfigError(
+                "BitGo wallet_passphrase must not be empty".to_string(),
+            ));
+        }
+
+        reqwest::Url::parse(&self.express_url)
+            .map(|_| ())
+            .map_err(|e| SignerError::ConfigError(format!("Invalid BitGo Express URL: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use wiremock::{
+        matchers::{body_partial_json, header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const TEST_WALLET_ID: &str = "62f3a1c0e1f0a40007f5a1b2";
+
+    async fn mount_wallet(mock_server: &MockServer, pubkey: &Pubkey) {
+        Mock::given(method("GET"))
+            .and(path(format!("/api/v2/tsol/wallet/{TEST_WALLET_ID}")))
+            .and(header("authorization", "Bearer v2xaccess"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": TEST_WALLET_ID,
+                "coin": "tsol",
+                "coinSpecific": { "rootAddress": pubkey.to_string() },
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    async fn create_test_signer(mock_server: &MockServer, keypair: &Keypair) -> BitGoSigner {
+        mount_wallet(mock_server, &keypair_pubkey(keypair)).await;
+
+        let mut signer = BitGoSigner::new(
+            "v2xaccess".to_string(),
+            TEST_WALLET_ID.to_string(),
+            "correct horse".to_string(),
+        )
+        .with_coin("tsol".to_string())
+        .with_express_url(mock_server.uri());
+        signer.init().await.unwrap();
+        signer
+    }
+
+    fn signed_response(tx: &Transaction, keypair: &Keypair) -> ResponseTemplate {
+        let mut signed = tx.clone();
+        let signature = keypair_sign_message(keypair, &signed.message_data());
+        TransactionUtil::add_signature_to_transaction(
+            &mut signed,
+            &keypair_pubkey(keypair),
+            signature,
+        )
+        .unwrap();
+
+        ResponseTemplate::new(200).set_body_json(json!({
+            "txHex": TransactionUtil::serialize_transaction(&signed).unwrap(),
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_bitgo_init() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair).await;
+
+        assert_eq!(signer.pubkey(), keypair_pubkey(&keypair));
+    }
+
+    #[tokio::test]
+    async fn test_bitgo_sign_transaction() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair).await;
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        Mock::given(method("POST"))
+            .and(path(format!("/api/v2/tsol/wallet/{TEST_WALLET_ID}/signtx")))
+            .and(body_partial_json(json!({
+                "walletPassphrase": "correct horse",
+                "txPrebuild": { "txBase64": TransactionUtil::serialize_transaction(&tx).unwrap() },
+            })))
+            .respond_with(signed_response(&tx, &keypair))
+            .mount(&mock_server)
+            .await;
+
+        let SignedTransaction {
+            base64_tx: serialized,
+            signature,
+            ..
+        } = signer.sign_transaction(&mut tx).await.unwrap();
+        assert_eq!(tx.signatures[0], signature);
+        assert_eq!(
+            serialized,
+            TransactionUtil::serialize_transaction(&tx).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bitgo_tss_endpoint() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair)
+            .await
+            .with_wallet_type(BitGoWalletType::Tss);
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/api/v2/tsol/wallet/{TEST_WALLET_ID}/signtxtss"
+            )))
+            .respond_with(signed_response(&tx, &keypair))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        signer.sign_partial_transaction(&mut tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_bitgo_rejects_altered_transaction() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair).await;
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        let mut altered = tx.clone();
+        altered.message.recent_blockhash = crate::sdk_adapter::Hash::new_unique();
+        Mock::given(method("POST"))
+            .respond_with(signed_response(&altered, &keypair))
+            .mount(&mock_server)
+            .await;
+
+        assert!(matches!(
+            signer.sign_transaction(&mut tx).await,
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_bitgo_wrong_passphrase() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair).await;
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "error": "unable to decrypt keychain with the given wallet passphrase",
+            })))
+            .mount(&mock_server)
+            .await;
+
+        assert!(matches!(
+            signer.sign_transaction(&mut tx).await,
+            Err(SignerError::RemoteApiError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_bitgo_sign_message_unsupported() {
+        let signer = BitGoSigner::new(
+            "v2xaccess".to_string(),
+            TEST_WALLET_ID.to_string(),
+            "correct horse".to_string(),
+        );
+
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::SigningFailed(_))
+        ));
+        assert!(signer.config_debug().contains("access_token=[REDACTED]"));
+        assert!(!signer.config_debug().contains("correct horse"));
+    }
+}
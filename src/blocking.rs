@@ -0,0 +1,162 @@
+//! Synchronous API for callers without an async runtime
+//!
+//! [`SolanaSignerBlocking`] owns a single-threaded Tokio runtime and drives the
+//! wrapped signer's futures to completion on the calling thread.
+
+use std::future::Future;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction, VersionedTransaction};
+use crate::traits::{HealthStatus, SignOptions, SignedTransaction, SolanaSigner};
+
+/// Blocking wrapper around any [`SolanaSigner`]
+///
+/// The methods block the calling thread and panic if called from within an async
+/// runtime; async code should use the wrapped signer directly.
+pub struct SolanaSignerBlocking<S> {
+    inner: S,
+    runtime: Runtime,
+}
+
+impl<S: SolanaSigner> SolanaSignerBlocking<S> {
+    /// Wrap a signer, creating a dedicated runtime
+    pub fn new(inner: S) -> Result<Self, SignerError> {
+        Ok(Self::with_runtime(inner, new_runtime()?))
+    }
+
+    /// Wrap a signer, driving it on the given runtime
+    pub fn with_runtime(inner: S, runtime: Runtime) -> Self {
+        Self { inner, runtime }
+    }
+
+    /// Build the signer with an async constructor, then wrap it
+    ///
+    /// The constructor runs on the wrapper's runtime, so backends that spawn
+    /// background tasks or cache connections during initialization keep working
+    /// (e.g. `SolanaSignerBlocking::from_future(Signer::from_privy(...))`).
+    pub fn from_future<F>(constructor: F) -> Result<Self, SignerError>
+    where
+        F: Future<Output = Result<S, SignerError>>,
+    {
+        let runtime = new_runtime()?;
+        let inner = runtime.block_on(constructor)?;
+
+        Ok(Self::with_runtime(inner, runtime))
+    }
+
+    /// Get a reference to the wrapped signer
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwrap the signer, shutting down the runtime
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Get the public key of this signer
+    pub fn pubkey(&self) -> Pubkey {
+        self.inner.pubkey()
+    }
+
+    /// Blocking version of [`SolanaSigner::sign_transaction`]
+    pub fn sign_transaction(&self, tx: &mut Transaction) -> Result<SignedTransaction, SignerError> {
+        self.runtime.block_on(self.inner.sign_transaction(tx))
+    }
+
+    /// Blocking version of [`SolanaSigner::sign_transaction_with`]
+    pub fn sign_transaction_with(
+        &self,
+        tx: &mut Transaction,
+        options: SignOptions,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.runtime
+            .block_on(self.inner.sign_transaction_with(tx, options))
+    }
+
+    /// Blocking version of [`SolanaSigner::sign_message`]
+    pub fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.runtime.block_on(self.inner.sign_message(message))
+    }
+
+    /// Blocking version of [`SolanaSigner::sign_partial_transaction`]
+    pub fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.runtime
+            .block_on(self.inner.sign_partial_transaction(tx))
+    }
+
+    /// Blocking version of [`SolanaSigner::sign_versioned_transaction`]
+    pub fn sign_versioned_transaction(
+        &self,
+        tx: &mut VersionedTransaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.runtime
+            .block_on(self.inner.sign_versioned_transaction(tx))
+    }
+
+    /// Blocking version of [`SolanaSigner::sign_all_transactions`]
+    pub fn sign_all_transactions(
+        &self,
+        txs: &mut [Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        self.runtime.block_on(self.inner.sign_all_transactions(txs))
+    }
+
+    /// Blocking version of [`SolanaSigner::is_available`]
+    pub fn is_available(&self) -> bool {
+        self.runtime.block_on(self.inner.is_available())
+    }
+
+    /// Blocking version of [`SolanaSigner::health`]
+    pub fn health(&self) -> HealthStatus {
+        self.runtime.block_on(self.inner.health())
+    }
+
+    /// Blocking version of [`SolanaSigner::warm_up`]
+    pub fn warm_up(&self) -> Result<(), SignerError> {
+        self.runtime.block_on(self.inner.warm_up())
+    }
+}
+
+fn new_runtime() -> Result<Runtime, SignerError> {
+    Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| SignerError::Other(format!("Failed to start Tokio runtime: {e}")))
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::sdk_adapter::Keypair;
+    use crate::test_util::create_test_transaction;
+
+    #[test]
+    fn test_sign_without_runtime() {
+        let signer = SolanaSignerBlocking::new(MemorySigner::new(Keypair::new())).unwrap();
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        let signed = signer.sign_transaction(&mut tx).unwrap();
+
+        assert_eq!(tx.signatures[0], signed.signature);
+        assert!(signer.is_available());
+        assert!(signer.health().is_available());
+    }
+
+    #[test]
+    fn test_from_future() {
+        let keypair = Keypair::new();
+        let expected = crate::sdk_adapter::keypair_sign_message(&keypair, b"hello");
+
+        let signer =
+            SolanaSignerBlocking::from_future(async { Ok(MemorySigner::new(keypair)) }).unwrap();
+
+        assert_eq!(signer.sign_message(b"hello").unwrap(), expected);
+    }
+}
@@ -0,0 +1,531 @@
+//! Cobo WaaS 2.0 signer integration
+//!
+//! Requests are authenticated with Cobo's API-key scheme: an Ed25519 API key signs
+//! the double SHA-256 of `METHOD|PATH|NONCE|QUERY|BODY`. Signing is asynchronous on
+//! Cobo's side, so a message-sign transaction is created and then polled until it
+//! completes (or is rejected) before the signature is returned.
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{SignedTransaction, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use ed25519_dalek::Signer as _;
+use serde::Deserialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::time::{Duration, Instant};
+
+/// Default Cobo WaaS 2.0 API base URL
+const DEFAULT_API_BASE_URL: &str = "https://api.cobo.com/v2";
+
+#[derive(Deserialize)]
+struct CreateTransactionResponse {
+    transaction_id: String,
+}
+
+#[derive(Deserialize)]
+struct TransactionResponse {
+    status: String,
+    #[serde(default)]
+    result: Option<SignResult>,
+}
+
+#[derive(Deserialize)]
+struct SignResult {
+    signature: String,
+}
+
+/// Cobo-based signer using an MPC wallet address through the WaaS 2.0 API
+#[derive(Clone)]
+pub struct CoboSigner {
+    api_key: ed25519_dalek::SigningKey,
+    wallet_id: String,
+    public_key: Pubkey,
+    api_base_url: String,
+    poll_interval: Duration,
+    timeout: Duration,
+    client: reqwest::Client,
+}
+
+impl std::fmt::Debug for CoboSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoboSigner")
+            .field("public_key", &self.public_key)
+            .field("wallet_id", &self.wallet_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CoboSigner {
+    /// Create a new CoboSigner
+    ///
+    /// # Arguments
+    ///
+    /// * `api_secret` - Hex-encoded 32-byte Ed25519 secret of the registered API key
+    /// * `wallet_id` - Cobo MPC wallet ID
+    /// * `address` - Solana address of the wallet to sign with (base58-encoded)
+    pub fn new(api_secret: &str, wallet_id: String, address: String) -> Result<Self, SignerError> {
+        let secret: [u8; 32] = hex::decode(api_secret.trim())
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| {
+                SignerError::InvalidPrivateKey(
+                    "Cobo API secret must be 32 hex-encoded bytes".to_string(),
+                )
+            })?;
+        let public_key = address
+            .parse::<Pubkey>()
+            .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid address: {e}")))?;
+
+        Ok(Self {
+            api_key: ed25519_dalek::SigningKey::from_bytes(&secret),
+            wallet_id,
+            public_key,
+            api_base_url: DEFAULT_API_BASE_URL.to_string(),
+            poll_interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(120),
+            client: reqwest::Client::new(),
+        })
+    }
+
+    /// Use a different API host, e.g. `https://api.dev.cobo.com/v2`
+    pub fn with_api_base_url(mut self, api_base_url: String) -> Self {
+        self.api_base_url = api_base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// How often to poll a pending transaction (default 1 second)
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// How long to wait for a transaction to complete (default 2 minutes)
+    ///
+    /// Transactions waiting on approval policies can take longer than this; the
+    /// request then fails with `SignerError::Timeout` but stays pending at Cobo.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Hex-encoded public half of the API key, sent as `BIZ-API-KEY`
+    fn api_key_hex(&self) -> String {
+        hex::encode(self.api_key.verifying_key().as_bytes())
+    }
+
+    /// Sign `METHOD|PATH|NONCE|QUERY|BODY` as Cobo expects, returning the hex signature
+    fn sign_request(&self, method: &str, path: &str, nonce: &str, body: &str) -> String {
+        let content = format!("{method}|{path}|{nonce}||{body}");
+        let digest = Sha256::digest(Sha256::digest(content.as_bytes()));
+        hex::encode(self.api_key.sign(&digest).to_bytes())
+    }
+
+    /// Send a signed request and fail on non-success statuses
+    async fn send(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        body: Option<&serde_json::Value>,
+        operation: &str,
+    ) -> Result<reqwest::Response, SignerError> {
+        let url = reqwest::Url::parse(&format!("{}{endpoint}", self.api_base_url))
+            .map_err(|e| SignerError::ConfigError(format!("Invalid Cobo API URL: {e}")))?;
+        let body = body.map(serde_json::to_string).transpose()?;
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| SignerError::Other(format!("System clock error: {e}")))?
+            .as_millis()
+            .to_string();
+        let signature = self.sign_request(
+            method.as_str(),
+            url.path(),
+            &nonce,
+            body.as_deref().unwrap_or_default(),
+        );
+
+        let mut request = self
+            .client
+            .request(method, url)
+            .header("BIZ-API-KEY", self.api_key_hex())
+            .header("BIZ-API-NONCE", nonce)
+            .header("BIZ-API-SIGNATURE", signature);
+        if let Some(body) = body {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+
+        let response = request.send().await?;
+
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+        #[cfg(feature = "unsafe-debug")]
+        log::error!("Cobo {operation} error - status: {status}, response: {error_text}");
+
+        #[cfg(not(feature = "unsafe-debug"))]
+        {
+            let _ = error_text;
+            log::error!("Cobo {operation} error - status: {status}");
+        }
+
+        Err(SignerError::RemoteApiError(format!("API error {status}")))
+    }
+
+    /// Poll the transaction until it reaches a final status
+    async fn wait_for_signature(&self, transaction_id: &str) -> Result<String, SignerError> {
+        let deadline = Instant::now() + self.timeout;
+
+        loop {
+            let transaction: TransactionResponse = self
+                .send(
+                    reqwest::Method::GET,
+                    &format!("/transactions/{transaction_id}"),
+                    None,
+                    "get transaction",
+                )
+                .await?
+                .json()
+                .await?;
+
+            match transaction.status.as_str() {
+                "Completed" => {
+                    return transaction.result.map(|r| r.signature).ok_or_else(|| {
+                        SignerError::SigningFailed(format!(
+                            "Cobo transaction {transaction_id} completed without a signature"
+                        ))
+                    })
+                }
+                "Failed" | "Rejected" => {
+                    return Err(SignerError::SigningFailed(format!(
+                        "Cobo transaction {transaction_id} was {}",
+                        transaction.status.to_lowercase()
+                    )))
+                }
+                _ => {}
+            }
+
+            if Instant::now() + self.poll_interval > deadline {
+                return Err(SignerError::Timeout(format!(
+                    "Cobo transaction {transaction_id} still {} after {:?}",
+                    transaction.status, self.timeout
+                )));
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let request_id = format!("solana-signers-{}", hex::encode(rand::random::<[u8; 16]>()));
+        let body = json!({
+            "request_id": request_id,
+            "chain_id": "SOL",
+            "source": {
+                "source_type": "Org-Controlled",
+                "wallet_id": self.wallet_id,
+                "address": self.public_key.to_string(),
+            },
+            "destination": {
+                "destination_type": "Raw_Message_Signature",
+                "message": hex::encode(message),
+            },
+        });
+
+        let created: CreateTransactionResponse = self
+            .send(
+                reqwest::Method::POST,
+                "/transactions/message_sign",
+                Some(&body),
+                "message sign",
+            )
+            .await?
+            .json()
+            .await?;
+
+        let signature_hex = self.wait_for_signature(&created.transaction_id).await?;
+        let signature_bytes = hex::decode(&signature_hex).map_err(|_| {
+            SignerError::SerializationError("Failed to decode signature".to_string())
+        })?;
+        let signature = Signature::try_from(signature_bytes.as_slice())
+            .map_err(|_| SignerError::SigningFailed("Invalid signature format".to_string()))?;
+
+        if !TransactionUtil::verify_signature(&self.public_key, message, &signature) {
+            return Err(SignerError::SigningFailed(format!(
+                "Cobo transaction {} returned a signature that does not verify",
+                created.transaction_id
+            )));
+        }
+
+        Ok(signature)
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for CoboSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Check that the API key is accepted and can read the wallet
+    async fn is_available(&self) -> bool {
+        self.send(
+            reqwest::Method::GET,
+            &format!("/wallets/{}", self.wallet_id),
+            None,
+            "get wallet",
+        )
+        .await
+        .is_ok()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("cobo", self.public_key, self.latency_class())
+            .with_key_id(&self.wallet_id)
+            .with_endpoint(&self.api_base_url)
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=cobo api={} wallet_id={} api_key={} pubkey={}",
+            self.api_base_url,
+            self.wallet_id,
+            self.api_key_hex(),
+            self.public_key
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        reqwest::Url::parse(&self.api_base_url)
+            .map_err(|e| SignerError::ConfigError(format!("Invalid Cobo API URL: {e}")))?;
+
+        if self.wallet_id.is_empty() {
+            return Err(SignerError::ConfigError(
+                "Cobo wallet_id must not be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use ed25519_dalek::Verifier;
+    use wiremock::{
+        matchers::{body_partial_json, header_exists, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const TEST_API_SECRET: &str =
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const TEST_WALLET_ID: &str = "f47ac10b-58cc-4372-a567-0e02b2c3d479";
+
+    fn create_test_signer(mock_server: &MockServer, keypair: &Keypair) -> CoboSigner {
+        CoboSigner::new(
+            TEST_API_SECRET,
+            TEST_WALLET_ID.to_string(),
+            keypair_pubkey(keypair).to_string(),
+        )
+        .unwrap()
+        .with_api_base_url(format!("{}/v2", mock_server.uri()))
+        .with_poll_interval(Duration::from_millis(10))
+    }
+
+    async fn mount_message_sign(mock_server: &MockServer, message: &[u8]) {
+        Mock::given(method("POST"))
+            .and(path("/v2/transactions/message_sign"))
+            .and(header_exists("BIZ-API-KEY"))
+            .and(header_exists("BIZ-API-NONCE"))
+            .and(header_exists("BIZ-API-SIGNATURE"))
+            .and(body_partial_json(json!({
+                "chain_id": "SOL",
+                "source": { "wallet_id": TEST_WALLET_ID },
+                "destination": { "message": hex::encode(message) },
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "request_id": "solana-signers-test",
+                "transaction_id": "tx-1",
+                "status": "Submitted",
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[test]
+    fn test_cobo_request_signature() {
+        let keypair = Keypair::new();
+        let server_url = "https://api.dev.cobo.com/v2".to_string();
+        let signer = CoboSigner::new(
+            TEST_API_SECRET,
+            TEST_WALLET_ID.to_string(),
+            keypair_pubkey(&keypair).to_string(),
+        )
+        .unwrap()
+        .with_api_base_url(server_url);
+
+        let signature = signer.sign_request("GET", "/v2/wallets", "1718000000000", "");
+        let signature =
+            ed25519_dalek::Signature::from_slice(&hex::decode(signature).unwrap()).unwrap();
+        let digest = Sha256::digest(Sha256::digest(b"GET|/v2/wallets|1718000000000||"));
+
+        assert!(signer
+            .api_key
+            .verifying_key()
+            .verify(&digest, &signature)
+            .is_ok());
+        assert_eq!(
+            signer.api_key_hex(),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cobo_sign_transaction_after_polling() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair);
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let message = tx.message_data();
+        let signature = keypair_sign_message(&keypair, &message);
+
+        mount_message_sign(&mock_server, &message).await;
+        Mock::given(method("GET"))
+            .and(path("/v2/transactions/tx-1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "status": "PendingAuthorization" })),
+            )
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v2/transactions/tx-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "Completed",
+                "result": { "signature": hex::encode(signature.as_ref()) },
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let returned = signer.sign_transaction(&mut tx).await.unwrap().signature;
+        assert_eq!(returned, signature);
+        assert_eq!(tx.signatures[0], signature);
+    }
+
+    #[tokio::test]
+    async fn test_cobo_rejected() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair);
+
+        mount_message_sign(&mock_server, b"hello").await;
+        Mock::given(method("GET"))
+            .and(path("/v2/transactions/tx-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Rejected" })))
+            .mount(&mock_server)
+            .await;
+
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_cobo_timeout() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer =
+            create_test_signer(&mock_server, &keypair).with_timeout(Duration::from_millis(50));
+
+        mount_message_sign(&mock_server, b"hello").await;
+        Mock::given(method("GET"))
+            .and(path("/v2/transactions/tx-1"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "PendingSignature" })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::Timeout(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_cobo_is_available() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let signer = create_test_signer(&mock_server, &keypair);
+
+        Mock::given(method("GET"))
+            .and(path(format!("/v2/wallets/{TEST_WALLET_ID}")))
+            .and(header_exists("BIZ-API-SIGNATURE"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "wallet_id": TEST_WALLET_ID,
+                "wallet_type": "MPC",
+            })))
+            .mount(&mock_server)
+            .await;
+
+        assert!(signer.is_available().await);
+        assert!(!signer.config_debug().contains(TEST_API_SECRET));
+    }
+
+    #[test]
+    fn test_cobo_invalid_api_secret() {
+        assert!(matches!(
+            CoboSigner::new(
+                "abcd",
+                TEST_WALLET_ID.to_string(),
+                Pubkey::new_unique().to_string()
+            ),
+            Err(SignerError::InvalidPrivateKey(_))
+        ));
+    }
+}
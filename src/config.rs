@@ -0,0 +1,488 @@
+//! Backend-tagged signer configuration
+//!
+//! [`SignerConfig`] collects a backend's constructor arguments as named fields. It
+//! deserializes from any serde format, tagged by `type`, so applications can embed
+//! signer settings in their own configuration files:
+//!
+//! ```toml
+//! [signer]
+//! type = "vault"
+//! vault_addr = "https://vault.example.com"
+//! vault_token = "..."
+//! key_name = "fee-payer"
+//! pubkey = "..."
+//! ```
+//!
+//! [`SignerConfig::build`] turns it into a [`Signer`], initializing backends that
+//! need it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::SignerError;
+use crate::Signer;
+
+/// Placeholder printed instead of secrets by `SignerConfig`'s `Debug` impl
+const REDACTED: &str = "[REDACTED]";
+
+/// Configuration for one signer backend
+///
+/// `Debug` output redacts private keys, tokens and API secrets.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SignerConfig {
+    /// Local keypair, see [`Signer::from_memory`]
+    #[cfg(feature = "memory")]
+    Memory {
+        /// Base58 string, U8Array string or path to a JSON keypair file
+        private_key: String,
+    },
+
+    /// HashiCorp Vault transit key, see [`Signer::from_vault`]
+    #[cfg(feature = "vault")]
+    Vault {
+        vault_addr: String,
+        vault_token: String,
+        key_name: String,
+        pubkey: String,
+    },
+
+    /// Privy embedded wallet, see [`Signer::from_privy`]
+    #[cfg(feature = "privy")]
+    Privy {
+        app_id: String,
+        app_secret: String,
+        wallet_id: String,
+    },
+
+    /// Turnkey private key, see [`Signer::from_turnkey`]
+    #[cfg(feature = "turnkey")]
+    Turnkey {
+        api_public_key: String,
+        api_private_key: String,
+        organization_id: String,
+        private_key_id: String,
+        public_key: String,
+    },
+
+    /// Google Cloud KMS key version, see [`Signer::from_gcp_kms`]
+    #[cfg(feature = "gcp-kms")]
+    GcpKms {
+        key_version_name: String,
+        access_token: String,
+    },
+
+    /// Trezor hardware wallet account, see [`Signer::from_trezor`]
+    #[cfg(feature = "trezor")]
+    Trezor { derivation_path: String },
+
+    /// Dfns wallet, see [`Signer::from_dfns`]
+    #[cfg(feature = "dfns")]
+    Dfns {
+        auth_token: String,
+        credential_id: String,
+        credential_private_key: String,
+        wallet_id: String,
+    },
+
+    /// First Ed25519 key held by ssh-agent, see [`Signer::from_ssh_agent`]
+    #[cfg(feature = "ssh-agent")]
+    SshAgent,
+
+    /// Keypair in the OS credential store, see [`Signer::from_keyring`]
+    #[cfg(feature = "keyring")]
+    Keyring { service: String, user: String },
+
+    /// Hardware wallet URI such as `usb://ledger?key=0`, see [`Signer::from_remote_wallet`]
+    #[cfg(feature = "remote-wallet")]
+    RemoteWallet { uri: String },
+
+    /// Cobo WaaS wallet, see [`Signer::from_cobo`]
+    #[cfg(feature = "cobo")]
+    Cobo {
+        api_secret: String,
+        wallet_id: String,
+        address: String,
+    },
+
+    /// Anchorage vault address, see [`Signer::from_anchorage`]
+    #[cfg(feature = "anchorage")]
+    Anchorage {
+        api_key: String,
+        signing_secret: String,
+        vault_id: String,
+        address: String,
+    },
+
+    /// Utila vault address, see [`Signer::from_utila`]
+    #[cfg(feature = "utila")]
+    Utila {
+        service_account: String,
+        private_key_pem: String,
+        vault_id: String,
+        address: String,
+    },
+}
+
+impl SignerConfig {
+    /// Build the configured signer, initializing it if the backend requires it
+    ///
+    /// Backends such as Privy fetch their public key here, so this may make
+    /// network or device calls.
+    pub async fn build(self) -> Result<Signer, SignerError> {
+        match self {
+            #[cfg(feature = "privy")]
+            SignerConfig::Privy {
+                app_id,
+                app_secret,
+                wallet_id,
+            } => Signer::from_privy(app_id, app_secret, wallet_id).await,
+
+            #[cfg(feature = "gcp-kms")]
+            SignerConfig::GcpKms {
+                key_version_name,
+                access_token,
+            } => Signer::from_gcp_kms(key_version_name, access_token).await,
+
+            #[cfg(feature = "trezor")]
+            SignerConfig::Trezor { derivation_path } => Signer::from_trezor(&derivation_path).await,
+
+            #[cfg(feature = "dfns")]
+            SignerConfig::Dfns {
+                auth_token,
+                credential_id,
+                credential_private_key,
+                wallet_id,
+            } => {
+                Signer::from_dfns(auth_token, credential_id, credential_private_key, wallet_id)
+                    .await
+            }
+
+            #[cfg(feature = "ssh-agent")]
+            SignerConfig::SshAgent => Signer::from_ssh_agent().await,
+
+            #[allow(unreachable_patterns)]
+            config => Signer::try_from(config),
+        }
+    }
+
+    /// Whether [`SignerConfig::build`] is needed, i.e. `Signer::try_from` would fail
+    /// because the backend must be initialized asynchronously
+    pub fn requires_init(&self) -> bool {
+        match self {
+            #[cfg(feature = "privy")]
+            SignerConfig::Privy { .. } => true,
+            #[cfg(feature = "gcp-kms")]
+            SignerConfig::GcpKms { .. } => true,
+            #[cfg(feature = "trezor")]
+            SignerConfig::Trezor { .. } => true,
+            #[cfg(feature = "dfns")]
+            SignerConfig::Dfns { .. } => true,
+            #[cfg(feature = "ssh-agent")]
+            SignerConfig::SshAgent => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Debug for SignerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "memory")]
+            SignerConfig::Memory { .. } => f
+                .debug_struct("Memory")
+                .field("private_key", &REDACTED)
+                .finish(),
+
+            #[cfg(feature = "vault")]
+            SignerConfig::Vault {
+                vault_addr,
+                key_name,
+                pubkey,
+                ..
+            } => f
+                .debug_struct("Vault")
+                .field("vault_addr", vault_addr)
+                .field("vault_token", &REDACTED)
+                .field("key_name", key_name)
+                .field("pubkey", pubkey)
+                .finish(),
+
+            #[cfg(feature = "privy")]
+            SignerConfig::Privy {
+                app_id, wallet_id, ..
+            } => f
+                .debug_struct("Privy")
+                .field("app_id", app_id)
+                .field("app_secret", &REDACTED)
+                .field("wallet_id", wallet_id)
+                .finish(),
+
+            #[cfg(feature = "turnkey")]
+            SignerConfig::Turnkey {
+                api_public_key,
+                organization_id,
+                private_key_id,
+                public_key,
+                ..
+            } => f
+                .debug_struct("Turnkey")
+                .field("api_public_key", api_public_key)
+                .field("api_private_key", &REDACTED)
+                .field("organization_id", organization_id)
+                .field("private_key_id", private_key_id)
+                .field("public_key", public_key)
+                .finish(),
+
+            #[cfg(feature = "gcp-kms")]
+            SignerConfig::GcpKms {
+                key_version_name, ..
+            } => f
+                .debug_struct("GcpKms")
+                .field("key_version_name", key_version_name)
+                .field("access_token", &REDACTED)
+                .finish(),
+
+            #[cfg(feature = "trezor")]
+            SignerConfig::Trezor { derivation_path } => f
+                .debug_struct("Trezor")
+                .field("derivation_path", derivation_path)
+                .finish(),
+
+            #[cfg(feature = "dfns")]
+            SignerConfig::Dfns {
+                credential_id,
+                wallet_id,
+                ..
+            } => f
+                .debug_struct("Dfns")
+                .field("auth_token", &REDACTED)
+                .field("credential_id", credential_id)
+                .field("credential_private_key", &REDACTED)
+                .field("wallet_id", wallet_id)
+                .finish(),
+
+            #[cfg(feature = "ssh-agent")]
+            SignerConfig::SshAgent => f.write_str("SshAgent"),
+
+            #[cfg(feature = "keyring")]
+            SignerConfig::Keyring { service, user } => f
+                .debug_struct("Keyring")
+                .field("service", service)
+                .field("user", user)
+                .finish(),
+
+            #[cfg(feature = "remote-wallet")]
+            SignerConfig::RemoteWallet { uri } => {
+                f.debug_struct("RemoteWallet").field("uri", uri).finish()
+            }
+
+            #[cfg(feature = "cobo")]
+            SignerConfig::Cobo {
+                wallet_id, address, ..
+            } => f
+                .debug_struct("Cobo")
+                .field("api_secret", &REDACTED)
+                .field("wallet_id", wallet_id)
+                .field("address", address)
+                .finish(),
+
+            #[cfg(feature = "anchorage")]
+            SignerConfig::Anchorage {
+                vault_id, address, ..
+            } => f
+                .debug_struct("Anchorage")
+                .field("api_key", &REDACTED)
+                .field("signing_secret", &REDACTED)
+                .field("vault_id", vault_id)
+                .field("address", address)
+                .finish(),
+
+            #[cfg(feature = "utila")]
+            SignerConfig::Utila {
+                service_account,
+                vault_id,
+                address,
+                ..
+            } => f
+                .debug_struct("Utila")
+                .field("service_account", service_account)
+                .field("private_key_pem", &REDACTED)
+                .field("vault_id", vault_id)
+                .field("address", address)
+                .finish(),
+        }
+    }
+}
+
+impl TryFrom<SignerConfig> for Signer {
+    type Error = SignerError;
+
+    fn try_from(config: SignerConfig) -> Result<Self, Self::Error> {
+        match config {
+            #[cfg(feature = "memory")]
+            SignerConfig::Memory { private_key } => Signer::from_memory(&private_key),
+
+            #[cfg(feature = "vault")]
+            SignerConfig::Vault {
+                vault_addr,
+                vault_token,
+                key_name,
+                pubkey,
+            } => Signer::from_vault(vault_addr, vault_token, key_name, pubkey),
+
+            #[cfg(feature = "turnkey")]
+            SignerConfig::Turnkey {
+                api_public_key,
+                api_private_key,
+                organization_id,
+                private_key_id,
+                public_key,
+            } => Signer::from_turnkey(
+                api_public_key,
+                api_private_key,
+                organization_id,
+                private_key_id,
+                public_key,
+            ),
+
+            #[cfg(feature = "keyring")]
+            SignerConfig::Keyring { service, user } => Signer::from_keyring(&service, &user),
+
+            #[cfg(feature = "remote-wallet")]
+            SignerConfig::RemoteWallet { uri } => Signer::from_remote_wallet(&uri),
+
+            #[cfg(feature = "cobo")]
+            SignerConfig::Cobo {
+                api_secret,
+                wallet_id,
+                address,
+            } => Signer::from_cobo(&api_secret, wallet_id, address),
+
+            #[cfg(feature = "anchorage")]
+            SignerConfig::Anchorage {
+                api_key,
+                signing_secret,
+                vault_id,
+                address,
+            } => Signer::from_anchorage(api_key, &signing_secret, vault_id, address),
+
+            #[cfg(feature = "utila")]
+            SignerConfig::Utila {
+                service_account,
+                private_key_pem,
+                vault_id,
+                address,
+            } => Signer::from_utila(service_account, &private_key_pem, vault_id, address),
+
+            #[allow(unreachable_patterns)]
+            config => Err(SignerError::ConfigError(format!(
+                "{config:?} must be initialized; use SignerConfig::build"
+            ))),
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "memory", feature = "vault", feature = "privy")))]
+mod tests {
+    use super::*;
+    #[cfg(feature = "memory")]
+    use crate::traits::SolanaSigner;
+
+    #[cfg(feature = "memory")]
+    #[test]
+    fn test_memory_config() {
+        let keypair = crate::sdk_adapter::Keypair::new();
+        let expected = crate::sdk_adapter::keypair_pubkey(&keypair);
+
+        let signer = Signer::try_from(SignerConfig::Memory {
+            private_key: keypair.to_base58_string(),
+        })
+        .unwrap();
+
+        assert!(matches!(signer, Signer::Memory(_)));
+        assert_eq!(signer.pubkey(), expected);
+    }
+
+    #[cfg(feature = "memory")]
+    #[test]
+    fn test_from_backend_signer() {
+        let memory = crate::memory::MemorySigner::new(crate::sdk_adapter::Keypair::new());
+        let expected = memory.pubkey();
+
+        let signer: Signer = memory.into();
+
+        assert_eq!(signer.pubkey(), expected);
+    }
+
+    #[cfg(feature = "vault")]
+    #[test]
+    fn test_vault_config_rejects_invalid_pubkey() {
+        let result = Signer::try_from(SignerConfig::Vault {
+            vault_addr: "https://vault.example.com".to_string(),
+            vault_token: "token".to_string(),
+            key_name: "key".to_string(),
+            pubkey: "not-a-pubkey".to_string(),
+        });
+
+        assert!(matches!(result, Err(SignerError::InvalidPublicKey(_))));
+    }
+
+    #[cfg(feature = "vault")]
+    #[test]
+    fn test_deserialize_and_redact() {
+        let config: SignerConfig = serde_json::from_value(serde_json::json!({
+            "type": "vault",
+            "vault_addr": "https://vault.example.com",
+            "vault_token": "s.super-secret",
+            "key_name": "fee-payer",
+            "pubkey": "2vfDxWYbhRt7GXiRYKf1Dr5Z8y7zVQCSERbDTKyBaAqQ",
+        }))
+        .unwrap();
+
+        let debug = format!("{config:?}");
+        assert!(debug.contains("fee-payer"));
+        assert!(!debug.contains("super-secret"));
+        assert!(!config.requires_init());
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["type"], "vault");
+    }
+
+    #[cfg(feature = "memory")]
+    #[tokio::test]
+    async fn test_build_memory() {
+        let keypair = crate::sdk_adapter::Keypair::new();
+        let config: SignerConfig = serde_json::from_value(serde_json::json!({
+            "type": "memory",
+            "private_key": keypair.to_base58_string(),
+        }))
+        .unwrap();
+
+        let signer = config.build().await.unwrap();
+
+        assert_eq!(
+            signer.pubkey(),
+            crate::sdk_adapter::keypair_pubkey(&keypair)
+        );
+    }
+
+    #[cfg(feature = "privy")]
+    #[test]
+    fn test_try_from_rejects_async_backend() {
+        let config = SignerConfig::Privy {
+            app_id: "app".to_string(),
+            app_secret: "hunter2".to_string(),
+            wallet_id: "wallet".to_string(),
+        };
+        assert!(config.requires_init());
+
+        match Signer::try_from(config) {
+            Err(SignerError::ConfigError(msg)) => {
+                assert!(msg.contains("SignerConfig::build"));
+                assert!(!msg.contains("hunter2"));
+            }
+            _ => panic!("Expected ConfigError"),
+        }
+    }
+}
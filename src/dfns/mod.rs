@@ -0,0 +1,579 @@
+//! Dfns wallets API signer integration
+//!
+//! Signature requests are authorized with Dfns user action signing: the request
+//! body is exchanged for a challenge, the challenge is signed with the service
+//! account's credential key, and the resulting user action token is sent with the
+//! request.
+
+mod types;
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use p256::ecdsa::signature::Signer as P256Signer;
+use p256::pkcs8::DecodePrivateKey;
+use types::{
+    ClientData, CredentialAssertion, FirstFactor, GenerateSignatureRequest, SignatureResponse,
+    UserActionChallenge, UserActionInitRequest, UserActionRequest, UserActionResponse,
+    WalletResponse,
+};
+
+/// Default Dfns API base URL
+const DEFAULT_API_BASE_URL: &str = "https://api.dfns.io";
+
+/// Default origin placed in the signed client data
+const DEFAULT_APP_ORIGIN: &str = "https://app.dfns.io";
+
+/// Dfns-based signer using a wallet's Ed25519 key through the Dfns wallets API
+#[derive(Clone)]
+pub struct DfnsSigner {
+    auth_token: String,
+    credential_id: String,
+    credential_private_key: String,
+    wallet_id: String,
+    app_origin: String,
+    api_base_url: String,
+    client: reqwest::Client,
+    public_key: Pubkey,
+}
+
+impl std::fmt::Debug for DfnsSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DfnsSigner")
+            .field("public_key", &self.public_key)
+            .field("wallet_id", &self.wallet_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DfnsSigner {
+    /// Create a new DfnsSigner (requires initialization)
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_token` - Service account or personal access token
+    /// * `credential_id` - ID of the credential registered for the token
+    /// * `credential_private_key` - PKCS#8 PEM of the credential's P-256 private key
+    /// * `wallet_id` - Dfns wallet ID
+    pub fn new(
+        auth_token: String,
+        credential_id: String,
+        credential_private_key: String,
+        wallet_id: String,
+    ) -> Self {
+        Self {
+            auth_token,
+            credential_id,
+            credential_private_key,
+            wallet_id,
+            app_origin: DEFAULT_APP_ORIGIN.to_string(),
+            api_base_url: DEFAULT_API_BASE_URL.to_string(),
+            client: reqwest::Client::new(),
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        }
+    }
+
+    /// Use a different API host, e.g. a regional Dfns endpoint
+    pub fn with_api_base_url(mut self, api_base_url: String) -> Self {
+        self.api_base_url = api_base_url;
+        self
+    }
+
+    /// Set the origin written into the signed client data
+    pub fn with_app_origin(mut self, app_origin: String) -> Self {
+        self.app_origin = app_origin;
+        self
+    }
+
+    /// Initialize the signer by fetching the wallet's public key
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        let wallet = self.fetch_wallet().await?;
+
+        if wallet.signing_key.curve != "ed25519" {
+            return Err(SignerError::ConfigError(format!(
+                "Dfns wallet key curve is {}, expected ed25519",
+                wallet.signing_key.curve
+            )));
+        }
+
+        let public_key = hex::decode(&wallet.signing_key.public_key).map_err(|e| {
+            SignerError::InvalidPublicKey(format!("Failed to decode public key: {e}"))
+        })?;
+        self.public_key = Pubkey::try_from(public_key.as_slice())
+            .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid public key: {e}")))?;
+        Ok(())
+    }
+
+    fn credential_signing_key(&self) -> Result<p256::ecdsa::SigningKey, SignerError> {
+        p256::ecdsa::SigningKey::from_pkcs8_pem(&self.credential_private_key).map_err(|e| {
+            SignerError::InvalidPrivateKey(format!("Invalid Dfns credential key: {e}"))
+        })
+    }
+
+    /// Send a request with the auth token and fail on non-success statuses
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+        operation: &str,
+    ) -> Result<reqwest::Response, SignerError> {
+        let response = request.bearer_auth(&self.auth_token).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+            #[cfg(feature = "unsafe-debug")]
+            log::error!("Dfns API {operation} error - status: {status}, response: {error_text}");
+
+            #[cfg(not(feature = "unsafe-debug"))]
+            log::error!("Dfns API {operation} error - status: {status}");
+
+            return Err(SignerError::RemoteApiError(format!("API error {status}")));
+        }
+
+        Ok(response)
+    }
+
+    async fn fetch_wallet(&self) -> Result<WalletResponse, SignerError> {
+        let url = format!("{}/wallets/{}", self.api_base_url, self.wallet_id);
+        let response = self.send(self.client.get(&url), "get_wallet").await?;
+        Ok(response.json().await?)
+    }
+
+    /// Sign a user action challenge with the credential key
+    fn sign_challenge(
+        &self,
+        challenge: &UserActionChallenge,
+    ) -> Result<CredentialAssertion<'_>, SignerError> {
+        let signing_key = self.credential_signing_key()?;
+
+        let client_data = serde_json::to_string(&ClientData {
+            kind: "key.get",
+            challenge: &challenge.challenge,
+            origin: &self.app_origin,
+            cross_origin: false,
+        })?;
+        let signature: p256::ecdsa::Signature = signing_key.sign(client_data.as_bytes());
+
+        Ok(CredentialAssertion {
+            cred_id: &self.credential_id,
+            client_data: URL_SAFE_NO_PAD.encode(client_data),
+            signature: URL_SAFE_NO_PAD.encode(signature.to_der()),
+        })
+    }
+
+    /// Obtain a user action token authorizing `POST {path}` with `payload`
+    async fn user_action(&self, path: &str, payload: &str) -> Result<String, SignerError> {
+        let init_request = UserActionInitRequest {
+            user_action_payload: payload,
+            user_action_http_method: "POST",
+            user_action_http_path: path,
+            user_action_server_kind: "Api",
+        };
+        let response = self
+            .send(
+                self.client
+                    .post(format!("{}/auth/action/init", self.api_base_url))
+                    .json(&init_request),
+                "user_action_init",
+            )
+            .await?;
+        let challenge: UserActionChallenge = response.json().await?;
+
+        let action_request = UserActionRequest {
+            challenge_identifier: &challenge.challenge_identifier,
+            first_factor: FirstFactor {
+                kind: "Key",
+                credential_assertion: self.sign_challenge(&challenge)?,
+            },
+        };
+        let response = self
+            .send(
+                self.client
+                    .post(format!("{}/auth/action", self.api_base_url))
+                    .json(&action_request),
+                "user_action",
+            )
+            .await?;
+        let action: UserActionResponse = response.json().await?;
+
+        Ok(action.user_action)
+    }
+
+    /// Sign message bytes with the wallet key
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let path = format!("/wallets/{}/signatures", self.wallet_id);
+        let payload = serde_json::to_string(&GenerateSignatureRequest {
+            kind: "Message",
+            message: format!("0x{}", hex::encode(message)),
+        })?;
+
+        // The user action is bound to the exact body, so send the same string
+        let user_action = self.user_action(&path, &payload).await?;
+        let response = self
+            .send(
+                self.client
+                    .post(format!("{}{}", self.api_base_url, path))
+                    .header("X-DFNS-USERACTION", user_action)
+                    .header("Content-Type", "application/json")
+                    .body(payload),
+                "generate_signature",
+            )
+            .await?;
+        let sign_response: SignatureResponse = response.json().await?;
+
+        let components = match (sign_response.status.as_str(), sign_response.signature) {
+            ("Signed", Some(components)) => components,
+            (status, _) => {
+                return Err(SignerError::SigningFailed(format!(
+                    "Dfns signature request is {status}"
+                )))
+            }
+        };
+
+        let mut sig_bytes = Vec::with_capacity(64);
+        for component in [&components.r, &components.s] {
+            let bytes = hex::decode(component.trim_start_matches("0x")).map_err(|e| {
+                SignerError::SerializationError(format!("Failed to decode signature: {e}"))
+            })?;
+            sig_bytes.extend_from_slice(&bytes);
+        }
+
+        Signature::try_from(sig_bytes.as_slice())
+            .map_err(|_| SignerError::SigningFailed("Invalid signature length".to_string()))
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for DfnsSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Check that the credentials are usable and the wallet is active
+    async fn is_available(&self) -> bool {
+        if self.credential_signing_key().is_err() {
+            return false;
+        }
+
+        match self.fetch_wallet().await {
+            Ok(wallet) => wallet.status == "Active",
+            Err(_) => false,
+        }
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("dfns", self.public_key, self.latency_class())
+            .with_key_id(&self.wallet_id)
+            .with_endpoint(&self.api_base_url)
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=dfns api={} wallet_id={} credential_id={} auth_token=[REDACTED] pubkey={}",
+            self.api_base_url, self.wallet_id, self.credential_id, self.public_key
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        if self.auth_token.is_empty() {
+            return Err(SignerError::ConfigError(
+                "Dfns auth_token must not be empty".to_string(),
+            ));
+        }
+
+        self.credential_signing_key().map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use p256::ecdsa::signature::Verifier;
+    use p256::pkcs8::{EncodePrivateKey, LineEnding};
+    use wiremock::{
+        matchers::{body_partial_json, header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const TEST_WALLET_ID: &str = "wa-test-wallet";
+
+    fn create_test_signer(api_base_url: String) -> (DfnsSigner, p256::ecdsa::VerifyingKey) {
+        let signing_key = p256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+        let pem = signing_key.to_pkcs8_pem(LineEnding::LF).unwrap();
+
+        let signer = DfnsSigner::new(
+            "test-token".to_string(),
+            "cr-test-credential".to_string(),
+            pem.to_string(),
+            TEST_WALLET_ID.to_string(),
+        )
+        .with_api_base_url(api_base_url);
+
+        (signer, *signing_key.verifying_key())
+    }
+
+    async fn mount_wallet(mock_server: &MockServer, pubkey: &Pubkey, status: &str) {
+        Mock::given(method("GET"))
+            .and(path(format!("/wallets/{TEST_WALLET_ID}")))
+            .and(header("Authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": TEST_WALLET_ID,
+                "network": "Solana",
+                "address": pubkey.to_string(),
+                "status": status,
+                "signingKey": {
+                    "scheme": "EdDSA",
+                    "curve": "ed25519",
+                    "publicKey": hex::encode(pubkey.to_bytes())
+                }
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    async fn mount_user_action(mock_server: &MockServer, message: &[u8]) {
+        let payload = serde_json::json!({
+            "kind": "Message",
+            "message": format!("0x{}", hex::encode(message))
+        })
+        .to_string();
+
+        Mock::given(method("POST"))
+            .and(path("/auth/action/init"))
+            .and(body_partial_json(serde_json::json!({
+                "userActionPayload": payload,
+                "userActionHttpMethod": "POST",
+                "userActionHttpPath": format!("/wallets/{TEST_WALLET_ID}/signatures"),
+                "userActionServerKind": "Api"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "challenge": "test-challenge",
+                "challengeIdentifier": "test-challenge-id",
+                "allowCredentials": { "key": [{ "id": "cr-test-credential", "type": "public-key" }] }
+            })))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/auth/action"))
+            .and(body_partial_json(serde_json::json!({
+                "challengeIdentifier": "test-challenge-id",
+                "firstFactor": {
+                    "kind": "Key",
+                    "credentialAssertion": { "credId": "cr-test-credential" }
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "userAction": "test-user-action"
+            })))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    fn signature_response(signature: &Signature) -> ResponseTemplate {
+        let (r, s) = signature.as_ref().split_at(32);
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "sig-test",
+            "walletId": TEST_WALLET_ID,
+            "status": "Signed",
+            "signature": {
+                "r": format!("0x{}", hex::encode(r)),
+                "s": format!("0x{}", hex::encode(s))
+            }
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_dfns_init() {
+        let mock_server = MockServer::start().await;
+        let pubkey = Pubkey::new_unique();
+        mount_wallet(&mock_server, &pubkey, "Active").await;
+
+        let (mut signer, _) = create_test_signer(mock_server.uri());
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), pubkey);
+    }
+
+    #[test]
+    fn test_dfns_sign_challenge() {
+        let (signer, verifying_key) = create_test_signer(DEFAULT_API_BASE_URL.to_string());
+        let challenge = UserActionChallenge {
+            challenge: "test-challenge".to_string(),
+            challenge_identifier: "test-challenge-id".to_string(),
+        };
+
+        let assertion = signer.sign_challenge(&challenge).unwrap();
+        let client_data = URL_SAFE_NO_PAD.decode(&assertion.client_data).unwrap();
+        let signature = p256::ecdsa::Signature::from_der(
+            &URL_SAFE_NO_PAD.decode(&assertion.signature).unwrap(),
+        )
+        .unwrap();
+
+        assert!(verifying_key.verify(&client_data, &signature).is_ok());
+        let client_data: serde_json::Value = serde_json::from_slice(&client_data).unwrap();
+        assert_eq!(client_data["type"], "key.get");
+        assert_eq!(client_data["challenge"], "test-challenge");
+        assert_eq!(client_data["origin"], DEFAULT_APP_ORIGIN);
+    }
+
+    #[tokio::test]
+    async fn test_dfns_sign_transaction() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        mount_wallet(&mock_server, &pubkey, "Active").await;
+
+        let mut tx = create_test_transaction(&pubkey);
+        let message = tx.message_data();
+        let signature = keypair_sign_message(&keypair, &message);
+        mount_user_action(&mock_server, &message).await;
+
+        Mock::given(method("POST"))
+            .and(path(format!("/wallets/{TEST_WALLET_ID}/signatures")))
+            .and(header("Authorization", "Bearer test-token"))
+            .and(header("X-DFNS-USERACTION", "test-user-action"))
+            .respond_with(signature_response(&signature))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let (mut signer, _) = create_test_signer(mock_server.uri());
+        signer.init().await.unwrap();
+
+        let SignedTransaction {
+            base64_tx: serialized,
+            signature: result,
+            ..
+        } = signer.sign_transaction(&mut tx).await.unwrap();
+
+        assert_eq!(result, signature);
+        assert_eq!(tx.signatures[0], signature);
+        assert!(!serialized.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dfns_sign_message_pending_approval() {
+        let mock_server = MockServer::start().await;
+        mount_user_action(&mock_server, b"test").await;
+
+        Mock::given(method("POST"))
+            .and(path(format!("/wallets/{TEST_WALLET_ID}/signatures")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "sig-test",
+                "status": "Pending"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let (signer, _) = create_test_signer(mock_server.uri());
+        let result = signer.sign_message(b"test").await;
+
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_dfns_sign_message_unauthorized() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/auth/action/init"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let (signer, _) = create_test_signer(mock_server.uri());
+        let result = signer.sign_message(b"test").await;
+
+        assert!(matches!(result, Err(SignerError::RemoteApiError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_dfns_is_available() {
+        let mock_server = MockServer::start().await;
+        mount_wallet(&mock_server, &Pubkey::new_unique(), "Active").await;
+
+        let (signer, _) = create_test_signer(mock_server.uri());
+        assert!(signer.is_available().await);
+
+        let signer = DfnsSigner::new(
+            "test-token".to_string(),
+            "cr-test-credential".to_string(),
+            "not a key".to_string(),
+            TEST_WALLET_ID.to_string(),
+        )
+        .with_api_base_url(mock_server.uri());
+        assert!(!signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_dfns_is_not_available_when_archived() {
+        let mock_server = MockServer::start().await;
+        mount_wallet(&mock_server, &Pubkey::new_unique(), "Archived").await;
+
+        let (signer, _) = create_test_signer(mock_server.uri());
+        assert!(!signer.is_available().await);
+    }
+
+    #[test]
+    fn test_dfns_config_debug() {
+        let (signer, _) = create_test_signer(DEFAULT_API_BASE_URL.to_string());
+
+        let output = signer.config_debug();
+        assert!(output.contains("backend=dfns"));
+        assert!(output.contains(TEST_WALLET_ID));
+        assert!(!output.contains("test-token"));
+        assert!(!output.contains("PRIVATE KEY"));
+        assert!(signer.validate_config().is_ok());
+    }
+}
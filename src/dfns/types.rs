@@ -0,0 +1,98 @@
+//! Dfns API types
+
+use serde::{Deserialize, Serialize};
+
+/// Request body for `POST /auth/action/init`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserActionInitRequest<'a> {
+    /// Exact JSON body of the request being signed
+    pub user_action_payload: &'a str,
+    pub user_action_http_method: &'static str,
+    pub user_action_http_path: &'a str,
+    pub user_action_server_kind: &'static str,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserActionChallenge {
+    pub challenge: String,
+    pub challenge_identifier: String,
+}
+
+/// Client data signed with the credential key, WebAuthn style
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientData<'a> {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub challenge: &'a str,
+    pub origin: &'a str,
+    pub cross_origin: bool,
+}
+
+/// Request body for `POST /auth/action`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserActionRequest<'a> {
+    pub challenge_identifier: &'a str,
+    pub first_factor: FirstFactor<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FirstFactor<'a> {
+    pub kind: &'static str,
+    pub credential_assertion: CredentialAssertion<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialAssertion<'a> {
+    pub cred_id: &'a str,
+    /// Base64url-encoded client data JSON
+    pub client_data: String,
+    /// Base64url-encoded DER signature over the client data
+    pub signature: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserActionResponse {
+    pub user_action: String,
+}
+
+/// Request body for `POST /wallets/{walletId}/signatures`
+#[derive(Serialize)]
+pub struct GenerateSignatureRequest {
+    pub kind: &'static str,
+    /// `0x`-prefixed hex of the bytes to sign
+    pub message: String,
+}
+
+#[derive(Deserialize)]
+pub struct SignatureResponse {
+    pub status: String,
+    pub signature: Option<SignatureComponents>,
+}
+
+#[derive(Deserialize)]
+pub struct SignatureComponents {
+    pub r: String,
+    pub s: String,
+}
+
+/// Subset of the wallet resource used to find the signing key
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletResponse {
+    pub status: String,
+    pub signing_key: WalletSigningKey,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletSigningKey {
+    pub curve: String,
+    pub public_key: String,
+}
@@ -0,0 +1,205 @@
+//! Build a [`Signer`] from environment variables
+
+use crate::error::SignerError;
+use crate::Signer;
+
+/// Environment variable selecting the backend
+const SIGNER_TYPE: &str = "SIGNER_TYPE";
+
+impl Signer {
+    /// Create a signer from environment variables
+    ///
+    /// `SIGNER_TYPE` selects the backend; each backend then reads its own variables,
+    /// the same ones the integration tests use (see `.env.example`):
+    ///
+    /// | `SIGNER_TYPE` | Variables |
+    /// |---------------|-----------|
+    /// | `memory` | `MEMORY_PRIVATE_KEY` |
+    /// | `vault` | `VAULT_ADDR`, `VAULT_TOKEN`, `VAULT_KEY_NAME`, `VAULT_SIGNER_PUBKEY` |
+    /// | `privy` | `PRIVY_APP_ID`, `PRIVY_APP_SECRET`, `PRIVY_WALLET_ID` |
+    /// | `turnkey` | `TURNKEY_API_PUBLIC_KEY`, `TURNKEY_API_PRIVATE_KEY`, `TURNKEY_ORGANIZATION_ID`, `TURNKEY_PRIVATE_KEY_ID`, `TURNKEY_PUBLIC_KEY` |
+    ///
+    /// Returns `SignerError::ConfigError` naming every missing variable, or the
+    /// supported values of `SIGNER_TYPE` if it is unset or unknown. Backends that
+    /// require initialization (e.g. Privy) are initialized before returning.
+    pub async fn from_env() -> Result<Self, SignerError> {
+        Self::from_env_with(|name| std::env::var(name).ok()).await
+    }
+
+    async fn from_env_with(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, SignerError> {
+        let env = EnvReader { lookup: &lookup };
+        let signer_type = env
+            .require(&[SIGNER_TYPE])
+            .map_err(|_| env.unknown_type(None))?;
+
+        match signer_type[0].to_ascii_lowercase().as_str() {
+            #[cfg(feature = "memory")]
+            "memory" => {
+                let [private_key] = env.require(&["MEMORY_PRIVATE_KEY"])?;
+                Signer::from_memory(&private_key)
+            }
+
+            #[cfg(feature = "vault")]
+            "vault" => {
+                let [addr, token, key_name, pubkey] = env.require(&[
+                    "VAULT_ADDR",
+                    "VAULT_TOKEN",
+                    "VAULT_KEY_NAME",
+                    "VAULT_SIGNER_PUBKEY",
+                ])?;
+                Signer::from_vault(addr, token, key_name, pubkey)
+            }
+
+            #[cfg(feature = "privy")]
+            "privy" => {
+                let [app_id, app_secret, wallet_id] =
+                    env.require(&["PRIVY_APP_ID", "PRIVY_APP_SECRET", "PRIVY_WALLET_ID"])?;
+                Signer::from_privy(app_id, app_secret, wallet_id).await
+            }
+
+            #[cfg(feature = "turnkey")]
+            "turnkey" => {
+                let [api_public_key, api_private_key, organization_id, private_key_id, public_key] =
+                    env.require(&[
+                        "TURNKEY_API_PUBLIC_KEY",
+                        "TURNKEY_API_PRIVATE_KEY",
+                        "TURNKEY_ORGANIZATION_ID",
+                        "TURNKEY_PRIVATE_KEY_ID",
+                        "TURNKEY_PUBLIC_KEY",
+                    ])?;
+                Signer::from_turnkey(
+                    api_public_key,
+                    api_private_key,
+                    organization_id,
+                    private_key_id,
+                    public_key,
+                )
+            }
+
+            other => Err(env.unknown_type(Some(other))),
+        }
+    }
+}
+
+struct EnvReader<'a, F> {
+    lookup: &'a F,
+}
+
+impl<F: Fn(&str) -> Option<String>> EnvReader<'_, F> {
+    /// Read all `names`, failing with every missing or empty one
+    fn require<const N: usize>(&self, names: &[&str; N]) -> Result<[String; N], SignerError> {
+        let values = names.map(|name| (self.lookup)(name).filter(|value| !value.is_empty()));
+
+        let missing: Vec<&str> = names
+            .iter()
+            .zip(&values)
+            .filter(|(_, value)| value.is_none())
+            .map(|(name, _)| *name)
+            .collect();
+        if !missing.is_empty() {
+            return Err(SignerError::ConfigError(format!(
+                "Missing environment variables: {}",
+                missing.join(", ")
+            )));
+        }
+
+        Ok(values.map(Option::unwrap_or_default))
+    }
+
+    fn unknown_type(&self, signer_type: Option<&str>) -> SignerError {
+        let supported: &[&str] = &[
+            #[cfg(feature = "memory")]
+            "memory",
+            #[cfg(feature = "vault")]
+            "vault",
+            #[cfg(feature = "privy")]
+            "privy",
+            #[cfg(feature = "turnkey")]
+            "turnkey",
+        ];
+
+        let problem = match signer_type {
+            Some(signer_type) => format!("Unsupported {SIGNER_TYPE} '{signer_type}'"),
+            None => format!("Missing environment variable {SIGNER_TYPE}"),
+        };
+
+        SignerError::ConfigError(format!(
+            "{problem}; expected one of: {}",
+            supported.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    async fn from_vars(vars: &[(&str, &str)]) -> Result<Signer, SignerError> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        Signer::from_env_with(|name| vars.get(name).cloned()).await
+    }
+
+    fn config_error(result: Result<Signer, SignerError>) -> String {
+        match result {
+            Err(SignerError::ConfigError(msg)) => msg,
+            Err(other) => panic!("Expected ConfigError, got {other:?}"),
+            Ok(_) => panic!("Expected ConfigError, got a signer"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_missing_signer_type() {
+        let msg = config_error(from_vars(&[]).await);
+
+        assert!(msg.contains(SIGNER_TYPE));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_signer_type() {
+        let msg = config_error(from_vars(&[(SIGNER_TYPE, "abacus")]).await);
+
+        assert!(msg.contains("abacus"));
+    }
+
+    #[cfg(feature = "memory")]
+    #[tokio::test]
+    async fn test_memory_from_env() {
+        use crate::traits::SolanaSigner;
+
+        let keypair = crate::sdk_adapter::Keypair::new();
+        let signer = from_vars(&[
+            (SIGNER_TYPE, "memory"),
+            ("MEMORY_PRIVATE_KEY", &keypair.to_base58_string()),
+        ])
+        .await
+        .unwrap();
+
+        assert_eq!(
+            signer.pubkey(),
+            crate::sdk_adapter::keypair_pubkey(&keypair)
+        );
+    }
+
+    #[cfg(feature = "vault")]
+    #[tokio::test]
+    async fn test_vault_reports_every_missing_variable() {
+        let msg = config_error(
+            from_vars(&[
+                (SIGNER_TYPE, "Vault"),
+                ("VAULT_ADDR", "http://127.0.0.1:8200"),
+                ("VAULT_TOKEN", ""),
+            ])
+            .await,
+        );
+
+        assert!(msg.contains("VAULT_TOKEN"));
+        assert!(msg.contains("VAULT_KEY_NAME"));
+        assert!(msg.contains("VAULT_SIGNER_PUBKEY"));
+        assert!(!msg.contains("VAULT_ADDR"));
+    }
+}
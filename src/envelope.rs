@@ -0,0 +1,336 @@
+//! Portable signing state for multi-party transactions
+//!
+//! A [`SigningEnvelope`] carries a transaction message, the signatures collected
+//! so far keyed by signer, and free-form metadata, much like a Bitcoin PSBT.
+//! Services pass it around as JSON or bincode; every signature is checked
+//! against the message when it is added or decoded, so a party never has to
+//! trust the state it receives.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction, VersionedMessage, VersionedTransaction};
+use crate::traits::{display_from_str, SolanaSigner};
+use crate::transaction_util::{Encoding, TransactionUtil};
+
+/// Current envelope format version
+pub const ENVELOPE_VERSION: u8 = 1;
+
+/// A transaction message with the signatures collected for it so far
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawEnvelope", into = "RawEnvelope")]
+pub struct SigningEnvelope {
+    message: VersionedMessage,
+    signatures: BTreeMap<Pubkey, Signature>,
+    metadata: BTreeMap<String, String>,
+}
+
+impl SigningEnvelope {
+    /// Wrap a message with no signatures
+    pub fn new(message: VersionedMessage) -> Self {
+        Self {
+            message,
+            signatures: BTreeMap::new(),
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Wrap a legacy transaction, keeping the signatures it already has
+    ///
+    /// Empty or invalid signature slots are dropped.
+    pub fn from_transaction(transaction: &Transaction) -> Self {
+        Self::from_versioned_transaction(&VersionedTransaction::from(transaction.clone()))
+    }
+
+    /// Wrap a versioned transaction, keeping the signatures it already has
+    ///
+    /// Empty or invalid signature slots are dropped.
+    pub fn from_versioned_transaction(transaction: &VersionedTransaction) -> Self {
+        let mut envelope = Self::new(transaction.message.clone());
+        let required = envelope.required_signers().to_vec();
+
+        for (pubkey, signature) in required.into_iter().zip(&transaction.signatures) {
+            // Only valid signatures are kept, so the error is not needed
+            let _ = envelope.add_signature(pubkey, *signature);
+        }
+
+        envelope
+    }
+
+    /// Attach a metadata entry, e.g. a request ID or the next party to sign
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Get the message being signed
+    pub fn message(&self) -> &VersionedMessage {
+        &self.message
+    }
+
+    /// Get the serialized message; these are the bytes each signer signs
+    pub fn message_bytes(&self) -> Vec<u8> {
+        self.message.serialize()
+    }
+
+    /// Get the collected signatures, keyed by signer
+    pub fn signatures(&self) -> &BTreeMap<Pubkey, Signature> {
+        &self.signatures
+    }
+
+    /// Get the metadata entries
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    /// Get the signers the message requires, in signer index order
+    pub fn required_signers(&self) -> &[Pubkey] {
+        let num_required_signatures = self.message.header().num_required_signatures as usize;
+        let keys = self.message.static_account_keys();
+        &keys[..num_required_signatures.min(keys.len())]
+    }
+
+    /// Get the required signers that have not signed yet
+    pub fn missing_signers(&self) -> Vec<Pubkey> {
+        self.required_signers()
+            .iter()
+            .filter(|pubkey| !self.signatures.contains_key(pubkey))
+            .copied()
+            .collect()
+    }
+
+    /// Check whether every required signer has signed
+    pub fn is_fully_signed(&self) -> bool {
+        self.missing_signers().is_empty()
+    }
+
+    /// Record a signature collected out of band
+    ///
+    /// Fails with `SignerError::SigningFailed` if `pubkey` is not a required
+    /// signer or the signature does not verify over the message.
+    pub fn add_signature(
+        &mut self,
+        pubkey: Pubkey,
+        signature: Signature,
+    ) -> Result<(), SignerError> {
+        if !self.required_signers().contains(&pubkey) {
+            return Err(SignerError::SigningFailed(format!(
+                "Pubkey {pubkey} is not a required signer"
+            )));
+        }
+        if !TransactionUtil::verify_signature(&pubkey, &self.message_bytes(), &signature) {
+            return Err(SignerError::SigningFailed(format!(
+                "Invalid signature for {pubkey}"
+            )));
+        }
+
+        self.signatures.insert(pubkey, signature);
+
+        Ok(())
+    }
+
+    /// Sign the message with `signer` and record the signature
+    pub async fn sign<S: SolanaSigner + ?Sized>(
+        &mut self,
+        signer: &S,
+    ) -> Result<Signature, SignerError> {
+        let signature = signer.sign_message(&self.message_bytes()).await?;
+        self.add_signature(signer.pubkey(), signature)?;
+
+        Ok(signature)
+    }
+
+    /// Add the signatures and metadata from another copy of the same envelope
+    ///
+    /// Fails without modifying `self` if the messages differ. Metadata from
+    /// `other` overwrites entries with the same key.
+    pub fn merge(&mut self, other: &SigningEnvelope) -> Result<(), SignerError> {
+        if other.message != self.message {
+            return Err(SignerError::SigningFailed(
+                "Cannot merge envelopes for different messages".to_string(),
+            ));
+        }
+
+        // Signatures in `other` were verified when they were added or decoded
+        self.signatures.extend(&other.signatures);
+        self.metadata
+            .extend(other.metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+
+        Ok(())
+    }
+
+    /// Build the transaction, leaving missing signature slots empty
+    pub fn to_versioned_transaction(&self) -> VersionedTransaction {
+        VersionedTransaction {
+            signatures: self
+                .required_signers()
+                .iter()
+                .map(|pubkey| self.signatures.get(pubkey).copied().unwrap_or_default())
+                .collect(),
+            message: self.message.clone(),
+        }
+    }
+
+    /// Build a legacy transaction, leaving missing signature slots empty
+    ///
+    /// Fails with `SignerError::SerializationError` for a v0 message.
+    pub fn to_transaction(&self) -> Result<Transaction, SignerError> {
+        self.to_versioned_transaction()
+            .into_legacy_transaction()
+            .ok_or_else(|| {
+                SignerError::SerializationError(
+                    "Envelope holds a versioned message, not a legacy one".to_string(),
+                )
+            })
+    }
+
+    /// Encode as JSON
+    pub fn to_json(&self) -> Result<String, SignerError> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Decode from JSON, verifying every signature
+    pub fn from_json(json: &str) -> Result<Self, SignerError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Encode as compact bincode
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SignerError> {
+        bincode::serialize(self).map_err(|e| {
+            SignerError::SerializationError(format!("Failed to serialize envelope: {e}"))
+        })
+    }
+
+    /// Decode from bincode, verifying every signature
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SignerError> {
+        bincode::deserialize(bytes).map_err(|e| {
+            SignerError::SerializationError(format!("Failed to deserialize envelope: {e}"))
+        })
+    }
+}
+
+/// Wire form of [`SigningEnvelope`]
+#[derive(Serialize, Deserialize)]
+struct RawEnvelope {
+    version: u8,
+    /// Base64-encoded serialized message
+    message: String,
+    signatures: Vec<RawSignature>,
+    #[serde(default)]
+    metadata: BTreeMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawSignature {
+    #[serde(with = "display_from_str")]
+    pubkey: Pubkey,
+    #[serde(with = "display_from_str")]
+    signature: Signature,
+}
+
+impl From<SigningEnvelope> for RawEnvelope {
+    fn from(envelope: SigningEnvelope) -> Self {
+        Self {
+            version: ENVELOPE_VERSION,
+            message: Encoding::Base64.encode(&envelope.message.serialize()),
+            signatures: envelope
+                .signatures
+                .into_iter()
+                .map(|(pubkey, signature)| RawSignature { pubkey, signature })
+                .collect(),
+            metadata: envelope.metadata,
+        }
+    }
+}
+
+impl TryFrom<RawEnvelope> for SigningEnvelope {
+    type Error = SignerError;
+
+    fn try_from(raw: RawEnvelope) -> Result<Self, SignerError> {
+        if raw.version != ENVELOPE_VERSION {
+            return Err(SignerError::SerializationError(format!(
+                "Unsupported envelope version {}",
+                raw.version
+            )));
+        }
+
+        let message = bincode::deserialize(&Encoding::Base64.decode(&raw.message)?)
+            .map_err(|e| SignerError::SerializationError(format!("Invalid message: {e}")))?;
+        let mut envelope = SigningEnvelope::new(message);
+        envelope.metadata = raw.metadata;
+        for RawSignature { pubkey, signature } in raw.signatures {
+            envelope.add_signature(pubkey, signature)?;
+        }
+
+        Ok(envelope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_multi_signer_test_transaction;
+
+    #[test]
+    fn test_envelope_roundtrip_between_parties() {
+        let first = Keypair::new();
+        let second = Keypair::new();
+        let tx = create_multi_signer_test_transaction(&[
+            keypair_pubkey(&first),
+            keypair_pubkey(&second),
+        ]);
+
+        // Each party signs its own copy and sends it back as JSON
+        let envelope = SigningEnvelope::from_transaction(&tx).with_metadata("request_id", "42");
+        let copies = [first, second].map(|keypair| {
+            let mut copy = SigningEnvelope::from_json(&envelope.to_json().unwrap()).unwrap();
+            let signature = keypair_sign_message(&keypair, &copy.message_bytes());
+            copy.add_signature(keypair_pubkey(&keypair), signature)
+                .unwrap();
+            copy.to_bytes().unwrap()
+        });
+
+        let mut merged = SigningEnvelope::from_bytes(&copies[0]).unwrap();
+        assert_eq!(merged.missing_signers(), vec![tx.message.account_keys[1]]);
+        merged
+            .merge(&SigningEnvelope::from_bytes(&copies[1]).unwrap())
+            .unwrap();
+
+        assert!(merged.is_fully_signed());
+        assert_eq!(merged.metadata()["request_id"], "42");
+        assert!(merged.to_transaction().unwrap().verify().is_ok());
+
+        // A forged signature is caught on decode
+        let json = merged.to_json().unwrap();
+        let signature = merged.signatures()[&tx.message.account_keys[0]].to_string();
+        let forged = json.replace(&signature, &Signature::from([1; 64]).to_string());
+        assert!(matches!(
+            SigningEnvelope::from_json(&forged),
+            Err(SignerError::SerializationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_add_signature_rejects_invalid() {
+        let signer = Keypair::new();
+        let tx = create_multi_signer_test_transaction(&[keypair_pubkey(&signer)]);
+        let mut envelope = SigningEnvelope::from_transaction(&tx);
+
+        let outsider = Keypair::new();
+        let result = envelope.add_signature(
+            keypair_pubkey(&outsider),
+            keypair_sign_message(&outsider, &envelope.message_bytes()),
+        );
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+
+        let result = envelope.add_signature(
+            keypair_pubkey(&signer),
+            keypair_sign_message(&signer, b"something else"),
+        );
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+        assert!(envelope.signatures().is_empty());
+    }
+}
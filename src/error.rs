@@ -38,6 +38,18 @@ pub enum SignerError {
     #[error("Signer not available: {0}")]
     NotAvailable(String),
 
+    /// Operation did not complete before its deadline
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    /// Transaction is malformed and would be rejected by the runtime
+    #[error("Invalid transaction: {0}")]
+    InvalidTransaction(String),
+
+    /// Serialized transaction exceeds the network packet size
+    #[error("Transaction too large: {0}")]
+    TransactionTooLarge(String),
+
     /// IO error (file operations)
     #[error("IO error: {0}")]
     IoError(String),
@@ -47,6 +59,38 @@ pub enum SignerError {
     Other(String),
 }
 
+impl SignerError {
+    /// Suggested HTTP status code for a gateway fronting a signer
+    ///
+    /// - `400` for invalid input or configuration (`InvalidPublicKey`,
+    ///   `SerializationError`, `ConfigError`, `InvalidTransaction`)
+    /// - `413` when the transaction does not fit in a packet (`TransactionTooLarge`)
+    /// - `422` when the signer could not sign the given transaction (`SigningFailed`)
+    /// - `502` when the upstream signing service failed (`RemoteApiError`, `HttpError`)
+    /// - `503` when the signer is not available (`NotAvailable`)
+    /// - `504` when the operation ran out of time (`Timeout`)
+    /// - `500` for everything else, including an unusable local key
+    ///
+    /// The error variants do not distinguish policy denials, so those surface as
+    /// `502` through the remote error they arrive in.
+    pub fn suggested_http_status(&self) -> u16 {
+        match self {
+            SignerError::InvalidPublicKey(_)
+            | SignerError::SerializationError(_)
+            | SignerError::ConfigError(_)
+            | SignerError::InvalidTransaction(_) => 400,
+            SignerError::TransactionTooLarge(_) => 413,
+            SignerError::SigningFailed(_) => 422,
+            SignerError::RemoteApiError(_) | SignerError::HttpError(_) => 502,
+            SignerError::NotAvailable(_) => 503,
+            SignerError::Timeout(_) => 504,
+            SignerError::InvalidPrivateKey(_) | SignerError::IoError(_) | SignerError::Other(_) => {
+                500
+            }
+        }
+    }
+}
+
 impl From<std::io::Error> for SignerError {
     fn from(err: std::io::Error) -> Self {
         SignerError::IoError(err.to_string())
@@ -59,7 +103,27 @@ impl From<serde_json::Error> for SignerError {
     }
 }
 
-#[cfg(any(feature = "vault", feature = "privy", feature = "turnkey"))]
+#[cfg(any(
+    feature = "vault",
+    feature = "privy",
+    feature = "turnkey",
+    feature = "gcp-kms",
+    feature = "trezor",
+    feature = "dfns",
+    feature = "web3auth",
+    feature = "aws-secrets-manager",
+    feature = "gcp-secret-manager",
+    feature = "onepassword",
+    feature = "infisical",
+    feature = "doppler",
+    feature = "http-remote",
+    feature = "akeyless",
+    feature = "conjur",
+    feature = "bitgo",
+    feature = "cobo",
+    feature = "anchorage",
+    feature = "utila"
+))]
 impl From<reqwest::Error> for SignerError {
     fn from(err: reqwest::Error) -> Self {
         SignerError::HttpError(err.to_string())
@@ -86,8 +150,43 @@ impl fmt::Debug for SignerError {
             }
             SignerError::ConfigError(_) => write!(f, "SignerError::ConfigError([REDACTED])"),
             SignerError::NotAvailable(_) => write!(f, "SignerError::NotAvailable([REDACTED])"),
+            SignerError::Timeout(_) => write!(f, "SignerError::Timeout([REDACTED])"),
+            SignerError::InvalidTransaction(_) => {
+                write!(f, "SignerError::InvalidTransaction([REDACTED])")
+            }
+            SignerError::TransactionTooLarge(_) => {
+                write!(f, "SignerError::TransactionTooLarge([REDACTED])")
+            }
             SignerError::IoError(_) => write!(f, "SignerError::IoError([REDACTED])"),
             SignerError::Other(_) => write!(f, "SignerError::Other([REDACTED])"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggested_http_status() {
+        let cases = [
+            (SignerError::ConfigError("bad url".to_string()), 400),
+            (SignerError::InvalidPublicKey("bad key".to_string()), 400),
+            (
+                SignerError::TransactionTooLarge("1300 bytes".to_string()),
+                413,
+            ),
+            (SignerError::SigningFailed("not a signer".to_string()), 422),
+            (SignerError::RemoteApiError("denied".to_string()), 502),
+            (SignerError::HttpError("connection reset".to_string()), 502),
+            (SignerError::NotAvailable("vault sealed".to_string()), 503),
+            (SignerError::Timeout("deadline exceeded".to_string()), 504),
+            (SignerError::InvalidPrivateKey("bad key".to_string()), 500),
+            (SignerError::Other("cancelled".to_string()), 500),
+        ];
+
+        for (error, status) in cases {
+            assert_eq!(error.suggested_http_status(), status, "{error}");
+        }
+    }
+}
@@ -0,0 +1,458 @@
+//! Google Cloud KMS signer integration
+
+mod types;
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use types::{AsymmetricSignRequest, AsymmetricSignResponse, CryptoKeyVersion, PublicKeyResponse};
+
+/// DER prefix of an Ed25519 `SubjectPublicKeyInfo`, followed by the 32 key bytes
+const ED25519_SPKI_PREFIX: [u8; 12] = [
+    0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00,
+];
+
+/// Cloud KMS algorithm name for Ed25519 signing keys
+const ED25519_ALGORITHM: &str = "EC_SIGN_ED25519";
+
+/// Google Cloud KMS signer using `asymmetricSign` on an Ed25519 key version
+#[derive(Clone)]
+pub struct GcpKmsSigner {
+    key_version_name: String,
+    access_token: String,
+    api_base_url: String,
+    client: reqwest::Client,
+    public_key: Pubkey,
+}
+
+impl std::fmt::Debug for GcpKmsSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcpKmsSigner")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GcpKmsSigner {
+    /// Create a new GcpKmsSigner (requires initialization)
+    ///
+    /// # Arguments
+    ///
+    /// * `key_version_name` - Full resource name of the crypto key version, see
+    ///   [`GcpKmsSigner::key_version_name`]
+    /// * `access_token` - OAuth2 access token with `cloudkms.cryptoKeyVersions.useToSign`
+    pub fn new(key_version_name: String, access_token: String) -> Self {
+        Self {
+            key_version_name,
+            access_token,
+            api_base_url: "https://cloudkms.googleapis.com/v1".to_string(),
+            client: reqwest::Client::new(),
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        }
+    }
+
+    /// Build the resource name of a crypto key version
+    ///
+    /// `projects/{project}/locations/{location}/keyRings/{key_ring}/cryptoKeys/{key}/cryptoKeyVersions/{version}`
+    pub fn key_version_name(
+        project: &str,
+        location: &str,
+        key_ring: &str,
+        key: &str,
+        version: u32,
+    ) -> String {
+        format!(
+            "projects/{project}/locations/{location}/keyRings/{key_ring}/cryptoKeys/{key}/cryptoKeyVersions/{version}"
+        )
+    }
+
+    /// Initialize the signer by fetching the public key of the key version
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        let pubkey = self.fetch_public_key().await?;
+        self.public_key = pubkey;
+        Ok(())
+    }
+
+    /// Send a request with the bearer token and fail on non-success statuses
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+        operation: &str,
+    ) -> Result<reqwest::Response, SignerError> {
+        let response = request.bearer_auth(&self.access_token).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+            #[cfg(feature = "unsafe-debug")]
+            log::error!("GCP KMS {operation} error - status: {status}, response: {error_text}");
+
+            #[cfg(not(feature = "unsafe-debug"))]
+            log::error!("GCP KMS {operation} error - status: {status}");
+
+            return Err(SignerError::RemoteApiError(format!("API error {status}")));
+        }
+
+        Ok(response)
+    }
+
+    /// Fetch and parse the Ed25519 public key of the key version
+    async fn fetch_public_key(&self) -> Result<Pubkey, SignerError> {
+        let url = format!("{}/{}/publicKey", self.api_base_url, self.key_version_name);
+
+        let response = self.send(self.client.get(&url), "getPublicKey").await?;
+        let public_key: PublicKeyResponse = response.json().await?;
+
+        if public_key.algorithm != ED25519_ALGORITHM {
+            return Err(SignerError::ConfigError(format!(
+                "GCP KMS key algorithm is {}, expected {ED25519_ALGORITHM}",
+                public_key.algorithm
+            )));
+        }
+
+        Self::pubkey_from_pem(&public_key.pem)
+    }
+
+    /// Extract the raw Ed25519 key from a PEM-encoded `SubjectPublicKeyInfo`
+    fn pubkey_from_pem(pem: &str) -> Result<Pubkey, SignerError> {
+        let body: String = pem
+            .lines()
+            .filter(|line| !line.starts_with("-----"))
+            .map(str::trim)
+            .collect();
+
+        let der = STANDARD.decode(body).map_err(|e| {
+            SignerError::InvalidPublicKey(format!("Failed to decode public key PEM: {e}"))
+        })?;
+
+        match der.strip_prefix(&ED25519_SPKI_PREFIX[..]) {
+            Some(key) if key.len() == 32 => Pubkey::try_from(key)
+                .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid public key: {e}"))),
+            _ => Err(SignerError::InvalidPublicKey(
+                "Public key is not an Ed25519 SubjectPublicKeyInfo".to_string(),
+            )),
+        }
+    }
+
+    /// Sign message bytes using `asymmetricSign`
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let url = format!(
+            "{}/{}:asymmetricSign",
+            self.api_base_url, self.key_version_name
+        );
+
+        let request = AsymmetricSignRequest {
+            data: STANDARD.encode(message),
+        };
+
+        let response = self
+            .send(self.client.post(&url).json(&request), "asymmetricSign")
+            .await?;
+        let sign_response: AsymmetricSignResponse = response.json().await?;
+
+        let sig_bytes = STANDARD.decode(&sign_response.signature).map_err(|e| {
+            SignerError::SerializationError(format!("Failed to decode signature: {e}"))
+        })?;
+
+        Signature::try_from(sig_bytes.as_slice())
+            .map_err(|_| SignerError::SigningFailed("Invalid signature length".to_string()))
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for GcpKmsSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        // Read the key version metadata and check that it can still sign
+        let url = format!("{}/{}", self.api_base_url, self.key_version_name);
+
+        let response = match self.send(self.client.get(&url), "get").await {
+            Ok(response) => response,
+            Err(_) => return false,
+        };
+
+        match response.json::<CryptoKeyVersion>().await {
+            Ok(version) => version.state == "ENABLED",
+            Err(_) => false,
+        }
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("gcp-kms", self.public_key, self.latency_class())
+            .with_key_id(&self.key_version_name)
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=gcp-kms key_version_name={} access_token=[REDACTED] pubkey={}",
+            self.key_version_name, self.public_key
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        if !self.key_version_name.starts_with("projects/")
+            || !self.key_version_name.contains("/cryptoKeyVersions/")
+        {
+            return Err(SignerError::ConfigError(
+                "GCP KMS key_version_name must be a full crypto key version resource name"
+                    .to_string(),
+            ));
+        }
+
+        if self.access_token.is_empty() {
+            return Err(SignerError::ConfigError(
+                "GCP KMS access_token must not be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use wiremock::{
+        matchers::{body_json, header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const TEST_KEY_VERSION: &str =
+        "projects/test-project/locations/global/keyRings/test-ring/cryptoKeys/test-key/cryptoKeyVersions/1";
+
+    fn create_test_pem(pubkey: &Pubkey) -> String {
+        let mut der = ED25519_SPKI_PREFIX.to_vec();
+        der.extend_from_slice(&pubkey.to_bytes());
+        format!(
+            "-----BEGIN PUBLIC KEY-----\n{}\n-----END PUBLIC KEY-----\n",
+            STANDARD.encode(der)
+        )
+    }
+
+    fn create_test_signer(api_base_url: String) -> GcpKmsSigner {
+        let mut signer = GcpKmsSigner::new(TEST_KEY_VERSION.to_string(), "test-token".to_string());
+        signer.api_base_url = api_base_url;
+        signer
+    }
+
+    async fn mount_public_key(mock_server: &MockServer, pubkey: &Pubkey, algorithm: &str) {
+        Mock::given(method("GET"))
+            .and(path(format!("/{TEST_KEY_VERSION}/publicKey")))
+            .and(header("Authorization", "Bearer test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "pem": create_test_pem(pubkey),
+                "algorithm": algorithm,
+                "name": TEST_KEY_VERSION
+            })))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[test]
+    fn test_key_version_name() {
+        let name =
+            GcpKmsSigner::key_version_name("test-project", "global", "test-ring", "test-key", 1);
+        assert_eq!(name, TEST_KEY_VERSION);
+    }
+
+    #[tokio::test]
+    async fn test_gcp_kms_init() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        mount_public_key(&mock_server, &pubkey, ED25519_ALGORITHM).await;
+
+        let mut signer = create_test_signer(mock_server.uri());
+        assert!(!signer.is_available().await);
+
+        signer.init().await.unwrap();
+        assert_eq!(signer.pubkey(), pubkey);
+    }
+
+    #[tokio::test]
+    async fn test_gcp_kms_init_rejects_non_ed25519_key() {
+        let mock_server = MockServer::start().await;
+        mount_public_key(&mock_server, &Pubkey::new_unique(), "EC_SIGN_P256_SHA256").await;
+
+        let mut signer = create_test_signer(mock_server.uri());
+        let result = signer.init().await;
+
+        assert!(matches!(result, Err(SignerError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_pubkey_from_pem_invalid() {
+        let pem = format!(
+            "-----BEGIN PUBLIC KEY-----\n{}\n-----END PUBLIC KEY-----\n",
+            STANDARD.encode([1u8; 44])
+        );
+
+        let result = GcpKmsSigner::pubkey_from_pem(&pem);
+        assert!(matches!(result, Err(SignerError::InvalidPublicKey(_))));
+    }
+
+    #[tokio::test]
+    async fn test_gcp_kms_sign_transaction() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        mount_public_key(&mock_server, &pubkey, ED25519_ALGORITHM).await;
+
+        let mut tx = create_test_transaction(&pubkey);
+        let message = tx.message_data();
+        let signature = keypair_sign_message(&keypair, &message);
+
+        Mock::given(method("POST"))
+            .and(path(format!("/{TEST_KEY_VERSION}:asymmetricSign")))
+            .and(header("Authorization", "Bearer test-token"))
+            .and(body_json(
+                serde_json::json!({ "data": STANDARD.encode(&message) }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "signature": STANDARD.encode(signature.as_ref()),
+                "name": TEST_KEY_VERSION
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = create_test_signer(mock_server.uri());
+        signer.init().await.unwrap();
+
+        let SignedTransaction {
+            base64_tx: serialized,
+            signature: result,
+            ..
+        } = signer.sign_transaction(&mut tx).await.unwrap();
+
+        assert_eq!(result, signature);
+        assert_eq!(tx.signatures[0], signature);
+        assert!(!serialized.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_gcp_kms_sign_message_unauthorized() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path(format!("/{TEST_KEY_VERSION}:asymmetricSign")))
+            .respond_with(ResponseTemplate::new(403))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = create_test_signer(mock_server.uri());
+        let result = signer.sign_message(b"test").await;
+
+        assert!(matches!(result, Err(SignerError::RemoteApiError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_gcp_kms_is_available() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("/{TEST_KEY_VERSION}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "name": TEST_KEY_VERSION,
+                "state": "ENABLED",
+                "algorithm": ED25519_ALGORITHM
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = create_test_signer(mock_server.uri());
+        assert!(signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_gcp_kms_is_not_available_when_disabled() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path(format!("/{TEST_KEY_VERSION}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "name": TEST_KEY_VERSION,
+                "state": "DISABLED"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = create_test_signer(mock_server.uri());
+        assert!(!signer.is_available().await);
+    }
+
+    #[test]
+    fn test_gcp_kms_validate_config() {
+        let signer = create_test_signer("https://cloudkms.googleapis.com/v1".to_string());
+        assert!(signer.validate_config().is_ok());
+
+        let signer = GcpKmsSigner::new("test-key".to_string(), "test-token".to_string());
+        assert!(matches!(
+            signer.validate_config(),
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_gcp_kms_config_debug() {
+        let signer = GcpKmsSigner::new(TEST_KEY_VERSION.to_string(), "secret-token".to_string());
+
+        let output = signer.config_debug();
+        assert!(output.contains("backend=gcp-kms"));
+        assert!(output.contains(TEST_KEY_VERSION));
+        assert!(!output.contains("secret-token"));
+    }
+}
@@ -0,0 +1,28 @@
+//! Google Cloud KMS API types
+
+use serde::{Deserialize, Serialize};
+
+/// Request body for `cryptoKeyVersions.asymmetricSign`
+#[derive(Serialize)]
+pub struct AsymmetricSignRequest {
+    /// Base64-encoded data to sign (Ed25519 keys sign the raw data, not a digest)
+    pub data: String,
+}
+
+#[derive(Deserialize)]
+pub struct AsymmetricSignResponse {
+    pub signature: String,
+}
+
+/// Response of `cryptoKeyVersions.getPublicKey`
+#[derive(Deserialize)]
+pub struct PublicKeyResponse {
+    pub pem: String,
+    pub algorithm: String,
+}
+
+/// Subset of the `CryptoKeyVersion` resource used for health checks
+#[derive(Deserialize)]
+pub struct CryptoKeyVersion {
+    pub state: String,
+}
@@ -0,0 +1,209 @@
+//! Minimal Assuan client for talking to gpg-agent
+//!
+//! Assuan is line based: the client sends a command, the server answers with any
+//! number of `D` (data), `S` (status), `#` (comment) and `INQUIRE` lines, and ends
+//! with `OK` or `ERR <code> <description>`. Binary data in `D` lines is
+//! percent-escaped.
+
+use crate::error::SignerError;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufStream};
+
+/// Longest line the Assuan protocol allows, including the trailing newline
+const MAX_LINE_LEN: usize = 1000;
+
+/// GnuPG error codes (the low 16 bits of an `ERR` code) we map specially
+const GPG_ERR_BAD_PASSPHRASE: u32 = 11;
+const GPG_ERR_NO_SECKEY: u32 = 17;
+const GPG_ERR_CANCELED: u32 = 99;
+
+pub struct Connection<S> {
+    stream: BufStream<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Connection<S> {
+    /// Wrap a freshly opened stream and consume the server's greeting
+    pub async fn open(stream: S) -> Result<Self, SignerError> {
+        let mut connection = Self {
+            stream: BufStream::new(stream),
+        };
+
+        let greeting = connection.read_line().await?;
+        if !(greeting == "OK" || greeting.starts_with("OK ")) {
+            return Err(SignerError::NotAvailable(
+                "gpg-agent did not greet the connection".to_string(),
+            ));
+        }
+
+        Ok(connection)
+    }
+
+    /// Send `command` and collect its data
+    ///
+    /// An `INQUIRE` for `inquire.0` is answered with `inquire.1`; any other inquiry
+    /// (such as `PINENTRY_LAUNCHED`) gets an empty answer.
+    pub async fn command(
+        &mut self,
+        command: &str,
+        inquire: Option<(&str, &[u8])>,
+    ) -> Result<Vec<u8>, SignerError> {
+        self.write(format!("{command}\n").as_bytes()).await?;
+
+        let mut data = Vec::new();
+        loop {
+            let line = self.read_line().await?;
+
+            if line == "OK" || line.starts_with("OK ") {
+                return Ok(data);
+            } else if let Some(escaped) = line.strip_prefix("D ") {
+                data.extend(unescape(escaped)?);
+            } else if let Some(error) = line.strip_prefix("ERR ") {
+                return Err(agent_error(error));
+            } else if let Some(keyword) = line.strip_prefix("INQUIRE ") {
+                let keyword = keyword.split(' ').next().unwrap_or_default();
+                match inquire {
+                    Some((expected, answer)) if expected == keyword => {
+                        self.send_data(answer).await?
+                    }
+                    _ => self.write(b"END\n").await?,
+                }
+            }
+            // Status ("S ...") and comment ("# ...") lines carry nothing we need
+        }
+    }
+
+    async fn send_data(&mut self, data: &[u8]) -> Result<(), SignerError> {
+        let mut line = String::from("D ");
+        for byte in data {
+            match byte {
+                b'%' | b'\r' | b'\n' => line.push_str(&format!("%{byte:02X}")),
+                _ => line.push(*byte as char),
+            }
+            // Leave room for one more escaped byte and the newline
+            if line.len() >= MAX_LINE_LEN - 4 {
+                line.push('\n');
+                self.write(&latin1(&line)).await?;
+                line = String::from("D ");
+            }
+        }
+        if line.len() > 2 {
+            line.push('\n');
+            self.write(&latin1(&line)).await?;
+        }
+
+        self.write(b"END\n").await
+    }
+
+    async fn write(&mut self, bytes: &[u8]) -> Result<(), SignerError> {
+        self.stream.write_all(bytes).await.map_err(io_error)?;
+        self.stream.flush().await.map_err(io_error)
+    }
+
+    async fn read_line(&mut self) -> Result<String, SignerError> {
+        let mut line = Vec::new();
+        let read = self
+            .stream
+            .read_until(b'\n', &mut line)
+            .await
+            .map_err(io_error)?;
+        if read == 0 {
+            return Err(SignerError::NotAvailable(
+                "gpg-agent closed the connection".to_string(),
+            ));
+        }
+        if line.len() > MAX_LINE_LEN {
+            return Err(SignerError::SerializationError(
+                "gpg-agent sent an over-long line".to_string(),
+            ));
+        }
+
+        line.pop();
+        // D lines may carry raw high bytes; keep them one char per byte
+        Ok(line.iter().map(|b| *b as char).collect())
+    }
+}
+
+/// Encode a string built one char per byte back into bytes
+fn latin1(line: &str) -> Vec<u8> {
+    line.chars().map(|c| c as u8).collect()
+}
+
+fn io_error(e: std::io::Error) -> SignerError {
+    SignerError::NotAvailable(format!("gpg-agent I/O: {e}"))
+}
+
+/// Undo the percent-escaping of a `D` line
+pub fn unescape(escaped: &str) -> Result<Vec<u8>, SignerError> {
+    let bytes = latin1(escaped);
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| {
+                    SignerError::SerializationError(
+                        "gpg-agent sent an invalid escape sequence".to_string(),
+                    )
+                })?;
+            out.push(hex);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    Ok(out)
+}
+
+/// Map an `ERR <code> <description>` reply to a `SignerError`
+fn agent_error(error: &str) -> SignerError {
+    let (code, description) = error.split_once(' ').unwrap_or((error, ""));
+    let description = if description.is_empty() {
+        "unknown error"
+    } else {
+        description
+    };
+
+    match code.parse::<u32>().map(|code| code & 0xFFFF) {
+        Ok(GPG_ERR_CANCELED) => {
+            SignerError::SigningFailed("Signing was cancelled in pinentry".to_string())
+        }
+        Ok(GPG_ERR_BAD_PASSPHRASE) => {
+            SignerError::SigningFailed("gpg-agent rejected the passphrase".to_string())
+        }
+        Ok(GPG_ERR_NO_SECKEY) => {
+            SignerError::ConfigError("gpg-agent has no secret key for this keygrip".to_string())
+        }
+        _ => SignerError::RemoteApiError(format!("gpg-agent error: {description}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(unescape("a%25b%0A%0Dc").unwrap(), b"a%b\n\rc");
+        assert!(unescape("bad%zz").is_err());
+    }
+
+    #[test]
+    fn test_agent_error_codes() {
+        // Error source "gpg-agent" (5) in the high bits
+        assert!(matches!(
+            agent_error("83886179 Operation cancelled <Pinentry>"),
+            SignerError::SigningFailed(_)
+        ));
+        assert!(matches!(
+            agent_error("67108881 No secret key <GPG Agent>"),
+            SignerError::ConfigError(_)
+        ));
+        assert!(matches!(
+            agent_error("67108949 Not implemented"),
+            SignerError::RemoteApiError(_)
+        ));
+    }
+}
@@ -0,0 +1,456 @@
+//! gpg-agent signer integration
+//!
+//! Signs with an Ed25519 key managed by gpg-agent, including keys on an OpenPGP
+//! smartcard, over the agent's Assuan socket. Keys are selected by keygrip, as
+//! listed by `gpg --list-secret-keys --with-keygrip`.
+//!
+//! The agent may ask for a passphrase or card PIN through pinentry. It uses the
+//! display and terminal it was started with, so run
+//! `gpg-connect-agent updatestartuptty /bye` first when signing from a terminal.
+
+mod assuan;
+mod sexp;
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use sexp::Sexp;
+use std::path::PathBuf;
+
+/// Curve names gpg-agent uses for Ed25519 keys (name and OID)
+const ED25519_CURVES: [&str; 2] = ["ed25519", "1.3.6.1.4.1.11591.15.1"];
+
+/// Find the agent socket the way GnuPG does: `gpgconf`, else `$GNUPGHOME` or `~/.gnupg`
+fn default_socket_path() -> Result<PathBuf, SignerError> {
+    if let Ok(output) = std::process::Command::new("gpgconf")
+        .args(["--list-dirs", "agent-socket"])
+        .output()
+    {
+        let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if output.status.success() && !path.is_empty() {
+            return Ok(PathBuf::from(path));
+        }
+    }
+
+    if let Some(home) = std::env::var_os("GNUPGHOME") {
+        return Ok(PathBuf::from(home).join("S.gpg-agent"));
+    }
+
+    std::env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join(".gnupg").join("S.gpg-agent"))
+        .ok_or_else(|| SignerError::ConfigError("Cannot locate the gpg-agent socket".to_string()))
+}
+
+/// Extract the Ed25519 public key from a `READKEY` reply
+fn parse_public_key(sexp: &Sexp) -> Result<Pubkey, SignerError> {
+    let curve = sexp
+        .find_value(b"curve")
+        .map(|curve| String::from_utf8_lossy(curve).to_lowercase())
+        .unwrap_or_default();
+    if !ED25519_CURVES.contains(&curve.as_str()) {
+        return Err(SignerError::ConfigError(format!(
+            "gpg-agent key is not an Ed25519 signing key (curve '{curve}')"
+        )));
+    }
+
+    let q = sexp
+        .find_value(b"q")
+        .ok_or_else(|| SignerError::InvalidPublicKey("gpg-agent key has no point".to_string()))?;
+    // Native EdDSA points may carry a 0x40 prefix byte
+    let point = match q {
+        [0x40, point @ ..] if point.len() == 32 => point,
+        point => point,
+    };
+
+    Pubkey::try_from(point).map_err(|_| {
+        SignerError::InvalidPublicKey("gpg-agent key point is not 32 bytes".to_string())
+    })
+}
+
+/// Assemble a Solana signature from an EdDSA `sig-val` reply
+fn parse_signature(sexp: &Sexp) -> Result<Signature, SignerError> {
+    let malformed =
+        || SignerError::SigningFailed("gpg-agent returned a malformed signature".to_string());
+    let eddsa = sexp.find(b"eddsa").ok_or_else(malformed)?;
+    let r = eddsa.find_value(b"r").ok_or_else(malformed)?;
+    let s = eddsa.find_value(b"s").ok_or_else(malformed)?;
+    if r.len() > 32 || s.len() > 32 {
+        return Err(malformed());
+    }
+
+    // Leading zero bytes of r and s may be stripped, as with any MPI
+    let mut bytes = [0u8; 64];
+    bytes[32 - r.len()..32].copy_from_slice(r);
+    bytes[64 - s.len()..].copy_from_slice(s);
+
+    Ok(Signature::from(bytes))
+}
+
+/// Signer backed by an Ed25519 key managed by gpg-agent
+#[derive(Debug, Clone)]
+pub struct GpgAgentSigner {
+    keygrip: String,
+    socket_path: Option<PathBuf>,
+    public_key: Pubkey,
+}
+
+impl GpgAgentSigner {
+    /// Create a new GpgAgentSigner for the key with the given keygrip (requires initialization)
+    ///
+    /// The keygrip is the 40-hex-digit identifier shown by
+    /// `gpg --list-secret-keys --with-keygrip`; use the Ed25519 signing subkey's.
+    pub fn new(keygrip: &str) -> Result<Self, SignerError> {
+        if keygrip.len() != 40 || !keygrip.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(SignerError::ConfigError(
+                "A keygrip must be 40 hexadecimal digits".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            keygrip: keygrip.to_ascii_uppercase(),
+            socket_path: None,
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        })
+    }
+
+    /// Talk to the agent listening on `socket_path` instead of the default socket
+    pub fn with_socket_path(mut self, socket_path: PathBuf) -> Self {
+        self.socket_path = Some(socket_path);
+        self
+    }
+
+    /// Initialize the signer by reading the key's public key from the agent
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        if self.socket_path.is_none() {
+            self.socket_path = Some(default_socket_path()?);
+        }
+
+        self.public_key = self.read_public_key().await?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    async fn connect(&self) -> Result<assuan::Connection<tokio::net::UnixStream>, SignerError> {
+        let path = self.socket_path.as_ref().ok_or_else(|| {
+            SignerError::ConfigError(
+                "GpgAgentSigner is not initialized; call init() first".to_string(),
+            )
+        })?;
+
+        let stream = tokio::net::UnixStream::connect(path).await.map_err(|e| {
+            SignerError::NotAvailable(format!(
+                "Failed to connect to gpg-agent at {}: {e}",
+                path.display()
+            ))
+        })?;
+
+        assuan::Connection::open(stream).await
+    }
+
+    #[cfg(unix)]
+    async fn read_public_key(&self) -> Result<Pubkey, SignerError> {
+        let mut connection = self.connect().await?;
+        let reply = connection
+            .command(&format!("READKEY {}", self.keygrip), None)
+            .await?;
+
+        parse_public_key(&Sexp::parse(&reply)?)
+    }
+
+    #[cfg(unix)]
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let mut connection = self.connect().await?;
+
+        connection
+            .command(&format!("SIGKEY {}", self.keygrip), None)
+            .await?;
+        // EdDSA signs the data itself; the agent asks for it instead of a digest
+        connection
+            .command("SETHASH --inquire", Some(("TBSDATA", message)))
+            .await?;
+        let reply = connection.command("PKSIGN", None).await?;
+
+        let signature = parse_signature(&Sexp::parse(&reply)?)?;
+        if !TransactionUtil::verify_signature(&self.public_key, message, &signature) {
+            return Err(SignerError::SigningFailed(
+                "gpg-agent returned a signature that does not verify".to_string(),
+            ));
+        }
+
+        Ok(signature)
+    }
+
+    #[cfg(not(unix))]
+    async fn read_public_key(&self) -> Result<Pubkey, SignerError> {
+        Err(SignerError::NotAvailable(
+            "The gpg-agent signer only supports Unix domain sockets".to_string(),
+        ))
+    }
+
+    #[cfg(not(unix))]
+    async fn sign_bytes(&self, _message: &[u8]) -> Result<Signature, SignerError> {
+        Err(SignerError::NotAvailable(
+            "The gpg-agent signer only supports Unix domain sockets".to_string(),
+        ))
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for GpgAgentSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Check that the agent is reachable and still holds the key
+    /// Signs one at a time; the agent serializes card operations anyway
+    async fn sign_all_transactions(
+        &self,
+        txs: &mut [Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        crate::batch::sign_sequentially(self, txs).await
+    }
+
+    async fn is_available(&self) -> bool {
+        matches!(self.read_public_key().await, Ok(pubkey) if pubkey == self.public_key)
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        // Smartcard-backed keys and pinentry prompts make signing slow
+        LatencyClass::Hardware
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        let metadata = SignerMetadata::new("gpg-agent", self.public_key, self.latency_class())
+            .with_key_id(&self.keygrip);
+
+        match &self.socket_path {
+            Some(path) => metadata.with_endpoint(path.display().to_string()),
+            None => metadata,
+        }
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=gpg-agent keygrip={} socket={} pubkey={}",
+            self.keygrip,
+            self.socket_path
+                .as_ref()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| "<default>".to_string()),
+            self.public_key
+        )
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::UnixListener;
+
+    const KEYGRIP: &str = "0123456789ABCDEF0123456789ABCDEF01234567";
+
+    fn escape(bytes: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for byte in bytes {
+            match byte {
+                b'%' | b'\r' | b'\n' => out.extend(format!("%{byte:02X}").bytes()),
+                _ => out.push(*byte),
+            }
+        }
+        out
+    }
+
+    fn atom(bytes: &[u8]) -> Vec<u8> {
+        let mut out = format!("{}:", bytes.len()).into_bytes();
+        out.extend_from_slice(bytes);
+        out
+    }
+
+    /// Serve a fake gpg-agent holding `keypair` under `KEYGRIP`
+    fn spawn_agent(keypair: Keypair) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "solana-signers-gpg-agent-{}-{}.sock",
+            std::process::id(),
+            Pubkey::new_unique()
+        ));
+        // Socket files outlive the test process; clear one left by an earlier run
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        let pubkey = keypair_pubkey(&keypair).to_bytes();
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let (read, mut write) = stream.into_split();
+                let mut lines = BufReader::new(read);
+                write.write_all(b"OK Pleased to meet you\n").await.unwrap();
+
+                let mut tbs = Vec::new();
+                let mut line = Vec::new();
+                while lines.read_until(b'\n', &mut line).await.unwrap() > 0 {
+                    let command = String::from_utf8_lossy(&line).trim_end().to_string();
+                    line.clear();
+                    let mut reply = Vec::new();
+
+                    if command == format!("READKEY {KEYGRIP}") {
+                        let mut q = vec![0x40];
+                        q.extend_from_slice(&pubkey);
+                        let mut sexp =
+                            b"(10:public-key(3:ecc(5:curve7:Ed25519)(5:flags5:eddsa)(1:q".to_vec();
+                        sexp.extend(atom(&q));
+                        sexp.extend_from_slice(b")))");
+                        reply.extend_from_slice(b"D ");
+                        reply.extend(escape(&sexp));
+                        reply.extend_from_slice(b"\nOK\n");
+                    } else if command.starts_with("READKEY") || command.starts_with("SIGKEY 0000") {
+                        reply.extend_from_slice(b"ERR 67108881 No secret key <GPG Agent>\n");
+                    } else if command.starts_with("SIGKEY") {
+                        reply.extend_from_slice(b"OK\n");
+                    } else if command == "SETHASH --inquire" {
+                        write.write_all(b"INQUIRE TBSDATA\n").await.unwrap();
+                        loop {
+                            let mut data = Vec::new();
+                            lines.read_until(b'\n', &mut data).await.unwrap();
+                            data.pop();
+                            if data == b"END" {
+                                break;
+                            }
+                            let escaped: String = data[2..].iter().map(|b| *b as char).collect();
+                            tbs.extend(assuan::unescape(&escaped).unwrap());
+                        }
+                        reply.extend_from_slice(b"OK\n");
+                    } else if command == "PKSIGN" {
+                        // Exercise the inquiry pinentry triggers before signing
+                        write
+                            .write_all(b"S PROGRESS x\nINQUIRE PINENTRY_LAUNCHED 1234\n")
+                            .await
+                            .unwrap();
+                        let mut end = Vec::new();
+                        lines.read_until(b'\n', &mut end).await.unwrap();
+
+                        let signature = keypair_sign_message(&keypair, &tbs);
+                        let bytes: &[u8] = signature.as_ref();
+                        // Strip leading zeros from r and s as libgcrypt may
+                        let r = &bytes[..32];
+                        let r = &r[r.iter().take_while(|b| **b == 0).count()..];
+                        let mut sexp = b"(7:sig-val(5:eddsa(1:r".to_vec();
+                        sexp.extend(atom(r));
+                        sexp.extend_from_slice(b")(1:s");
+                        sexp.extend(atom(&bytes[32..]));
+                        sexp.extend_from_slice(b")))");
+                        reply.extend_from_slice(b"D ");
+                        reply.extend(escape(&sexp));
+                        reply.extend_from_slice(b"\nOK\n");
+                        tbs.clear();
+                    } else {
+                        reply.extend_from_slice(b"ERR 67108949 Unknown command\n");
+                    }
+
+                    write.write_all(&reply).await.unwrap();
+                }
+            }
+        });
+
+        path
+    }
+
+    #[tokio::test]
+    async fn test_gpg_agent_sign_message_and_transaction() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        // Newlines and percent signs must survive the Assuan escaping
+        let message = b"line one\nline two 100%\r\n";
+        let expected = keypair_sign_message(&keypair, message);
+        let path = spawn_agent(keypair);
+
+        let mut signer = GpgAgentSigner::new(KEYGRIP).unwrap().with_socket_path(path);
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert!(signer.is_available().await);
+        assert_eq!(signer.sign_message(message).await.unwrap(), expected);
+
+        let mut tx = create_test_transaction(&pubkey);
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+    }
+
+    #[tokio::test]
+    async fn test_gpg_agent_unknown_keygrip() {
+        let path = spawn_agent(Keypair::new());
+
+        let mut signer = GpgAgentSigner::new(&"0".repeat(40))
+            .unwrap()
+            .with_socket_path(path);
+        assert!(matches!(
+            signer.init().await,
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_gpg_agent_rejects_non_ed25519_keys() {
+        let sexp = Sexp::parse(b"(10:public-key(3:ecc(5:curve10:Curve25519)(1:q1:\x40)))").unwrap();
+        assert!(matches!(
+            parse_public_key(&sexp),
+            Err(SignerError::ConfigError(_))
+        ));
+
+        assert!(GpgAgentSigner::new("not-a-keygrip").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_gpg_agent_unreachable() {
+        let signer = GpgAgentSigner::new(KEYGRIP)
+            .unwrap()
+            .with_socket_path("/nonexistent/S.gpg-agent".into());
+
+        assert!(!signer.is_available().await);
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::NotAvailable(_))
+        ));
+    }
+}
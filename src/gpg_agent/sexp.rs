@@ -0,0 +1,123 @@
+//! Canonical S-expressions, as gpg-agent uses for keys and signatures
+
+use crate::error::SignerError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sexp {
+    Atom(Vec<u8>),
+    List(Vec<Sexp>),
+}
+
+impl Sexp {
+    /// Parse one canonical S-expression, e.g. `(3:foo(3:bar1:x))`
+    pub fn parse(bytes: &[u8]) -> Result<Sexp, SignerError> {
+        let (sexp, rest) = Self::parse_one(bytes)?;
+        if !rest.is_empty() {
+            return Err(malformed());
+        }
+        Ok(sexp)
+    }
+
+    fn parse_one(bytes: &[u8]) -> Result<(Sexp, &[u8]), SignerError> {
+        match bytes.first() {
+            Some(b'(') => {
+                let mut items = Vec::new();
+                let mut rest = &bytes[1..];
+                loop {
+                    match rest.first() {
+                        Some(b')') => return Ok((Sexp::List(items), &rest[1..])),
+                        Some(_) => {
+                            let (item, remaining) = Self::parse_one(rest)?;
+                            items.push(item);
+                            rest = remaining;
+                        }
+                        None => return Err(malformed()),
+                    }
+                }
+            }
+            Some(b'0'..=b'9') => {
+                let colon = bytes
+                    .iter()
+                    .position(|b| *b == b':')
+                    .ok_or_else(malformed)?;
+                let len: usize = std::str::from_utf8(&bytes[..colon])
+                    .ok()
+                    .and_then(|digits| digits.parse().ok())
+                    .ok_or_else(malformed)?;
+                let start = colon + 1;
+                let atom = bytes
+                    .get(start..start.saturating_add(len))
+                    .ok_or_else(malformed)?;
+                Ok((Sexp::Atom(atom.to_vec()), &bytes[start + len..]))
+            }
+            _ => Err(malformed()),
+        }
+    }
+
+    /// Name of a list, i.e. its leading atom
+    pub fn name(&self) -> Option<&[u8]> {
+        match self {
+            Sexp::List(items) => match items.first() {
+                Some(Sexp::Atom(name)) => Some(name),
+                _ => None,
+            },
+            Sexp::Atom(_) => None,
+        }
+    }
+
+    /// Depth-first search for the first list named `name`
+    pub fn find(&self, name: &[u8]) -> Option<&Sexp> {
+        if self.name() == Some(name) {
+            return Some(self);
+        }
+        match self {
+            Sexp::List(items) => items.iter().find_map(|item| item.find(name)),
+            Sexp::Atom(_) => None,
+        }
+    }
+
+    /// Second element of a `(name value)` list
+    pub fn value(&self) -> Option<&[u8]> {
+        match self {
+            Sexp::List(items) => match items.get(1) {
+                Some(Sexp::Atom(value)) => Some(value),
+                _ => None,
+            },
+            Sexp::Atom(_) => None,
+        }
+    }
+
+    /// Value of the first `(name value)` list anywhere inside this expression
+    pub fn find_value(&self, name: &[u8]) -> Option<&[u8]> {
+        self.find(name).and_then(Sexp::value)
+    }
+}
+
+fn malformed() -> SignerError {
+    SignerError::SerializationError("gpg-agent returned a malformed S-expression".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_find() {
+        let sexp =
+            Sexp::parse(b"(10:public-key(3:ecc(5:curve7:Ed25519)(5:flags5:eddsa)(1:q2:\x40\x01)))")
+                .unwrap();
+
+        assert_eq!(sexp.name(), Some(&b"public-key"[..]));
+        assert_eq!(sexp.find_value(b"curve"), Some(&b"Ed25519"[..]));
+        assert_eq!(sexp.find_value(b"q"), Some(&[0x40, 0x01][..]));
+        assert_eq!(sexp.find_value(b"missing"), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed() {
+        assert!(Sexp::parse(b"(3:ab)").is_err());
+        assert!(Sexp::parse(b"(3:abc").is_err());
+        assert!(Sexp::parse(b"(3:abc))").is_err());
+        assert!(Sexp::parse(b"x").is_err());
+    }
+}
@@ -0,0 +1,461 @@
+//! Remote gRPC signer integration
+//!
+//! Talks to any server implementing the `solana_signers.v1.RemoteSigner` service
+//! defined in `remote_signer.proto` (shipped next to this module). Organizations
+//! can centralize signing, policy checks and audit logging in their own service
+//! while applications keep using [`SolanaSigner`].
+
+mod proto;
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use std::time::Duration;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::transport::{Channel, Endpoint};
+use tonic::Code;
+
+/// Fully qualified name of the gRPC service this client speaks
+pub const SERVICE_NAME: &str = proto::SERVICE;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn status_error(status: tonic::Status) -> SignerError {
+    let message = format!(
+        "gRPC signer returned {:?}: {}",
+        status.code(),
+        status.message()
+    );
+
+    match status.code() {
+        Code::Unavailable => SignerError::NotAvailable(message),
+        Code::DeadlineExceeded => SignerError::Timeout(message),
+        Code::Unauthenticated | Code::InvalidArgument => SignerError::ConfigError(message),
+        Code::PermissionDenied | Code::FailedPrecondition => SignerError::SigningFailed(message),
+        _ => SignerError::RemoteApiError(message),
+    }
+}
+
+/// Signer backed by a remote service speaking the `RemoteSigner` gRPC protocol
+#[derive(Clone)]
+pub struct GrpcRemoteSigner {
+    endpoint: String,
+    channel: Option<Channel>,
+    auth_token: Option<String>,
+    timeout: Duration,
+    public_key: Pubkey,
+}
+
+impl std::fmt::Debug for GrpcRemoteSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GrpcRemoteSigner")
+            .field("endpoint", &self.endpoint)
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GrpcRemoteSigner {
+    /// Create a new GrpcRemoteSigner for a plaintext endpoint like `http://signer:50051` (requires initialization)
+    ///
+    /// For TLS or custom connection settings, build a [`Channel`] yourself and use
+    /// [`GrpcRemoteSigner::with_channel`].
+    pub fn new(endpoint: String) -> Self {
+        Self {
+            endpoint,
+            channel: None,
+            auth_token: None,
+            timeout: DEFAULT_TIMEOUT,
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        }
+    }
+
+    /// Create a new GrpcRemoteSigner over an existing channel (requires initialization)
+    pub fn with_channel(channel: Channel) -> Self {
+        Self {
+            channel: Some(channel),
+            ..Self::new("<custom channel>".to_string())
+        }
+    }
+
+    /// Send `authorization: Bearer <token>` with every call
+    pub fn with_auth_token(mut self, token: String) -> Self {
+        self.auth_token = Some(token);
+        self
+    }
+
+    /// Set the deadline for each call (30 seconds by default)
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Initialize the signer by connecting and fetching the server's public key
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        if self.channel.is_none() {
+            let endpoint = Endpoint::from_shared(self.endpoint.clone())
+                .map_err(|e| SignerError::ConfigError(format!("Invalid gRPC endpoint: {e}")))?
+                .connect_timeout(self.timeout);
+            let channel = endpoint.connect().await.map_err(|e| {
+                SignerError::NotAvailable(format!(
+                    "Failed to connect to gRPC signer {}: {e}",
+                    self.endpoint
+                ))
+            })?;
+            self.channel = Some(channel);
+        }
+
+        let response: proto::GetPubkeyResponse = self
+            .call(proto::GET_PUBKEY_PATH, proto::GetPubkeyRequest {})
+            .await?;
+        self.public_key = Pubkey::try_from(response.pubkey.as_slice()).map_err(|_| {
+            SignerError::InvalidPublicKey("gRPC signer returned a malformed public key".to_string())
+        })?;
+
+        Ok(())
+    }
+
+    async fn call<Req, Resp>(&self, path: &'static str, message: Req) -> Result<Resp, SignerError>
+    where
+        Req: prost::Message + Send + Sync + 'static,
+        Resp: prost::Message + Default + Send + Sync + 'static,
+    {
+        let channel = self.channel.clone().ok_or_else(|| {
+            SignerError::ConfigError(
+                "GrpcRemoteSigner is not initialized; call init() first".to_string(),
+            )
+        })?;
+
+        let mut request = tonic::Request::new(message);
+        request.set_timeout(self.timeout);
+        if let Some(token) = &self.auth_token {
+            let value = format!("Bearer {token}").parse().map_err(|_| {
+                SignerError::ConfigError("gRPC auth token is not a valid header value".to_string())
+            })?;
+            request.metadata_mut().insert("authorization", value);
+        }
+
+        let mut grpc = tonic::client::Grpc::new(channel);
+        grpc.ready().await.map_err(|e| {
+            SignerError::NotAvailable(format!("gRPC signer {} is not ready: {e}", self.endpoint))
+        })?;
+
+        let response = grpc
+            .unary(
+                request,
+                PathAndQuery::from_static(path),
+                tonic_prost::ProstCodec::default(),
+            )
+            .await
+            .map_err(status_error)?;
+
+        Ok(response.into_inner())
+    }
+
+    /// Parse a returned signature and check it against our key and `message`
+    fn checked_signature(
+        &self,
+        signature: &[u8],
+        message: &[u8],
+    ) -> Result<Signature, SignerError> {
+        let signature = Signature::try_from(signature).map_err(|_| {
+            SignerError::SigningFailed("gRPC signer returned a malformed signature".to_string())
+        })?;
+
+        if !TransactionUtil::verify_signature(&self.public_key, message, &signature) {
+            return Err(SignerError::SigningFailed(
+                "gRPC signer returned a signature that does not verify".to_string(),
+            ));
+        }
+
+        Ok(signature)
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+        partial: bool,
+    ) -> Result<SignedTransaction, SignerError> {
+        let request = proto::SignTransactionRequest {
+            transaction: bincode::serialize(transaction)
+                .map_err(|e| SignerError::SerializationError(e.to_string()))?,
+            partial,
+        };
+        let response: proto::SignTransactionResponse =
+            self.call(proto::SIGN_TRANSACTION_PATH, request).await?;
+
+        let signature = self.checked_signature(&response.signature, &transaction.message_data())?;
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for GrpcRemoteSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx, false).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let response: proto::SignMessageResponse = self
+            .call(
+                proto::SIGN_MESSAGE_PATH,
+                proto::SignMessageRequest {
+                    message: message.to_vec(),
+                },
+            )
+            .await?;
+
+        self.checked_signature(&response.signature, message)
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx, true).await
+    }
+
+    /// Ask the server's `Health` method whether it can sign
+    async fn is_available(&self) -> bool {
+        self.call::<_, proto::HealthResponse>(proto::HEALTH_PATH, proto::HealthRequest {})
+            .await
+            .map(|response| response.serving)
+            .unwrap_or(false)
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("grpc", self.public_key, self.latency_class())
+            .with_endpoint(&self.endpoint)
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=grpc endpoint={} auth_token={} pubkey={}",
+            self.endpoint,
+            if self.auth_token.is_some() {
+                "[REDACTED]"
+            } else {
+                "none"
+            },
+            self.public_key
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use std::convert::Infallible;
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+    use tonic::body::Body;
+    use tonic::codegen::{http, BoxFuture, Service};
+    use tonic::server::Grpc;
+    use tonic::transport::server::TcpIncoming;
+    use tonic::{Request, Response, Status};
+    use tonic_prost::ProstCodec;
+
+    /// Adapts a closure into a unary gRPC handler
+    struct Unary<F>(F);
+
+    impl<F, Req, Resp> Service<Request<Req>> for Unary<F>
+    where
+        F: FnMut(Req) -> Result<Resp, Status>,
+    {
+        type Response = Response<Resp>;
+        type Error = Status;
+        type Future = std::future::Ready<Result<Response<Resp>, Status>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Status>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<Req>) -> Self::Future {
+            std::future::ready((self.0)(request.into_inner()).map(Response::new))
+        }
+    }
+
+    /// Reference server signing with an in-memory keypair
+    #[derive(Clone)]
+    struct TestServer {
+        keypair: Arc<Keypair>,
+        refuse_transactions: bool,
+    }
+
+    impl Service<http::Request<Body>> for TestServer {
+        type Response = http::Response<Body>;
+        type Error = Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+            let server = self.clone();
+            Box::pin(async move {
+                let authorized = request
+                    .headers()
+                    .get("authorization")
+                    .is_some_and(|value| value == "Bearer secret");
+                if !authorized {
+                    return Ok(Status::unauthenticated("missing token").into_http());
+                }
+
+                let keypair = server.keypair.clone();
+                let response = match request.uri().path() {
+                    proto::GET_PUBKEY_PATH => {
+                        Grpc::new(ProstCodec::default())
+                            .unary(
+                                Unary(move |_: proto::GetPubkeyRequest| {
+                                    Ok(proto::GetPubkeyResponse {
+                                        pubkey: keypair_pubkey(&keypair).to_bytes().to_vec(),
+                                    })
+                                }),
+                                request,
+                            )
+                            .await
+                    }
+                    proto::SIGN_MESSAGE_PATH => {
+                        Grpc::new(ProstCodec::default())
+                            .unary(
+                                Unary(move |req: proto::SignMessageRequest| {
+                                    Ok(proto::SignMessageResponse {
+                                        signature: keypair_sign_message(&keypair, &req.message)
+                                            .as_ref()
+                                            .to_vec(),
+                                    })
+                                }),
+                                request,
+                            )
+                            .await
+                    }
+                    proto::SIGN_TRANSACTION_PATH => {
+                        let refuse = server.refuse_transactions;
+                        Grpc::new(ProstCodec::default())
+                            .unary(
+                                Unary(move |req: proto::SignTransactionRequest| {
+                                    if refuse {
+                                        return Err(Status::permission_denied("policy violation"));
+                                    }
+                                    let tx: Transaction = bincode::deserialize(&req.transaction)
+                                        .map_err(|e| Status::invalid_argument(e.to_string()))?;
+                                    Ok(proto::SignTransactionResponse {
+                                        signature: keypair_sign_message(
+                                            &keypair,
+                                            &tx.message_data(),
+                                        )
+                                        .as_ref()
+                                        .to_vec(),
+                                    })
+                                }),
+                                request,
+                            )
+                            .await
+                    }
+                    proto::HEALTH_PATH => {
+                        Grpc::new(ProstCodec::default())
+                            .unary(
+                                Unary(|_: proto::HealthRequest| {
+                                    Ok(proto::HealthResponse { serving: true })
+                                }),
+                                request,
+                            )
+                            .await
+                    }
+                    _ => Status::unimplemented("unknown method").into_http(),
+                };
+
+                Ok(response)
+            })
+        }
+    }
+
+    async fn start_server(keypair: Keypair, refuse_transactions: bool) -> String {
+        let incoming = TcpIncoming::bind("127.0.0.1:0".parse().unwrap()).unwrap();
+        let address = incoming.local_addr().unwrap();
+        let server = TestServer {
+            keypair: Arc::new(keypair),
+            refuse_transactions,
+        };
+
+        tokio::spawn(tonic::transport::Server::builder().serve_with_incoming(server, incoming));
+
+        format!("http://{address}")
+    }
+
+    #[tokio::test]
+    async fn test_grpc_sign_message_and_transaction() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let expected = keypair_sign_message(&keypair, b"hello");
+        let endpoint = start_server(keypair, false).await;
+
+        let mut signer = GrpcRemoteSigner::new(endpoint).with_auth_token("secret".to_string());
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert_eq!(signer.sign_message(b"hello").await.unwrap(), expected);
+        assert!(signer.is_available().await);
+
+        let mut tx = create_test_transaction(&pubkey);
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+    }
+
+    #[tokio::test]
+    async fn test_grpc_status_mapping() {
+        let endpoint = start_server(Keypair::new(), true).await;
+
+        let mut unauthenticated = GrpcRemoteSigner::new(endpoint.clone());
+        assert!(matches!(
+            unauthenticated.init().await,
+            Err(SignerError::ConfigError(_))
+        ));
+
+        let mut signer = GrpcRemoteSigner::new(endpoint).with_auth_token("secret".to_string());
+        signer.init().await.unwrap();
+        let mut tx = create_test_transaction(&signer.pubkey());
+        assert!(matches!(
+            signer.sign_transaction(&mut tx).await,
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_grpc_unreachable_and_uninitialized() {
+        let mut signer = GrpcRemoteSigner::new("http://127.0.0.1:1".to_string())
+            .with_timeout(Duration::from_secs(2));
+
+        assert!(!signer.is_available().await);
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::ConfigError(_))
+        ));
+        assert!(matches!(
+            signer.init().await,
+            Err(SignerError::NotAvailable(_))
+        ));
+    }
+}
@@ -0,0 +1,56 @@
+//! Message types of `remote_signer.proto`, kept in sync by hand
+//!
+//! Written out with `prost` derives rather than generated, so building the crate
+//! does not need `protoc`.
+
+/// Fully qualified gRPC service name
+pub const SERVICE: &str = "solana_signers.v1.RemoteSigner";
+
+pub const GET_PUBKEY_PATH: &str = "/solana_signers.v1.RemoteSigner/GetPubkey";
+pub const SIGN_MESSAGE_PATH: &str = "/solana_signers.v1.RemoteSigner/SignMessage";
+pub const SIGN_TRANSACTION_PATH: &str = "/solana_signers.v1.RemoteSigner/SignTransaction";
+pub const HEALTH_PATH: &str = "/solana_signers.v1.RemoteSigner/Health";
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetPubkeyRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GetPubkeyResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub pubkey: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignMessageRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    pub message: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignMessageResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignTransactionRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    pub transaction: Vec<u8>,
+    #[prost(bool, tag = "2")]
+    pub partial: bool,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignTransactionResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct HealthRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct HealthResponse {
+    #[prost(bool, tag = "1")]
+    pub serving: bool,
+}
@@ -0,0 +1,303 @@
+//! HD wallet signer deriving many Solana accounts from one seed
+//!
+//! Accounts use the standard Solana path `m/44'/501'/{index}'/0'` with SLIP-0010
+//! Ed25519 derivation, so the same seed yields the same addresses as Phantom,
+//! Solflare and `solana-keygen`.
+
+use crate::memory::MemorySigner;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerMetadata};
+use crate::{error::SignerError, traits::SolanaSigner};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+const HARDENED: u32 = 0x8000_0000;
+
+/// Derive the SLIP-0010 Ed25519 private key at a fully hardened `path`
+fn derive_ed25519_key(seed: &[u8], path: &[u32]) -> [u8; 32] {
+    let hmac = |key: &[u8], data: &[&[u8]]| {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+        for part in data {
+            mac.update(part);
+        }
+        let output = mac.finalize().into_bytes();
+        let mut key = [0u8; 32];
+        let mut chain_code = [0u8; 32];
+        key.copy_from_slice(&output[..32]);
+        chain_code.copy_from_slice(&output[32..]);
+        (key, chain_code)
+    };
+
+    let (mut key, mut chain_code) = hmac(b"ed25519 seed", &[seed]);
+    for index in path {
+        (key, chain_code) = hmac(
+            &chain_code,
+            &[&[0], &key, &(index | HARDENED).to_be_bytes()],
+        );
+    }
+
+    key
+}
+
+/// Derived accounts, indexed both ways
+#[derive(Default)]
+struct Accounts {
+    by_index: HashMap<u32, Arc<MemorySigner>>,
+    by_pubkey: HashMap<Pubkey, u32>,
+}
+
+/// Signer backed by an HD wallet seed, deriving per-index Solana accounts
+///
+/// As a [`SolanaSigner`] it signs with the default account (index 0 unless changed
+/// with [`HdWalletSigner::with_default_index`]); use
+/// [`HdWalletSigner::signer_for_index`] or [`HdWalletSigner::signer_for_pubkey`] to
+/// sign with the others.
+pub struct HdWalletSigner {
+    seed: Vec<u8>,
+    default_signer: Arc<MemorySigner>,
+    accounts: RwLock<Accounts>,
+}
+
+impl std::fmt::Debug for HdWalletSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HdWalletSigner")
+            .field("pubkey", &self.default_signer.pubkey())
+            .finish_non_exhaustive()
+    }
+}
+
+impl HdWalletSigner {
+    /// Create a signer from a BIP-39 seed (16 to 64 bytes)
+    ///
+    /// To start from a mnemonic, convert it to a seed with a BIP-39 library first.
+    pub fn new(seed: &[u8]) -> Result<Self, SignerError> {
+        if !(16..=64).contains(&seed.len()) {
+            return Err(SignerError::InvalidPrivateKey(
+                "HD wallet seed must be between 16 and 64 bytes".to_string(),
+            ));
+        }
+
+        let default_signer = Arc::new(Self::derive(seed, 0)?);
+        let signer = Self {
+            seed: seed.to_vec(),
+            default_signer: default_signer.clone(),
+            accounts: RwLock::new(Accounts::default()),
+        };
+        signer.insert(0, default_signer)?;
+
+        Ok(signer)
+    }
+
+    /// Sign with the account at `index` when used as a [`SolanaSigner`]
+    pub fn with_default_index(mut self, index: u32) -> Result<Self, SignerError> {
+        self.default_signer = self.signer_for_index(index)?;
+        Ok(self)
+    }
+
+    /// Derivation path of the account at `index`
+    pub fn derivation_path(index: u32) -> String {
+        format!("m/44'/501'/{index}'/0'")
+    }
+
+    fn derive(seed: &[u8], index: u32) -> Result<MemorySigner, SignerError> {
+        let key = derive_ed25519_key(seed, &[44, 501, index, 0]);
+        MemorySigner::from_ed25519_signing_key(ed25519_dalek::SigningKey::from_bytes(&key))
+    }
+
+    fn insert(&self, index: u32, signer: Arc<MemorySigner>) -> Result<(), SignerError> {
+        let mut accounts = self
+            .accounts
+            .write()
+            .map_err(|_| SignerError::Other("HD wallet account cache poisoned".to_string()))?;
+        accounts.by_pubkey.insert(signer.pubkey(), index);
+        accounts.by_index.insert(index, signer);
+        Ok(())
+    }
+
+    /// Get the signer for the account at `index`, deriving it on first use
+    pub fn signer_for_index(&self, index: u32) -> Result<Arc<MemorySigner>, SignerError> {
+        if index >= HARDENED {
+            return Err(SignerError::ConfigError(format!(
+                "HD wallet account index must be below {HARDENED}"
+            )));
+        }
+
+        let cached = self
+            .accounts
+            .read()
+            .map_err(|_| SignerError::Other("HD wallet account cache poisoned".to_string()))?
+            .by_index
+            .get(&index)
+            .cloned();
+        if let Some(signer) = cached {
+            return Ok(signer);
+        }
+
+        let signer = Arc::new(Self::derive(&self.seed, index)?);
+        self.insert(index, signer.clone())?;
+        Ok(signer)
+    }
+
+    /// Get the signer for `pubkey` among the accounts derived so far
+    ///
+    /// Accounts are only known once derived; call [`HdWalletSigner::derive_range`]
+    /// up front to make a block of indexes discoverable by public key.
+    pub fn signer_for_pubkey(&self, pubkey: &Pubkey) -> Option<Arc<MemorySigner>> {
+        let accounts = self.accounts.read().ok()?;
+        let index = accounts.by_pubkey.get(pubkey)?;
+        accounts.by_index.get(index).cloned()
+    }
+
+    /// Index of the derived account with `pubkey`
+    pub fn index_of(&self, pubkey: &Pubkey) -> Option<u32> {
+        self.accounts.read().ok()?.by_pubkey.get(pubkey).copied()
+    }
+
+    /// Derive every account in `indexes` and return their public keys in order
+    pub fn derive_range(&self, indexes: std::ops::Range<u32>) -> Result<Vec<Pubkey>, SignerError> {
+        indexes
+            .map(|index| self.signer_for_index(index).map(|signer| signer.pubkey()))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for HdWalletSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.default_signer.pubkey()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.default_signer.sign_transaction(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.default_signer.sign_message(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.default_signer.sign_partial_transaction(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("hd-wallet", self.pubkey(), self.latency_class())
+    }
+
+    fn config_debug(&self) -> String {
+        let derived = self
+            .accounts
+            .read()
+            .map(|accounts| accounts.by_index.len())
+            .unwrap_or_default();
+        format!(
+            "backend=hd-wallet seed=[REDACTED] derived_accounts={} pubkey={}",
+            derived,
+            self.pubkey()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::create_test_transaction;
+
+    fn hex(bytes: [u8; 32]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn test_slip10_ed25519_vectors() {
+        // SLIP-0010 Ed25519 test vector 1
+        let seed: Vec<u8> = (0u8..16).collect();
+
+        assert_eq!(
+            hex(derive_ed25519_key(&seed, &[])),
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+        );
+        assert_eq!(
+            hex(derive_ed25519_key(&seed, &[0])),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+        assert_eq!(
+            hex(derive_ed25519_key(&seed, &[0, 1])),
+            "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signer_for_index_and_pubkey() {
+        let signer = HdWalletSigner::new(&[7u8; 64]).unwrap();
+
+        let first = signer.signer_for_index(0).unwrap();
+        let second = signer.signer_for_index(1).unwrap();
+        assert_eq!(signer.pubkey(), first.pubkey());
+        assert_ne!(first.pubkey(), second.pubkey());
+        assert!(Arc::ptr_eq(&second, &signer.signer_for_index(1).unwrap()));
+
+        let found = signer.signer_for_pubkey(&second.pubkey()).unwrap();
+        assert_eq!(found.pubkey(), second.pubkey());
+        assert_eq!(signer.index_of(&second.pubkey()), Some(1));
+        assert!(signer.signer_for_pubkey(&Pubkey::new_unique()).is_none());
+
+        let mut tx = create_test_transaction(&second.pubkey());
+        found.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+    }
+
+    #[test]
+    fn test_derive_range_is_deterministic() {
+        let signer = HdWalletSigner::new(&[1u8; 32]).unwrap();
+        let other = HdWalletSigner::new(&[1u8; 32]).unwrap();
+
+        let pubkeys = signer.derive_range(0..5).unwrap();
+        assert_eq!(pubkeys, other.derive_range(0..5).unwrap());
+        assert_eq!(signer.index_of(&pubkeys[4]), Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_default_index() {
+        let signer = HdWalletSigner::new(&[3u8; 32])
+            .unwrap()
+            .with_default_index(3)
+            .unwrap();
+        let expected = signer.signer_for_index(3).unwrap();
+
+        assert_eq!(signer.pubkey(), expected.pubkey());
+        assert_eq!(
+            signer.sign_message(b"hello").await.unwrap(),
+            expected.sign_message(b"hello").await.unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_seed_and_index() {
+        assert!(matches!(
+            HdWalletSigner::new(&[0u8; 8]),
+            Err(SignerError::InvalidPrivateKey(_))
+        ));
+
+        let signer = HdWalletSigner::new(&[0u8; 16]).unwrap();
+        assert!(matches!(
+            signer.signer_for_index(HARDENED),
+            Err(SignerError::ConfigError(_))
+        ));
+        assert_eq!(HdWalletSigner::derivation_path(7), "m/44'/501'/7'/0'");
+    }
+}
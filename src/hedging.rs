@@ -0,0 +1,104 @@
+//! Hedged requests for idempotent remote signer calls
+
+use std::future::Future;
+use std::time::Duration;
+
+/// Configuration for hedging a request against a secondary endpoint
+///
+/// Only used for idempotent, read-only calls such as availability checks. Signing
+/// is never hedged, since a duplicate request could create duplicate activities.
+#[derive(Debug, Clone)]
+pub struct Hedging {
+    /// How long to wait for the primary endpoint before also trying the secondary
+    pub delay: Duration,
+    /// Base URL of the secondary endpoint
+    pub secondary_base_url: String,
+}
+
+/// Run an availability check, hedging against the secondary endpoint if configured
+///
+/// The primary check starts immediately; the secondary starts after `delay`. The
+/// first check to report `true` wins. If one check reports `false`, the result of
+/// the other is awaited before giving up.
+pub(crate) async fn hedged_check<F, Fut>(
+    hedging: Option<&Hedging>,
+    primary_base_url: &str,
+    check: F,
+) -> bool
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let Some(hedging) = hedging else {
+        return check(primary_base_url.to_string()).await;
+    };
+
+    let primary = check(primary_base_url.to_string());
+    let secondary = async {
+        tokio::time::sleep(hedging.delay).await;
+        check(hedging.secondary_base_url.clone()).await
+    };
+    tokio::pin!(primary, secondary);
+
+    tokio::select! {
+        available = &mut primary => available || secondary.await,
+        available = &mut secondary => available || primary.await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    async fn check_after(delay_ms: u64, result: bool) -> bool {
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+        result
+    }
+
+    #[tokio::test]
+    async fn test_hedged_check_secondary_wins() {
+        let hedging = Hedging {
+            delay: Duration::from_millis(10),
+            secondary_base_url: "secondary".to_string(),
+        };
+
+        let started = Instant::now();
+        let available = hedged_check(Some(&hedging), "primary", |base| async move {
+            if base == "primary" {
+                check_after(5_000, true).await
+            } else {
+                check_after(0, true).await
+            }
+        })
+        .await;
+
+        assert!(available);
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_hedged_check_falls_back_when_first_fails() {
+        let hedging = Hedging {
+            delay: Duration::from_millis(10),
+            secondary_base_url: "secondary".to_string(),
+        };
+
+        let available = hedged_check(Some(&hedging), "primary", |base| async move {
+            check_after(0, base == "secondary").await
+        })
+        .await;
+
+        assert!(available);
+    }
+
+    #[tokio::test]
+    async fn test_hedged_check_disabled() {
+        let available = hedged_check(None, "primary", |base| async move {
+            check_after(0, base == "primary").await
+        })
+        .await;
+
+        assert!(available);
+    }
+}
@@ -0,0 +1,112 @@
+//! Shared HTTP client construction for remote signers
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+
+use crate::error::SignerError;
+use crate::traits::SignOptions;
+
+/// Header carrying [`SignOptions::context`] on signing requests
+#[cfg(any(feature = "vault", feature = "turnkey"))]
+pub(crate) const CONTEXT_HEADER: &str = "X-Signer-Context";
+
+/// Settings used to (re)build a remote signer's HTTP client
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HttpClientConfig {
+    /// Static DNS overrides, applied with `reqwest::ClientBuilder::resolve`
+    pub resolve: Vec<(String, SocketAddr)>,
+    /// Client certificate presented for mutual TLS
+    #[cfg(any(feature = "http-remote", feature = "vault"))]
+    pub identity: Option<reqwest::Identity>,
+    /// Extra CA certificates trusted for the server's certificate
+    pub root_certificates: Vec<reqwest::Certificate>,
+    /// Skip server certificate verification; for development only
+    pub accept_invalid_certs: bool,
+    /// Total timeout for each request
+    pub timeout: Option<Duration>,
+    /// The caller supplied their own client, so these settings cannot be applied
+    /// by rebuilding it
+    #[cfg(any(feature = "vault", feature = "privy", feature = "turnkey"))]
+    pub custom_client: bool,
+}
+
+impl HttpClientConfig {
+    /// Build a replacement client after `setting` changed
+    ///
+    /// Fails with `SignerError::ConfigError` when a caller-supplied client is in
+    /// use, rather than silently dropping its proxies, TLS roots and timeouts.
+    #[cfg(any(feature = "vault", feature = "privy", feature = "turnkey"))]
+    pub fn rebuild(&self, setting: &str) -> Result<Client, SignerError> {
+        if self.custom_client {
+            return Err(SignerError::ConfigError(format!(
+                "{setting} cannot be applied to a caller-supplied HTTP client; configure it on that client instead"
+            )));
+        }
+
+        self.build()
+    }
+
+    /// Build a client from these settings
+    pub fn build(&self) -> Result<Client, SignerError> {
+        let mut builder = Client::builder();
+
+        for (domain, addr) in &self.resolve {
+            builder = builder.resolve(domain, *addr);
+        }
+
+        #[cfg(any(feature = "http-remote", feature = "vault"))]
+        if let Some(identity) = &self.identity {
+            builder = builder.identity(identity.clone());
+        }
+
+        for certificate in &self.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+
+        if self.accept_invalid_certs {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        builder
+            .build()
+            .map_err(|e| SignerError::ConfigError(format!("Failed to build HTTP client: {e}")))
+    }
+}
+
+/// Host of `url` for redacted config summaries
+///
+/// Drops credentials, path and query that may be embedded in a configured URL,
+/// and reports `<invalid>` when it does not parse.
+#[cfg(any(
+    feature = "vault",
+    feature = "privy",
+    feature = "turnkey",
+    feature = "akeyless"
+))]
+pub(crate) fn redacted_host(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "<invalid>".to_string())
+}
+
+/// Run `future` with whatever time is left until `deadline`
+///
+/// Returns `SignerError::Timeout` without polling `future` if the deadline has
+/// already passed, and drops the in-flight request if it expires meanwhile.
+pub(crate) async fn with_deadline<T>(
+    deadline: Instant,
+    future: impl Future<Output = Result<T, SignerError>>,
+) -> Result<T, SignerError> {
+    SignOptions::new()
+        .with_deadline(deadline)
+        .within_deadline(future)
+        .await
+}
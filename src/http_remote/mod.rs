@@ -0,0 +1,433 @@
+//! HTTP signing-daemon client
+//!
+//! Talks to an in-house signing service over a small JSON protocol, relative to a
+//! base URL:
+//!
+//! - `GET /pubkey` → `{"pubkey":"<base58>"}`
+//! - `POST /sign_message` with `{"message":"<base64>"}` → `{"signature":"<base58>"}`
+//! - `POST /sign_transaction` with `{"transaction":"<base64 wire transaction>","partial":false}`
+//!   → `{"signature":"<base58>"}`
+//!
+//! Non-2xx responses may carry `{"error":"..."}`. Requests can be authenticated with
+//! a bearer token, a TLS client certificate, or both.
+
+use crate::http_client::{with_deadline, HttpClientConfig};
+use crate::interceptor::{IdentityInterceptor, RequestInterceptor};
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Instant;
+
+#[derive(Deserialize)]
+struct PubkeyResponse {
+    pubkey: String,
+}
+
+#[derive(Serialize)]
+struct SignMessageRequest {
+    message: String,
+}
+
+#[derive(Serialize)]
+struct SignTransactionRequest {
+    transaction: String,
+    partial: bool,
+}
+
+#[derive(Deserialize)]
+struct SignatureResponse {
+    signature: String,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Signer backed by a JSON-over-HTTP signing daemon
+#[derive(Clone)]
+pub struct HttpRemoteSigner {
+    base_url: String,
+    bearer_token: Option<String>,
+    client: Arc<reqwest::Client>,
+    interceptor: Arc<dyn RequestInterceptor>,
+    http_config: HttpClientConfig,
+    public_key: Pubkey,
+}
+
+impl std::fmt::Debug for HttpRemoteSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpRemoteSigner")
+            .field("base_url", &self.base_url)
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpRemoteSigner {
+    /// Create a new HttpRemoteSigner for the daemon at `base_url` (requires initialization)
+    pub fn new(base_url: String) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            bearer_token: None,
+            client: Arc::new(reqwest::Client::new()),
+            interceptor: Arc::new(IdentityInterceptor),
+            http_config: HttpClientConfig::default(),
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        }
+    }
+
+    /// Send `Authorization: Bearer <token>` with every request
+    pub fn with_bearer_token(mut self, token: String) -> Self {
+        self.bearer_token = Some(token);
+        self
+    }
+
+    /// Present a client certificate for mutual TLS
+    ///
+    /// # Arguments
+    ///
+    /// * `certificate_pem` - PEM-encoded client certificate (chain)
+    /// * `private_key_pem` - PEM-encoded PKCS#8 private key for the certificate
+    pub fn with_client_certificate(
+        mut self,
+        certificate_pem: &[u8],
+        private_key_pem: &[u8],
+    ) -> Result<Self, SignerError> {
+        let identity = reqwest::Identity::from_pkcs8_pem(certificate_pem, private_key_pem)
+            .map_err(|e| SignerError::ConfigError(format!("Invalid client certificate: {e}")))?;
+
+        self.http_config.identity = Some(identity);
+        self.client = Arc::new(self.http_config.build()?);
+        Ok(self)
+    }
+
+    /// Trust `ca_certificate_pem` when verifying the daemon's certificate
+    pub fn with_ca_certificate(mut self, ca_certificate_pem: &[u8]) -> Result<Self, SignerError> {
+        let certificate = reqwest::Certificate::from_pem(ca_certificate_pem)
+            .map_err(|e| SignerError::ConfigError(format!("Invalid CA certificate: {e}")))?;
+
+        self.http_config.root_certificates.push(certificate);
+        self.client = Arc::new(self.http_config.build()?);
+        Ok(self)
+    }
+
+    /// Set an interceptor applied to every outbound request
+    pub fn with_request_interceptor(
+        mut self,
+        interceptor: impl RequestInterceptor + 'static,
+    ) -> Self {
+        self.interceptor = Arc::new(interceptor);
+        self
+    }
+
+    /// Initialize the signer by fetching the daemon's public key
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        self.public_key = self.fetch_pubkey().await?;
+        Ok(())
+    }
+
+    /// Sign a transaction within the time remaining until `deadline`
+    ///
+    /// Returns `SignerError::Timeout` immediately if `deadline` has already passed,
+    /// or once it passes while the request is in flight.
+    pub async fn sign_transaction_by_deadline(
+        &self,
+        tx: &mut Transaction,
+        deadline: Instant,
+    ) -> Result<SignedTransaction, SignerError> {
+        with_deadline(deadline, self.sign_and_serialize(tx, false)).await
+    }
+
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+        operation: &str,
+    ) -> Result<reqwest::Response, SignerError> {
+        let request = match &self.bearer_token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        };
+        let response = self.interceptor.intercept(request).send().await?;
+
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+        #[cfg(feature = "unsafe-debug")]
+        log::error!("Signing daemon {operation} error - status: {status}, response: {error_text}");
+
+        #[cfg(not(feature = "unsafe-debug"))]
+        log::error!("Signing daemon {operation} error - status: {status}");
+
+        // Daemons explain policy refusals in the error body; those are safe to surface
+        let reason = serde_json::from_str::<ErrorResponse>(&error_text)
+            .map(|body| format!(": {}", body.error))
+            .unwrap_or_default();
+
+        Err(match status {
+            401 | 403 => SignerError::SigningFailed(format!("Request refused ({status}){reason}")),
+            503 => SignerError::NotAvailable(format!("Signing daemon unavailable{reason}")),
+            _ => SignerError::RemoteApiError(format!("API error {status}{reason}")),
+        })
+    }
+
+    async fn fetch_pubkey(&self) -> Result<Pubkey, SignerError> {
+        let request = self.client.get(format!("{}/pubkey", self.base_url));
+        let response: PubkeyResponse = self.send(request, "pubkey").await?.json().await?;
+
+        response
+            .pubkey
+            .parse()
+            .map_err(|_| SignerError::InvalidPublicKey(response.pubkey))
+    }
+
+    /// Parse a returned signature and check it against our key and `message`
+    fn checked_signature(&self, signature: &str, message: &[u8]) -> Result<Signature, SignerError> {
+        let signature: Signature = signature.parse().map_err(|_| {
+            SignerError::SigningFailed("Signing daemon returned a malformed signature".to_string())
+        })?;
+
+        if !TransactionUtil::verify_signature(&self.public_key, message, &signature) {
+            return Err(SignerError::SigningFailed(
+                "Signing daemon returned a signature that does not verify".to_string(),
+            ));
+        }
+
+        Ok(signature)
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+        partial: bool,
+    ) -> Result<SignedTransaction, SignerError> {
+        let body = SignTransactionRequest {
+            transaction: TransactionUtil::serialize_transaction(transaction)?,
+            partial,
+        };
+        let request = self
+            .client
+            .post(format!("{}/sign_transaction", self.base_url))
+            .json(&body);
+        let response: SignatureResponse =
+            self.send(request, "sign_transaction").await?.json().await?;
+
+        let signature = self.checked_signature(&response.signature, &transaction.message_data())?;
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for HttpRemoteSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx, false).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let request = self
+            .client
+            .post(format!("{}/sign_message", self.base_url))
+            .json(&SignMessageRequest {
+                message: STANDARD.encode(message),
+            });
+        let response: SignatureResponse = self.send(request, "sign_message").await?.json().await?;
+
+        self.checked_signature(&response.signature, message)
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx, true).await
+    }
+
+    /// Check that the daemon answers and still reports the same key
+    async fn is_available(&self) -> bool {
+        matches!(self.fetch_pubkey().await, Ok(pubkey) if pubkey == self.public_key)
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("http-remote", self.public_key, self.latency_class())
+            .with_endpoint(&self.base_url)
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=http-remote url={} bearer_token={} client_certificate={} pubkey={}",
+            self.base_url,
+            if self.bearer_token.is_some() {
+                "[REDACTED]"
+            } else {
+                "none"
+            },
+            self.http_config.identity.is_some(),
+            self.public_key
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        reqwest::Url::parse(&self.base_url)
+            .map_err(|e| SignerError::ConfigError(format!("Invalid signing daemon URL: {e}")))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    async fn daemon(keypair: Keypair) -> MockServer {
+        let server = MockServer::start().await;
+        let pubkey = keypair_pubkey(&keypair);
+        let keypair = Arc::new(keypair);
+
+        Mock::given(method("GET"))
+            .and(path("/pubkey"))
+            .and(header("authorization", "Bearer daemon-token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "pubkey": pubkey.to_string() })),
+            )
+            .mount(&server)
+            .await;
+
+        let signer = keypair.clone();
+        Mock::given(method("POST"))
+            .and(path("/sign_message"))
+            .respond_with(move |request: &Request| {
+                let body: serde_json::Value = request.body_json().unwrap();
+                let message = STANDARD.decode(body["message"].as_str().unwrap()).unwrap();
+                let signature = keypair_sign_message(&signer, &message);
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "signature": signature.to_string() }))
+            })
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/sign_transaction"))
+            .and(body_partial_json(serde_json::json!({ "partial": true })))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .set_body_json(serde_json::json!({ "error": "partial signing disabled" })),
+            )
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/sign_transaction"))
+            .respond_with(move |request: &Request| {
+                let body: serde_json::Value = request.body_json().unwrap();
+                let bytes = STANDARD
+                    .decode(body["transaction"].as_str().unwrap())
+                    .unwrap();
+                let tx: Transaction = bincode::deserialize(&bytes).unwrap();
+                let signature = keypair_sign_message(&keypair, &tx.message_data());
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "signature": signature.to_string() }))
+            })
+            .mount(&server)
+            .await;
+
+        server
+    }
+
+    #[tokio::test]
+    async fn test_http_remote_sign() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let expected = keypair_sign_message(&keypair, b"hello");
+        let server = daemon(keypair).await;
+
+        let mut signer =
+            HttpRemoteSigner::new(server.uri()).with_bearer_token("daemon-token".to_string());
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert!(signer.is_available().await);
+        assert_eq!(signer.sign_message(b"hello").await.unwrap(), expected);
+
+        let mut tx = create_test_transaction(&pubkey);
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+    }
+
+    #[tokio::test]
+    async fn test_http_remote_refusal_and_auth() {
+        let server = daemon(Keypair::new()).await;
+
+        let mut unauthenticated = HttpRemoteSigner::new(server.uri());
+        assert!(unauthenticated.init().await.is_err());
+
+        let mut signer =
+            HttpRemoteSigner::new(server.uri()).with_bearer_token("daemon-token".to_string());
+        signer.init().await.unwrap();
+
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let err = signer.sign_partial_transaction(&mut tx).await.unwrap_err();
+        assert!(matches!(err, SignerError::SigningFailed(_)));
+        assert!(err.to_string().contains("partial signing disabled"));
+    }
+
+    #[tokio::test]
+    async fn test_http_remote_deadline_passed() {
+        let server = daemon(Keypair::new()).await;
+        let mut signer =
+            HttpRemoteSigner::new(server.uri()).with_bearer_token("daemon-token".to_string());
+        signer.init().await.unwrap();
+
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let result = signer
+            .sign_transaction_by_deadline(&mut tx, Instant::now())
+            .await;
+        assert!(matches!(result, Err(SignerError::Timeout(_))));
+    }
+
+    #[test]
+    fn test_http_remote_invalid_certificates() {
+        let signer = HttpRemoteSigner::new("https://signer.internal".to_string());
+
+        assert!(matches!(
+            signer.clone().with_ca_certificate(b"not a certificate"),
+            Err(SignerError::ConfigError(_))
+        ));
+        assert!(matches!(
+            signer.with_client_certificate(b"not a certificate", b"not a key"),
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+}
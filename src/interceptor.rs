@@ -0,0 +1,31 @@
+//! Hooks for transforming outbound HTTP requests made by remote signers
+
+use reqwest::RequestBuilder;
+
+/// Transforms every outbound request made by a remote signer before it is sent
+///
+/// Useful when requests must pass through infrastructure that expects extra
+/// headers, an additional signature, or a wrapped body.
+pub trait RequestInterceptor: Send + Sync {
+    /// Transform the request before it is sent
+    fn intercept(&self, req: RequestBuilder) -> RequestBuilder;
+}
+
+/// Interceptor that leaves requests untouched (the default)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdentityInterceptor;
+
+impl RequestInterceptor for IdentityInterceptor {
+    fn intercept(&self, req: RequestBuilder) -> RequestBuilder {
+        req
+    }
+}
+
+impl<F> RequestInterceptor for F
+where
+    F: Fn(RequestBuilder) -> RequestBuilder + Send + Sync,
+{
+    fn intercept(&self, req: RequestBuilder) -> RequestBuilder {
+        self(req)
+    }
+}
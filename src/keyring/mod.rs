@@ -0,0 +1,200 @@
+//! OS credential store signer integration
+//!
+//! Keeps the keypair in the platform credential store (macOS Keychain, Windows
+//! Credential Manager, or the Secret Service/libsecret on Linux) instead of a file
+//! or environment variable. Keys are stored as base58-encoded 64-byte keypairs,
+//! the same format wallets export.
+
+use crate::memory::MemorySigner;
+use crate::sdk_adapter::{Keypair, Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerMetadata};
+use crate::{error::SignerError, traits::SolanaSigner};
+use ::keyring::Entry;
+
+fn keyring_error(err: ::keyring::Error) -> SignerError {
+    match err {
+        ::keyring::Error::NoEntry => {
+            SignerError::ConfigError("No keypair stored in the OS keyring entry".to_string())
+        }
+        ::keyring::Error::PlatformFailure(e) | ::keyring::Error::NoStorageAccess(e) => {
+            SignerError::NotAvailable(format!("OS keyring unavailable: {e}"))
+        }
+        other => SignerError::ConfigError(format!("OS keyring error: {other}")),
+    }
+}
+
+/// Signer whose keypair is loaded from the OS credential store
+#[derive(Debug)]
+pub struct KeyringSigner {
+    service: String,
+    user: String,
+    signer: MemorySigner,
+}
+
+impl KeyringSigner {
+    /// Load the keypair stored under `service` and `user`
+    pub fn load(service: &str, user: &str) -> Result<Self, SignerError> {
+        let entry = Entry::new(service, user).map_err(keyring_error)?;
+        Self::from_entry(&entry, service, user)
+    }
+
+    /// Store `keypair` under `service` and `user`, replacing any existing key
+    pub fn store(service: &str, user: &str, keypair: &Keypair) -> Result<(), SignerError> {
+        let entry = Entry::new(service, user).map_err(keyring_error)?;
+        Self::store_in_entry(&entry, keypair)
+    }
+
+    /// Generate a new keypair, store it under `service` and `user`, and load it
+    ///
+    /// Fails with `SignerError::ConfigError` if a key is already stored there, so an
+    /// existing key is never overwritten by accident.
+    pub fn generate(service: &str, user: &str) -> Result<Self, SignerError> {
+        let entry = Entry::new(service, user).map_err(keyring_error)?;
+
+        match entry.get_password() {
+            Ok(_) => {
+                return Err(SignerError::ConfigError(format!(
+                    "A keypair is already stored for {service}/{user}"
+                )))
+            }
+            Err(::keyring::Error::NoEntry) => {}
+            Err(e) => return Err(keyring_error(e)),
+        }
+
+        Self::store_in_entry(&entry, &Keypair::new())?;
+        Self::from_entry(&entry, service, user)
+    }
+
+    /// Remove the keypair stored under `service` and `user`
+    pub fn delete(service: &str, user: &str) -> Result<(), SignerError> {
+        Entry::new(service, user)
+            .map_err(keyring_error)?
+            .delete_credential()
+            .map_err(keyring_error)
+    }
+
+    fn from_entry(entry: &Entry, service: &str, user: &str) -> Result<Self, SignerError> {
+        let encoded = entry.get_password().map_err(keyring_error)?;
+        let bytes = bs58::decode(encoded.trim()).into_vec().map_err(|_| {
+            SignerError::InvalidPrivateKey("OS keyring entry is not a base58 keypair".to_string())
+        })?;
+
+        Ok(Self {
+            service: service.to_string(),
+            user: user.to_string(),
+            signer: MemorySigner::from_bytes(&bytes)?,
+        })
+    }
+
+    fn store_in_entry(entry: &Entry, keypair: &Keypair) -> Result<(), SignerError> {
+        entry
+            .set_password(&keypair.to_base58_string())
+            .map_err(keyring_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for KeyringSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.signer.pubkey()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.signer.sign_transaction(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.signer.sign_message(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.signer.sign_partial_transaction(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        // The key was loaded into memory when the signer was created
+        true
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("keyring", self.pubkey(), self.latency_class())
+            .with_key_id(format!("{}/{}", self.service, self.user))
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=keyring service={} user={} pubkey={}",
+            self.service,
+            self.user,
+            self.pubkey()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message};
+
+    fn mock_entry() -> Entry {
+        // Mock entries keep their secret in the entry itself, so reuse the same one
+        ::keyring::set_default_credential_builder(::keyring::mock::default_credential_builder());
+        Entry::new("solana-signers-test", "payer").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_keyring_store_and_load() {
+        let entry = mock_entry();
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+
+        KeyringSigner::store_in_entry(&entry, &keypair).unwrap();
+        let signer = KeyringSigner::from_entry(&entry, "solana-signers-test", "payer").unwrap();
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert_eq!(
+            signer.sign_message(b"hello").await.unwrap(),
+            keypair_sign_message(&keypair, b"hello")
+        );
+        assert!(signer.is_available().await);
+    }
+
+    #[test]
+    fn test_keyring_missing_entry() {
+        let entry = mock_entry();
+
+        let result = KeyringSigner::from_entry(&entry, "solana-signers-test", "payer");
+        assert!(matches!(result, Err(SignerError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_keyring_invalid_entry() {
+        let entry = mock_entry();
+        entry.set_password("not a keypair").unwrap();
+
+        let result = KeyringSigner::from_entry(&entry, "solana-signers-test", "payer");
+        assert!(matches!(result, Err(SignerError::InvalidPrivateKey(_))));
+    }
+
+    #[test]
+    fn test_keyring_config_debug_hides_key() {
+        let entry = mock_entry();
+        let keypair = Keypair::new();
+        KeyringSigner::store_in_entry(&entry, &keypair).unwrap();
+        let signer = KeyringSigner::from_entry(&entry, "solana-signers-test", "payer").unwrap();
+
+        let output = signer.config_debug();
+        assert!(output.contains("service=solana-signers-test"));
+        assert!(!output.contains(&keypair.to_base58_string()));
+    }
+}
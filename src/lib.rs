@@ -10,22 +10,98 @@
 //! - `vault`: HashiCorp Vault integration
 //! - `privy`: Privy API integration
 //! - `turnkey`: Turnkey API integration
+//! - `gcp-kms`: Google Cloud KMS integration
+//! - `trezor`: Trezor hardware wallet via Trezor Bridge
+//! - `yubikey`: YubiKey PIV/OpenPGP key via PC/SC (not part of `all`)
+//! - `dfns`: Dfns wallets API with user action signing
+//! - `web3auth`: Web3Auth user keys reconstructed from an id token
+//! - `keyring`: Keypair stored in the OS credential store (Keychain, Credential Manager, Secret Service)
+//! - `hd-wallet`: Accounts derived from one seed at `m/44'/501'/i'/0'`
+//! - `ssh-agent`: Ed25519 keys held by ssh-agent
+//! - `nitro`: Signing enclave reached over vsock, with attestation checks
+//! - `aws-secrets-manager`: Memory signers loaded from AWS Secrets Manager
+//! - `gcp-secret-manager`: Memory signers loaded from Google Cloud Secret Manager
+//! - `onepassword`: Keypair fetched from a 1Password Connect vault item
+//! - `infisical`: Memory signers loaded from Infisical with machine identity auth
+//! - `doppler`: Memory signers loaded from Doppler, with webhook-triggered reloads
+//! - `conjur`: Memory signers loaded from CyberArk Conjur variables
+//! - `age`: Memory signers decrypted from age-encrypted keypair files
+//! - `grpc`: Remote signing service speaking the crate's gRPC protocol
+//! - `http-remote`: JSON-over-HTTP signing daemon with bearer or mTLS auth
+//! - `uds`: Length-prefixed signing over a Unix socket, with a reusable server
+//! - `mpc`: Adapter for pluggable round-based MPC signing backends
+//! - `remote-wallet`: Hardware wallets via `solana-remote-wallet` (`usb://ledger?key=0`)
+//! - `vault-kv`: Keypairs read from Vault KV v2 and signed locally
+//! - `gpg-agent`: Ed25519 keys managed by gpg-agent, including smartcards
+//! - `offline`: Air-gapped signing through request and signature artifacts
+//! - `akeyless`: Akeyless classic-key signing with access key or cloud identity auth
+//! - `bitgo`: BitGo custody wallets signed through BitGo Express
+//! - `cobo`: Cobo WaaS 2.0 MPC wallets with API-key request signing
+//! - `anchorage`: Anchorage Digital custody with request signing and approval polling
+//! - `utila`: Utila MPC custody with service-account auth and approval polling
 //! - `all`: Enable all signer backends
+//! - `dalek-interop`: Build memory signers from `ed25519_dalek::SigningKey`
+//! - `squads`: Squads v4 multisig wrapper that proposes vault transactions
+//! - `rpc`: `sign_and_send_transaction` helpers over `solana-rpc-client` (requires `sdk-v2`)
+//! - `blocking`: `SolanaSignerBlocking`, a synchronous wrapper for CLIs and non-async code
 //!
 //! ## SDK Version Selection
 //! - `sdk-v2` (default): Use Solana SDK v2.3.x
 //! - `sdk-v3`: Use Solana SDK v3.x
 //!
+//! ## Testing
+//! - `test-utils`: Helpers for downstream tests
+//! - `record`: Record/replay signer wrappers for deterministic integration tests
+//!
 //! **Note**: Only one SDK version can be enabled at a time.
 
+pub mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod config;
+mod env;
+pub mod envelope;
 pub mod error;
+#[cfg(any(feature = "vault", feature = "turnkey"))]
+pub mod hedging;
+#[cfg(any(
+    feature = "vault",
+    feature = "privy",
+    feature = "turnkey",
+    feature = "http-remote",
+    feature = "akeyless"
+))]
+mod http_client;
+#[cfg(any(
+    feature = "vault",
+    feature = "privy",
+    feature = "turnkey",
+    feature = "http-remote",
+    feature = "akeyless"
+))]
+pub mod interceptor;
+pub mod middleware;
+pub mod null;
+pub mod offchain_message;
+pub mod preflight;
+pub mod presigner;
+#[cfg(feature = "record")]
+pub mod record;
+#[cfg(feature = "rpc")]
+pub mod rpc;
 mod sdk_adapter;
-#[cfg(test)]
+pub mod sdk_signer;
+pub mod shared;
+#[cfg(feature = "squads")]
+pub mod squads;
+#[cfg(any(test, feature = "test-utils"))]
 pub mod test_util;
 #[cfg(feature = "integration-tests")]
 pub mod tests;
 pub mod traits;
 pub mod transaction_util;
+pub mod tx_builder;
+pub mod tx_inspect;
 
 #[cfg(feature = "memory")]
 pub mod memory;
@@ -39,16 +115,122 @@ pub mod privy;
 #[cfg(feature = "turnkey")]
 pub mod turnkey;
 
+#[cfg(feature = "gcp-kms")]
+pub mod gcp_kms;
+
+#[cfg(feature = "trezor")]
+pub mod trezor;
+
+#[cfg(feature = "yubikey")]
+pub mod yubikey;
+
+#[cfg(feature = "dfns")]
+pub mod dfns;
+
+#[cfg(feature = "web3auth")]
+pub mod web3auth;
+
+#[cfg(feature = "keyring")]
+pub mod keyring;
+
+#[cfg(feature = "hd-wallet")]
+pub mod hd_wallet;
+
+#[cfg(feature = "ssh-agent")]
+pub mod ssh_agent;
+
+#[cfg(feature = "nitro")]
+pub mod nitro;
+
+#[cfg(feature = "onepassword")]
+pub mod onepassword;
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+#[cfg(feature = "http-remote")]
+pub mod http_remote;
+
+#[cfg(feature = "uds")]
+pub mod uds;
+
+#[cfg(feature = "mpc")]
+pub mod mpc;
+
+#[cfg(feature = "remote-wallet")]
+pub mod remote_wallet;
+
+#[cfg(feature = "gpg-agent")]
+pub mod gpg_agent;
+
+#[cfg(feature = "offline")]
+pub mod offline;
+
+#[cfg(feature = "akeyless")]
+pub mod akeyless;
+
+#[cfg(feature = "bitgo")]
+pub mod bitgo;
+
+#[cfg(feature = "cobo")]
+pub mod cobo;
+
+#[cfg(feature = "anchorage")]
+pub mod anchorage;
+
+#[cfg(feature = "utila")]
+pub mod utila;
+
 // Re-export core types
+pub use batch::{sign_many, sign_stream};
+#[cfg(feature = "blocking")]
+pub use blocking::SolanaSignerBlocking;
+pub use config::SignerConfig;
+pub use envelope::SigningEnvelope;
 pub use error::SignerError;
-pub use traits::SolanaSigner;
+#[cfg(any(
+    feature = "vault",
+    feature = "privy",
+    feature = "turnkey",
+    feature = "http-remote",
+    feature = "akeyless"
+))]
+pub use interceptor::RequestInterceptor;
+pub use middleware::{
+    ComputeBudgetMiddleware, LayeredSigner, PacketSizeMiddleware, SanitizeMiddleware, SignRequest,
+    SignerMiddleware,
+};
+pub use null::NullSigner;
+pub use offchain_message::{OffchainMessage, OffchainMessageFormat};
+pub use preflight::preflight;
+pub use presigner::Presigner;
+#[cfg(feature = "record")]
+pub use record::{RecordingSigner, ReplaySigner};
+#[cfg(feature = "rpc")]
+pub use rpc::SolanaSignerRpcExt;
+pub use sdk_signer::SdkSignerAdapter;
+pub use shared::SharedSigner;
+#[cfg(feature = "squads")]
+pub use squads::SquadsSigner;
+pub use traits::{
+    HealthState, HealthStatus, LatencyClass, SignOptions, SignedTransaction, SignerCapabilities,
+    SignerMetadata, SolanaSigner,
+};
 
 // Re-export signer types
 #[cfg(feature = "memory")]
 pub use memory::MemorySigner;
+#[cfg(any(
+    feature = "aws-secrets-manager",
+    feature = "gcp-secret-manager",
+    feature = "infisical",
+    feature = "doppler",
+    feature = "conjur"
+))]
+pub use memory::RefreshingSigner;
 
 #[cfg(feature = "vault")]
-pub use vault::VaultSigner;
+pub use vault::{KubernetesAuth, VaultAuth, VaultFlavor, VaultSigner};
 
 #[cfg(feature = "privy")]
 pub use privy::PrivySigner;
@@ -56,17 +238,107 @@ pub use privy::PrivySigner;
 #[cfg(feature = "turnkey")]
 pub use turnkey::TurnkeySigner;
 
-use crate::traits::SignedTransaction;
+#[cfg(feature = "gcp-kms")]
+pub use gcp_kms::GcpKmsSigner;
+
+#[cfg(feature = "trezor")]
+pub use trezor::TrezorSigner;
+
+#[cfg(feature = "yubikey")]
+pub use yubikey::YubiKeySigner;
+
+#[cfg(feature = "dfns")]
+pub use dfns::DfnsSigner;
+
+#[cfg(feature = "web3auth")]
+pub use web3auth::Web3AuthSigner;
+
+#[cfg(feature = "keyring")]
+pub use keyring::KeyringSigner;
+
+#[cfg(feature = "hd-wallet")]
+pub use hd_wallet::HdWalletSigner;
+
+#[cfg(feature = "ssh-agent")]
+pub use ssh_agent::SshAgentSigner;
+
+#[cfg(feature = "nitro")]
+pub use nitro::NitroEnclaveSigner;
+
+#[cfg(feature = "onepassword")]
+pub use onepassword::OnePasswordSigner;
+
+#[cfg(feature = "grpc")]
+pub use grpc::GrpcRemoteSigner;
+
+#[cfg(feature = "http-remote")]
+pub use http_remote::HttpRemoteSigner;
+
+#[cfg(feature = "uds")]
+pub use uds::{UdsSigner, UdsSignerServer};
+
+#[cfg(feature = "mpc")]
+pub use mpc::{MpcBackend, MpcSigner};
+
+#[cfg(feature = "remote-wallet")]
+pub use remote_wallet::RemoteWalletSigner;
+
+#[cfg(feature = "vault-kv")]
+pub use vault::VaultKvSigner;
+
+#[cfg(feature = "gpg-agent")]
+pub use gpg_agent::GpgAgentSigner;
+
+#[cfg(feature = "offline")]
+pub use offline::{FileExchange, OfflineSigner, OfflineTransport};
+
+#[cfg(feature = "akeyless")]
+pub use akeyless::{AkeylessAuth, AkeylessSigner};
+
+#[cfg(feature = "bitgo")]
+pub use bitgo::{BitGoSigner, BitGoWalletType};
+
+#[cfg(feature = "cobo")]
+pub use cobo::CoboSigner;
+
+#[cfg(feature = "anchorage")]
+pub use anchorage::AnchorageSigner;
+
+#[cfg(feature = "utila")]
+pub use utila::UtilaSigner;
 
 // Ensure at least one signer backend is enabled
 #[cfg(not(any(
     feature = "memory",
     feature = "vault",
     feature = "privy",
-    feature = "turnkey"
+    feature = "turnkey",
+    feature = "gcp-kms",
+    feature = "trezor",
+    feature = "yubikey",
+    feature = "dfns",
+    feature = "web3auth",
+    feature = "keyring",
+    feature = "hd-wallet",
+    feature = "ssh-agent",
+    feature = "nitro",
+    feature = "onepassword",
+    feature = "grpc",
+    feature = "http-remote",
+    feature = "uds",
+    feature = "mpc",
+    feature = "remote-wallet",
+    feature = "vault-kv",
+    feature = "gpg-agent",
+    feature = "offline",
+    feature = "akeyless",
+    feature = "bitgo",
+    feature = "cobo",
+    feature = "anchorage",
+    feature = "utila"
 )))]
 compile_error!(
-    "At least one signer backend feature must be enabled: memory, vault, privy, or turnkey"
+    "At least one signer backend feature must be enabled, e.g. memory, vault, privy, or turnkey"
 );
 
 /// Unified signer enum supporting multiple backends
@@ -82,6 +354,75 @@ pub enum Signer {
 
     #[cfg(feature = "turnkey")]
     Turnkey(TurnkeySigner),
+
+    #[cfg(feature = "gcp-kms")]
+    GcpKms(GcpKmsSigner),
+
+    #[cfg(feature = "trezor")]
+    Trezor(TrezorSigner),
+
+    #[cfg(feature = "yubikey")]
+    YubiKey(YubiKeySigner),
+
+    #[cfg(feature = "dfns")]
+    Dfns(DfnsSigner),
+
+    #[cfg(feature = "web3auth")]
+    Web3Auth(Web3AuthSigner),
+
+    #[cfg(feature = "keyring")]
+    Keyring(KeyringSigner),
+
+    #[cfg(feature = "hd-wallet")]
+    HdWallet(HdWalletSigner),
+
+    #[cfg(feature = "ssh-agent")]
+    SshAgent(SshAgentSigner),
+
+    #[cfg(feature = "nitro")]
+    NitroEnclave(NitroEnclaveSigner),
+
+    #[cfg(feature = "onepassword")]
+    OnePassword(OnePasswordSigner),
+
+    #[cfg(feature = "grpc")]
+    Grpc(GrpcRemoteSigner),
+
+    #[cfg(feature = "http-remote")]
+    HttpRemote(HttpRemoteSigner),
+
+    #[cfg(feature = "uds")]
+    Uds(UdsSigner),
+
+    #[cfg(feature = "mpc")]
+    Mpc(MpcSigner),
+
+    #[cfg(feature = "remote-wallet")]
+    RemoteWallet(RemoteWalletSigner),
+
+    #[cfg(feature = "vault-kv")]
+    VaultKv(VaultKvSigner),
+
+    #[cfg(feature = "gpg-agent")]
+    GpgAgent(GpgAgentSigner),
+
+    #[cfg(feature = "offline")]
+    Offline(OfflineSigner),
+
+    #[cfg(feature = "akeyless")]
+    Akeyless(AkeylessSigner),
+
+    #[cfg(feature = "bitgo")]
+    BitGo(BitGoSigner),
+
+    #[cfg(feature = "cobo")]
+    Cobo(CoboSigner),
+
+    #[cfg(feature = "anchorage")]
+    Anchorage(AnchorageSigner),
+
+    #[cfg(feature = "utila")]
+    Utila(UtilaSigner),
 }
 
 impl Signer {
@@ -93,6 +434,91 @@ impl Signer {
         )?))
     }
 
+    /// Create a signer from the keypair stored in the OS keyring under `service` and `user`
+    #[cfg(feature = "keyring")]
+    pub fn from_keyring(service: &str, user: &str) -> Result<Self, SignerError> {
+        Ok(Self::Keyring(KeyringSigner::load(service, user)?))
+    }
+
+    /// Create an HD wallet signer from a BIP-39 seed, signing with account 0
+    #[cfg(feature = "hd-wallet")]
+    pub fn from_hd_wallet(seed: &[u8]) -> Result<Self, SignerError> {
+        Ok(Self::HdWallet(HdWalletSigner::new(seed)?))
+    }
+
+    /// Create an MPC signer driving `backend`
+    #[cfg(feature = "mpc")]
+    pub fn from_mpc(backend: impl MpcBackend + 'static) -> Self {
+        Self::Mpc(MpcSigner::new(backend))
+    }
+
+    /// Create a signer for the hardware wallet at a CLI-style `usb://` keypair URI
+    #[cfg(feature = "remote-wallet")]
+    pub fn from_remote_wallet(uri: &str) -> Result<Self, SignerError> {
+        Ok(Self::RemoteWallet(RemoteWalletSigner::new(uri)?))
+    }
+
+    /// Create an offline signer for the cold key `pubkey`, exchanging artifacts through `dir`
+    #[cfg(feature = "offline")]
+    pub fn from_offline_dir(
+        pubkey: sdk_adapter::Pubkey,
+        dir: impl Into<std::path::PathBuf>,
+    ) -> Self {
+        Self::Offline(OfflineSigner::new(pubkey, FileExchange::new(dir)))
+    }
+
+    /// Create an Akeyless signer for the classic key `key_name`
+    #[cfg(feature = "akeyless")]
+    pub fn from_akeyless(
+        auth: AkeylessAuth,
+        key_name: String,
+        pubkey: String,
+    ) -> Result<Self, SignerError> {
+        Ok(Self::Akeyless(AkeylessSigner::new(auth, key_name, pubkey)?))
+    }
+
+    /// Create a Cobo signer for the wallet address `address`
+    #[cfg(feature = "cobo")]
+    pub fn from_cobo(
+        api_secret: &str,
+        wallet_id: String,
+        address: String,
+    ) -> Result<Self, SignerError> {
+        Ok(Self::Cobo(CoboSigner::new(api_secret, wallet_id, address)?))
+    }
+
+    /// Create an Anchorage signer for `address` in the vault `vault_id`
+    #[cfg(feature = "anchorage")]
+    pub fn from_anchorage(
+        api_key: String,
+        signing_secret: &str,
+        vault_id: String,
+        address: String,
+    ) -> Result<Self, SignerError> {
+        Ok(Self::Anchorage(AnchorageSigner::new(
+            api_key,
+            signing_secret,
+            vault_id,
+            address,
+        )?))
+    }
+
+    /// Create a Utila signer for `address` in the vault `vault_id`
+    #[cfg(feature = "utila")]
+    pub fn from_utila(
+        service_account: String,
+        private_key_pem: &str,
+        vault_id: String,
+        address: String,
+    ) -> Result<Self, SignerError> {
+        Ok(Self::Utila(UtilaSigner::new(
+            service_account,
+            private_key_pem,
+            vault_id,
+            address,
+        )?))
+    }
+
     /// Create a Vault signer
     #[cfg(feature = "vault")]
     pub fn from_vault(
@@ -121,6 +547,171 @@ impl Signer {
         Ok(Self::Privy(signer))
     }
 
+    /// Create a Google Cloud KMS signer (requires initialization)
+    #[cfg(feature = "gcp-kms")]
+    pub async fn from_gcp_kms(
+        key_version_name: String,
+        access_token: String,
+    ) -> Result<Self, SignerError> {
+        let mut signer = GcpKmsSigner::new(key_version_name, access_token);
+        signer.init().await?;
+        Ok(Self::GcpKms(signer))
+    }
+
+    /// Create a Trezor signer for the given derivation path (requires a connected device)
+    #[cfg(feature = "trezor")]
+    pub async fn from_trezor(derivation_path: &str) -> Result<Self, SignerError> {
+        let mut signer = TrezorSigner::new(derivation_path)?;
+        signer.init().await?;
+        Ok(Self::Trezor(signer))
+    }
+
+    /// Create a YubiKey signer for the key in `applet` (requires a connected device)
+    #[cfg(feature = "yubikey")]
+    pub async fn from_yubikey(
+        applet: yubikey::YubiKeyApplet,
+        pin: Option<String>,
+    ) -> Result<Self, SignerError> {
+        let mut signer = YubiKeySigner::new(applet);
+        if let Some(pin) = pin {
+            signer = signer.with_pin(pin);
+        }
+        signer.init().await?;
+        Ok(Self::YubiKey(signer))
+    }
+
+    /// Create a Dfns signer (requires initialization)
+    #[cfg(feature = "dfns")]
+    pub async fn from_dfns(
+        auth_token: String,
+        credential_id: String,
+        credential_private_key: String,
+        wallet_id: String,
+    ) -> Result<Self, SignerError> {
+        let mut signer =
+            DfnsSigner::new(auth_token, credential_id, credential_private_key, wallet_id);
+        signer.init().await?;
+        Ok(Self::Dfns(signer))
+    }
+
+    /// Create a Web3Auth signer by reconstructing the user key from the Torus nodes
+    #[cfg(feature = "web3auth")]
+    pub async fn from_web3auth(
+        verifier: String,
+        verifier_id: String,
+        id_token: String,
+        node_urls: Vec<String>,
+    ) -> Result<Self, SignerError> {
+        let mut signer = Web3AuthSigner::new(verifier, verifier_id, id_token, node_urls);
+        signer.init().await?;
+        Ok(Self::Web3Auth(signer))
+    }
+
+    /// Create an ssh-agent signer using the first Ed25519 key in the agent at `$SSH_AUTH_SOCK`
+    #[cfg(feature = "ssh-agent")]
+    pub async fn from_ssh_agent() -> Result<Self, SignerError> {
+        let mut signer = SshAgentSigner::new()?;
+        signer.init().await?;
+        Ok(Self::SshAgent(signer))
+    }
+
+    /// Create a Nitro Enclaves signer for the enclave with context ID `cid` (requires a running enclave)
+    #[cfg(feature = "nitro")]
+    pub async fn from_nitro_enclave(
+        cid: u32,
+        attestation_policy: Option<nitro::AttestationPolicy>,
+    ) -> Result<Self, SignerError> {
+        let mut signer = NitroEnclaveSigner::new(cid);
+        if let Some(policy) = attestation_policy {
+            signer = signer.with_attestation_policy(policy);
+        }
+        signer.init().await?;
+        Ok(Self::NitroEnclave(signer))
+    }
+
+    /// Create a signer from the keypair in a 1Password Connect vault item
+    #[cfg(feature = "onepassword")]
+    pub async fn from_onepassword(
+        connect_host: String,
+        token: String,
+        vault_id: String,
+        item_id: String,
+    ) -> Result<Self, SignerError> {
+        let mut signer = OnePasswordSigner::new(connect_host, token, vault_id, item_id);
+        signer.init().await?;
+        Ok(Self::OnePassword(signer))
+    }
+
+    /// Create a gRPC remote signer for a plaintext endpoint like `http://signer:50051`
+    #[cfg(feature = "grpc")]
+    pub async fn from_grpc(
+        endpoint: String,
+        auth_token: Option<String>,
+    ) -> Result<Self, SignerError> {
+        let mut signer = GrpcRemoteSigner::new(endpoint);
+        if let Some(token) = auth_token {
+            signer = signer.with_auth_token(token);
+        }
+        signer.init().await?;
+        Ok(Self::Grpc(signer))
+    }
+
+    /// Create a signer for the JSON-over-HTTP signing daemon at `base_url`
+    #[cfg(feature = "http-remote")]
+    pub async fn from_http_remote(
+        base_url: String,
+        bearer_token: Option<String>,
+    ) -> Result<Self, SignerError> {
+        let mut signer = HttpRemoteSigner::new(base_url);
+        if let Some(token) = bearer_token {
+            signer = signer.with_bearer_token(token);
+        }
+        signer.init().await?;
+        Ok(Self::HttpRemote(signer))
+    }
+
+    /// Create a signer for the sidecar listening on the Unix socket at `socket_path`
+    #[cfg(feature = "uds")]
+    pub async fn from_uds(socket_path: std::path::PathBuf) -> Result<Self, SignerError> {
+        let mut signer = UdsSigner::new(socket_path);
+        signer.init().await?;
+        Ok(Self::Uds(signer))
+    }
+
+    /// Create a signer from the keypair stored in a Vault KV v2 secret at `path`
+    #[cfg(feature = "vault-kv")]
+    pub async fn from_vault_kv(
+        vault_addr: String,
+        token: String,
+        path: String,
+    ) -> Result<Self, SignerError> {
+        let mut signer = VaultKvSigner::new(vault_addr, token, path);
+        signer.init().await?;
+        Ok(Self::VaultKv(signer))
+    }
+
+    /// Create a signer for the gpg-agent key with the given keygrip
+    #[cfg(feature = "gpg-agent")]
+    pub async fn from_gpg_agent(keygrip: &str) -> Result<Self, SignerError> {
+        let mut signer = GpgAgentSigner::new(keygrip)?;
+        signer.init().await?;
+        Ok(Self::GpgAgent(signer))
+    }
+
+    /// Create a BitGo signer by fetching the wallet's root address through BitGo Express
+    #[cfg(feature = "bitgo")]
+    pub async fn from_bitgo(
+        access_token: String,
+        wallet_id: String,
+        wallet_passphrase: String,
+        express_url: String,
+    ) -> Result<Self, SignerError> {
+        let mut signer = BitGoSigner::new(access_token, wallet_id, wallet_passphrase)
+            .with_express_url(express_url);
+        signer.init().await?;
+        Ok(Self::BitGo(signer))
+    }
+
     /// Create a Turnkey signer
     #[cfg(feature = "turnkey")]
     pub fn from_turnkey(
@@ -140,6 +731,49 @@ impl Signer {
     }
 }
 
+macro_rules! impl_from_backend {
+    ($($feature:literal => $variant:ident($ty:ty)),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl From<$ty> for Signer {
+                fn from(signer: $ty) -> Self {
+                    Signer::$variant(signer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_backend! {
+    "memory" => Memory(MemorySigner),
+    "vault" => Vault(VaultSigner),
+    "privy" => Privy(PrivySigner),
+    "turnkey" => Turnkey(TurnkeySigner),
+    "gcp-kms" => GcpKms(GcpKmsSigner),
+    "trezor" => Trezor(TrezorSigner),
+    "yubikey" => YubiKey(YubiKeySigner),
+    "dfns" => Dfns(DfnsSigner),
+    "web3auth" => Web3Auth(Web3AuthSigner),
+    "keyring" => Keyring(KeyringSigner),
+    "hd-wallet" => HdWallet(HdWalletSigner),
+    "ssh-agent" => SshAgent(SshAgentSigner),
+    "nitro" => NitroEnclave(NitroEnclaveSigner),
+    "onepassword" => OnePassword(OnePasswordSigner),
+    "grpc" => Grpc(GrpcRemoteSigner),
+    "http-remote" => HttpRemote(HttpRemoteSigner),
+    "uds" => Uds(UdsSigner),
+    "mpc" => Mpc(MpcSigner),
+    "remote-wallet" => RemoteWallet(RemoteWalletSigner),
+    "vault-kv" => VaultKv(VaultKvSigner),
+    "gpg-agent" => GpgAgent(GpgAgentSigner),
+    "offline" => Offline(OfflineSigner),
+    "akeyless" => Akeyless(AkeylessSigner),
+    "bitgo" => BitGo(BitGoSigner),
+    "cobo" => Cobo(CoboSigner),
+    "anchorage" => Anchorage(AnchorageSigner),
+    "utila" => Utila(UtilaSigner),
+}
+
 #[async_trait::async_trait]
 impl SolanaSigner for Signer {
     fn pubkey(&self) -> sdk_adapter::Pubkey {
@@ -155,6 +789,75 @@ impl SolanaSigner for Signer {
 
             #[cfg(feature = "turnkey")]
             Signer::Turnkey(s) => s.pubkey(),
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.pubkey(),
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.pubkey(),
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.pubkey(),
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.pubkey(),
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.pubkey(),
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.pubkey(),
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.pubkey(),
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.pubkey(),
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.pubkey(),
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.pubkey(),
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.pubkey(),
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.pubkey(),
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.pubkey(),
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.pubkey(),
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.pubkey(),
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.pubkey(),
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.pubkey(),
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.pubkey(),
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.pubkey(),
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.pubkey(),
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.pubkey(),
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.pubkey(),
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.pubkey(),
         }
     }
 
@@ -174,57 +877,1275 @@ impl SolanaSigner for Signer {
 
             #[cfg(feature = "turnkey")]
             Signer::Turnkey(s) => s.sign_transaction(tx).await,
-        }
-    }
 
-    async fn sign_message(&self, message: &[u8]) -> Result<sdk_adapter::Signature, SignerError> {
-        match self {
-            #[cfg(feature = "memory")]
-            Signer::Memory(s) => s.sign_message(message).await,
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.sign_transaction(tx).await,
 
-            #[cfg(feature = "vault")]
-            Signer::Vault(s) => s.sign_message(message).await,
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.sign_transaction(tx).await,
 
-            #[cfg(feature = "privy")]
-            Signer::Privy(s) => s.sign_message(message).await,
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.sign_transaction(tx).await,
 
-            #[cfg(feature = "turnkey")]
-            Signer::Turnkey(s) => s.sign_message(message).await,
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.sign_transaction(tx).await,
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.sign_transaction(tx).await,
         }
     }
 
-    async fn sign_partial_transaction(
+    async fn sign_transaction_with(
         &self,
         tx: &mut sdk_adapter::Transaction,
+        options: SignOptions,
     ) -> Result<SignedTransaction, SignerError> {
         match self {
             #[cfg(feature = "memory")]
-            Signer::Memory(s) => s.sign_partial_transaction(tx).await,
+            Signer::Memory(s) => s.sign_transaction_with(tx, options).await,
 
             #[cfg(feature = "vault")]
-            Signer::Vault(s) => s.sign_partial_transaction(tx).await,
+            Signer::Vault(s) => s.sign_transaction_with(tx, options).await,
 
             #[cfg(feature = "privy")]
-            Signer::Privy(s) => s.sign_partial_transaction(tx).await,
+            Signer::Privy(s) => s.sign_transaction_with(tx, options).await,
 
             #[cfg(feature = "turnkey")]
-            Signer::Turnkey(s) => s.sign_partial_transaction(tx).await,
+            Signer::Turnkey(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.sign_transaction_with(tx, options).await,
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.sign_transaction_with(tx, options).await,
         }
     }
 
-    async fn is_available(&self) -> bool {
+    async fn sign_message(&self, message: &[u8]) -> Result<sdk_adapter::Signature, SignerError> {
         match self {
             #[cfg(feature = "memory")]
-            Signer::Memory(s) => s.is_available().await,
+            Signer::Memory(s) => s.sign_message(message).await,
 
             #[cfg(feature = "vault")]
-            Signer::Vault(s) => s.is_available().await,
+            Signer::Vault(s) => s.sign_message(message).await,
 
             #[cfg(feature = "privy")]
-            Signer::Privy(s) => s.is_available().await,
+            Signer::Privy(s) => s.sign_message(message).await,
 
             #[cfg(feature = "turnkey")]
-            Signer::Turnkey(s) => s.is_available().await,
+            Signer::Turnkey(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.sign_message(message).await,
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.sign_message(message).await,
+        }
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut sdk_adapter::Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.sign_partial_transaction(tx).await,
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.sign_partial_transaction(tx).await,
+        }
+    }
+
+    async fn sign_all_transactions(
+        &self,
+        txs: &mut [sdk_adapter::Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.sign_all_transactions(txs).await,
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.sign_all_transactions(txs).await,
+        }
+    }
+
+    async fn is_available(&self) -> bool {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.is_available().await,
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.is_available().await,
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.is_available().await,
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.is_available().await,
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.is_available().await,
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.is_available().await,
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.is_available().await,
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.is_available().await,
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.is_available().await,
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.is_available().await,
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.is_available().await,
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.is_available().await,
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.is_available().await,
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.is_available().await,
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.is_available().await,
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.is_available().await,
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.is_available().await,
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.is_available().await,
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.is_available().await,
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.is_available().await,
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.is_available().await,
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.is_available().await,
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.is_available().await,
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.is_available().await,
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.is_available().await,
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.is_available().await,
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.is_available().await,
+        }
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.latency_class(),
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.latency_class(),
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.latency_class(),
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.latency_class(),
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.latency_class(),
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.latency_class(),
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.latency_class(),
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.latency_class(),
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.latency_class(),
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.latency_class(),
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.latency_class(),
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.latency_class(),
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.latency_class(),
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.latency_class(),
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.latency_class(),
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.latency_class(),
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.latency_class(),
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.latency_class(),
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.latency_class(),
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.latency_class(),
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.latency_class(),
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.latency_class(),
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.latency_class(),
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.latency_class(),
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.latency_class(),
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.latency_class(),
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.latency_class(),
+        }
+    }
+
+    fn output_encoding(&self) -> transaction_util::Encoding {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.output_encoding(),
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.output_encoding(),
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.output_encoding(),
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.output_encoding(),
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.output_encoding(),
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.output_encoding(),
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.output_encoding(),
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.output_encoding(),
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.output_encoding(),
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.output_encoding(),
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.output_encoding(),
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.output_encoding(),
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.output_encoding(),
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.output_encoding(),
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.output_encoding(),
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.output_encoding(),
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.output_encoding(),
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.output_encoding(),
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.output_encoding(),
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.output_encoding(),
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.output_encoding(),
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.output_encoding(),
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.output_encoding(),
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.output_encoding(),
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.output_encoding(),
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.output_encoding(),
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.output_encoding(),
+        }
+    }
+
+    async fn health(&self) -> HealthStatus {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.health().await,
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.health().await,
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.health().await,
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.health().await,
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.health().await,
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.health().await,
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.health().await,
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.health().await,
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.health().await,
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.health().await,
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.health().await,
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.health().await,
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.health().await,
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.health().await,
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.health().await,
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.health().await,
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.health().await,
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.health().await,
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.health().await,
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.health().await,
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.health().await,
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.health().await,
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.health().await,
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.health().await,
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.health().await,
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.health().await,
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.health().await,
+        }
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.capabilities(),
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.capabilities(),
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.capabilities(),
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.capabilities(),
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.capabilities(),
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.capabilities(),
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.capabilities(),
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.capabilities(),
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.capabilities(),
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.capabilities(),
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.capabilities(),
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.capabilities(),
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.capabilities(),
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.capabilities(),
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.capabilities(),
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.capabilities(),
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.capabilities(),
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.capabilities(),
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.capabilities(),
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.capabilities(),
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.capabilities(),
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.capabilities(),
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.capabilities(),
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.capabilities(),
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.capabilities(),
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.capabilities(),
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.capabilities(),
+        }
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.metadata(),
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.metadata(),
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.metadata(),
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.metadata(),
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.metadata(),
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.metadata(),
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.metadata(),
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.metadata(),
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.metadata(),
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.metadata(),
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.metadata(),
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.metadata(),
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.metadata(),
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.metadata(),
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.metadata(),
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.metadata(),
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.metadata(),
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.metadata(),
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.metadata(),
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.metadata(),
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.metadata(),
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.metadata(),
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.metadata(),
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.metadata(),
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.metadata(),
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.metadata(),
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.metadata(),
+        }
+    }
+
+    fn config_debug(&self) -> String {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.config_debug(),
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.config_debug(),
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.config_debug(),
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.config_debug(),
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.config_debug(),
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.config_debug(),
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.config_debug(),
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.config_debug(),
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.config_debug(),
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.config_debug(),
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.config_debug(),
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.config_debug(),
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.config_debug(),
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.config_debug(),
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.config_debug(),
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.config_debug(),
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.config_debug(),
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.config_debug(),
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.config_debug(),
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.config_debug(),
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.config_debug(),
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.config_debug(),
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.config_debug(),
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.config_debug(),
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.config_debug(),
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.config_debug(),
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.config_debug(),
+        }
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.validate_config(),
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.validate_config(),
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.validate_config(),
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.validate_config(),
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.validate_config(),
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.validate_config(),
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.validate_config(),
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.validate_config(),
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.validate_config(),
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.validate_config(),
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.validate_config(),
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.validate_config(),
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.validate_config(),
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.validate_config(),
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.validate_config(),
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.validate_config(),
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.validate_config(),
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.validate_config(),
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.validate_config(),
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.validate_config(),
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.validate_config(),
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.validate_config(),
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.validate_config(),
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.validate_config(),
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.validate_config(),
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.validate_config(),
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.validate_config(),
+        }
+    }
+
+    async fn warm_up(&self) -> Result<(), SignerError> {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.warm_up().await,
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.warm_up().await,
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.warm_up().await,
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.warm_up().await,
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.warm_up().await,
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.warm_up().await,
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.warm_up().await,
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.warm_up().await,
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.warm_up().await,
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.warm_up().await,
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.warm_up().await,
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.warm_up().await,
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.warm_up().await,
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.warm_up().await,
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.warm_up().await,
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.warm_up().await,
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.warm_up().await,
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.warm_up().await,
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.warm_up().await,
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.warm_up().await,
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.warm_up().await,
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.warm_up().await,
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.warm_up().await,
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.warm_up().await,
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.warm_up().await,
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.warm_up().await,
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.warm_up().await,
+        }
+    }
+
+    async fn health_check(&self) -> Result<(), SignerError> {
+        match self {
+            #[cfg(feature = "memory")]
+            Signer::Memory(s) => s.health_check().await,
+
+            #[cfg(feature = "vault")]
+            Signer::Vault(s) => s.health_check().await,
+
+            #[cfg(feature = "privy")]
+            Signer::Privy(s) => s.health_check().await,
+
+            #[cfg(feature = "turnkey")]
+            Signer::Turnkey(s) => s.health_check().await,
+
+            #[cfg(feature = "gcp-kms")]
+            Signer::GcpKms(s) => s.health_check().await,
+
+            #[cfg(feature = "trezor")]
+            Signer::Trezor(s) => s.health_check().await,
+
+            #[cfg(feature = "yubikey")]
+            Signer::YubiKey(s) => s.health_check().await,
+
+            #[cfg(feature = "dfns")]
+            Signer::Dfns(s) => s.health_check().await,
+
+            #[cfg(feature = "web3auth")]
+            Signer::Web3Auth(s) => s.health_check().await,
+
+            #[cfg(feature = "keyring")]
+            Signer::Keyring(s) => s.health_check().await,
+
+            #[cfg(feature = "hd-wallet")]
+            Signer::HdWallet(s) => s.health_check().await,
+
+            #[cfg(feature = "ssh-agent")]
+            Signer::SshAgent(s) => s.health_check().await,
+
+            #[cfg(feature = "nitro")]
+            Signer::NitroEnclave(s) => s.health_check().await,
+
+            #[cfg(feature = "onepassword")]
+            Signer::OnePassword(s) => s.health_check().await,
+
+            #[cfg(feature = "grpc")]
+            Signer::Grpc(s) => s.health_check().await,
+
+            #[cfg(feature = "http-remote")]
+            Signer::HttpRemote(s) => s.health_check().await,
+
+            #[cfg(feature = "uds")]
+            Signer::Uds(s) => s.health_check().await,
+
+            #[cfg(feature = "mpc")]
+            Signer::Mpc(s) => s.health_check().await,
+
+            #[cfg(feature = "remote-wallet")]
+            Signer::RemoteWallet(s) => s.health_check().await,
+
+            #[cfg(feature = "vault-kv")]
+            Signer::VaultKv(s) => s.health_check().await,
+
+            #[cfg(feature = "gpg-agent")]
+            Signer::GpgAgent(s) => s.health_check().await,
+
+            #[cfg(feature = "offline")]
+            Signer::Offline(s) => s.health_check().await,
+
+            #[cfg(feature = "akeyless")]
+            Signer::Akeyless(s) => s.health_check().await,
+
+            #[cfg(feature = "bitgo")]
+            Signer::BitGo(s) => s.health_check().await,
+
+            #[cfg(feature = "cobo")]
+            Signer::Cobo(s) => s.health_check().await,
+
+            #[cfg(feature = "anchorage")]
+            Signer::Anchorage(s) => s.health_check().await,
+
+            #[cfg(feature = "utila")]
+            Signer::Utila(s) => s.health_check().await,
         }
     }
 }
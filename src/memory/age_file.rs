@@ -0,0 +1,214 @@
+//! age-encrypted keypair files
+//!
+//! Decrypts a keypair file encrypted with [age](https://age-encryption.org), either
+//! to a passphrase (`age -p`) or to an X25519 identity (`age -r`), binary or
+//! ASCII-armored. The plaintext is the usual `id.json` byte array or a base58
+//! keypair. It is only ever held in memory, in buffers that are zeroized on drop.
+
+use super::MemorySigner;
+use crate::error::SignerError;
+use age::armor::ArmoredReader;
+use age::secrecy::SecretString;
+use std::io::Read;
+use std::path::Path;
+use zeroize::Zeroizing;
+
+/// Length of a Solana keypair (secret key followed by public key)
+const KEYPAIR_LEN: usize = 64;
+
+fn age_error(err: age::DecryptError) -> SignerError {
+    match err {
+        age::DecryptError::DecryptionFailed | age::DecryptError::NoMatchingKeys => {
+            SignerError::InvalidPrivateKey(
+                "Could not decrypt the age file with the given passphrase or identity".to_string(),
+            )
+        }
+        age::DecryptError::ExcessiveWork { .. } => SignerError::ConfigError(
+            "The age file's passphrase work factor is too high to decrypt here".to_string(),
+        ),
+        other => SignerError::InvalidPrivateKey(format!("Invalid age file: {other}")),
+    }
+}
+
+/// Decrypt the age file at `path` with whichever identities `identities` provides
+fn decrypt(
+    path: &Path,
+    passphrase: bool,
+    identities: &[&dyn age::Identity],
+) -> Result<Zeroizing<Vec<u8>>, SignerError> {
+    let ciphertext = std::fs::read(path)
+        .map_err(|e| SignerError::IoError(format!("{}: {e}", path.display())))?;
+
+    let decryptor =
+        age::Decryptor::new_buffered(ArmoredReader::new(&ciphertext[..])).map_err(age_error)?;
+    if decryptor.is_scrypt() != passphrase {
+        return Err(SignerError::ConfigError(if passphrase {
+            "The age file is encrypted to recipients, not a passphrase".to_string()
+        } else {
+            "The age file is encrypted with a passphrase, not to recipients".to_string()
+        }));
+    }
+
+    let mut reader = decryptor
+        .decrypt(identities.iter().copied())
+        .map_err(age_error)?;
+
+    // The plaintext is never longer than the ciphertext, so reserving that much up
+    // front keeps read_to_end from reallocating and leaving unzeroized copies behind
+    let mut plaintext = Zeroizing::new(Vec::with_capacity(ciphertext.len()));
+    reader
+        .read_to_end(&mut plaintext)
+        .map_err(|e| SignerError::InvalidPrivateKey(format!("Invalid age payload: {e}")))?;
+
+    Ok(plaintext)
+}
+
+/// Decode a `[0, 1, ...]` or base58 keypair without intermediate heap copies
+fn parse_keypair(plaintext: &[u8]) -> Result<MemorySigner, SignerError> {
+    let invalid = || {
+        SignerError::InvalidPrivateKey(
+            "Decrypted age file is not a keypair byte array or base58 keypair".to_string(),
+        )
+    };
+    let text = std::str::from_utf8(plaintext)
+        .map_err(|_| invalid())?
+        .trim();
+    let mut bytes = Zeroizing::new([0u8; KEYPAIR_LEN]);
+
+    if let Some(array) = text.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        let mut len = 0;
+        for value in array.split(',') {
+            let slot = bytes.get_mut(len).ok_or_else(invalid)?;
+            *slot = value.trim().parse().map_err(|_| invalid())?;
+            len += 1;
+        }
+        if len != KEYPAIR_LEN {
+            return Err(invalid());
+        }
+    } else {
+        let len = bs58::decode(text)
+            .onto(&mut bytes[..])
+            .map_err(|_| invalid())?;
+        if len != KEYPAIR_LEN {
+            return Err(invalid());
+        }
+    }
+
+    MemorySigner::from_bytes(&bytes[..])
+}
+
+impl MemorySigner {
+    /// Creates a new signer from a keypair file encrypted with an age passphrase
+    pub fn from_age_file_with_passphrase(
+        path: impl AsRef<Path>,
+        passphrase: &str,
+    ) -> Result<Self, SignerError> {
+        let identity = age::scrypt::Identity::new(SecretString::from(passphrase.to_string()));
+        let plaintext = decrypt(path.as_ref(), true, &[&identity])?;
+
+        parse_keypair(&plaintext)
+    }
+
+    /// Creates a new signer from a keypair file encrypted to an age identity
+    ///
+    /// `identity_path` is an age identity file (as written by `age-keygen`) holding
+    /// one or more `AGE-SECRET-KEY-1...` lines.
+    pub fn from_age_file_with_identity_file(
+        path: impl AsRef<Path>,
+        identity_path: impl AsRef<Path>,
+    ) -> Result<Self, SignerError> {
+        let identity_path = identity_path.as_ref();
+        let identities = age::IdentityFile::from_file(identity_path.display().to_string())
+            .map_err(|e| SignerError::IoError(format!("{}: {e}", identity_path.display())))?
+            .into_identities()
+            .map_err(age_error)?;
+        let identities: Vec<&dyn age::Identity> = identities
+            .iter()
+            .map(|identity| identity.as_ref())
+            .collect();
+        let plaintext = decrypt(path.as_ref(), false, &identities)?;
+
+        parse_keypair(&plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, Keypair, Pubkey};
+    use crate::traits::SolanaSigner;
+    use age::secrecy::ExposeSecret;
+    use std::io::Write;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("solana-signers-{name}-{}", Pubkey::new_unique()))
+    }
+
+    fn encrypt(recipient: &dyn age::Recipient, plaintext: &[u8]) -> Vec<u8> {
+        let encryptor = age::Encryptor::with_recipients(std::iter::once(recipient)).unwrap();
+        let mut ciphertext = Vec::new();
+        let mut writer = encryptor.wrap_output(&mut ciphertext).unwrap();
+        writer.write_all(plaintext).unwrap();
+        writer.finish().unwrap();
+        ciphertext
+    }
+
+    fn id_json(keypair: &Keypair) -> Vec<u8> {
+        serde_json::to_vec(&keypair.to_bytes().to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_age_passphrase() {
+        let keypair = Keypair::new();
+        let mut recipient = age::scrypt::Recipient::new(SecretString::from("hunter2".to_string()));
+        // Keep the test fast; real files use a much higher work factor
+        recipient.set_work_factor(10);
+        let path = temp_path("age-passphrase.json.age");
+        std::fs::write(&path, encrypt(&recipient, &id_json(&keypair))).unwrap();
+
+        let signer = MemorySigner::from_age_file_with_passphrase(&path, "hunter2");
+        let wrong = MemorySigner::from_age_file_with_passphrase(&path, "hunter3");
+        let identity_file = MemorySigner::from_age_file_with_identity_file(&path, &path);
+        std::fs::remove_file(&path).unwrap();
+
+        let signer = signer.unwrap();
+        assert_eq!(signer.pubkey(), keypair_pubkey(&keypair));
+        signer.sign_message(b"hello").await.unwrap();
+        assert!(matches!(wrong, Err(SignerError::InvalidPrivateKey(_))));
+        assert!(identity_file.is_err());
+    }
+
+    #[test]
+    fn test_age_identity_file() {
+        let keypair = Keypair::new();
+        let identity = age::x25519::Identity::generate();
+        let identity_path = temp_path("age-identity.txt");
+        let path = temp_path("age-recipient.json.age");
+        std::fs::write(
+            &identity_path,
+            format!("# test key\n{}\n", identity.to_string().expose_secret()),
+        )
+        .unwrap();
+        std::fs::write(
+            &path,
+            encrypt(&identity.to_public(), keypair.to_base58_string().as_bytes()),
+        )
+        .unwrap();
+
+        let signer = MemorySigner::from_age_file_with_identity_file(&path, &identity_path);
+        let passphrase = MemorySigner::from_age_file_with_passphrase(&path, "hunter2");
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&identity_path).unwrap();
+
+        assert_eq!(signer.unwrap().pubkey(), keypair_pubkey(&keypair));
+        assert!(matches!(passphrase, Err(SignerError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_parse_keypair_rejects_bad_plaintext() {
+        assert!(parse_keypair(b"[1, 2, 3]").is_err());
+        assert!(parse_keypair(&[b'['; 70]).is_err());
+        assert!(parse_keypair(b"not base58!").is_err());
+        assert!(parse_keypair(&[0xff, 0xfe]).is_err());
+    }
+}
@@ -0,0 +1,429 @@
+//! Keypairs loaded from AWS Secrets Manager
+//!
+//! Requests are signed with AWS Signature Version 4 using static credentials, by
+//! default read from `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and the optional
+//! `AWS_SESSION_TOKEN`. The secret may hold a base58 keypair or a `[0, 1, ...]` byte
+//! array in `SecretString`, or the raw 64 keypair bytes in `SecretBinary`.
+
+use super::keypair_util::KeypairUtil;
+use super::refresh::RefreshingSigner;
+use super::MemorySigner;
+use crate::error::SignerError;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::Duration;
+
+const SERVICE: &str = "secretsmanager";
+
+/// Static AWS credentials used to sign Secrets Manager requests
+#[derive(Clone)]
+pub struct AwsCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+}
+
+impl std::fmt::Debug for AwsCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AwsCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AwsCredentials {
+    /// Create credentials from an access key pair and optional session token
+    pub fn new(
+        access_key_id: String,
+        secret_access_key: String,
+        session_token: Option<String>,
+    ) -> Self {
+        Self {
+            access_key_id,
+            secret_access_key,
+            session_token,
+        }
+    }
+
+    /// Read credentials from `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN`
+    pub fn from_env() -> Result<Self, SignerError> {
+        let var = |name: &str| {
+            std::env::var(name).map_err(|_| SignerError::ConfigError(format!("{name} is not set")))
+        };
+
+        Ok(Self::new(
+            var("AWS_ACCESS_KEY_ID")?,
+            var("AWS_SECRET_ACCESS_KEY")?,
+            std::env::var("AWS_SESSION_TOKEN").ok(),
+        ))
+    }
+}
+
+#[derive(Serialize)]
+struct GetSecretValueRequest<'a> {
+    #[serde(rename = "SecretId")]
+    secret_id: &'a str,
+}
+
+#[derive(Deserialize)]
+struct GetSecretValueResponse {
+    #[serde(rename = "SecretString")]
+    secret_string: Option<String>,
+    #[serde(rename = "SecretBinary")]
+    secret_binary: Option<String>,
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Request parts covered by a SigV4 signature
+struct SignableRequest<'a> {
+    method: &'a str,
+    query: &'a str,
+    /// Lowercase header names sorted by name, including `host` and `x-amz-date`
+    headers: &'a [(&'a str, &'a str)],
+    payload: &'a [u8],
+}
+
+/// Compute the SigV4 `Authorization` header value
+fn sigv4_authorization(
+    credentials: &AwsCredentials,
+    region: &str,
+    service: &str,
+    request: &SignableRequest<'_>,
+    amz_date: &str,
+) -> String {
+    let SignableRequest {
+        method,
+        query,
+        headers,
+        payload,
+    } = request;
+    let date = &amz_date[..8];
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{name}:{}\n", value.trim()))
+        .collect();
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let canonical_request = format!(
+        "{method}\n/\n{query}\n{canonical_headers}\n{signed_headers}\n{}",
+        hex::encode(Sha256::digest(payload))
+    );
+    let scope = format!("{date}/{region}/{service}/aws4_request");
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let signing_key = [region, service, "aws4_request"].iter().fold(
+        hmac_sha256(
+            format!("AWS4{}", credentials.secret_access_key).as_bytes(),
+            date.as_bytes(),
+        ),
+        |key, part| hmac_sha256(&key, part.as_bytes()),
+    );
+    let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        credentials.access_key_id
+    )
+}
+
+/// Source of a keypair stored in AWS Secrets Manager
+#[derive(Debug, Clone)]
+pub struct AwsSecretsManager {
+    secret_id: String,
+    region: String,
+    credentials: AwsCredentials,
+    endpoint: String,
+    client: reqwest::Client,
+}
+
+impl AwsSecretsManager {
+    /// Create a source for the secret `secret_arn` with credentials from the environment
+    ///
+    /// The region is taken from the ARN.
+    pub fn new(secret_arn: &str) -> Result<Self, SignerError> {
+        let region = secret_arn
+            .split(':')
+            .nth(3)
+            .filter(|region| secret_arn.starts_with("arn:") && !region.is_empty())
+            .ok_or_else(|| {
+                SignerError::ConfigError(format!("{secret_arn} is not a Secrets Manager ARN"))
+            })?;
+
+        Ok(Self::with_credentials(
+            secret_arn.to_string(),
+            region.to_string(),
+            AwsCredentials::from_env()?,
+        ))
+    }
+
+    /// Create a source for `secret_id` (ARN or name) in `region` with explicit credentials
+    pub fn with_credentials(
+        secret_id: String,
+        region: String,
+        credentials: AwsCredentials,
+    ) -> Self {
+        Self {
+            endpoint: format!("https://{SERVICE}.{region}.amazonaws.com"),
+            secret_id,
+            region,
+            credentials,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Use a custom endpoint (VPC endpoint, FIPS endpoint or a local emulator)
+    pub fn with_endpoint(mut self, endpoint: String) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    /// Fetch the secret and build a signer from it
+    pub async fn load(&self) -> Result<MemorySigner, SignerError> {
+        let body = serde_json::to_vec(&GetSecretValueRequest {
+            secret_id: &self.secret_id,
+        })
+        .map_err(|e| SignerError::SerializationError(e.to_string()))?;
+
+        let url = reqwest::Url::parse(&self.endpoint)
+            .map_err(|e| SignerError::ConfigError(format!("Invalid endpoint: {e}")))?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => {
+                return Err(SignerError::ConfigError(
+                    "Secrets Manager endpoint has no host".to_string(),
+                ))
+            }
+        };
+        let amz_date = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+        let mut headers = vec![
+            ("content-type", "application/x-amz-json-1.1"),
+            ("host", host.as_str()),
+            ("x-amz-date", amz_date.as_str()),
+        ];
+        if let Some(token) = &self.credentials.session_token {
+            headers.push(("x-amz-security-token", token.as_str()));
+        }
+        headers.push(("x-amz-target", "secretsmanager.GetSecretValue"));
+
+        let authorization = sigv4_authorization(
+            &self.credentials,
+            &self.region,
+            SERVICE,
+            &SignableRequest {
+                method: "POST",
+                query: "",
+                headers: &headers,
+                payload: &body,
+            },
+            &amz_date,
+        );
+
+        let mut request = self
+            .client
+            .post(url)
+            .header("authorization", authorization)
+            .body(body);
+        for (name, value) in headers.iter().filter(|(name, _)| *name != "host") {
+            request = request.header(*name, *value);
+        }
+
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+            #[cfg(feature = "unsafe-debug")]
+            log::error!(
+                "Secrets Manager GetSecretValue error - status: {status}, response: {error_text}"
+            );
+
+            #[cfg(not(feature = "unsafe-debug"))]
+            {
+                let _ = error_text;
+                log::error!("Secrets Manager GetSecretValue error - status: {status}");
+            }
+
+            return Err(SignerError::RemoteApiError(format!("API error {status}")));
+        }
+
+        let secret: GetSecretValueResponse = response.json().await?;
+        match (secret.secret_string, secret.secret_binary) {
+            (Some(secret), _) => Ok(MemorySigner::new(KeypairUtil::from_secret_string(&secret)?)),
+            (None, Some(binary)) => {
+                let bytes = STANDARD.decode(binary).map_err(|e| {
+                    SignerError::SerializationError(format!("Invalid SecretBinary: {e}"))
+                })?;
+                MemorySigner::from_bytes(&bytes)
+            }
+            (None, None) => Err(SignerError::ConfigError(format!(
+                "Secret {} has no value",
+                self.secret_id
+            ))),
+        }
+    }
+
+    /// Fetch the secret now and again whenever `refresh_interval` has elapsed
+    pub async fn refreshing(
+        self,
+        refresh_interval: Duration,
+    ) -> Result<RefreshingSigner, SignerError> {
+        let source = format!("aws-secrets-manager:{}", self.secret_id);
+        let this = Arc::new(self);
+
+        RefreshingSigner::new(
+            source,
+            Arc::new(move || {
+                let this = this.clone();
+                Box::pin(async move { this.load().await })
+            }),
+            refresh_interval,
+        )
+        .await
+    }
+}
+
+impl MemorySigner {
+    /// Creates a new signer from the keypair stored in the Secrets Manager secret `secret_arn`
+    ///
+    /// Credentials are read from the environment; use [`AwsSecretsManager`] for explicit
+    /// credentials or [`AwsSecretsManager::refreshing`] to follow secret rotation.
+    pub async fn from_aws_secrets_manager(secret_arn: &str) -> Result<Self, SignerError> {
+        AwsSecretsManager::new(secret_arn)?.load().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::Keypair;
+    use crate::traits::SolanaSigner;
+    use wiremock::matchers::{header, header_exists, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn test_sigv4_reference_example() {
+        // AWS SigV4 documentation example (IAM ListUsers)
+        let credentials = AwsCredentials::new(
+            "AKIDEXAMPLE".to_string(),
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            None,
+        );
+        let authorization = sigv4_authorization(
+            &credentials,
+            "us-east-1",
+            "iam",
+            &SignableRequest {
+                method: "GET",
+                query: "Action=ListUsers&Version=2010-05-08",
+                headers: &[
+                    (
+                        "content-type",
+                        "application/x-www-form-urlencoded; charset=utf-8",
+                    ),
+                    ("host", "iam.amazonaws.com"),
+                    ("x-amz-date", "20150830T123600Z"),
+                ],
+                payload: b"",
+            },
+            "20150830T123600Z",
+        );
+
+        assert_eq!(
+            authorization,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/iam/aws4_request, \
+             SignedHeaders=content-type;host;x-amz-date, \
+             Signature=5d672d79c15b13162d9279b0855cfba6789a8edb4c82c400e06b5924a6f2b5d7"
+        );
+    }
+
+    fn source(server: &MockServer) -> AwsSecretsManager {
+        AwsSecretsManager::with_credentials(
+            "solana/payer".to_string(),
+            "us-east-1".to_string(),
+            AwsCredentials::new(
+                "AKID".to_string(),
+                "secret".to_string(),
+                Some("token".to_string()),
+            ),
+        )
+        .with_endpoint(server.uri())
+    }
+
+    #[tokio::test]
+    async fn test_load_secret_string() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let expected = MemorySigner::new(keypair.insecure_clone()).pubkey();
+
+        Mock::given(method("POST"))
+            .and(header("x-amz-target", "secretsmanager.GetSecretValue"))
+            .and(header("x-amz-security-token", "token"))
+            .and(header_exists("authorization"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "SecretString": keypair.to_base58_string()
+            })))
+            .mount(&server)
+            .await;
+
+        let signer = source(&server).load().await.unwrap();
+        assert_eq!(signer.pubkey(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_load_secret_binary() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let expected = MemorySigner::new(keypair.insecure_clone()).pubkey();
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "SecretBinary": STANDARD.encode(keypair.to_bytes())
+            })))
+            .mount(&server)
+            .await;
+
+        let signer = source(&server)
+            .refreshing(Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert_eq!(signer.pubkey(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_load_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("AccessDeniedException"))
+            .mount(&server)
+            .await;
+
+        assert!(matches!(
+            source(&server).load().await,
+            Err(SignerError::RemoteApiError(_))
+        ));
+        assert!(matches!(
+            AwsSecretsManager::new("solana/payer"),
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+}
@@ -0,0 +1,302 @@
+//! Keypairs loaded from CyberArk Conjur
+//!
+//! Authenticates a host or user with its API key and reads a variable through the
+//! Conjur REST API, so access is governed by the Conjur policy granting `execute` on
+//! that variable. Access tokens live for eight minutes; they are cached and renewed
+//! shortly before they expire.
+
+use super::keypair_util::KeypairUtil;
+use super::refresh::RefreshingSigner;
+use super::MemorySigner;
+use crate::error::SignerError;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Lifetime of a Conjur access token
+const TOKEN_LIFETIME: Duration = Duration::from_secs(8 * 60);
+
+/// Renew access tokens this long before they expire
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+struct CachedToken {
+    /// Base64 of the signed token, as sent in the `Authorization` header
+    encoded: String,
+    expires_at: Instant,
+}
+
+/// Source of keypairs stored as Conjur variables
+pub struct Conjur {
+    appliance_url: String,
+    account: String,
+    login: String,
+    api_key: String,
+    authenticator: String,
+    client: reqwest::Client,
+    token: tokio::sync::Mutex<Option<CachedToken>>,
+}
+
+impl std::fmt::Debug for Conjur {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Conjur")
+            .field("appliance_url", &self.appliance_url)
+            .field("account", &self.account)
+            .field("login", &self.login)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Conjur {
+    /// Create a source for a Conjur server
+    ///
+    /// # Arguments
+    ///
+    /// * `appliance_url` - Base URL of the Conjur server, e.g. `https://conjur.example.com`
+    /// * `account` - Conjur organization account
+    /// * `login` - Host or user identity, e.g. `host/solana/payer-service`
+    /// * `api_key` - API key of that identity
+    pub fn new(appliance_url: String, account: String, login: String, api_key: String) -> Self {
+        Self {
+            appliance_url: appliance_url.trim_end_matches('/').to_string(),
+            account,
+            login,
+            api_key,
+            authenticator: "authn".to_string(),
+            client: reqwest::Client::new(),
+            token: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Authenticate through another API-key authenticator, e.g. `authn-ldap/corp`
+    pub fn with_authenticator(mut self, authenticator: String) -> Self {
+        self.authenticator = authenticator;
+        self
+    }
+
+    /// Build `{appliance_url}/{segments...}`, escaping each segment (including `/`)
+    fn url(&self, segments: &[&str]) -> Result<reqwest::Url, SignerError> {
+        let mut url = reqwest::Url::parse(&self.appliance_url)
+            .map_err(|e| SignerError::ConfigError(format!("Invalid Conjur URL: {e}")))?;
+        url.path_segments_mut()
+            .map_err(|_| SignerError::ConfigError("Invalid Conjur URL".to_string()))?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    }
+
+    /// Return a valid access token, authenticating again if the cached one is about to expire
+    async fn access_token(&self) -> Result<String, SignerError> {
+        let mut token = self.token.lock().await;
+
+        if let Some(cached) = token.as_ref() {
+            if Instant::now() + TOKEN_EXPIRY_MARGIN < cached.expires_at {
+                return Ok(cached.encoded.clone());
+            }
+        }
+
+        let mut segments: Vec<&str> = self.authenticator.split('/').collect();
+        segments.extend([self.account.as_str(), &self.login, "authenticate"]);
+        let response = self
+            .client
+            .post(self.url(&segments)?)
+            .body(self.api_key.clone())
+            .send()
+            .await?;
+        let issued_at = Instant::now();
+        let body = Self::check_response(response, "authenticate")
+            .await?
+            .bytes()
+            .await?;
+
+        let encoded = STANDARD.encode(&body);
+        *token = Some(CachedToken {
+            encoded: encoded.clone(),
+            expires_at: issued_at + TOKEN_LIFETIME,
+        });
+
+        Ok(encoded)
+    }
+
+    async fn check_response(
+        response: reqwest::Response,
+        operation: &str,
+    ) -> Result<reqwest::Response, SignerError> {
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+        #[cfg(feature = "unsafe-debug")]
+        log::error!("Conjur {operation} error - status: {status}, response: {error_text}");
+
+        #[cfg(not(feature = "unsafe-debug"))]
+        {
+            let _ = error_text;
+            log::error!("Conjur {operation} error - status: {status}");
+        }
+
+        Err(SignerError::RemoteApiError(format!("API error {status}")))
+    }
+
+    /// Fetch the variable `variable_id` (e.g. `solana/prod/payer`) and build a signer from it
+    pub async fn load(&self, variable_id: &str) -> Result<MemorySigner, SignerError> {
+        let url = self.url(&["secrets", &self.account, "variable", variable_id])?;
+        let response = self
+            .client
+            .get(url)
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!("Token token=\"{}\"", self.access_token().await?),
+            )
+            .send()
+            .await?;
+        let value = Self::check_response(response, "get variable")
+            .await?
+            .text()
+            .await?;
+
+        Ok(MemorySigner::new(KeypairUtil::from_secret_string(&value)?))
+    }
+
+    /// Fetch `variable_id` now and again whenever `refresh_interval` has elapsed
+    pub async fn refreshing(
+        self,
+        variable_id: String,
+        refresh_interval: Duration,
+    ) -> Result<RefreshingSigner, SignerError> {
+        let source = format!("conjur:{}:variable:{variable_id}", self.account);
+        let this = Arc::new(self);
+        let variable_id = Arc::new(variable_id);
+
+        RefreshingSigner::new(
+            source,
+            Arc::new(move || {
+                let this = this.clone();
+                let variable_id = variable_id.clone();
+                Box::pin(async move { this.load(&variable_id).await })
+            }),
+            refresh_interval,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::Keypair;
+    use crate::traits::SolanaSigner;
+    use wiremock::matchers::{body_string, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const TOKEN: &str =
+        r#"{"protected":"eyJhbGciOiJjb25qdXIifQ==","payload":"e30=","signature":"c2ln"}"#;
+
+    async fn mount_authenticate(server: &MockServer, expected_calls: u64) {
+        Mock::given(method("POST"))
+            .and(path("/authn/acme/host%2Fsolana%2Fpayer/authenticate"))
+            .and(body_string("api-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TOKEN))
+            .expect(expected_calls)
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_variable(server: &MockServer, keypair: &Keypair) {
+        Mock::given(method("GET"))
+            .and(path("/secrets/acme/variable/solana%2Fprod%2Fpayer"))
+            .and(header(
+                "authorization",
+                format!("Token token=\"{}\"", STANDARD.encode(TOKEN)).as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_string(keypair.to_base58_string()))
+            .mount(server)
+            .await;
+    }
+
+    fn source(server: &MockServer) -> Conjur {
+        Conjur::new(
+            server.uri(),
+            "acme".to_string(),
+            "host/solana/payer".to_string(),
+            "api-key".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_conjur_load_reuses_token() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let expected = MemorySigner::new(keypair.insecure_clone()).pubkey();
+        mount_authenticate(&server, 1).await;
+        mount_variable(&server, &keypair).await;
+
+        let source = source(&server);
+        assert_eq!(
+            source.load("solana/prod/payer").await.unwrap().pubkey(),
+            expected
+        );
+        assert_eq!(
+            source.load("solana/prod/payer").await.unwrap().pubkey(),
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_conjur_refreshing() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        mount_authenticate(&server, 1).await;
+        mount_variable(&server, &keypair).await;
+
+        let signer = source(&server)
+            .refreshing("solana/prod/payer".to_string(), Duration::from_secs(3600))
+            .await
+            .unwrap();
+        signer.sign_message(b"hello").await.unwrap();
+        assert!(signer
+            .config_debug()
+            .contains("conjur:acme:variable:solana/prod/payer"));
+    }
+
+    #[tokio::test]
+    async fn test_conjur_custom_authenticator() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        Mock::given(method("POST"))
+            .and(path(
+                "/authn-ldap/corp/acme/host%2Fsolana%2Fpayer/authenticate",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TOKEN))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_variable(&server, &keypair).await;
+
+        source(&server)
+            .with_authenticator("authn-ldap/corp".to_string())
+            .load("solana/prod/payer")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_conjur_permission_denied() {
+        let server = MockServer::start().await;
+        mount_authenticate(&server, 1).await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+
+        assert!(matches!(
+            source(&server).load("solana/prod/payer").await,
+            Err(SignerError::RemoteApiError(_))
+        ));
+    }
+}
@@ -0,0 +1,262 @@
+//! Keypairs loaded from Doppler
+//!
+//! Reads one secret from a Doppler project config, authenticating with a service
+//! token (scoped to a single config) or any other Doppler API token. Rotation can be
+//! picked up on a timer, or immediately by calling [`RefreshingSigner::refresh`] from
+//! a Doppler webhook handler after [`Doppler::verify_webhook_signature`] passes.
+
+use super::keypair_util::KeypairUtil;
+use super::refresh::RefreshingSigner;
+use super::MemorySigner;
+use crate::error::SignerError;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Deserialize)]
+struct SecretResponse {
+    value: SecretValue,
+}
+
+#[derive(Deserialize)]
+struct SecretValue {
+    /// Value with secret references resolved
+    computed: Option<String>,
+    raw: Option<String>,
+}
+
+/// Source of a keypair stored as a Doppler secret
+#[derive(Clone)]
+pub struct Doppler {
+    token: String,
+    project: Option<String>,
+    config: Option<String>,
+    secret_name: String,
+    api_base_url: String,
+    client: reqwest::Client,
+}
+
+impl std::fmt::Debug for Doppler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Doppler")
+            .field("project", &self.project)
+            .field("config", &self.config)
+            .field("secret_name", &self.secret_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Doppler {
+    /// Create a source for `secret_name` readable with a service token
+    ///
+    /// Service tokens are scoped to one project config, so no project or config is needed.
+    pub fn new(token: String, secret_name: String) -> Self {
+        Self {
+            token,
+            project: None,
+            config: None,
+            secret_name,
+            api_base_url: "https://api.doppler.com/v3".to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Read the secret from `project` and `config` (required for personal and service account tokens)
+    pub fn with_project_config(mut self, project: String, config: String) -> Self {
+        self.project = Some(project);
+        self.config = Some(config);
+        self
+    }
+
+    /// Use a custom API base URL
+    pub fn with_api_base_url(mut self, api_base_url: String) -> Self {
+        self.api_base_url = api_base_url;
+        self
+    }
+
+    /// Fetch the secret and build a signer from it
+    pub async fn load(&self) -> Result<MemorySigner, SignerError> {
+        let mut query = vec![("name", self.secret_name.as_str())];
+        if let (Some(project), Some(config)) = (&self.project, &self.config) {
+            query.push(("project", project));
+            query.push(("config", config));
+        }
+
+        let response = self
+            .client
+            .get(format!("{}/configs/config/secret", self.api_base_url))
+            .bearer_auth(&self.token)
+            .query(&query)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+            #[cfg(feature = "unsafe-debug")]
+            log::error!("Doppler secret error - status: {status}, response: {error_text}");
+
+            #[cfg(not(feature = "unsafe-debug"))]
+            {
+                let _ = error_text;
+                log::error!("Doppler secret error - status: {status}");
+            }
+
+            return Err(SignerError::RemoteApiError(format!("API error {status}")));
+        }
+
+        let secret: SecretResponse = response.json().await?;
+        let value = secret.value.computed.or(secret.value.raw).ok_or_else(|| {
+            SignerError::ConfigError(format!("Doppler secret {} has no value", self.secret_name))
+        })?;
+
+        Ok(MemorySigner::new(KeypairUtil::from_secret_string(&value)?))
+    }
+
+    /// Fetch the secret now and again whenever `refresh_interval` has elapsed
+    ///
+    /// Pass `Duration::MAX` to reload only when [`RefreshingSigner::refresh`] is called,
+    /// e.g. from a webhook handler.
+    pub async fn refreshing(
+        self,
+        refresh_interval: Duration,
+    ) -> Result<RefreshingSigner, SignerError> {
+        let source = match (&self.project, &self.config) {
+            (Some(project), Some(config)) => {
+                format!("doppler:{project}/{config}/{}", self.secret_name)
+            }
+            _ => format!("doppler:{}", self.secret_name),
+        };
+        let this = Arc::new(self);
+
+        RefreshingSigner::new(
+            source,
+            Arc::new(move || {
+                let this = this.clone();
+                Box::pin(async move { this.load().await })
+            }),
+            refresh_interval,
+        )
+        .await
+    }
+
+    /// Check the `X-Doppler-Signature` header of a webhook delivery
+    ///
+    /// The header is `sha256=<hex HMAC-SHA256 of the raw body>` keyed with the
+    /// webhook's signing secret. Comparison is constant-time.
+    pub fn verify_webhook_signature(
+        webhook_secret: &str,
+        body: &[u8],
+        signature_header: &str,
+    ) -> Result<(), SignerError> {
+        let invalid = || SignerError::ConfigError("Invalid Doppler webhook signature".to_string());
+
+        let signature = signature_header
+            .trim()
+            .strip_prefix("sha256=")
+            .and_then(|signature| hex::decode(signature).ok())
+            .ok_or_else(invalid)?;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(webhook_secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(body);
+        mac.verify_slice(&signature).map_err(|_| invalid())
+    }
+}
+
+impl MemorySigner {
+    /// Creates a new signer from the Doppler secret `secret_name`, read with a service token
+    pub async fn from_doppler(token: String, secret_name: String) -> Result<Self, SignerError> {
+        Doppler::new(token, secret_name).load().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::Keypair;
+    use crate::traits::SolanaSigner;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn secret_response(value: String) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "SOLANA_PAYER",
+            "value": { "raw": value, "computed": value }
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_doppler_load_with_service_token() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let expected = MemorySigner::new(keypair.insecure_clone()).pubkey();
+
+        Mock::given(method("GET"))
+            .and(path("/configs/config/secret"))
+            .and(header("authorization", "Bearer dp.st.prd.token"))
+            .and(query_param("name", "SOLANA_PAYER"))
+            .respond_with(secret_response(keypair.to_base58_string()))
+            .mount(&server)
+            .await;
+
+        let signer = Doppler::new("dp.st.prd.token".to_string(), "SOLANA_PAYER".to_string())
+            .with_api_base_url(server.uri())
+            .load()
+            .await
+            .unwrap();
+        assert_eq!(signer.pubkey(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_doppler_webhook_triggered_reload() {
+        let server = MockServer::start().await;
+        let first = Keypair::new();
+        let second = Keypair::new();
+        let second_pubkey = MemorySigner::new(second.insecure_clone()).pubkey();
+
+        Mock::given(method("GET"))
+            .and(query_param("project", "payments"))
+            .and(query_param("config", "prd"))
+            .respond_with(secret_response(first.to_base58_string()))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(secret_response(second.to_base58_string()))
+            .mount(&server)
+            .await;
+
+        let signer = Doppler::new("dp.sa.token".to_string(), "SOLANA_PAYER".to_string())
+            .with_project_config("payments".to_string(), "prd".to_string())
+            .with_api_base_url(server.uri())
+            .refreshing(Duration::MAX)
+            .await
+            .unwrap();
+        signer.sign_message(b"hello").await.unwrap();
+        assert_ne!(signer.pubkey(), second_pubkey);
+
+        let body = br#"{"type":"secrets.update"}"#;
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"whsec").unwrap();
+        mac.update(body);
+        let header = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+
+        Doppler::verify_webhook_signature("whsec", body, &header).unwrap();
+        signer.refresh().await.unwrap();
+        assert_eq!(signer.pubkey(), second_pubkey);
+    }
+
+    #[test]
+    fn test_doppler_rejects_bad_webhook_signature() {
+        let body = br#"{"type":"secrets.update"}"#;
+
+        assert!(Doppler::verify_webhook_signature("whsec", body, "sha256=00").is_err());
+        assert!(Doppler::verify_webhook_signature("whsec", body, "not-a-signature").is_err());
+    }
+}
@@ -0,0 +1,289 @@
+//! Keypairs loaded from Google Cloud Secret Manager
+//!
+//! The secret version payload may hold a base58 keypair, a `[0, 1, ...]` byte array,
+//! or the raw 64 keypair bytes.
+
+use super::keypair_util::KeypairUtil;
+use super::refresh::RefreshingSigner;
+use super::MemorySigner;
+use crate::error::SignerError;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Callback returning a current OAuth2 access token
+///
+/// Access tokens expire after an hour, so refreshing signers should mint tokens
+/// through a provider rather than hold a single one.
+pub type AccessTokenProvider = Arc<dyn Fn() -> Result<String, SignerError> + Send + Sync>;
+
+#[derive(Deserialize)]
+struct AccessSecretVersionResponse {
+    name: String,
+    payload: SecretPayload,
+}
+
+#[derive(Deserialize)]
+struct SecretPayload {
+    data: String,
+}
+
+/// Source of a keypair stored in Google Cloud Secret Manager
+#[derive(Clone)]
+pub struct GcpSecretManager {
+    version_name: String,
+    token_provider: AccessTokenProvider,
+    api_base_url: String,
+    client: reqwest::Client,
+}
+
+impl std::fmt::Debug for GcpSecretManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcpSecretManager")
+            .field("version_name", &self.version_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GcpSecretManager {
+    /// Create a source for a secret resource name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - `projects/{project}/secrets/{secret}` (tracks the `latest` version) or
+    ///   `projects/{project}/secrets/{secret}/versions/{version}`
+    /// * `access_token` - OAuth2 access token with `secretmanager.versions.access`
+    pub fn new(name: &str, access_token: String) -> Result<Self, SignerError> {
+        Self::with_token_provider(name, Arc::new(move || Ok(access_token.clone())))
+    }
+
+    /// Create a source that asks `token_provider` for an access token on every fetch
+    pub fn with_token_provider(
+        name: &str,
+        token_provider: AccessTokenProvider,
+    ) -> Result<Self, SignerError> {
+        Ok(Self {
+            version_name: Self::resolve_version_name(name)?,
+            token_provider,
+            api_base_url: "https://secretmanager.googleapis.com/v1".to_string(),
+            client: reqwest::Client::new(),
+        })
+    }
+
+    /// Use a custom API base URL (regional endpoint or a local emulator)
+    pub fn with_api_base_url(mut self, api_base_url: String) -> Self {
+        self.api_base_url = api_base_url;
+        self
+    }
+
+    /// Normalize a secret or secret version resource name to a version name
+    fn resolve_version_name(name: &str) -> Result<String, SignerError> {
+        let parts: Vec<&str> = name.trim_matches('/').split('/').collect();
+
+        match parts.as_slice() {
+            ["projects", project, "secrets", secret]
+                if !project.is_empty() && !secret.is_empty() =>
+            {
+                Ok(format!(
+                    "projects/{project}/secrets/{secret}/versions/latest"
+                ))
+            }
+            ["projects", project, "secrets", secret, "versions", version]
+                if !project.is_empty() && !secret.is_empty() && !version.is_empty() =>
+            {
+                Ok(parts.join("/"))
+            }
+            _ => Err(SignerError::ConfigError(format!(
+                "{name} is not a Secret Manager secret or secret version name"
+            ))),
+        }
+    }
+
+    /// Fetch the secret version and build a signer from it
+    pub async fn load(&self) -> Result<MemorySigner, SignerError> {
+        let url = format!("{}/{}:access", self.api_base_url, self.version_name);
+        let response = self
+            .client
+            .get(&url)
+            .bearer_auth((self.token_provider)()?)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+            #[cfg(feature = "unsafe-debug")]
+            log::error!("Secret Manager access error - status: {status}, response: {error_text}");
+
+            #[cfg(not(feature = "unsafe-debug"))]
+            {
+                let _ = error_text;
+                log::error!("Secret Manager access error - status: {status}");
+            }
+
+            return Err(SignerError::RemoteApiError(format!("API error {status}")));
+        }
+
+        let secret: AccessSecretVersionResponse = response.json().await?;
+        log::debug!("Loaded keypair from {}", secret.name);
+
+        let data = STANDARD
+            .decode(secret.payload.data)
+            .map_err(|e| SignerError::SerializationError(format!("Invalid secret payload: {e}")))?;
+
+        // A 64-byte payload is the raw keypair; text encodings are always longer
+        if data.len() == 64 {
+            return MemorySigner::from_bytes(&data);
+        }
+
+        let text = String::from_utf8(data).map_err(|_| {
+            SignerError::InvalidPrivateKey("Secret payload is not a keypair".to_string())
+        })?;
+        Ok(MemorySigner::new(KeypairUtil::from_secret_string(&text)?))
+    }
+
+    /// Fetch the secret now and again whenever `refresh_interval` has elapsed
+    ///
+    /// With a `latest` version name, this follows rotations as new versions are added.
+    pub async fn refreshing(
+        self,
+        refresh_interval: Duration,
+    ) -> Result<RefreshingSigner, SignerError> {
+        let source = format!("gcp-secret-manager:{}", self.version_name);
+        let this = Arc::new(self);
+
+        RefreshingSigner::new(
+            source,
+            Arc::new(move || {
+                let this = this.clone();
+                Box::pin(async move { this.load().await })
+            }),
+            refresh_interval,
+        )
+        .await
+    }
+}
+
+impl MemorySigner {
+    /// Creates a new signer from the keypair stored in a Secret Manager secret
+    ///
+    /// See [`GcpSecretManager::new`] for the accepted resource names, and
+    /// [`GcpSecretManager::refreshing`] to follow secret rotation.
+    pub async fn from_gcp_secret_manager(
+        name: &str,
+        access_token: String,
+    ) -> Result<Self, SignerError> {
+        GcpSecretManager::new(name, access_token)?.load().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::Keypair;
+    use crate::traits::SolanaSigner;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const VERSION_PATH: &str = "/projects/my-project/secrets/payer/versions/latest:access";
+
+    fn secret_response(data: &[u8]) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "name": "projects/123/secrets/payer/versions/4",
+            "payload": { "data": STANDARD.encode(data) }
+        }))
+    }
+
+    #[test]
+    fn test_resolve_version_name() {
+        assert_eq!(
+            GcpSecretManager::resolve_version_name("projects/p/secrets/s").unwrap(),
+            "projects/p/secrets/s/versions/latest"
+        );
+        assert_eq!(
+            GcpSecretManager::resolve_version_name("projects/p/secrets/s/versions/3").unwrap(),
+            "projects/p/secrets/s/versions/3"
+        );
+        assert!(GcpSecretManager::resolve_version_name("payer").is_err());
+        assert!(GcpSecretManager::resolve_version_name("projects/p/secrets/").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_text_and_raw_payloads() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let expected = MemorySigner::new(keypair.insecure_clone()).pubkey();
+
+        Mock::given(method("GET"))
+            .and(path(VERSION_PATH))
+            .and(header("authorization", "Bearer token"))
+            .respond_with(secret_response(keypair.to_base58_string().as_bytes()))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(VERSION_PATH))
+            .respond_with(secret_response(&keypair.to_bytes()))
+            .mount(&server)
+            .await;
+
+        let source =
+            GcpSecretManager::new("projects/my-project/secrets/payer", "token".to_string())
+                .unwrap()
+                .with_api_base_url(server.uri());
+
+        assert_eq!(source.load().await.unwrap().pubkey(), expected);
+        assert_eq!(source.load().await.unwrap().pubkey(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_refreshing_follows_rotation() {
+        let server = MockServer::start().await;
+        let first = Keypair::new();
+        let second = Keypair::new();
+        let second_pubkey = MemorySigner::new(second.insecure_clone()).pubkey();
+
+        Mock::given(method("GET"))
+            .respond_with(secret_response(&first.to_bytes()))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(secret_response(&second.to_bytes()))
+            .mount(&server)
+            .await;
+
+        let signer =
+            GcpSecretManager::new("projects/my-project/secrets/payer", "token".to_string())
+                .unwrap()
+                .with_api_base_url(server.uri())
+                .refreshing(Duration::ZERO)
+                .await
+                .unwrap();
+
+        signer.sign_message(b"hello").await.unwrap();
+        assert_eq!(signer.pubkey(), second_pubkey);
+    }
+
+    #[tokio::test]
+    async fn test_load_api_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+
+        let source = GcpSecretManager::new("projects/p/secrets/s", "token".to_string())
+            .unwrap()
+            .with_api_base_url(server.uri());
+        assert!(matches!(
+            source.load().await,
+            Err(SignerError::RemoteApiError(_))
+        ));
+    }
+}
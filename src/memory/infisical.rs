@@ -0,0 +1,325 @@
+//! Keypairs loaded from Infisical
+//!
+//! Authenticates with a machine identity (Universal Auth client ID and secret) and
+//! reads a secret from a project environment and folder path. Access tokens are
+//! cached and renewed by logging in again shortly before they expire.
+
+use super::keypair_util::KeypairUtil;
+use super::refresh::RefreshingSigner;
+use super::MemorySigner;
+use crate::error::SignerError;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Renew access tokens this long before they expire
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LoginRequest<'a> {
+    client_id: &'a str,
+    client_secret: &'a str,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoginResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+#[derive(Deserialize)]
+struct SecretResponse {
+    secret: Secret,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Secret {
+    secret_value: String,
+}
+
+struct CachedToken {
+    access_token: String,
+    expires_at: Instant,
+}
+
+/// Source of keypairs stored as Infisical secrets
+pub struct Infisical {
+    site_url: String,
+    client_id: String,
+    client_secret: String,
+    project_id: String,
+    environment: String,
+    secret_path: String,
+    client: reqwest::Client,
+    token: tokio::sync::Mutex<Option<CachedToken>>,
+}
+
+impl std::fmt::Debug for Infisical {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Infisical")
+            .field("site_url", &self.site_url)
+            .field("project_id", &self.project_id)
+            .field("environment", &self.environment)
+            .field("secret_path", &self.secret_path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Infisical {
+    /// Create a source reading secrets from `environment` (e.g. `prod`) of a project
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` / `client_secret` - Universal Auth credentials of a machine identity
+    /// * `project_id` - Project (workspace) ID
+    /// * `environment` - Environment slug
+    pub fn new(
+        client_id: String,
+        client_secret: String,
+        project_id: String,
+        environment: String,
+    ) -> Self {
+        Self {
+            site_url: "https://app.infisical.com".to_string(),
+            client_id,
+            client_secret,
+            project_id,
+            environment,
+            secret_path: "/".to_string(),
+            client: reqwest::Client::new(),
+            token: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Read secrets from the folder `secret_path` instead of the root (`/`)
+    pub fn with_secret_path(mut self, secret_path: String) -> Self {
+        self.secret_path = secret_path;
+        self
+    }
+
+    /// Use a self-hosted or regional Infisical instance
+    pub fn with_site_url(mut self, site_url: String) -> Self {
+        self.site_url = site_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Return a valid access token, logging in again if the cached one is about to expire
+    async fn access_token(&self) -> Result<String, SignerError> {
+        let mut token = self.token.lock().await;
+
+        if let Some(cached) = token.as_ref() {
+            if Instant::now() + TOKEN_EXPIRY_MARGIN < cached.expires_at {
+                return Ok(cached.access_token.clone());
+            }
+        }
+
+        let url = format!("{}/api/v1/auth/universal-auth/login", self.site_url);
+        let response = self
+            .client
+            .post(&url)
+            .json(&LoginRequest {
+                client_id: &self.client_id,
+                client_secret: &self.client_secret,
+            })
+            .send()
+            .await?;
+        let login: LoginResponse = Self::check_response(response, "login")
+            .await?
+            .json()
+            .await?;
+
+        let access_token = login.access_token;
+        *token = Some(CachedToken {
+            access_token: access_token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(login.expires_in),
+        });
+
+        Ok(access_token)
+    }
+
+    async fn check_response(
+        response: reqwest::Response,
+        operation: &str,
+    ) -> Result<reqwest::Response, SignerError> {
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+        #[cfg(feature = "unsafe-debug")]
+        log::error!("Infisical {operation} error - status: {status}, response: {error_text}");
+
+        #[cfg(not(feature = "unsafe-debug"))]
+        {
+            let _ = error_text;
+            log::error!("Infisical {operation} error - status: {status}");
+        }
+
+        Err(SignerError::RemoteApiError(format!("API error {status}")))
+    }
+
+    /// Fetch the secret `secret_name` and build a signer from it
+    pub async fn load(&self, secret_name: &str) -> Result<MemorySigner, SignerError> {
+        let url = format!("{}/api/v3/secrets/raw/{secret_name}", self.site_url);
+        let response = self
+            .client
+            .get(&url)
+            .bearer_auth(self.access_token().await?)
+            .query(&[
+                ("workspaceId", self.project_id.as_str()),
+                ("environment", self.environment.as_str()),
+                ("secretPath", self.secret_path.as_str()),
+            ])
+            .send()
+            .await?;
+        let secret: SecretResponse = Self::check_response(response, "get secret")
+            .await?
+            .json()
+            .await?;
+
+        Ok(MemorySigner::new(KeypairUtil::from_secret_string(
+            &secret.secret.secret_value,
+        )?))
+    }
+
+    /// Fetch `secret_name` now and again whenever `refresh_interval` has elapsed
+    pub async fn refreshing(
+        self,
+        secret_name: String,
+        refresh_interval: Duration,
+    ) -> Result<RefreshingSigner, SignerError> {
+        let source = format!(
+            "infisical:{}{}/{secret_name}",
+            self.environment,
+            self.secret_path.trim_end_matches('/')
+        );
+        let this = Arc::new(self);
+        let secret_name = Arc::new(secret_name);
+
+        RefreshingSigner::new(
+            source,
+            Arc::new(move || {
+                let this = this.clone();
+                let secret_name = secret_name.clone();
+                Box::pin(async move { this.load(&secret_name).await })
+            }),
+            refresh_interval,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::Keypair;
+    use crate::traits::SolanaSigner;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mount_login(server: &MockServer, expires_in: u64, expected_calls: u64) {
+        Mock::given(method("POST"))
+            .and(path("/api/v1/auth/universal-auth/login"))
+            .and(body_json(serde_json::json!({
+                "clientId": "client-id",
+                "clientSecret": "client-secret"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "accessToken": "access-token",
+                "expiresIn": expires_in,
+                "accessTokenMaxTTL": 2592000,
+                "tokenType": "Bearer"
+            })))
+            .expect(expected_calls)
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_secret(server: &MockServer, keypair: &Keypair) {
+        Mock::given(method("GET"))
+            .and(path("/api/v3/secrets/raw/SOLANA_PAYER"))
+            .and(header("authorization", "Bearer access-token"))
+            .and(query_param("workspaceId", "project-id"))
+            .and(query_param("environment", "prod"))
+            .and(query_param("secretPath", "/solana"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "secret": {
+                    "secretKey": "SOLANA_PAYER",
+                    "secretValue": keypair.to_base58_string(),
+                    "version": 1
+                }
+            })))
+            .mount(server)
+            .await;
+    }
+
+    fn source(server: &MockServer) -> Infisical {
+        Infisical::new(
+            "client-id".to_string(),
+            "client-secret".to_string(),
+            "project-id".to_string(),
+            "prod".to_string(),
+        )
+        .with_site_url(server.uri())
+        .with_secret_path("/solana".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_infisical_load_reuses_token() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let expected = MemorySigner::new(keypair.insecure_clone()).pubkey();
+        mount_login(&server, 7200, 1).await;
+        mount_secret(&server, &keypair).await;
+
+        let source = source(&server);
+        assert_eq!(
+            source.load("SOLANA_PAYER").await.unwrap().pubkey(),
+            expected
+        );
+        assert_eq!(
+            source.load("SOLANA_PAYER").await.unwrap().pubkey(),
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_infisical_renews_expiring_token() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        // Tokens inside the expiry margin are renewed on every use
+        mount_login(&server, 30, 2).await;
+        mount_secret(&server, &keypair).await;
+
+        let signer = source(&server)
+            .refreshing("SOLANA_PAYER".to_string(), Duration::ZERO)
+            .await
+            .unwrap();
+        signer.sign_message(b"hello").await.unwrap();
+        assert!(signer
+            .config_debug()
+            .contains("infisical:prod/solana/SOLANA_PAYER"));
+    }
+
+    #[tokio::test]
+    async fn test_infisical_login_failure() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+
+        assert!(matches!(
+            source(&server).load("SOLANA_PAYER").await,
+            Err(SignerError::RemoteApiError(_))
+        ));
+    }
+}
@@ -29,6 +29,27 @@ impl KeypairUtil {
         Self::from_base58_safe(private_key)
     }
 
+    /// Creates a new keypair from key material fetched from a secret store
+    ///
+    /// Accepts base58 or the U8Array format. Unlike [`Self::from_private_key_string`],
+    /// the value is never interpreted as a file path.
+    #[cfg(any(
+        feature = "aws-secrets-manager",
+        feature = "gcp-secret-manager",
+        feature = "infisical",
+        feature = "doppler",
+        feature = "conjur"
+    ))]
+    pub fn from_secret_string(secret: &str) -> Result<Keypair, SignerError> {
+        let secret = secret.trim();
+
+        if secret.starts_with('[') {
+            Self::from_u8_array_string(secret)
+        } else {
+            Self::from_base58_safe(secret)
+        }
+    }
+
     /// Creates a new keypair from a base58-encoded private key string with proper error handling
     pub fn from_base58_safe(private_key: &str) -> Result<Keypair, SignerError> {
         // Try to decode as base58 first
@@ -1,22 +1,64 @@
 //! Memory-based local keypair signer
 
+#[cfg(feature = "age")]
+mod age_file;
+#[cfg(feature = "aws-secrets-manager")]
+mod aws_secrets_manager;
+#[cfg(feature = "conjur")]
+mod conjur;
+#[cfg(feature = "doppler")]
+mod doppler;
+#[cfg(feature = "gcp-secret-manager")]
+mod gcp_secret_manager;
+#[cfg(feature = "infisical")]
+mod infisical;
 mod keypair_util;
+#[cfg(any(
+    feature = "aws-secrets-manager",
+    feature = "gcp-secret-manager",
+    feature = "infisical",
+    feature = "doppler",
+    feature = "conjur"
+))]
+mod refresh;
+
+#[cfg(feature = "aws-secrets-manager")]
+pub use aws_secrets_manager::{AwsCredentials, AwsSecretsManager};
+#[cfg(feature = "conjur")]
+pub use conjur::Conjur;
+#[cfg(feature = "doppler")]
+pub use doppler::Doppler;
+#[cfg(feature = "gcp-secret-manager")]
+pub use gcp_secret_manager::{AccessTokenProvider, GcpSecretManager};
+#[cfg(feature = "infisical")]
+pub use infisical::Infisical;
+#[cfg(any(
+    feature = "aws-secrets-manager",
+    feature = "gcp-secret-manager",
+    feature = "infisical",
+    feature = "doppler",
+    feature = "conjur"
+))]
+pub use refresh::RefreshingSigner;
 
 use crate::{
     error::SignerError,
     sdk_adapter::keypair_from_bytes,
-    traits::{SignedTransaction, SolanaSigner},
-    transaction_util::TransactionUtil,
+    traits::{LatencyClass, SignedTransaction, SignerMetadata, SolanaSigner},
+    transaction_util::{Encoding, TransactionUtil},
 };
 
 use crate::sdk_adapter::{
     keypair_pubkey, keypair_sign_message, Keypair, Pubkey, Signature, Transaction,
 };
 use keypair_util::KeypairUtil;
+use std::collections::HashMap;
+use std::path::Path;
 
 /// A Solana-based signer that uses an in-memory keypair
 pub struct MemorySigner {
     keypair: Keypair,
+    output_encoding: Encoding,
 }
 
 impl std::fmt::Debug for MemorySigner {
@@ -27,10 +69,23 @@ impl std::fmt::Debug for MemorySigner {
     }
 }
 
+/// Cloning copies the secret key; both copies are zeroized when dropped
+impl Clone for MemorySigner {
+    fn clone(&self) -> Self {
+        Self {
+            keypair: self.keypair.insecure_clone(),
+            output_encoding: self.output_encoding,
+        }
+    }
+}
+
 impl MemorySigner {
     /// Creates a new signer from a Solana keypair
     pub fn new(keypair: Keypair) -> Self {
-        Self { keypair }
+        Self {
+            keypair,
+            output_encoding: Encoding::default(),
+        }
     }
 
     /// Creates a new signer from a private key byte array
@@ -38,7 +93,23 @@ impl MemorySigner {
         let keypair = keypair_from_bytes(private_key).map_err(|e| {
             SignerError::InvalidPrivateKey(format!("Invalid private key bytes: {e}"))
         })?;
-        Ok(Self { keypair })
+        Ok(Self::new(keypair))
+    }
+
+    /// Creates a new signer from an `ed25519-dalek` signing key
+    ///
+    /// The resulting public key is checked against the key's verifying key.
+    #[cfg(feature = "dalek-interop")]
+    pub fn from_ed25519_signing_key(key: ed25519_dalek::SigningKey) -> Result<Self, SignerError> {
+        let signer = Self::from_bytes(&key.to_keypair_bytes())?;
+
+        if signer.pubkey().to_bytes() != key.verifying_key().to_bytes() {
+            return Err(SignerError::InvalidPrivateKey(
+                "Public key does not match the signing key".to_string(),
+            ));
+        }
+
+        Ok(signer)
     }
 
     /// Creates a new signer from a private key string that can be in multiple formats:
@@ -50,6 +121,88 @@ impl MemorySigner {
         Ok(Self::new(keypair))
     }
 
+    /// Loads every `*.json` keypair file in a directory, keyed by pubkey
+    ///
+    /// Entries without a `.json` extension (and subdirectories) are skipped. A `.json`
+    /// file that cannot be read or parsed as a keypair fails the whole load, with the
+    /// offending filename included in the error.
+    pub fn load_dir(path: impl AsRef<Path>) -> Result<HashMap<Pubkey, MemorySigner>, SignerError> {
+        let path = path.as_ref();
+        let entries = std::fs::read_dir(path)
+            .map_err(|e| SignerError::IoError(format!("{}: {e}", path.display())))?;
+
+        let mut signers = HashMap::new();
+
+        for entry in entries {
+            let file_path = entry
+                .map_err(|e| SignerError::IoError(format!("{}: {e}", path.display())))?
+                .path();
+
+            if !file_path.is_file() || file_path.extension().is_none_or(|ext| ext != "json") {
+                continue;
+            }
+
+            let content = std::fs::read_to_string(&file_path)
+                .map_err(|e| SignerError::IoError(format!("{}: {e}", file_path.display())))?;
+
+            let keypair = KeypairUtil::from_json_keypair(&content).map_err(|e| match e {
+                SignerError::InvalidPrivateKey(msg) => {
+                    SignerError::InvalidPrivateKey(format!("{}: {msg}", file_path.display()))
+                }
+                other => other,
+            })?;
+
+            let signer = Self::new(keypair);
+            signers.insert(signer.pubkey(), signer);
+        }
+
+        Ok(signers)
+    }
+
+    /// Sign each message and return `(message_hex, signature_base58)` test vectors
+    ///
+    /// Ed25519 signing is deterministic, so the same key and messages always produce
+    /// the same vectors. Useful for checking other SDKs against this crate.
+    #[cfg(feature = "test-utils")]
+    pub fn dump_vectors(&self, messages: &[&[u8]]) -> Vec<(String, String)> {
+        messages
+            .iter()
+            .map(|message| {
+                let signature = keypair_sign_message(&self.keypair, message);
+                (hex::encode(message), signature.to_string())
+            })
+            .collect()
+    }
+
+    /// Verify `(message_hex, signature_base58)` test vectors against this signer's pubkey
+    ///
+    /// Fails on the first vector that cannot be decoded or does not verify.
+    #[cfg(feature = "test-utils")]
+    pub fn verify_vectors(&self, vectors: &[(String, String)]) -> Result<(), SignerError> {
+        for (index, (message_hex, signature_b58)) in vectors.iter().enumerate() {
+            let message = hex::decode(message_hex).map_err(|e| {
+                SignerError::SerializationError(format!("Vector {index}: invalid message hex: {e}"))
+            })?;
+            let signature: Signature = signature_b58.parse().map_err(|e| {
+                SignerError::SerializationError(format!("Vector {index}: invalid signature: {e}"))
+            })?;
+
+            if !TransactionUtil::verify_signature(&self.pubkey(), &message, &signature) {
+                return Err(SignerError::SigningFailed(format!(
+                    "Vector {index}: signature does not verify"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set the encoding used for serialized transactions returned by this signer
+    pub fn with_output_encoding(mut self, encoding: Encoding) -> Self {
+        self.output_encoding = encoding;
+        self
+    }
+
     async fn sign_bytes(&self, serialized: &[u8]) -> Result<Signature, SignerError> {
         Ok(keypair_sign_message(&self.keypair, serialized))
     }
@@ -69,7 +222,12 @@ impl SolanaSigner for MemorySigner {
 
         TransactionUtil::add_signature_to_transaction(tx, &self.pubkey(), signature)?;
 
-        Ok((TransactionUtil::serialize_transaction(tx)?, signature))
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction_with_encoding(tx, self.output_encoding)?,
+            signature,
+            self.pubkey(),
+        )
+        .with_encoding(self.output_encoding))
     }
 
     async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
@@ -84,18 +242,50 @@ impl SolanaSigner for MemorySigner {
 
         TransactionUtil::add_signature_to_transaction(tx, &self.pubkey(), signature)?;
 
-        Ok((TransactionUtil::serialize_transaction(tx)?, signature))
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction_with_encoding(tx, self.output_encoding)?,
+            signature,
+            self.pubkey(),
+        )
+        .with_encoding(self.output_encoding))
+    }
+
+    /// Signs in a loop: signing is CPU-bound, so concurrency would not help
+    async fn sign_all_transactions(
+        &self,
+        txs: &mut [Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        crate::batch::sign_sequentially(self, txs).await
     }
 
     async fn is_available(&self) -> bool {
         // Memory signer is always available
         true
     }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn output_encoding(&self) -> Encoding {
+        self.output_encoding
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("memory", self.pubkey(), self.latency_class())
+    }
+
+    fn config_debug(&self) -> String {
+        format!("backend=memory pubkey={}", self.pubkey())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::test_util::create_test_transaction;
+    use crate::sdk_adapter::Keypair;
+    use crate::test_util::{
+        create_multi_signer_test_transaction, create_test_transaction, create_v0_test_transaction,
+    };
 
     use super::*;
 
@@ -132,6 +322,233 @@ mod tests {
         assert_eq!(sig.as_ref().len(), 64);
     }
 
+    #[tokio::test]
+    async fn test_signed_transaction() {
+        let signer = create_test_signer();
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        let signed = signer.sign_transaction(&mut tx).await.unwrap();
+
+        assert_eq!(signed.signer_pubkey, signer.pubkey());
+        assert_eq!(signed.to_string(), signed.signature.to_string());
+        assert_eq!(signed.signature_bytes(), tx.signatures[0].as_ref());
+        assert_eq!(
+            signed.transaction_bytes().unwrap(),
+            bincode::serialize(&tx).unwrap()
+        );
+
+        let json = serde_json::to_value(&signed).unwrap();
+        assert_eq!(json["signature"], signed.signature.to_string());
+        assert_eq!(json["signer_pubkey"], TEST_PUBKEY);
+        assert_eq!(
+            serde_json::from_value::<SignedTransaction>(json).unwrap(),
+            signed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify() {
+        let signer = create_test_signer();
+        let signature = signer.sign_message(b"Hello Solana!").await.unwrap();
+
+        assert!(signer.verify(b"Hello Solana!", &signature).unwrap());
+        assert!(!signer.verify(b"Goodbye Solana!", &signature).unwrap());
+
+        let other = MemorySigner::new(Keypair::new());
+        assert!(!other.verify(b"Hello Solana!", &signature).unwrap());
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let capabilities = create_test_signer().capabilities();
+
+        assert!(capabilities.supports_partial_sign);
+        assert!(capabilities.supports_versioned_tx);
+        assert!(!capabilities.requires_init);
+    }
+
+    #[tokio::test]
+    async fn test_sign_offchain_message() {
+        let signer = create_test_signer();
+        let signature = signer
+            .sign_offchain_message(b"Hello Solana!")
+            .await
+            .unwrap();
+
+        let message = crate::offchain_message::OffchainMessage::new(b"Hello Solana!").unwrap();
+        assert!(message.verify(&signer.pubkey(), &signature));
+        assert!(!signer.verify(b"Hello Solana!", &signature).unwrap());
+
+        let result = signer.sign_offchain_message(b"").await;
+        assert!(matches!(result, Err(SignerError::SerializationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_sign_versioned_transaction() {
+        let signer = create_test_signer();
+        let mut tx =
+            create_v0_test_transaction(&signer.pubkey(), &Pubkey::new_unique(), vec![0], vec![1]);
+
+        let SignedTransaction {
+            base64_tx: serialized,
+            signature,
+            ..
+        } = signer.sign_versioned_transaction(&mut tx).await.unwrap();
+
+        assert_eq!(tx.signatures[0], signature);
+        assert!(TransactionUtil::verify_signature(
+            &signer.pubkey(),
+            &tx.message.serialize(),
+            &signature
+        ));
+        assert_eq!(
+            serialized,
+            TransactionUtil::serialize_versioned_transaction(&tx).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_all_transactions() {
+        let signer = create_test_signer();
+        let mut txs = vec![
+            create_test_transaction(&signer.pubkey()),
+            create_test_transaction(&Pubkey::new_unique()),
+            create_test_transaction(&signer.pubkey()),
+        ];
+
+        let results = signer.sign_all_transactions(&mut txs).await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().signature, txs[0].signatures[0]);
+        assert!(matches!(results[1], Err(SignerError::SigningFailed(_))));
+        assert_eq!(results[2].as_ref().unwrap().signature, txs[2].signatures[0]);
+    }
+
+    #[test]
+    fn test_latency_class() {
+        assert_eq!(create_test_signer().latency_class(), LatencyClass::Local);
+    }
+
+    #[test]
+    fn test_config_debug() {
+        let signer = create_test_signer();
+        let output = signer.config_debug();
+
+        assert_eq!(output, format!("backend=memory pubkey={TEST_PUBKEY}"));
+        assert!(!output.contains(&bs58::encode(signer.keypair.to_bytes()).into_string()));
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = create_test_signer().metadata();
+
+        assert_eq!(metadata.backend, "memory");
+        assert_eq!(metadata.pubkey.to_string(), TEST_PUBKEY);
+        assert_eq!(metadata.key_id, None);
+        assert_eq!(metadata.endpoint, None);
+        assert_eq!(metadata.latency_class, LatencyClass::Local);
+    }
+
+    #[tokio::test]
+    async fn test_health() {
+        let health = create_test_signer().health().await;
+
+        assert_eq!(health.state, crate::traits::HealthState::Healthy);
+        assert_eq!(health.reason, None);
+        assert!(health.is_available());
+    }
+
+    fn create_temp_dir(name: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("solana-signers-{name}-{}", Pubkey::new_unique()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_dir() {
+        let dir = create_temp_dir("load-dir");
+        let other = Keypair::new();
+        std::fs::write(dir.join("signer.json"), TEST_KEYPAIR_BYTES).unwrap();
+        std::fs::write(
+            dir.join("other.json"),
+            serde_json::to_string(&other.to_bytes().to_vec()).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(dir.join("README.md"), "not a keypair").unwrap();
+
+        let signers = MemorySigner::load_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let signers = signers.unwrap();
+        assert_eq!(signers.len(), 2);
+        assert!(signers.contains_key(&keypair_pubkey(&other)));
+        assert_eq!(
+            signers[&TEST_PUBKEY.parse::<Pubkey>().unwrap()].pubkey(),
+            create_test_signer().pubkey()
+        );
+    }
+
+    #[test]
+    fn test_load_dir_invalid_file() {
+        let dir = create_temp_dir("load-dir-invalid");
+        std::fs::write(dir.join("signer.json"), TEST_KEYPAIR_BYTES).unwrap();
+        std::fs::write(dir.join("broken.json"), "{\"not\": \"a keypair\"}").unwrap();
+
+        let result = MemorySigner::load_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        match result {
+            Err(SignerError::InvalidPrivateKey(msg)) => assert!(msg.contains("broken.json")),
+            other => panic!("Expected InvalidPrivateKey, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_load_dir_missing() {
+        let result = MemorySigner::load_dir("/nonexistent/solana-signers-keys");
+        assert!(matches!(result, Err(SignerError::IoError(_))));
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn test_dump_and_verify_vectors() {
+        let signer = create_test_signer();
+        let messages: [&[u8]; 3] = [b"", b"Hello Solana!", &[0u8, 1, 2, 255]];
+
+        let vectors = signer.dump_vectors(&messages);
+
+        assert_eq!(vectors.len(), 3);
+        assert_eq!(vectors[1].0, "48656c6c6f20536f6c616e6121");
+        assert_eq!(vectors, signer.dump_vectors(&messages));
+        assert!(signer.verify_vectors(&vectors).is_ok());
+
+        let mut tampered = vectors.clone();
+        tampered[2].0 = "00".to_string();
+        assert!(matches!(
+            signer.verify_vectors(&tampered),
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+
+    #[cfg(feature = "dalek-interop")]
+    #[tokio::test]
+    async fn test_from_ed25519_signing_key() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[42u8; 32]);
+        let verifying_key = key.verifying_key();
+
+        let signer = MemorySigner::from_ed25519_signing_key(key).unwrap();
+
+        assert_eq!(signer.pubkey().to_bytes(), verifying_key.to_bytes());
+
+        let signature = signer.sign_message(b"hello").await.unwrap();
+        assert!(TransactionUtil::verify_signature(
+            &signer.pubkey(),
+            b"hello",
+            &signature
+        ));
+    }
+
     #[tokio::test]
     async fn test_is_available() {
         let signer = create_test_signer();
@@ -147,7 +564,11 @@ mod tests {
         let result = signer.sign_transaction(&mut tx).await;
         assert!(result.is_ok());
 
-        let (serialized_tx, signature) = result.unwrap();
+        let SignedTransaction {
+            base64_tx: serialized_tx,
+            signature,
+            ..
+        } = result.unwrap();
 
         // Verify the signature is valid
         assert_eq!(signature.as_ref().len(), 64);
@@ -160,6 +581,27 @@ mod tests {
         assert_eq!(tx.signatures[0], signature);
     }
 
+    #[cfg(feature = "unsafe-debug")]
+    #[tokio::test]
+    async fn test_sign_transaction_debug() {
+        let signer = create_test_signer();
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let expected_message = tx.message_data();
+
+        let result = signer.sign_transaction_debug(&mut tx).await.unwrap();
+
+        assert_eq!(result.message_bytes, expected_message);
+        assert!(TransactionUtil::verify_signature(
+            &signer.pubkey(),
+            &result.message_bytes,
+            &result.signature
+        ));
+        assert_eq!(
+            result.serialized,
+            TransactionUtil::serialize_transaction(&tx).unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn test_sign_partial_transaction() {
         let signer = create_test_signer();
@@ -169,7 +611,11 @@ mod tests {
         let result = signer.sign_partial_transaction(&mut tx).await;
         assert!(result.is_ok());
 
-        let (serialized_tx, signature) = result.unwrap();
+        let SignedTransaction {
+            base64_tx: serialized_tx,
+            signature,
+            ..
+        } = result.unwrap();
 
         // Verify the signature is valid
         assert_eq!(signature.as_ref().len(), 64);
@@ -181,4 +627,165 @@ mod tests {
         assert_eq!(tx.signatures.len(), 1);
         assert_eq!(tx.signatures[0], signature);
     }
+
+    #[tokio::test]
+    async fn test_sign_partial_detailed() {
+        let signer = create_test_signer().with_output_encoding(Encoding::Base58);
+        let co_signer_a = keypair_pubkey(&Keypair::new());
+        let co_signer_b = keypair_pubkey(&Keypair::new());
+
+        let mut tx =
+            create_multi_signer_test_transaction(&[signer.pubkey(), co_signer_a, co_signer_b]);
+
+        let result = signer.sign_partial_detailed(&mut tx).await.unwrap();
+
+        assert_eq!(result.encoding, Encoding::Base58);
+        assert_eq!(
+            result.serialized,
+            TransactionUtil::serialize_transaction_with_encoding(&tx, Encoding::Base58).unwrap()
+        );
+        assert_eq!(tx.signatures[0], result.signature);
+
+        let mut remaining = result.remaining;
+        let mut expected = vec![co_signer_a, co_signer_b];
+        remaining.sort();
+        expected.sort();
+        assert_eq!(remaining, expected);
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction_hex_output() {
+        let signer = create_test_signer().with_output_encoding(Encoding::Hex);
+
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        let SignedTransaction {
+            base64_tx: serialized_tx,
+            signature,
+            ..
+        } = signer.sign_transaction(&mut tx).await.unwrap();
+
+        let bytes: Vec<u8> = (0..serialized_tx.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&serialized_tx[i..i + 2], 16).unwrap())
+            .collect();
+        let decoded: Transaction = bincode::deserialize(&bytes).unwrap();
+
+        assert_eq!(decoded, tx);
+        assert_eq!(decoded.signatures[0], signature);
+    }
+
+    #[tokio::test]
+    async fn test_signed_transaction_reencode() {
+        let signer = create_test_signer().with_output_encoding(Encoding::Hex);
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        let signed = signer.sign_transaction(&mut tx).await.unwrap();
+        let bytes = TransactionUtil::serialize_transaction_bytes(&tx).unwrap();
+
+        assert_eq!(signed.encoding, Encoding::Hex);
+        assert_eq!(signed.transaction_bytes().unwrap(), bytes);
+        assert_eq!(
+            signed.encoded(Encoding::Base58).unwrap(),
+            bs58::encode(&bytes).into_string()
+        );
+
+        let json = serde_json::to_value(&signed).unwrap();
+        assert_eq!(json["encoding"], "hex");
+    }
+
+    #[tokio::test]
+    async fn test_sign_bundle() {
+        let signer = create_test_signer();
+        let mut txs: Vec<Transaction> = (0..3)
+            .map(|_| create_test_transaction(&signer.pubkey()))
+            .collect();
+
+        let bundle = signer.sign_bundle(&mut txs).await.unwrap();
+
+        assert_eq!(bundle.len(), 3);
+        for (serialized, tx) in bundle.iter().zip(txs.iter()) {
+            assert_eq!(
+                serialized,
+                &TransactionUtil::serialize_transaction(tx).unwrap()
+            );
+            assert!(TransactionUtil::verify_signature(
+                &signer.pubkey(),
+                &tx.message_data(),
+                &tx.signatures[0]
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_precomputed() {
+        let signer = create_test_signer();
+        let mut expected_tx = create_test_transaction(&signer.pubkey());
+        let mut tx = expected_tx.clone();
+        let message_bytes = tx.message_data();
+
+        let expected = signer.sign_transaction(&mut expected_tx).await.unwrap();
+
+        assert_eq!(
+            signer
+                .sign_precomputed(&message_bytes, &mut tx.clone(), None)
+                .await
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            signer
+                .sign_precomputed(&message_bytes, &mut tx, Some(0))
+                .await
+                .unwrap(),
+            expected
+        );
+        assert_eq!(tx, expected_tx);
+    }
+
+    #[tokio::test]
+    async fn test_default_signing_paths_use_output_encoding() {
+        let signer = create_test_signer().with_output_encoding(Encoding::Base58);
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let message_bytes = tx.message_data();
+
+        let signed = signer
+            .sign_precomputed(&message_bytes, &mut tx, None)
+            .await
+            .unwrap();
+        assert_eq!(signed.encoding, Encoding::Base58);
+        assert_eq!(
+            signed.base64_tx,
+            TransactionUtil::serialize_transaction_with_encoding(&tx, Encoding::Base58).unwrap()
+        );
+
+        let mut versioned = crate::test_util::create_v0_test_transaction(
+            &signer.pubkey(),
+            &Pubkey::new_unique(),
+            vec![0],
+            vec![],
+        );
+        let signed = signer
+            .sign_versioned_transaction(&mut versioned)
+            .await
+            .unwrap();
+        assert_eq!(signed.encoding, Encoding::Base58);
+        assert_eq!(
+            signed.transaction_bytes().unwrap(),
+            bincode::serialize(&versioned).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_precomputed_wrong_slot() {
+        let signer = create_test_signer();
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let message_bytes = tx.message_data();
+
+        let result = signer
+            .sign_precomputed(&message_bytes, &mut tx, Some(1))
+            .await;
+
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+    }
 }
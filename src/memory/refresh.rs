@@ -0,0 +1,236 @@
+//! Memory signer that reloads its keypair from a secret store
+
+use super::MemorySigner;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerMetadata};
+use crate::transaction_util::Encoding;
+use crate::{error::SignerError, traits::SolanaSigner};
+use futures::future::BoxFuture;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Fetches the current keypair from its source
+pub(crate) type KeyLoader =
+    Arc<dyn Fn() -> BoxFuture<'static, Result<MemorySigner, SignerError>> + Send + Sync>;
+
+struct Loaded {
+    signer: Arc<MemorySigner>,
+    loaded_at: Instant,
+}
+
+/// Memory signer that re-fetches its keypair once `refresh_interval` has elapsed
+///
+/// Refreshes happen lazily on the next signing call, so rotating the secret takes
+/// effect within one interval without a background task. If a refresh fails, the
+/// previously loaded key keeps signing and the refresh is retried after another
+/// interval.
+pub struct RefreshingSigner {
+    source: String,
+    loader: KeyLoader,
+    refresh_interval: Duration,
+    current: RwLock<Loaded>,
+    refreshing: tokio::sync::Mutex<()>,
+}
+
+impl std::fmt::Debug for RefreshingSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshingSigner")
+            .field("source", &self.source)
+            .field("pubkey", &self.pubkey())
+            .finish_non_exhaustive()
+    }
+}
+
+impl RefreshingSigner {
+    /// Load the keypair once and keep `loader` for later refreshes
+    pub(crate) async fn new(
+        source: String,
+        loader: KeyLoader,
+        refresh_interval: Duration,
+    ) -> Result<Self, SignerError> {
+        let signer = loader().await?;
+
+        Ok(Self {
+            source,
+            loader,
+            refresh_interval,
+            current: RwLock::new(Loaded {
+                signer: Arc::new(signer),
+                loaded_at: Instant::now(),
+            }),
+            refreshing: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    fn snapshot(&self) -> (Arc<MemorySigner>, Instant) {
+        let current = self.current.read().unwrap_or_else(|e| e.into_inner());
+        (current.signer.clone(), current.loaded_at)
+    }
+
+    fn store(&self, signer: Option<MemorySigner>) {
+        let mut current = self.current.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(signer) = signer {
+            current.signer = Arc::new(signer);
+        }
+        current.loaded_at = Instant::now();
+    }
+
+    /// Re-fetch the keypair now, regardless of the refresh interval
+    pub async fn refresh(&self) -> Result<(), SignerError> {
+        let _guard = self.refreshing.lock().await;
+        let signer = (self.loader)().await?;
+
+        if signer.pubkey() != self.pubkey() {
+            log::info!(
+                "Keypair from {} rotated to {}",
+                self.source,
+                signer.pubkey()
+            );
+        }
+        self.store(Some(signer));
+
+        Ok(())
+    }
+
+    /// The signer to use now, refreshing first if the interval has elapsed
+    async fn current(&self) -> Arc<MemorySigner> {
+        let (signer, loaded_at) = self.snapshot();
+        if loaded_at.elapsed() < self.refresh_interval {
+            return signer;
+        }
+
+        let _guard = self.refreshing.lock().await;
+        // Another caller may have refreshed while we waited for the lock
+        let (signer, loaded_at) = self.snapshot();
+        if loaded_at.elapsed() < self.refresh_interval {
+            return signer;
+        }
+
+        match (self.loader)().await {
+            Ok(fresh) => self.store(Some(fresh)),
+            Err(e) => {
+                log::warn!(
+                    "Failed to refresh keypair from {}, keeping the current key: {e}",
+                    self.source
+                );
+                self.store(None);
+            }
+        }
+
+        self.snapshot().0
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for RefreshingSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.snapshot().0.pubkey()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.current().await.sign_transaction(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.current().await.sign_message(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.current().await.sign_partial_transaction(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        // A key is always loaded; failed refreshes fall back to it
+        true
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn output_encoding(&self) -> Encoding {
+        self.snapshot().0.output_encoding()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("memory", self.pubkey(), self.latency_class()).with_key_id(&self.source)
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=memory source={} refresh_interval={}s pubkey={}",
+            self.source,
+            self.refresh_interval.as_secs(),
+            self.pubkey()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::Keypair;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn rotating_loader(keys: Vec<Keypair>, calls: Arc<AtomicUsize>) -> KeyLoader {
+        let keys: Vec<Vec<u8>> = keys.iter().map(|k| k.to_bytes().to_vec()).collect();
+        Arc::new(move || {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            let result = match keys.get(call) {
+                Some(bytes) => MemorySigner::from_bytes(bytes),
+                None => Err(SignerError::NotAvailable("secret store down".to_string())),
+            };
+            Box::pin(async move { result })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_refresh_picks_up_rotated_key() {
+        let first = Keypair::new();
+        let second = Keypair::new();
+        let second_pubkey = MemorySigner::from_bytes(&second.to_bytes())
+            .unwrap()
+            .pubkey();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let signer = RefreshingSigner::new(
+            "test".to_string(),
+            rotating_loader(vec![first, second], calls.clone()),
+            Duration::ZERO,
+        )
+        .await
+        .unwrap();
+
+        signer.sign_message(b"hello").await.unwrap();
+        assert_eq!(signer.pubkey(), second_pubkey);
+
+        // The store is now failing, so the rotated key keeps signing
+        signer.sign_message(b"hello").await.unwrap();
+        assert_eq!(signer.pubkey(), second_pubkey);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(signer.refresh().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_no_refresh_within_interval() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let signer = RefreshingSigner::new(
+            "test".to_string(),
+            rotating_loader(vec![Keypair::new(), Keypair::new()], calls.clone()),
+            Duration::from_secs(3600),
+        )
+        .await
+        .unwrap();
+
+        signer.sign_message(b"hello").await.unwrap();
+        signer.sign_message(b"hello").await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(signer.config_debug().contains("refresh_interval=3600s"));
+    }
+}
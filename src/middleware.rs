@@ -0,0 +1,570 @@
+//! Pre/post-sign hooks layered over any signer
+//!
+//! [`LayeredSigner`] runs a stack of [`SignerMiddleware`] around every signing
+//! request, so policy checks, metrics and transaction rewrites (e.g. fee
+//! injection) can be added once instead of in each backend.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction, VersionedTransaction};
+use crate::traits::{
+    HealthStatus, LatencyClass, SignOptions, SignedTransaction, SignerCapabilities, SignerMetadata,
+    SolanaSigner,
+};
+use crate::transaction_util::{Encoding, TransactionUtil};
+
+/// The payload of a signing request, as seen by middleware
+pub enum SignRequest<'a> {
+    /// A transaction passed to [`SolanaSigner::sign_transaction`]
+    Transaction(&'a mut Transaction),
+    /// A transaction passed to [`SolanaSigner::sign_partial_transaction`]
+    PartialTransaction(&'a mut Transaction),
+    /// A transaction passed to [`SolanaSigner::sign_versioned_transaction`]
+    VersionedTransaction(&'a mut VersionedTransaction),
+    /// Raw bytes passed to [`SolanaSigner::sign_message`]
+    Message(&'a [u8]),
+}
+
+impl SignRequest<'_> {
+    /// The exact bytes the signer will sign for this request
+    pub fn message_bytes(&self) -> Vec<u8> {
+        match self {
+            SignRequest::Transaction(tx) | SignRequest::PartialTransaction(tx) => tx.message_data(),
+            SignRequest::VersionedTransaction(tx) => tx.message.serialize(),
+            SignRequest::Message(message) => message.to_vec(),
+        }
+    }
+}
+
+/// Hooks invoked around each signing request of a [`LayeredSigner`]
+#[async_trait]
+pub trait SignerMiddleware: Send + Sync {
+    /// Called before the request reaches the wrapped signer
+    ///
+    /// Transactions may be modified in place; the signer signs the modified
+    /// transaction. Returning an error rejects the request without signing.
+    async fn before_sign(&self, _request: &mut SignRequest<'_>) -> Result<(), SignerError> {
+        Ok(())
+    }
+
+    /// Called once the request has been signed or rejected
+    ///
+    /// Also runs when a `before_sign` hook rejected the request, so every request
+    /// is observed exactly once.
+    async fn after_sign(
+        &self,
+        _request: &SignRequest<'_>,
+        _result: Result<&Signature, &SignerError>,
+    ) {
+    }
+}
+
+#[async_trait]
+impl<M: SignerMiddleware + ?Sized> SignerMiddleware for Arc<M> {
+    async fn before_sign(&self, request: &mut SignRequest<'_>) -> Result<(), SignerError> {
+        (**self).before_sign(request).await
+    }
+
+    async fn after_sign(
+        &self,
+        request: &SignRequest<'_>,
+        result: Result<&Signature, &SignerError>,
+    ) {
+        (**self).after_sign(request, result).await
+    }
+}
+
+/// Middleware that sets the compute unit limit and price before signing
+///
+/// Applies [`TransactionUtil::with_compute_budget`] to legacy transactions. A
+/// partially-signed transaction is only rewritten if it already carries the same
+/// budget, since changing it would invalidate the other parties' signatures.
+/// Versioned transactions are rejected rather than signed without the budget.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ComputeBudgetMiddleware {
+    cu_limit: Option<u32>,
+    cu_price: Option<u64>,
+}
+
+impl ComputeBudgetMiddleware {
+    /// Create a middleware that sets neither limit nor price
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the compute unit limit
+    pub fn with_cu_limit(mut self, cu_limit: u32) -> Self {
+        self.cu_limit = Some(cu_limit);
+        self
+    }
+
+    /// Set the compute unit price, in micro-lamports per compute unit
+    pub fn with_cu_price(mut self, cu_price: u64) -> Self {
+        self.cu_price = Some(cu_price);
+        self
+    }
+}
+
+#[async_trait]
+impl SignerMiddleware for ComputeBudgetMiddleware {
+    async fn before_sign(&self, request: &mut SignRequest<'_>) -> Result<(), SignerError> {
+        match request {
+            SignRequest::Transaction(tx) | SignRequest::PartialTransaction(tx) => {
+                let mut updated = (**tx).clone();
+                TransactionUtil::with_compute_budget(&mut updated, self.cu_limit, self.cu_price)?;
+
+                if updated.message != tx.message {
+                    let signed = tx
+                        .signatures
+                        .iter()
+                        .any(|signature| *signature != Signature::default());
+                    if signed {
+                        return Err(SignerError::SigningFailed(
+                            "Cannot change the compute budget of a partially-signed transaction"
+                                .to_string(),
+                        ));
+                    }
+                    **tx = updated;
+                }
+
+                Ok(())
+            }
+            SignRequest::VersionedTransaction(_) => Err(SignerError::SigningFailed(
+                "Compute budget injection is not supported for versioned transactions".to_string(),
+            )),
+            SignRequest::Message(_) => Ok(()),
+        }
+    }
+}
+
+/// Middleware that rejects transactions too large to fit in a packet
+///
+/// Fails with `SignerError::TransactionTooLarge` before the request reaches the
+/// signer, instead of signing a transaction that RPC submission would reject.
+/// Add it after any middleware that rewrites transactions.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PacketSizeMiddleware;
+
+#[async_trait]
+impl SignerMiddleware for PacketSizeMiddleware {
+    async fn before_sign(&self, request: &mut SignRequest<'_>) -> Result<(), SignerError> {
+        match request {
+            SignRequest::Transaction(tx) | SignRequest::PartialTransaction(tx) => {
+                TransactionUtil::check_packet_size(tx).map(|_| ())
+            }
+            SignRequest::VersionedTransaction(tx) => {
+                TransactionUtil::check_versioned_packet_size(tx).map(|_| ())
+            }
+            SignRequest::Message(_) => Ok(()),
+        }
+    }
+}
+
+/// Middleware that rejects malformed transactions before they are signed
+///
+/// Runs [`TransactionUtil::sanitize`] or [`TransactionUtil::sanitize_versioned`],
+/// so a bad transaction fails with `SignerError::InvalidTransaction` instead of
+/// being signed and rejected on-chain.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SanitizeMiddleware;
+
+#[async_trait]
+impl SignerMiddleware for SanitizeMiddleware {
+    async fn before_sign(&self, request: &mut SignRequest<'_>) -> Result<(), SignerError> {
+        match request {
+            SignRequest::Transaction(tx) | SignRequest::PartialTransaction(tx) => {
+                TransactionUtil::sanitize(tx)
+            }
+            SignRequest::VersionedTransaction(tx) => TransactionUtil::sanitize_versioned(tx),
+            SignRequest::Message(_) => Ok(()),
+        }
+    }
+}
+
+/// Signer wrapper that runs middleware around every signing request
+///
+/// `before_sign` hooks run in the order they were added and `after_sign` hooks in
+/// reverse order. The first `before_sign` error stops the remaining hooks and
+/// the signing call.
+pub struct LayeredSigner<S> {
+    inner: S,
+    middleware: Vec<Arc<dyn SignerMiddleware>>,
+}
+
+impl<S: SolanaSigner> LayeredSigner<S> {
+    /// Wrap `inner` with no middleware
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            middleware: Vec::new(),
+        }
+    }
+
+    /// Add a middleware layer, outside the ones added before it
+    pub fn with_middleware(mut self, middleware: impl SignerMiddleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Get a reference to the wrapped signer
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwrap the signer, dropping the middleware
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    async fn before_sign(&self, request: &mut SignRequest<'_>) -> Result<(), SignerError> {
+        for middleware in &self.middleware {
+            middleware.before_sign(request).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn after_sign(
+        &self,
+        request: &SignRequest<'_>,
+        result: Result<&Signature, &SignerError>,
+    ) {
+        for middleware in self.middleware.iter().rev() {
+            middleware.after_sign(request, result).await;
+        }
+    }
+}
+
+#[async_trait]
+impl<S: SolanaSigner> SolanaSigner for LayeredSigner<S> {
+    fn pubkey(&self) -> Pubkey {
+        self.inner.pubkey()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let result = match self.before_sign(&mut SignRequest::Transaction(tx)).await {
+            Ok(()) => self.inner.sign_transaction(tx).await,
+            Err(e) => Err(e),
+        };
+
+        self.after_sign(
+            &SignRequest::Transaction(tx),
+            result.as_ref().map(|signed| &signed.signature),
+        )
+        .await;
+
+        result
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let result = match self.before_sign(&mut SignRequest::Message(message)).await {
+            Ok(()) => self.inner.sign_message(message).await,
+            Err(e) => Err(e),
+        };
+
+        self.after_sign(&SignRequest::Message(message), result.as_ref())
+            .await;
+
+        result
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let result = match self
+            .before_sign(&mut SignRequest::PartialTransaction(tx))
+            .await
+        {
+            Ok(()) => self.inner.sign_partial_transaction(tx).await,
+            Err(e) => Err(e),
+        };
+
+        self.after_sign(
+            &SignRequest::PartialTransaction(tx),
+            result.as_ref().map(|signed| &signed.signature),
+        )
+        .await;
+
+        result
+    }
+
+    async fn sign_versioned_transaction(
+        &self,
+        tx: &mut VersionedTransaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let result = match self
+            .before_sign(&mut SignRequest::VersionedTransaction(tx))
+            .await
+        {
+            Ok(()) => self.inner.sign_versioned_transaction(tx).await,
+            Err(e) => Err(e),
+        };
+
+        self.after_sign(
+            &SignRequest::VersionedTransaction(tx),
+            result.as_ref().map(|signed| &signed.signature),
+        )
+        .await;
+
+        result
+    }
+
+    async fn sign_transaction_with(
+        &self,
+        tx: &mut Transaction,
+        options: SignOptions,
+    ) -> Result<SignedTransaction, SignerError> {
+        let result = match self.before_sign(&mut SignRequest::Transaction(tx)).await {
+            Ok(()) => self.inner.sign_transaction_with(tx, options).await,
+            Err(e) => Err(e),
+        };
+
+        self.after_sign(
+            &SignRequest::Transaction(tx),
+            result.as_ref().map(|signed| &signed.signature),
+        )
+        .await;
+
+        result
+    }
+
+    async fn sign_precomputed(
+        &self,
+        _message_bytes: &[u8],
+        tx: &mut Transaction,
+        pubkey_slot_hint: Option<usize>,
+    ) -> Result<SignedTransaction, SignerError> {
+        // Middleware may rewrite the transaction, which would invalidate the
+        // precomputed bytes, so sign it as a regular transaction instead
+        if let Some(index) = pubkey_slot_hint {
+            let pubkey = self.pubkey();
+            if tx.message.account_keys.get(index) != Some(&pubkey) {
+                return Err(SignerError::SigningFailed(format!(
+                    "Pubkey {pubkey} is not at signer index {index}"
+                )));
+            }
+        }
+
+        self.sign_transaction(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        self.inner.latency_class()
+    }
+
+    fn output_encoding(&self) -> Encoding {
+        self.inner.output_encoding()
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        self.inner.metadata()
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "layered({}) {}",
+            self.middleware.len(),
+            self.inner.config_debug()
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        self.inner.validate_config()
+    }
+
+    async fn warm_up(&self) -> Result<(), SignerError> {
+        self.inner.warm_up().await
+    }
+
+    async fn health_check(&self) -> Result<(), SignerError> {
+        self.inner.health_check().await
+    }
+
+    async fn health(&self) -> HealthStatus {
+        self.inner.health().await
+    }
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::sdk_adapter::{Hash, Keypair};
+    use crate::test_util::create_test_transaction;
+    use std::sync::Mutex;
+
+    /// Rejects messages containing a forbidden byte string
+    struct DenyMessages(&'static [u8]);
+
+    #[async_trait]
+    impl SignerMiddleware for DenyMessages {
+        async fn before_sign(&self, request: &mut SignRequest<'_>) -> Result<(), SignerError> {
+            if let SignRequest::Message(message) = request {
+                if message.windows(self.0.len()).any(|w| w == self.0) {
+                    return Err(SignerError::SigningFailed("message denied".to_string()));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Records the order of hook calls and whether signing succeeded
+    struct Log {
+        name: &'static str,
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl SignerMiddleware for Log {
+        async fn before_sign(&self, _request: &mut SignRequest<'_>) -> Result<(), SignerError> {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("before {}", self.name));
+            Ok(())
+        }
+
+        async fn after_sign(
+            &self,
+            _request: &SignRequest<'_>,
+            result: Result<&Signature, &SignerError>,
+        ) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("after {} ok={}", self.name, result.is_ok()));
+        }
+    }
+
+    /// Rewrites the blockhash before signing
+    struct SetBlockhash(Hash);
+
+    #[async_trait]
+    impl SignerMiddleware for SetBlockhash {
+        async fn before_sign(&self, request: &mut SignRequest<'_>) -> Result<(), SignerError> {
+            if let SignRequest::Transaction(tx) = request {
+                tx.message.recent_blockhash = self.0;
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hook_order_and_rejection() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let signer = LayeredSigner::new(MemorySigner::new(Keypair::new()))
+            .with_middleware(Log {
+                name: "outer",
+                events: events.clone(),
+            })
+            .with_middleware(DenyMessages(b"forbidden"))
+            .with_middleware(Log {
+                name: "inner",
+                events: events.clone(),
+            });
+
+        signer.sign_message(b"hello").await.unwrap();
+        let result = signer.sign_message(b"a forbidden message").await;
+
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "before outer",
+                "before inner",
+                "after inner ok=true",
+                "after outer ok=true",
+                "before outer",
+                "after inner ok=false",
+                "after outer ok=false",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_middleware_can_modify_transaction() {
+        let blockhash = Hash::new_unique();
+        let signer = LayeredSigner::new(MemorySigner::new(Keypair::new()))
+            .with_middleware(SetBlockhash(blockhash));
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        signer.sign_transaction(&mut tx).await.unwrap();
+
+        assert_eq!(tx.message.recent_blockhash, blockhash);
+        assert!(tx.verify().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_compute_budget_middleware() {
+        let signer = LayeredSigner::new(MemorySigner::new(Keypair::new())).with_middleware(
+            ComputeBudgetMiddleware::new()
+                .with_cu_limit(100_000)
+                .with_cu_price(1_000),
+        );
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        signer.sign_transaction(&mut tx).await.unwrap();
+
+        assert_eq!(tx.message.instructions.len(), 3);
+        assert!(tx.verify().is_ok());
+
+        // Already carries the budget, so signing again leaves the message alone
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert_eq!(tx.message.instructions.len(), 3);
+        assert!(tx.verify().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_compute_budget_middleware_rejects_signed_transaction() {
+        let signer = LayeredSigner::new(MemorySigner::new(Keypair::new()))
+            .with_middleware(ComputeBudgetMiddleware::new().with_cu_price(1_000));
+        let mut tx = create_test_transaction(&signer.pubkey());
+        signer.inner().sign_transaction(&mut tx).await.unwrap();
+
+        let result = signer.sign_partial_transaction(&mut tx).await;
+
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+        assert!(tx.verify().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_packet_size_middleware() {
+        let signer = LayeredSigner::new(MemorySigner::new(Keypair::new()))
+            .with_middleware(PacketSizeMiddleware);
+        let mut tx = create_test_transaction(&signer.pubkey());
+        signer.sign_transaction(&mut tx).await.unwrap();
+
+        tx.message.instructions[0].data = vec![0; 1232];
+        let result = signer.sign_transaction(&mut tx).await;
+
+        assert!(matches!(result, Err(SignerError::TransactionTooLarge(_))));
+    }
+
+    #[tokio::test]
+    async fn test_sanitize_middleware() {
+        let signer = LayeredSigner::new(MemorySigner::new(Keypair::new()))
+            .with_middleware(SanitizeMiddleware);
+        let mut tx = create_test_transaction(&signer.pubkey());
+        tx.message.account_keys[1] = signer.pubkey();
+
+        let result = signer.sign_transaction(&mut tx).await;
+
+        assert!(matches!(result, Err(SignerError::InvalidTransaction(_))));
+        assert_eq!(tx.signatures, vec![Signature::default()]);
+    }
+}
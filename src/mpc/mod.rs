@@ -0,0 +1,411 @@
+//! Pluggable MPC signer abstraction
+//!
+//! Threshold Ed25519 protocols differ in their rounds and messages, but a signing
+//! session always looks the same from the outside: each party turns the messages it
+//! received last round into messages for the next one, until it can output the
+//! signature. [`MpcBackend`] captures that loop for one local party plus the
+//! transport to its peers (or a vendor's coordinator), and [`MpcSigner`] drives it
+//! behind the usual [`SolanaSigner`] interface.
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{SignedTransaction, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Identifier of a party in an MPC signing group
+pub type PartyId = u16;
+
+/// Default cap on rounds per signing session
+pub const DEFAULT_MAX_ROUNDS: u32 = 16;
+
+/// Protocol message exchanged between parties during a signing session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MpcMessage {
+    /// Sending party
+    pub from: PartyId,
+    /// Receiving party, or `None` to broadcast to every party
+    pub to: Option<PartyId>,
+    /// Protocol-specific payload
+    pub payload: Vec<u8>,
+}
+
+/// Outcome of one round of a signing session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MpcRound {
+    /// Messages to deliver before the next round
+    Send(Vec<MpcMessage>),
+    /// The session produced the final signature
+    Finished(Signature),
+}
+
+/// Local party's state for one signing session
+#[async_trait::async_trait]
+pub trait MpcSession: Send {
+    /// Advance the protocol given the messages received since the last round
+    ///
+    /// The first call receives no messages.
+    async fn round(&mut self, incoming: Vec<MpcMessage>) -> Result<MpcRound, SignerError>;
+}
+
+/// One party of a threshold Ed25519 signing group plus the transport to its peers
+#[async_trait::async_trait]
+pub trait MpcBackend: Send + Sync {
+    /// Group public key the parties sign for
+    fn public_key(&self) -> Pubkey;
+
+    /// Start a signing session for `message`
+    ///
+    /// `session_id` is unique per signing request and must be shared with the other
+    /// parties so they join the same session.
+    async fn start_session(
+        &self,
+        session_id: &str,
+        message: &[u8],
+    ) -> Result<Box<dyn MpcSession>, SignerError>;
+
+    /// Deliver this party's `outgoing` messages and wait for the peers' messages for `round`
+    async fn exchange(
+        &self,
+        session_id: &str,
+        round: u32,
+        outgoing: Vec<MpcMessage>,
+    ) -> Result<Vec<MpcMessage>, SignerError>;
+
+    /// Check that enough parties are reachable to sign
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    /// Short description of the backend for `config_debug`
+    fn describe(&self) -> String {
+        "mpc".to_string()
+    }
+}
+
+/// Signer driving an [`MpcBackend`] signing session per request
+#[derive(Clone)]
+pub struct MpcSigner {
+    backend: Arc<dyn MpcBackend>,
+    max_rounds: u32,
+    timeout: Option<Duration>,
+}
+
+impl std::fmt::Debug for MpcSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MpcSigner")
+            .field("backend", &self.backend.describe())
+            .field("max_rounds", &self.max_rounds)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl MpcSigner {
+    /// Create a new MpcSigner over `backend`
+    pub fn new(backend: impl MpcBackend + 'static) -> Self {
+        Self::from_arc(Arc::new(backend))
+    }
+
+    /// Create a new MpcSigner over a shared `backend`
+    pub fn from_arc(backend: Arc<dyn MpcBackend>) -> Self {
+        Self {
+            backend,
+            max_rounds: DEFAULT_MAX_ROUNDS,
+            timeout: None,
+        }
+    }
+
+    /// Abort sessions that have not finished after `max_rounds` rounds (default 16)
+    pub fn with_max_rounds(mut self, max_rounds: u32) -> Self {
+        self.max_rounds = max_rounds;
+        self
+    }
+
+    /// Abort sessions that take longer than `timeout` overall
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Run one signing session to completion
+    async fn run_session(
+        &self,
+        session_id: &str,
+        message: &[u8],
+    ) -> Result<Signature, SignerError> {
+        let mut session = self.backend.start_session(session_id, message).await?;
+        let mut incoming = Vec::new();
+
+        for round in 0..self.max_rounds {
+            match session.round(incoming).await? {
+                MpcRound::Finished(signature) => return Ok(signature),
+                MpcRound::Send(outgoing) => {
+                    incoming = self.backend.exchange(session_id, round, outgoing).await?;
+                }
+            }
+        }
+
+        Err(SignerError::SigningFailed(format!(
+            "MPC session {session_id} did not finish within {} rounds",
+            self.max_rounds
+        )))
+    }
+
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let session_id = hex_session_id(rand::random());
+        let session = self.run_session(&session_id, message);
+
+        let signature = match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, session).await.map_err(|_| {
+                SignerError::Timeout(format!("MPC session {session_id} timed out"))
+            })??,
+            None => session.await?,
+        };
+
+        // A misbehaving party can make the group output garbage; never return it
+        if !TransactionUtil::verify_signature(&self.backend.public_key(), message, &signature) {
+            return Err(SignerError::SigningFailed(format!(
+                "MPC session {session_id} produced a signature that does not verify"
+            )));
+        }
+
+        Ok(signature)
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(
+            transaction,
+            &self.backend.public_key(),
+            signature,
+        )?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.backend.public_key(),
+        ))
+    }
+}
+
+fn hex_session_id(bytes: [u8; 16]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for MpcSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.backend.public_key()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.backend.is_available().await
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("mpc", self.pubkey(), self.latency_class())
+            .with_key_id(self.backend.describe())
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=mpc protocol={} max_rounds={} pubkey={}",
+            self.backend.describe(),
+            self.max_rounds,
+            self.pubkey()
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        if self.max_rounds == 0 {
+            return Err(SignerError::ConfigError(
+                "MPC max_rounds must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use std::sync::Mutex;
+
+    /// Two-round stand-in protocol: the local party broadcasts a commitment, and once
+    /// the peer's commitment arrives it outputs the signature from a shared keypair
+    struct TestBackend {
+        keypair: Arc<Keypair>,
+        peer_replies: bool,
+        sessions: Mutex<Vec<String>>,
+    }
+
+    struct TestSession {
+        keypair: Arc<Keypair>,
+        message: Vec<u8>,
+        committed: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl MpcSession for TestSession {
+        async fn round(&mut self, incoming: Vec<MpcMessage>) -> Result<MpcRound, SignerError> {
+            if !self.committed {
+                self.committed = true;
+                return Ok(MpcRound::Send(vec![MpcMessage {
+                    from: 1,
+                    to: None,
+                    payload: b"commitment".to_vec(),
+                }]));
+            }
+
+            match incoming.iter().find(|message| message.from == 2) {
+                Some(_) => Ok(MpcRound::Finished(keypair_sign_message(
+                    &self.keypair,
+                    &self.message,
+                ))),
+                None => Ok(MpcRound::Send(Vec::new())),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MpcBackend for TestBackend {
+        fn public_key(&self) -> Pubkey {
+            keypair_pubkey(&self.keypair)
+        }
+
+        async fn start_session(
+            &self,
+            session_id: &str,
+            message: &[u8],
+        ) -> Result<Box<dyn MpcSession>, SignerError> {
+            self.sessions.lock().unwrap().push(session_id.to_string());
+            Ok(Box::new(TestSession {
+                keypair: self.keypair.clone(),
+                message: message.to_vec(),
+                committed: false,
+            }))
+        }
+
+        async fn exchange(
+            &self,
+            _session_id: &str,
+            _round: u32,
+            outgoing: Vec<MpcMessage>,
+        ) -> Result<Vec<MpcMessage>, SignerError> {
+            if !self.peer_replies {
+                return Ok(Vec::new());
+            }
+            Ok(outgoing
+                .into_iter()
+                .map(|message| MpcMessage { from: 2, ..message })
+                .collect())
+        }
+    }
+
+    fn backend(keypair: Keypair, peer_replies: bool) -> TestBackend {
+        TestBackend {
+            keypair: Arc::new(keypair),
+            peer_replies,
+            sessions: Mutex::new(Vec::new()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mpc_sign_message_and_transaction() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let expected = keypair_sign_message(&keypair, b"hello");
+        let backend = Arc::new(backend(keypair, true));
+        let signer = MpcSigner::from_arc(backend.clone());
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert_eq!(signer.sign_message(b"hello").await.unwrap(), expected);
+
+        let mut tx = create_test_transaction(&pubkey);
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+
+        let sessions = backend.sessions.lock().unwrap();
+        assert_eq!(sessions.len(), 2);
+        assert_ne!(sessions[0], sessions[1]);
+    }
+
+    #[tokio::test]
+    async fn test_mpc_round_limit() {
+        let signer = MpcSigner::new(backend(Keypair::new(), false)).with_max_rounds(4);
+
+        let err = signer.sign_message(b"hello").await.unwrap_err();
+        assert!(matches!(err, SignerError::SigningFailed(_)));
+        assert!(err.to_string().contains("4 rounds"));
+    }
+
+    #[tokio::test]
+    async fn test_mpc_rejects_wrong_signature() {
+        struct WrongKey(TestBackend);
+
+        #[async_trait::async_trait]
+        impl MpcBackend for WrongKey {
+            fn public_key(&self) -> Pubkey {
+                Pubkey::new_unique()
+            }
+
+            async fn start_session(
+                &self,
+                session_id: &str,
+                message: &[u8],
+            ) -> Result<Box<dyn MpcSession>, SignerError> {
+                self.0.start_session(session_id, message).await
+            }
+
+            async fn exchange(
+                &self,
+                session_id: &str,
+                round: u32,
+                outgoing: Vec<MpcMessage>,
+            ) -> Result<Vec<MpcMessage>, SignerError> {
+                self.0.exchange(session_id, round, outgoing).await
+            }
+        }
+
+        let signer = MpcSigner::new(WrongKey(backend(Keypair::new(), true)));
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_mpc_validate_config() {
+        let signer = MpcSigner::new(backend(Keypair::new(), true)).with_max_rounds(0);
+        assert!(matches!(
+            signer.validate_config(),
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+}
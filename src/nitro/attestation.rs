@@ -0,0 +1,487 @@
+//! Nitro Enclaves attestation document verification
+//!
+//! An attestation document is a COSE_Sign1 structure whose CBOR payload carries the
+//! enclave's PCR measurements, the nonce and public key it was asked to bind, and
+//! an ECDSA P-384 certificate chain rooted in the AWS Nitro Enclaves root CA.
+
+use crate::error::SignerError;
+use ciborium::Value;
+use p384::ecdsa::signature::Verifier;
+use p384::ecdsa::{Signature as EcdsaSignature, VerifyingKey};
+use std::collections::BTreeMap;
+use x509_cert::der::{Decode, Encode};
+use x509_cert::Certificate;
+
+/// OID of ecdsa-with-SHA384, the only algorithm used in the Nitro certificate chain
+const ECDSA_WITH_SHA384: &str = "1.2.840.10045.4.3.3";
+
+/// What an attestation document must show before the enclave is trusted
+#[derive(Debug, Clone)]
+pub struct AttestationPolicy {
+    root_certificate: Vec<u8>,
+    expected_pcrs: BTreeMap<u64, Vec<u8>>,
+}
+
+impl AttestationPolicy {
+    /// Trust documents chaining to `root_certificate`, the DER-encoded AWS Nitro Enclaves root CA
+    ///
+    /// AWS publishes the root certificate in the Nitro Enclaves documentation; pin the
+    /// copy you downloaded and checked against its published fingerprint.
+    pub fn new(root_certificate: Vec<u8>) -> Self {
+        Self {
+            root_certificate,
+            expected_pcrs: BTreeMap::new(),
+        }
+    }
+
+    /// Require PCR `index` to equal `value` (e.g. PCR0 for the enclave image hash)
+    pub fn with_pcr(mut self, index: u64, value: Vec<u8>) -> Self {
+        self.expected_pcrs.insert(index, value);
+        self
+    }
+
+    /// Verify `document` and check it binds `nonce` and the Ed25519 `public_key`
+    pub fn verify(
+        &self,
+        document: &[u8],
+        nonce: &[u8],
+        public_key: &[u8],
+        now_unix_secs: u64,
+    ) -> Result<(), SignerError> {
+        let sign1 = CoseSign1::decode(document)?;
+        let claims = AttestationClaims::decode(&sign1.payload)?;
+
+        let leaf = self.verify_chain(&claims, now_unix_secs)?;
+        sign1.verify(&leaf)?;
+        self.check_claims(&claims, nonce, public_key)
+    }
+
+    /// Verify the certificate chain and return the leaf key that signed the document
+    fn verify_chain(
+        &self,
+        claims: &AttestationClaims,
+        now_unix_secs: u64,
+    ) -> Result<VerifyingKey, SignerError> {
+        let root = claims
+            .cabundle
+            .first()
+            .ok_or_else(|| attestation_error("document has an empty CA bundle"))?;
+        if *root != self.root_certificate {
+            return Err(attestation_error(
+                "certificate chain does not start at the pinned root",
+            ));
+        }
+
+        let mut issuer = parse_certificate(root)?;
+        check_validity(&issuer, now_unix_secs)?;
+
+        for der in claims.cabundle[1..]
+            .iter()
+            .chain(std::iter::once(&claims.certificate))
+        {
+            let certificate = parse_certificate(der)?;
+            check_validity(&certificate, now_unix_secs)?;
+            verify_issued_by(&certificate, &issuer)?;
+            issuer = certificate;
+        }
+
+        certificate_key(&issuer)
+    }
+
+    fn check_claims(
+        &self,
+        claims: &AttestationClaims,
+        nonce: &[u8],
+        public_key: &[u8],
+    ) -> Result<(), SignerError> {
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err(attestation_error("nonce does not match the request"));
+        }
+
+        if claims.public_key.as_deref() != Some(public_key) {
+            return Err(attestation_error(
+                "document does not bind the enclave's signing key",
+            ));
+        }
+
+        for (index, expected) in &self.expected_pcrs {
+            if claims.pcrs.get(index) != Some(expected) {
+                return Err(attestation_error(&format!(
+                    "PCR{index} does not match the expected measurement"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn attestation_error(reason: &str) -> SignerError {
+    SignerError::SigningFailed(format!("Enclave attestation rejected: {reason}"))
+}
+
+fn parse_certificate(der: &[u8]) -> Result<Certificate, SignerError> {
+    Certificate::from_der(der).map_err(|e| attestation_error(&format!("invalid certificate: {e}")))
+}
+
+fn check_validity(certificate: &Certificate, now_unix_secs: u64) -> Result<(), SignerError> {
+    let validity = &certificate.tbs_certificate.validity;
+    let not_before = validity.not_before.to_unix_duration().as_secs();
+    let not_after = validity.not_after.to_unix_duration().as_secs();
+
+    if now_unix_secs < not_before || now_unix_secs > not_after {
+        return Err(attestation_error("certificate is expired or not yet valid"));
+    }
+
+    Ok(())
+}
+
+fn certificate_key(certificate: &Certificate) -> Result<VerifyingKey, SignerError> {
+    let key = certificate
+        .tbs_certificate
+        .subject_public_key_info
+        .subject_public_key
+        .raw_bytes();
+    VerifyingKey::from_sec1_bytes(key)
+        .map_err(|_| attestation_error("certificate key is not a P-384 key"))
+}
+
+fn verify_issued_by(certificate: &Certificate, issuer: &Certificate) -> Result<(), SignerError> {
+    if certificate.signature_algorithm.oid.to_string() != ECDSA_WITH_SHA384 {
+        return Err(attestation_error(
+            "certificate is not signed with ecdsa-with-SHA384",
+        ));
+    }
+
+    let tbs = certificate
+        .tbs_certificate
+        .to_der()
+        .map_err(|e| attestation_error(&format!("invalid certificate: {e}")))?;
+    let signature = EcdsaSignature::from_der(certificate.signature.raw_bytes())
+        .map_err(|_| attestation_error("malformed certificate signature"))?;
+
+    certificate_key(issuer)?
+        .verify(&tbs, &signature)
+        .map_err(|_| attestation_error("certificate chain signature is invalid"))
+}
+
+/// The parts of a COSE_Sign1 structure needed to verify it
+struct CoseSign1 {
+    protected: Vec<u8>,
+    payload: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+impl CoseSign1 {
+    fn decode(document: &[u8]) -> Result<Self, SignerError> {
+        let value: Value = ciborium::de::from_reader(document)
+            .map_err(|e| attestation_error(&format!("document is not CBOR: {e}")))?;
+
+        // The COSE_Sign1 tag (18) is optional
+        let value = match value {
+            Value::Tag(18, inner) => *inner,
+            other => other,
+        };
+
+        match value {
+            Value::Array(items) if items.len() == 4 => {
+                let mut items = items.into_iter();
+                let protected = into_bytes(items.next())?;
+                let _unprotected = items.next();
+                let payload = into_bytes(items.next())?;
+                let signature = into_bytes(items.next())?;
+                Ok(Self {
+                    protected,
+                    payload,
+                    signature,
+                })
+            }
+            _ => Err(attestation_error("document is not a COSE_Sign1 structure")),
+        }
+    }
+
+    fn verify(&self, key: &VerifyingKey) -> Result<(), SignerError> {
+        let sig_structure = Value::Array(vec![
+            Value::Text("Signature1".to_string()),
+            Value::Bytes(self.protected.clone()),
+            Value::Bytes(Vec::new()),
+            Value::Bytes(self.payload.clone()),
+        ]);
+        let mut to_be_signed = Vec::new();
+        ciborium::ser::into_writer(&sig_structure, &mut to_be_signed)
+            .map_err(|e| SignerError::SerializationError(e.to_string()))?;
+
+        let signature = EcdsaSignature::from_slice(&self.signature)
+            .map_err(|_| attestation_error("malformed document signature"))?;
+
+        key.verify(&to_be_signed, &signature)
+            .map_err(|_| attestation_error("document signature is invalid"))
+    }
+}
+
+fn into_bytes(value: Option<Value>) -> Result<Vec<u8>, SignerError> {
+    match value {
+        Some(Value::Bytes(bytes)) => Ok(bytes),
+        _ => Err(attestation_error("expected a byte string")),
+    }
+}
+
+/// Attestation document payload fields we check
+struct AttestationClaims {
+    pcrs: BTreeMap<u64, Vec<u8>>,
+    certificate: Vec<u8>,
+    cabundle: Vec<Vec<u8>>,
+    public_key: Option<Vec<u8>>,
+    nonce: Option<Vec<u8>>,
+}
+
+impl AttestationClaims {
+    fn decode(payload: &[u8]) -> Result<Self, SignerError> {
+        let value: Value = ciborium::de::from_reader(payload)
+            .map_err(|e| attestation_error(&format!("payload is not CBOR: {e}")))?;
+        let Value::Map(entries) = value else {
+            return Err(attestation_error("payload is not a map"));
+        };
+
+        let mut claims = Self {
+            pcrs: BTreeMap::new(),
+            certificate: Vec::new(),
+            cabundle: Vec::new(),
+            public_key: None,
+            nonce: None,
+        };
+
+        for (key, value) in entries {
+            let Value::Text(key) = key else { continue };
+            match (key.as_str(), value) {
+                ("pcrs", Value::Map(pcrs)) => {
+                    for (index, value) in pcrs {
+                        let index = index
+                            .as_integer()
+                            .and_then(|index| u64::try_from(index).ok())
+                            .ok_or_else(|| attestation_error("PCR index is not an integer"))?;
+                        claims.pcrs.insert(index, into_bytes(Some(value))?);
+                    }
+                }
+                ("certificate", value) => claims.certificate = into_bytes(Some(value))?,
+                ("cabundle", Value::Array(certificates)) => {
+                    claims.cabundle = certificates
+                        .into_iter()
+                        .map(|certificate| into_bytes(Some(certificate)))
+                        .collect::<Result<_, _>>()?;
+                }
+                ("public_key", Value::Bytes(bytes)) => claims.public_key = Some(bytes),
+                ("nonce", Value::Bytes(bytes)) => claims.nonce = Some(bytes),
+                _ => {}
+            }
+        }
+
+        Ok(claims)
+    }
+}
+
+/// Builds attestation documents signed by a throwaway certificate chain
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::*;
+    use p384::ecdsa::signature::Signer;
+    use p384::ecdsa::{DerSignature, SigningKey};
+    use std::str::FromStr;
+    use std::time::Duration;
+    use x509_cert::builder::{Builder, CertificateBuilder, Profile};
+    use x509_cert::name::Name;
+    use x509_cert::serial_number::SerialNumber;
+    use x509_cert::spki::SubjectPublicKeyInfoOwned;
+    use x509_cert::time::Validity;
+
+    pub struct TestChain {
+        pub root: Vec<u8>,
+        leaf: Vec<u8>,
+        leaf_key: SigningKey,
+    }
+
+    fn certificate(
+        profile: Profile,
+        subject: &str,
+        key: &SigningKey,
+        issuer_key: &SigningKey,
+    ) -> Vec<u8> {
+        let spki = SubjectPublicKeyInfoOwned::from_key(*key.verifying_key()).unwrap();
+        CertificateBuilder::new(
+            profile,
+            SerialNumber::from(1u32),
+            Validity::from_now(Duration::from_secs(3600)).unwrap(),
+            Name::from_str(subject).unwrap(),
+            spki,
+            issuer_key,
+        )
+        .unwrap()
+        .build::<DerSignature>()
+        .unwrap()
+        .to_der()
+        .unwrap()
+    }
+
+    impl TestChain {
+        pub fn new() -> Self {
+            let root_key = SigningKey::random(&mut rand::thread_rng());
+            let leaf_key = SigningKey::random(&mut rand::thread_rng());
+            let root = certificate(Profile::Root, "CN=test root", &root_key, &root_key);
+            let leaf = certificate(
+                Profile::Leaf {
+                    issuer: Name::from_str("CN=test root").unwrap(),
+                    enable_key_agreement: false,
+                    enable_key_encipherment: false,
+                },
+                "CN=test enclave",
+                &leaf_key,
+                &root_key,
+            );
+
+            Self {
+                root,
+                leaf,
+                leaf_key,
+            }
+        }
+
+        pub fn document(&self, nonce: &[u8], public_key: &[u8], pcr0: &[u8]) -> Vec<u8> {
+            let payload = Value::Map(vec![
+                (
+                    Value::Text("module_id".to_string()),
+                    Value::Text("i-test-enc".to_string()),
+                ),
+                (
+                    Value::Text("pcrs".to_string()),
+                    Value::Map(vec![(
+                        Value::Integer(0.into()),
+                        Value::Bytes(pcr0.to_vec()),
+                    )]),
+                ),
+                (
+                    Value::Text("certificate".to_string()),
+                    Value::Bytes(self.leaf.clone()),
+                ),
+                (
+                    Value::Text("cabundle".to_string()),
+                    Value::Array(vec![Value::Bytes(self.root.clone())]),
+                ),
+                (
+                    Value::Text("public_key".to_string()),
+                    Value::Bytes(public_key.to_vec()),
+                ),
+                (
+                    Value::Text("nonce".to_string()),
+                    Value::Bytes(nonce.to_vec()),
+                ),
+            ]);
+            let mut payload_bytes = Vec::new();
+            ciborium::ser::into_writer(&payload, &mut payload_bytes).unwrap();
+
+            let mut protected = Vec::new();
+            ciborium::ser::into_writer(
+                &Value::Map(vec![(
+                    Value::Integer(1.into()),
+                    Value::Integer((-35).into()),
+                )]),
+                &mut protected,
+            )
+            .unwrap();
+
+            let mut to_be_signed = Vec::new();
+            ciborium::ser::into_writer(
+                &Value::Array(vec![
+                    Value::Text("Signature1".to_string()),
+                    Value::Bytes(protected.clone()),
+                    Value::Bytes(Vec::new()),
+                    Value::Bytes(payload_bytes.clone()),
+                ]),
+                &mut to_be_signed,
+            )
+            .unwrap();
+            let signature: EcdsaSignature = self.leaf_key.sign(&to_be_signed);
+
+            let mut document = Vec::new();
+            ciborium::ser::into_writer(
+                &Value::Tag(
+                    18,
+                    Box::new(Value::Array(vec![
+                        Value::Bytes(protected),
+                        Value::Map(Vec::new()),
+                        Value::Bytes(payload_bytes),
+                        Value::Bytes(signature.to_bytes().to_vec()),
+                    ])),
+                ),
+                &mut document,
+            )
+            .unwrap();
+            document
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_support::TestChain;
+    use super::*;
+
+    fn now() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    #[test]
+    fn test_verify_valid_document() {
+        let chain = TestChain::new();
+        let document = chain.document(b"nonce", &[7u8; 32], &[1u8; 48]);
+        let policy = AttestationPolicy::new(chain.root.clone()).with_pcr(0, vec![1u8; 48]);
+
+        policy
+            .verify(&document, b"nonce", &[7u8; 32], now())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_reject_mismatched_claims() {
+        let chain = TestChain::new();
+        let document = chain.document(b"nonce", &[7u8; 32], &[1u8; 48]);
+        let policy = AttestationPolicy::new(chain.root.clone());
+
+        assert!(policy
+            .verify(&document, b"other", &[7u8; 32], now())
+            .is_err());
+        assert!(policy
+            .verify(&document, b"nonce", &[8u8; 32], now())
+            .is_err());
+        assert!(policy
+            .clone()
+            .with_pcr(0, vec![2u8; 48])
+            .verify(&document, b"nonce", &[7u8; 32], now())
+            .is_err());
+        // Certificates in the test chain are valid for an hour
+        assert!(policy
+            .verify(&document, b"nonce", &[7u8; 32], now() + 7200)
+            .is_err());
+    }
+
+    #[test]
+    fn test_reject_untrusted_root_and_tampering() {
+        let chain = TestChain::new();
+        let other = TestChain::new();
+        let document = chain.document(b"nonce", &[7u8; 32], &[1u8; 48]);
+
+        let err = AttestationPolicy::new(other.root.clone())
+            .verify(&document, b"nonce", &[7u8; 32], now())
+            .unwrap_err();
+        assert!(err.to_string().contains("pinned root"));
+
+        let mut tampered = document.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        assert!(AttestationPolicy::new(chain.root.clone())
+            .verify(&tampered, b"nonce", &[7u8; 32], now())
+            .is_err());
+    }
+}
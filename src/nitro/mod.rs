@@ -0,0 +1,476 @@
+//! AWS Nitro Enclaves signer integration
+//!
+//! Forwards sign requests over vsock to a signing application running inside a Nitro
+//! Enclave. Messages are length-prefixed JSON (a 4-byte big-endian length followed
+//! by the body):
+//!
+//! - `{"type":"public_key"}` → `{"public_key":"<base58>"}`
+//! - `{"type":"sign","message":"<base64>"}` → `{"signature":"<base58>"}`
+//! - `{"type":"attestation","nonce":"<base64>"}` → `{"document":"<base64>"}`
+//!
+//! Any request may instead be answered with `{"error":"..."}`. The attestation
+//! document must carry the enclave's Ed25519 public key in its `public_key` field.
+
+mod attestation;
+
+pub use attestation::AttestationPolicy;
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default vsock port the enclave signing application listens on
+pub const DEFAULT_PORT: u32 = 5000;
+
+/// Largest reply we accept from the enclave
+const MAX_RESPONSE_LEN: usize = 1024 * 1024;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum EnclaveRequest {
+    PublicKey,
+    Sign { message: String },
+    Attestation { nonce: String },
+}
+
+#[derive(Deserialize)]
+struct EnclaveResponse {
+    public_key: Option<String>,
+    signature: Option<String>,
+    document: Option<String>,
+    error: Option<String>,
+}
+
+/// Blocking request/response channel to the enclave
+trait EnclaveTransport: Send + Sync {
+    fn exchange(&self, request: &[u8]) -> Result<Vec<u8>, SignerError>;
+}
+
+/// Transport over an `AF_VSOCK` stream socket
+struct VsockTransport {
+    cid: u32,
+    port: u32,
+}
+
+impl VsockTransport {
+    #[cfg(target_os = "linux")]
+    fn connect(&self) -> Result<std::fs::File, SignerError> {
+        use std::os::fd::{FromRawFd, OwnedFd};
+
+        let unavailable = |action: &str| {
+            SignerError::NotAvailable(format!(
+                "Failed to {action} vsock {}:{}: {}",
+                self.cid,
+                self.port,
+                std::io::Error::last_os_error()
+            ))
+        };
+
+        // SAFETY: plain socket(2) call; the returned descriptor is owned below
+        let fd = unsafe { libc::socket(libc::AF_VSOCK, libc::SOCK_STREAM | libc::SOCK_CLOEXEC, 0) };
+        if fd < 0 {
+            return Err(unavailable("open"));
+        }
+        // SAFETY: `fd` is a freshly opened descriptor nothing else owns
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+        // SAFETY: sockaddr_vm is plain old data, so all-zero is a valid value
+        let mut address: libc::sockaddr_vm = unsafe { std::mem::zeroed() };
+        address.svm_family = libc::AF_VSOCK as libc::sa_family_t;
+        address.svm_cid = self.cid;
+        address.svm_port = self.port;
+
+        // SAFETY: `address` is a valid sockaddr_vm and the length matches it
+        let result = unsafe {
+            libc::connect(
+                std::os::fd::AsRawFd::as_raw_fd(&fd),
+                &address as *const libc::sockaddr_vm as *const libc::sockaddr,
+                std::mem::size_of::<libc::sockaddr_vm>() as libc::socklen_t,
+            )
+        };
+        if result < 0 {
+            return Err(unavailable("connect to"));
+        }
+
+        Ok(std::fs::File::from(fd))
+    }
+}
+
+impl EnclaveTransport for VsockTransport {
+    #[cfg(target_os = "linux")]
+    fn exchange(&self, request: &[u8]) -> Result<Vec<u8>, SignerError> {
+        use std::io::{Read, Write};
+
+        let io_error = |e: std::io::Error| SignerError::NotAvailable(format!("vsock I/O: {e}"));
+        let mut stream = self.connect()?;
+
+        stream
+            .write_all(&(request.len() as u32).to_be_bytes())
+            .map_err(io_error)?;
+        stream.write_all(request).map_err(io_error)?;
+
+        let mut length = [0u8; 4];
+        stream.read_exact(&mut length).map_err(io_error)?;
+        let length = u32::from_be_bytes(length) as usize;
+        if length > MAX_RESPONSE_LEN {
+            return Err(SignerError::SerializationError(format!(
+                "Enclave response of {length} bytes is too large"
+            )));
+        }
+
+        let mut response = vec![0u8; length];
+        stream.read_exact(&mut response).map_err(io_error)?;
+        Ok(response)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn exchange(&self, _request: &[u8]) -> Result<Vec<u8>, SignerError> {
+        Err(SignerError::NotAvailable(format!(
+            "vsock {}:{} is only reachable from Linux",
+            self.cid, self.port
+        )))
+    }
+}
+
+/// Signer that delegates to a signing application inside a Nitro Enclave
+#[derive(Clone)]
+pub struct NitroEnclaveSigner {
+    cid: u32,
+    port: u32,
+    transport: Arc<dyn EnclaveTransport>,
+    attestation_policy: Option<AttestationPolicy>,
+    timeout: Duration,
+    public_key: Pubkey,
+}
+
+impl std::fmt::Debug for NitroEnclaveSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NitroEnclaveSigner")
+            .field("cid", &self.cid)
+            .field("port", &self.port)
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NitroEnclaveSigner {
+    /// Create a new NitroEnclaveSigner for the enclave with context ID `cid` (requires initialization)
+    pub fn new(cid: u32) -> Self {
+        Self::with_transport(
+            cid,
+            DEFAULT_PORT,
+            Arc::new(VsockTransport {
+                cid,
+                port: DEFAULT_PORT,
+            }),
+        )
+    }
+
+    fn with_transport(cid: u32, port: u32, transport: Arc<dyn EnclaveTransport>) -> Self {
+        Self {
+            cid,
+            port,
+            transport,
+            attestation_policy: None,
+            timeout: DEFAULT_TIMEOUT,
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        }
+    }
+
+    /// Connect to a vsock port other than [`DEFAULT_PORT`]
+    pub fn with_port(mut self, port: u32) -> Self {
+        self.port = port;
+        self.transport = Arc::new(VsockTransport {
+            cid: self.cid,
+            port,
+        });
+        self
+    }
+
+    /// Verify the enclave's attestation document against `policy` during [`Self::init`]
+    pub fn with_attestation_policy(mut self, policy: AttestationPolicy) -> Self {
+        self.attestation_policy = Some(policy);
+        self
+    }
+
+    /// Give up on an enclave request after `timeout` (30 seconds by default)
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Initialize the signer by fetching the enclave's public key
+    ///
+    /// With an attestation policy, the key is only accepted once a fresh attestation
+    /// document binding it has been verified.
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        let response = self.request(EnclaveRequest::PublicKey).await?;
+        let public_key = response.public_key.ok_or_else(|| {
+            SignerError::SerializationError("Enclave response has no public_key".to_string())
+        })?;
+        let public_key: Pubkey = public_key
+            .parse()
+            .map_err(|_| SignerError::InvalidPublicKey(public_key))?;
+
+        self.attest(&public_key).await?;
+        self.public_key = public_key;
+
+        Ok(())
+    }
+
+    /// Verify a fresh attestation document binding `public_key`, if a policy is set
+    async fn attest(&self, public_key: &Pubkey) -> Result<(), SignerError> {
+        let Some(policy) = &self.attestation_policy else {
+            return Ok(());
+        };
+
+        let nonce: [u8; 32] = rand::random();
+        let response = self
+            .request(EnclaveRequest::Attestation {
+                nonce: STANDARD.encode(nonce),
+            })
+            .await?;
+        let document = response.document.ok_or_else(|| {
+            SignerError::SerializationError("Enclave response has no document".to_string())
+        })?;
+        let document = STANDARD.decode(document).map_err(|e| {
+            SignerError::SerializationError(format!("Invalid attestation document: {e}"))
+        })?;
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        policy.verify(&document, &nonce, &public_key.to_bytes(), now)
+    }
+
+    async fn request(&self, request: EnclaveRequest) -> Result<EnclaveResponse, SignerError> {
+        let body = serde_json::to_vec(&request)
+            .map_err(|e| SignerError::SerializationError(e.to_string()))?;
+        let transport = self.transport.clone();
+
+        let response = tokio::time::timeout(
+            self.timeout,
+            tokio::task::spawn_blocking(move || transport.exchange(&body)),
+        )
+        .await
+        .map_err(|_| SignerError::NotAvailable("Enclave request timed out".to_string()))?
+        .map_err(|e| SignerError::Other(format!("Enclave task failed: {e}")))??;
+
+        let response: EnclaveResponse = serde_json::from_slice(&response).map_err(|e| {
+            SignerError::SerializationError(format!("Invalid enclave response: {e}"))
+        })?;
+        if let Some(error) = response.error {
+            return Err(SignerError::RemoteApiError(format!(
+                "Enclave error: {error}"
+            )));
+        }
+
+        Ok(response)
+    }
+
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        if self.public_key == Pubkey::default() {
+            return Err(SignerError::ConfigError(
+                "NitroEnclaveSigner is not initialized; call init() first".to_string(),
+            ));
+        }
+
+        let response = self
+            .request(EnclaveRequest::Sign {
+                message: STANDARD.encode(message),
+            })
+            .await?;
+        let signature = response.signature.ok_or_else(|| {
+            SignerError::SerializationError("Enclave response has no signature".to_string())
+        })?;
+        let signature: Signature = signature.parse().map_err(|_| {
+            SignerError::SigningFailed("Enclave returned an invalid signature".to_string())
+        })?;
+
+        // The attested key is only meaningful if responses are checked against it
+        if !TransactionUtil::verify_signature(&self.public_key, message, &signature) {
+            return Err(SignerError::SigningFailed(
+                "Enclave signature does not verify against its public key".to_string(),
+            ));
+        }
+
+        Ok(signature)
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for NitroEnclaveSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Check that the enclave answers and still reports the same key
+    async fn is_available(&self) -> bool {
+        match self.request(EnclaveRequest::PublicKey).await {
+            Ok(response) => response.public_key == Some(self.public_key.to_string()),
+            Err(_) => false,
+        }
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("nitro", self.public_key, self.latency_class())
+            .with_endpoint(format!("vsock://{}:{}", self.cid, self.port))
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=nitro cid={} port={} attestation={} pubkey={}",
+            self.cid,
+            self.port,
+            self.attestation_policy.is_some(),
+            self.public_key
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::attestation::test_support::TestChain;
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+
+    /// In-process stand-in for the enclave signing application
+    struct FakeEnclave {
+        keypair: Keypair,
+        chain: TestChain,
+        attested_key: Option<[u8; 32]>,
+    }
+
+    impl EnclaveTransport for FakeEnclave {
+        fn exchange(&self, request: &[u8]) -> Result<Vec<u8>, SignerError> {
+            let request: serde_json::Value = serde_json::from_slice(request).unwrap();
+            let response = match request["type"].as_str().unwrap() {
+                "public_key" => {
+                    serde_json::json!({ "public_key": keypair_pubkey(&self.keypair).to_string() })
+                }
+                "sign" => {
+                    let message = STANDARD
+                        .decode(request["message"].as_str().unwrap())
+                        .unwrap();
+                    let signature = keypair_sign_message(&self.keypair, &message);
+                    serde_json::json!({ "signature": signature.to_string() })
+                }
+                "attestation" => {
+                    let nonce = STANDARD.decode(request["nonce"].as_str().unwrap()).unwrap();
+                    let key = self
+                        .attested_key
+                        .unwrap_or_else(|| keypair_pubkey(&self.keypair).to_bytes());
+                    let document = self.chain.document(&nonce, &key, &[0u8; 48]);
+                    serde_json::json!({ "document": STANDARD.encode(document) })
+                }
+                _ => serde_json::json!({ "error": "unknown request" }),
+            };
+            Ok(serde_json::to_vec(&response).unwrap())
+        }
+    }
+
+    fn signer(attested_key: Option<[u8; 32]>) -> (NitroEnclaveSigner, Pubkey, Vec<u8>) {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let chain = TestChain::new();
+        let root = chain.root.clone();
+        let enclave = FakeEnclave {
+            keypair,
+            chain,
+            attested_key,
+        };
+
+        (
+            NitroEnclaveSigner::with_transport(16, DEFAULT_PORT, Arc::new(enclave)),
+            pubkey,
+            root,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_nitro_init_with_attestation_and_sign() {
+        let (signer, pubkey, root) = signer(None);
+        let mut signer =
+            signer.with_attestation_policy(AttestationPolicy::new(root).with_pcr(0, vec![0u8; 48]));
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert!(signer.is_available().await);
+
+        let mut tx = create_test_transaction(&pubkey);
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+    }
+
+    #[tokio::test]
+    async fn test_nitro_rejects_attestation_for_other_key() {
+        let (signer, _, root) = signer(Some([9u8; 32]));
+        let mut signer = signer.with_attestation_policy(AttestationPolicy::new(root));
+
+        let err = signer.init().await.unwrap_err();
+        assert!(err.to_string().contains("signing key"));
+        assert_eq!(signer.pubkey(), Pubkey::default());
+    }
+
+    #[tokio::test]
+    async fn test_nitro_requires_init() {
+        let (signer, _, _) = signer(None);
+
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::ConfigError(_))
+        ));
+        assert!(signer.config_debug().contains("cid=16"));
+    }
+}
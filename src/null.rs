@@ -0,0 +1,104 @@
+//! Placeholder signer for sizing and fee estimation
+//!
+//! [`NullSigner`] fills its signature slot with an all-zero signature, the same
+//! size as a real one, so a transaction can be serialized, measured and simulated
+//! (with signature verification disabled) before it is routed to a real backend.
+
+use async_trait::async_trait;
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerMetadata, SolanaSigner};
+use crate::transaction_util::TransactionUtil;
+
+/// Signer that produces default (all-zero) signatures for a public key
+///
+/// Holds no key material. Its signatures never verify and
+/// [`TransactionUtil::remaining_required_signers`] still reports its slot as
+/// unsigned, so transactions signed with it must be re-signed before submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullSigner {
+    pubkey: Pubkey,
+}
+
+impl NullSigner {
+    /// Create a placeholder signer for `pubkey`
+    pub fn new(pubkey: Pubkey) -> Self {
+        Self { pubkey }
+    }
+
+    fn sign_and_serialize(&self, tx: &mut Transaction) -> Result<SignedTransaction, SignerError> {
+        let signature = Signature::default();
+
+        TransactionUtil::add_signature_to_transaction(tx, &self.pubkey, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(tx)?,
+            signature,
+            self.pubkey,
+        ))
+    }
+}
+
+#[async_trait]
+impl SolanaSigner for NullSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx)
+    }
+
+    async fn sign_message(&self, _message: &[u8]) -> Result<Signature, SignerError> {
+        Ok(Signature::default())
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx)
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("null", self.pubkey, self.latency_class())
+    }
+
+    fn config_debug(&self) -> String {
+        format!("backend=null pubkey={}", self.pubkey)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::create_multi_signer_test_transaction;
+
+    #[tokio::test]
+    async fn test_null_signer_matches_signed_size() {
+        let payer = NullSigner::new(Pubkey::new_unique());
+        let other = NullSigner::new(Pubkey::new_unique());
+        let mut tx = create_multi_signer_test_transaction(&[payer.pubkey(), other.pubkey()]);
+        let unsigned_len = bincode::serialize(&tx).unwrap().len();
+
+        payer.sign_transaction(&mut tx).await.unwrap();
+        let signed = other.sign_partial_transaction(&mut tx).await.unwrap();
+
+        assert_eq!(signed.signature, Signature::default());
+        assert_eq!(signed.transaction_bytes().unwrap().len(), unsigned_len);
+        assert_eq!(TransactionUtil::remaining_required_signers(&tx).len(), 2);
+        assert!(!payer.verify(&tx.message_data(), &signed.signature).unwrap());
+    }
+}
@@ -0,0 +1,228 @@
+//! Solana off-chain message format
+//!
+//! Off-chain messages are prefixed with a signing domain that can never begin a
+//! valid transaction message, so a signature over one cannot be replayed as a
+//! transaction signature. The encoding matches `solana-offchain-message` and the
+//! Solana CLI's `sign-offchain-message`, so signatures verify in wallets and
+//! other tooling.
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{Pubkey, Signature};
+use crate::transaction_util::TransactionUtil;
+
+/// Content format of an off-chain message, chosen from its bytes and length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffchainMessageFormat {
+    /// Printable ASCII (0x20-0x7e), short enough for hardware wallets to display
+    RestrictedAscii = 0,
+    /// UTF-8, short enough for hardware wallets to display
+    LimitedUtf8 = 1,
+    /// UTF-8 up to the maximum message length
+    ExtendedUtf8 = 2,
+}
+
+/// A version 0 off-chain message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffchainMessage {
+    format: OffchainMessageFormat,
+    message: Vec<u8>,
+}
+
+impl OffchainMessage {
+    /// Prefix of every serialized off-chain message
+    pub const SIGNING_DOMAIN: &'static [u8; 16] = b"\xffsolana offchain";
+    /// Signing domain, header version, format and message length
+    pub const HEADER_LEN: usize = Self::SIGNING_DOMAIN.len() + 1 + 1 + 2;
+    /// Longest message accepted
+    pub const MAX_LEN: usize = u16::MAX as usize - Self::HEADER_LEN;
+    /// Longest message a Ledger will sign (one packet, 1232 bytes, including the header)
+    pub const MAX_LEN_LEDGER: usize = 1232 - Self::HEADER_LEN;
+
+    const VERSION: u8 = 0;
+
+    /// Wrap message bytes, picking the most restrictive format that fits
+    ///
+    /// Fails for empty messages, messages over [`Self::MAX_LEN`] bytes, and
+    /// messages that are not valid UTF-8.
+    pub fn new(message: &[u8]) -> Result<Self, SignerError> {
+        if message.is_empty() {
+            return Err(SignerError::SerializationError(
+                "Off-chain message is empty".to_string(),
+            ));
+        }
+        if message.len() > Self::MAX_LEN {
+            return Err(SignerError::SerializationError(format!(
+                "Off-chain message is {} bytes, maximum is {}",
+                message.len(),
+                Self::MAX_LEN
+            )));
+        }
+
+        let format = Self::detect_format(message).ok_or_else(|| {
+            SignerError::SerializationError("Off-chain message is not valid UTF-8".to_string())
+        })?;
+
+        Ok(Self {
+            format,
+            message: message.to_vec(),
+        })
+    }
+
+    /// Parse a serialized off-chain message, checking its header
+    pub fn deserialize(data: &[u8]) -> Result<Self, SignerError> {
+        let invalid = |reason: &str| {
+            SignerError::SerializationError(format!("Invalid off-chain message: {reason}"))
+        };
+
+        if data.len() <= Self::HEADER_LEN {
+            return Err(invalid("too short"));
+        }
+        let (header, message) = data.split_at(Self::HEADER_LEN);
+        if &header[..16] != Self::SIGNING_DOMAIN {
+            return Err(invalid("missing signing domain"));
+        }
+        if header[16] != Self::VERSION {
+            return Err(invalid(&format!("unsupported version {}", header[16])));
+        }
+        if usize::from(u16::from_le_bytes([header[18], header[19]])) != message.len() {
+            return Err(invalid("length does not match header"));
+        }
+
+        let format = match header[17] {
+            0 => OffchainMessageFormat::RestrictedAscii,
+            1 => OffchainMessageFormat::LimitedUtf8,
+            2 => OffchainMessageFormat::ExtendedUtf8,
+            other => return Err(invalid(&format!("unknown format {other}"))),
+        };
+        if !format.accepts(message) {
+            return Err(invalid(&format!(
+                "content does not match format {format:?}"
+            )));
+        }
+
+        Ok(Self {
+            format,
+            message: message.to_vec(),
+        })
+    }
+
+    /// Serialize with the full header; these are the bytes that get signed
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::HEADER_LEN + self.message.len());
+        data.extend_from_slice(Self::SIGNING_DOMAIN);
+        data.push(Self::VERSION);
+        data.push(self.format as u8);
+        // `new` caps the length at MAX_LEN, which fits in a u16
+        data.extend_from_slice(&(self.message.len() as u16).to_le_bytes());
+        data.extend_from_slice(&self.message);
+        data
+    }
+
+    /// Check a signature over this message
+    pub fn verify(&self, pubkey: &Pubkey, signature: &Signature) -> bool {
+        TransactionUtil::verify_signature_strict(pubkey, &self.serialize(), signature)
+    }
+
+    /// Get the message format
+    pub fn format(&self) -> OffchainMessageFormat {
+        self.format
+    }
+
+    /// Get the message content, without the header
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    fn detect_format(message: &[u8]) -> Option<OffchainMessageFormat> {
+        [
+            OffchainMessageFormat::RestrictedAscii,
+            OffchainMessageFormat::LimitedUtf8,
+            OffchainMessageFormat::ExtendedUtf8,
+        ]
+        .into_iter()
+        .find(|format| format.accepts(message))
+    }
+}
+
+impl OffchainMessageFormat {
+    /// Whether `message` is non-empty, within this format's length limit and
+    /// uses only the characters it allows
+    fn accepts(self, message: &[u8]) -> bool {
+        let max_len = match self {
+            Self::RestrictedAscii | Self::LimitedUtf8 => OffchainMessage::MAX_LEN_LEDGER,
+            Self::ExtendedUtf8 => OffchainMessage::MAX_LEN,
+        };
+        if message.is_empty() || message.len() > max_len {
+            return false;
+        }
+
+        match self {
+            Self::RestrictedAscii => message.iter().all(|b| (0x20..=0x7e).contains(b)),
+            Self::LimitedUtf8 | Self::ExtendedUtf8 => std::str::from_utf8(message).is_ok(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_detection() {
+        let ascii = OffchainMessage::new(b"Test Message").unwrap();
+        assert_eq!(ascii.format(), OffchainMessageFormat::RestrictedAscii);
+
+        let utf8 = OffchainMessage::new("Тестовое сообщение".as_bytes()).unwrap();
+        assert_eq!(utf8.format(), OffchainMessageFormat::LimitedUtf8);
+
+        let long = OffchainMessage::new(&[b'a'; OffchainMessage::MAX_LEN_LEDGER + 1]).unwrap();
+        assert_eq!(long.format(), OffchainMessageFormat::ExtendedUtf8);
+
+        assert!(OffchainMessage::new(b"").is_err());
+        assert!(OffchainMessage::new(&[0xff, 0xfe]).is_err());
+        assert!(OffchainMessage::new(&[b'a'; OffchainMessage::MAX_LEN + 1]).is_err());
+    }
+
+    #[test]
+    fn test_serialize_roundtrip() {
+        let message = OffchainMessage::new(b"Test Message").unwrap();
+        let serialized = message.serialize();
+
+        assert!(serialized.starts_with(b"\xffsolana offchain\x00\x00\x0c\x00"));
+        assert_eq!(OffchainMessage::deserialize(&serialized).unwrap(), message);
+
+        let mut truncated = serialized.clone();
+        truncated.pop();
+        assert!(OffchainMessage::deserialize(&truncated).is_err());
+
+        let mut wrong_version = serialized.clone();
+        wrong_version[16] = 1;
+        assert!(OffchainMessage::deserialize(&wrong_version).is_err());
+
+        let mut utf8_as_ascii = OffchainMessage::new("héllo".as_bytes())
+            .unwrap()
+            .serialize();
+        utf8_as_ascii[17] = OffchainMessageFormat::RestrictedAscii as u8;
+        assert!(OffchainMessage::deserialize(&utf8_as_ascii).is_err());
+    }
+
+    #[cfg(feature = "sdk-v2")]
+    #[test]
+    #[allow(deprecated)]
+    fn test_matches_sdk_encoding() {
+        use solana_sdk::offchain_message::OffchainMessage as SdkOffchainMessage;
+
+        for content in [
+            "Test Message".as_bytes(),
+            "Тестовое сообщение".as_bytes(),
+            &[b'a'; OffchainMessage::MAX_LEN_LEDGER + 1],
+        ] {
+            let expected = SdkOffchainMessage::new(0, content)
+                .unwrap()
+                .serialize()
+                .unwrap();
+
+            assert_eq!(OffchainMessage::new(content).unwrap().serialize(), expected);
+        }
+    }
+}
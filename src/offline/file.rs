@@ -0,0 +1,190 @@
+//! Offline signing through a shared directory, e.g. on removable media
+
+use super::{OfflineTransport, SignRequest, SignatureResponse};
+use crate::error::SignerError;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+const REQUEST_SUFFIX: &str = ".request.json";
+const RESPONSE_SUFFIX: &str = ".signature.json";
+
+/// Exchanges artifacts as files in a directory
+///
+/// The online side writes `<id>.request.json` and polls for `<id>.signature.json`;
+/// the cold side lists pending requests with [`FileExchange::pending_requests`] and
+/// answers with [`FileExchange::submit`]. Both files are removed once the signature
+/// has been read.
+#[derive(Debug, Clone)]
+pub struct FileExchange {
+    dir: PathBuf,
+    poll_interval: Duration,
+    timeout: Duration,
+}
+
+impl FileExchange {
+    /// Exchange artifacts through `dir`
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            poll_interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(600),
+        }
+    }
+
+    /// How often to check for the signature file (default 1 second)
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// How long to wait for the signature file (default 10 minutes)
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn io_error(path: &Path, e: std::io::Error) -> SignerError {
+        SignerError::IoError(format!("{}: {e}", path.display()))
+    }
+
+    /// Write `contents` under a temporary name first so readers never see a partial file
+    fn write_atomically(&self, name: &str, contents: &str) -> Result<(), SignerError> {
+        let path = self.dir.join(name);
+        let partial = self.dir.join(format!(".{name}.partial"));
+
+        std::fs::write(&partial, contents).map_err(|e| Self::io_error(&partial, e))?;
+        std::fs::rename(&partial, &path).map_err(|e| Self::io_error(&path, e))
+    }
+
+    /// Requests waiting for a signature (cold side)
+    pub fn pending_requests(&self) -> Result<Vec<SignRequest>, SignerError> {
+        let entries = std::fs::read_dir(&self.dir).map_err(|e| Self::io_error(&self.dir, e))?;
+        let mut requests = Vec::new();
+
+        for entry in entries {
+            let path = entry.map_err(|e| Self::io_error(&self.dir, e))?.path();
+            let is_request = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(REQUEST_SUFFIX) && !name.starts_with('.'));
+            if !is_request {
+                continue;
+            }
+
+            let artifact = std::fs::read_to_string(&path).map_err(|e| Self::io_error(&path, e))?;
+            requests.push(SignRequest::from_artifact(&artifact)?);
+        }
+
+        Ok(requests)
+    }
+
+    /// Write the signature for a pending request (cold side)
+    pub fn submit(&self, response: &SignatureResponse) -> Result<(), SignerError> {
+        self.write_atomically(
+            &format!("{}{RESPONSE_SUFFIX}", response.id),
+            &response.to_artifact(),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl OfflineTransport for FileExchange {
+    async fn exchange(&self, request: &SignRequest) -> Result<SignatureResponse, SignerError> {
+        let request_path = self.dir.join(format!("{}{REQUEST_SUFFIX}", request.id));
+        let response_path = self.dir.join(format!("{}{RESPONSE_SUFFIX}", request.id));
+        self.write_atomically(
+            &format!("{}{REQUEST_SUFFIX}", request.id),
+            &request.to_artifact(),
+        )?;
+
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            match tokio::fs::read_to_string(&response_path).await {
+                Ok(artifact) => {
+                    let _ = std::fs::remove_file(&request_path);
+                    let _ = std::fs::remove_file(&response_path);
+                    return SignatureResponse::from_artifact(&artifact);
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(Self::io_error(&response_path, e)),
+            }
+
+            if Instant::now() >= deadline {
+                // Leave nothing behind for the cold side to sign after we gave up
+                let _ = std::fs::remove_file(&request_path);
+                return Err(SignerError::Timeout(format!(
+                    "No offline signature for request {} within {:?}",
+                    request.id, self.timeout
+                )));
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::offline::OfflineSigner;
+    use crate::sdk_adapter::{keypair_pubkey, Keypair, Pubkey};
+    use crate::test_util::create_test_transaction;
+    use crate::traits::SolanaSigner;
+
+    fn create_temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("solana-signers-{name}-{}", Pubkey::new_unique()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_file_exchange_round_trip() {
+        let dir = create_temp_dir("offline");
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let exchange = FileExchange::new(&dir).with_poll_interval(Duration::from_millis(10));
+        let signer = OfflineSigner::new(pubkey, exchange.clone());
+
+        // Cold side: answer whatever shows up in the directory
+        let cold = MemorySigner::from_bytes(&keypair.to_bytes()).unwrap();
+        let cold_side = tokio::spawn(async move {
+            loop {
+                let pending = exchange.pending_requests().unwrap();
+                if let Some(request) = pending.first() {
+                    exchange
+                        .submit(&request.respond(&cold).await.unwrap())
+                        .unwrap();
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        });
+
+        let mut tx = create_test_transaction(&pubkey);
+        signer.sign_transaction(&mut tx).await.unwrap();
+        cold_side.await.unwrap();
+
+        assert!(tx.is_signed());
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_file_exchange_timeout() {
+        let dir = create_temp_dir("offline-timeout");
+        let signer = OfflineSigner::new(
+            Pubkey::new_unique(),
+            FileExchange::new(&dir)
+                .with_poll_interval(Duration::from_millis(5))
+                .with_timeout(Duration::from_millis(20)),
+        );
+
+        let result = signer.sign_message(b"hello").await;
+        let leftover = std::fs::read_dir(&dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(result, Err(SignerError::Timeout(_))));
+        assert_eq!(leftover, 0);
+    }
+}
@@ -0,0 +1,398 @@
+//! Air-gapped offline signing
+//!
+//! [`OfflineSigner`] does not hold a key. Each signing call produces a
+//! [`SignRequest`] artifact, hands it to an [`OfflineTransport`] to carry to a cold
+//! machine (as a file on removable media, a QR code, ...), and completes once the
+//! matching [`SignatureResponse`] comes back. Call sites stay the same as for any
+//! other signer; they just wait for the round trip.
+//!
+//! Artifacts are compact JSON. A request for a maximum-size (1232-byte) transaction
+//! is under 2 KB, small enough for a single QR code in byte mode.
+//!
+//! On the cold machine, [`SignRequest::respond`] signs a request with any
+//! [`SolanaSigner`], typically a [`MemorySigner`](crate::MemorySigner).
+
+mod file;
+
+pub use file::FileExchange;
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Artifact format version written by this crate
+pub const ARTIFACT_VERSION: u8 = 1;
+
+/// What a [`SignRequest`] asks the cold signer to sign
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignRequestKind {
+    /// `payload` is a wire-format transaction; sign its message
+    Transaction,
+    /// `payload` is an arbitrary message
+    Message,
+}
+
+/// Unsigned payload carried to the air-gapped signer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignRequest {
+    pub version: u8,
+    /// Random identifier echoed back in the response
+    pub id: String,
+    /// Base58 public key expected to sign
+    pub pubkey: String,
+    pub kind: SignRequestKind,
+    /// Base64-encoded transaction or message
+    pub payload: String,
+}
+
+/// Signature artifact returned by the air-gapped signer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureResponse {
+    pub version: u8,
+    /// Identifier of the request being answered
+    pub id: String,
+    /// Base58 public key that signed
+    pub pubkey: String,
+    /// Base58 signature
+    pub signature: String,
+}
+
+fn to_artifact(value: &impl Serialize) -> String {
+    // Serializing these plain structs cannot fail
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+fn from_artifact<T: for<'de> Deserialize<'de>>(artifact: &str) -> Result<T, SignerError> {
+    serde_json::from_str(artifact.trim()).map_err(|e| {
+        SignerError::SerializationError(format!("Invalid offline signing artifact: {e}"))
+    })
+}
+
+impl SignRequest {
+    fn new(pubkey: &Pubkey, kind: SignRequestKind, payload: &[u8]) -> Self {
+        let id: [u8; 8] = rand::random();
+
+        Self {
+            version: ARTIFACT_VERSION,
+            id: id.iter().map(|b| format!("{b:02x}")).collect(),
+            pubkey: pubkey.to_string(),
+            kind,
+            payload: STANDARD.encode(payload),
+        }
+    }
+
+    /// Serialize to the text artifact written to a file or encoded in a QR code
+    pub fn to_artifact(&self) -> String {
+        to_artifact(self)
+    }
+
+    /// Parse a text artifact produced by [`SignRequest::to_artifact`]
+    pub fn from_artifact(artifact: &str) -> Result<Self, SignerError> {
+        let request: Self = from_artifact(artifact)?;
+        if request.version != ARTIFACT_VERSION {
+            return Err(SignerError::SerializationError(format!(
+                "Unsupported offline signing artifact version {}",
+                request.version
+            )));
+        }
+        Ok(request)
+    }
+
+    /// Decode the transaction carried by a `Transaction` request
+    pub fn transaction(&self) -> Result<Transaction, SignerError> {
+        if self.kind != SignRequestKind::Transaction {
+            return Err(SignerError::SerializationError(
+                "Offline sign request does not carry a transaction".to_string(),
+            ));
+        }
+
+        bincode::deserialize(&self.decoded_payload()?).map_err(|e| {
+            SignerError::SerializationError(format!("Invalid transaction in sign request: {e}"))
+        })
+    }
+
+    fn decoded_payload(&self) -> Result<Vec<u8>, SignerError> {
+        STANDARD.decode(&self.payload).map_err(|_| {
+            SignerError::SerializationError("Sign request payload is not base64".to_string())
+        })
+    }
+
+    /// Bytes the signature must cover
+    fn signed_bytes(&self) -> Result<Vec<u8>, SignerError> {
+        match self.kind {
+            SignRequestKind::Transaction => Ok(self.transaction()?.message_data()),
+            SignRequestKind::Message => self.decoded_payload(),
+        }
+    }
+
+    /// Sign this request on the cold machine with `signer`
+    ///
+    /// Transactions go through `sign_partial_transaction`, since the cold key is
+    /// often one of several signers.
+    pub async fn respond(
+        &self,
+        signer: &(impl SolanaSigner + ?Sized),
+    ) -> Result<SignatureResponse, SignerError> {
+        if signer.pubkey().to_string() != self.pubkey {
+            return Err(SignerError::ConfigError(format!(
+                "Sign request is for {}, not {}",
+                self.pubkey,
+                signer.pubkey()
+            )));
+        }
+
+        let signature = match self.kind {
+            SignRequestKind::Transaction => {
+                let mut tx = self.transaction()?;
+                signer.sign_partial_transaction(&mut tx).await?.signature
+            }
+            SignRequestKind::Message => signer.sign_message(&self.decoded_payload()?).await?,
+        };
+
+        Ok(SignatureResponse {
+            version: ARTIFACT_VERSION,
+            id: self.id.clone(),
+            pubkey: self.pubkey.clone(),
+            signature: signature.to_string(),
+        })
+    }
+}
+
+impl SignatureResponse {
+    /// Serialize to the text artifact written to a file or encoded in a QR code
+    pub fn to_artifact(&self) -> String {
+        to_artifact(self)
+    }
+
+    /// Parse a text artifact produced by [`SignatureResponse::to_artifact`]
+    pub fn from_artifact(artifact: &str) -> Result<Self, SignerError> {
+        from_artifact(artifact)
+    }
+}
+
+/// Carries sign requests to the air-gapped signer and brings signatures back
+///
+/// Implement this to show requests as QR codes and scan the response, or use
+/// [`FileExchange`] for removable media.
+#[async_trait::async_trait]
+pub trait OfflineTransport: Send + Sync {
+    /// Deliver `request` and wait for the response to it
+    async fn exchange(&self, request: &SignRequest) -> Result<SignatureResponse, SignerError>;
+}
+
+/// Signer completing each signature through an air-gapped round trip
+#[derive(Clone)]
+pub struct OfflineSigner {
+    public_key: Pubkey,
+    transport: Arc<dyn OfflineTransport>,
+}
+
+impl std::fmt::Debug for OfflineSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OfflineSigner")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OfflineSigner {
+    /// Create a new OfflineSigner for the cold key `public_key`
+    pub fn new(public_key: Pubkey, transport: impl OfflineTransport + 'static) -> Self {
+        Self {
+            public_key,
+            transport: Arc::new(transport),
+        }
+    }
+
+    /// Run one request through the transport and check the returned signature
+    async fn round_trip(&self, request: SignRequest) -> Result<Signature, SignerError> {
+        let response = self.transport.exchange(&request).await?;
+
+        if response.id != request.id || response.pubkey != request.pubkey {
+            return Err(SignerError::SigningFailed(
+                "Offline signature response does not match the request".to_string(),
+            ));
+        }
+
+        let signature: Signature = response.signature.parse().map_err(|_| {
+            SignerError::SigningFailed("Offline signature response is malformed".to_string())
+        })?;
+        if !TransactionUtil::verify_signature(
+            &self.public_key,
+            &request.signed_bytes()?,
+            &signature,
+        ) {
+            return Err(SignerError::SigningFailed(
+                "Offline signature does not verify".to_string(),
+            ));
+        }
+
+        Ok(signature)
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let wire = bincode::serialize(transaction).map_err(|e| {
+            SignerError::SerializationError(format!("Failed to serialize transaction: {e}"))
+        })?;
+        let request = SignRequest::new(&self.public_key, SignRequestKind::Transaction, &wire);
+        let signature = self.round_trip(request).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for OfflineSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let request = SignRequest::new(&self.public_key, SignRequestKind::Message, message);
+        self.round_trip(request).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Signs one at a time: each transaction is its own round trip to the cold machine
+    async fn sign_all_transactions(
+        &self,
+        txs: &mut [Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        crate::batch::sign_sequentially(self, txs).await
+    }
+
+    async fn is_available(&self) -> bool {
+        // Whether the cold machine is ready is only known once a request is answered
+        true
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Hardware
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("offline", self.public_key, self.latency_class())
+    }
+
+    fn config_debug(&self) -> String {
+        format!("backend=offline pubkey={}", self.public_key)
+    }
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+
+    /// Answers in-process, round-tripping both artifacts through their text form
+    struct ColdSigner(MemorySigner);
+
+    #[async_trait::async_trait]
+    impl OfflineTransport for ColdSigner {
+        async fn exchange(&self, request: &SignRequest) -> Result<SignatureResponse, SignerError> {
+            let request = SignRequest::from_artifact(&request.to_artifact())?;
+            let response = request.respond(&self.0).await?;
+            SignatureResponse::from_artifact(&response.to_artifact())
+        }
+    }
+
+    fn cold_signer(keypair: &Keypair) -> ColdSigner {
+        ColdSigner(MemorySigner::from_bytes(&keypair.to_bytes()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_offline_sign_message_and_transaction() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let signer = OfflineSigner::new(pubkey, cold_signer(&keypair));
+
+        assert_eq!(
+            signer.sign_message(b"hello").await.unwrap(),
+            keypair_sign_message(&keypair, b"hello")
+        );
+
+        let mut tx = create_test_transaction(&pubkey);
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+    }
+
+    #[tokio::test]
+    async fn test_offline_rejects_wrong_key() {
+        let signer = OfflineSigner::new(Pubkey::new_unique(), cold_signer(&Keypair::new()));
+
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_offline_rejects_mismatched_response() {
+        struct Replay(SignatureResponse);
+
+        #[async_trait::async_trait]
+        impl OfflineTransport for Replay {
+            async fn exchange(
+                &self,
+                _request: &SignRequest,
+            ) -> Result<SignatureResponse, SignerError> {
+                Ok(self.0.clone())
+            }
+        }
+
+        let keypair = Keypair::new();
+        let memory = MemorySigner::from_bytes(&keypair.to_bytes()).unwrap();
+        let stale = SignRequest::new(&memory.pubkey(), SignRequestKind::Message, b"old")
+            .respond(&memory)
+            .await
+            .unwrap();
+        let signer = OfflineSigner::new(memory.pubkey(), Replay(stale));
+
+        assert!(matches!(
+            signer.sign_message(b"new").await,
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_artifact_fits_in_a_qr_code() {
+        let pubkey = Pubkey::new_unique();
+        let request = SignRequest::new(&pubkey, SignRequestKind::Transaction, &[7u8; 1232]);
+
+        assert!(request.to_artifact().len() < 2953);
+        assert_eq!(
+            SignRequest::from_artifact(&request.to_artifact()).unwrap(),
+            request
+        );
+        assert!(SignRequest::from_artifact("{\"version\":2}").is_err());
+    }
+}
@@ -0,0 +1,333 @@
+//! 1Password Connect signer integration
+//!
+//! Fetches the keypair from an item in a 1Password vault through a 1Password Connect
+//! server and signs locally. The item field may hold a base58 keypair or a
+//! `[0, 1, ...]` byte array.
+//!
+//! Service account tokens are not supported: 1Password only exposes them through its
+//! CLI and SDKs, not a REST API.
+
+use crate::memory::MemorySigner;
+use crate::sdk_adapter::{Keypair, Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::{error::SignerError, traits::SolanaSigner};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Item {
+    #[serde(default)]
+    fields: Vec<ItemField>,
+}
+
+#[derive(Deserialize)]
+struct ItemField {
+    #[serde(default)]
+    label: Option<String>,
+    #[serde(rename = "type", default)]
+    field_type: Option<String>,
+    #[serde(default)]
+    value: Option<String>,
+}
+
+/// Signer whose keypair is loaded from a 1Password Connect vault item
+pub struct OnePasswordSigner {
+    connect_host: String,
+    token: String,
+    vault_id: String,
+    item_id: String,
+    field_label: Option<String>,
+    client: reqwest::Client,
+    signer: Option<MemorySigner>,
+}
+
+impl std::fmt::Debug for OnePasswordSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnePasswordSigner")
+            .field("vault_id", &self.vault_id)
+            .field("item_id", &self.item_id)
+            .field("pubkey", &self.pubkey())
+            .finish_non_exhaustive()
+    }
+}
+
+impl OnePasswordSigner {
+    /// Create a new OnePasswordSigner (requires initialization)
+    ///
+    /// # Arguments
+    ///
+    /// * `connect_host` - Base URL of the Connect server, e.g. `http://localhost:8080`
+    /// * `token` - Connect server access token with read access to the vault
+    /// * `vault_id` - UUID of the vault holding the item
+    /// * `item_id` - UUID of the item holding the keypair
+    pub fn new(connect_host: String, token: String, vault_id: String, item_id: String) -> Self {
+        Self {
+            connect_host: connect_host.trim_end_matches('/').to_string(),
+            token,
+            vault_id,
+            item_id,
+            field_label: None,
+            client: reqwest::Client::new(),
+            signer: None,
+        }
+    }
+
+    /// Read the keypair from the field labelled `label`
+    ///
+    /// Without this, the item's first concealed field (e.g. a password) is used.
+    pub fn with_field(mut self, label: String) -> Self {
+        self.field_label = Some(label);
+        self
+    }
+
+    /// Initialize the signer by fetching the keypair from the vault item
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        let item = self.fetch_item().await?;
+        let secret = self.select_field(&item)?;
+        let keypair = Self::parse_keypair(secret)?;
+
+        self.signer = Some(MemorySigner::new(keypair));
+        Ok(())
+    }
+
+    async fn fetch_item(&self) -> Result<Item, SignerError> {
+        let url = format!(
+            "{}/v1/vaults/{}/items/{}",
+            self.connect_host, self.vault_id, self.item_id
+        );
+        let response = self
+            .client
+            .get(&url)
+            .bearer_auth(&self.token)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+            #[cfg(feature = "unsafe-debug")]
+            log::error!("1Password Connect item error - status: {status}, response: {error_text}");
+
+            #[cfg(not(feature = "unsafe-debug"))]
+            {
+                let _ = error_text;
+                log::error!("1Password Connect item error - status: {status}");
+            }
+
+            return Err(SignerError::RemoteApiError(format!("API error {status}")));
+        }
+
+        Ok(response.json().await?)
+    }
+
+    fn select_field<'a>(&self, item: &'a Item) -> Result<&'a str, SignerError> {
+        let field = match &self.field_label {
+            Some(label) => item
+                .fields
+                .iter()
+                .find(|field| field.label.as_deref() == Some(label.as_str())),
+            None => item
+                .fields
+                .iter()
+                .find(|field| field.field_type.as_deref() == Some("CONCEALED")),
+        };
+
+        field
+            .and_then(|field| field.value.as_deref())
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                SignerError::ConfigError(match &self.field_label {
+                    Some(label) => format!("1Password item has no value in field '{label}'"),
+                    None => "1Password item has no concealed field".to_string(),
+                })
+            })
+    }
+
+    fn parse_keypair(secret: &str) -> Result<Keypair, SignerError> {
+        let secret = secret.trim();
+        let bytes = if secret.starts_with('[') {
+            serde_json::from_str::<Vec<u8>>(secret).map_err(|_| {
+                SignerError::InvalidPrivateKey(
+                    "1Password field is not a keypair byte array".to_string(),
+                )
+            })?
+        } else {
+            bs58::decode(secret).into_vec().map_err(|_| {
+                SignerError::InvalidPrivateKey(
+                    "1Password field is not a base58 keypair".to_string(),
+                )
+            })?
+        };
+
+        crate::sdk_adapter::keypair_from_bytes(&bytes)
+            .map_err(|e| SignerError::InvalidPrivateKey(format!("Invalid private key bytes: {e}")))
+    }
+
+    fn signer(&self) -> Result<&MemorySigner, SignerError> {
+        self.signer.as_ref().ok_or_else(|| {
+            SignerError::ConfigError(
+                "OnePasswordSigner is not initialized; call init() first".to_string(),
+            )
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for OnePasswordSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.signer
+            .as_ref()
+            .map(|signer| signer.pubkey())
+            .unwrap_or_default()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.signer()?.sign_transaction(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.signer()?.sign_message(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.signer()?.sign_partial_transaction(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        // The key is held in memory once fetched
+        self.signer.is_some()
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("onepassword", self.pubkey(), self.latency_class())
+            .with_key_id(&self.item_id)
+            .with_endpoint(&self.connect_host)
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=onepassword connect={} vault_id={} item_id={} token=[REDACTED] pubkey={}",
+            self.connect_host,
+            self.vault_id,
+            self.item_id,
+            self.pubkey()
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        if self.token.is_empty() {
+            return Err(SignerError::ConfigError(
+                "1Password Connect token must not be empty".to_string(),
+            ));
+        }
+
+        reqwest::Url::parse(&self.connect_host).map_err(|e| {
+            SignerError::ConfigError(format!("Invalid 1Password Connect host: {e}"))
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::keypair_pubkey;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const ITEM_PATH: &str = "/v1/vaults/vault-uuid/items/item-uuid";
+
+    async fn connect_server(fields: serde_json::Value) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(ITEM_PATH))
+            .and(header("authorization", "Bearer connect-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "item-uuid",
+                "title": "Solana payer",
+                "fields": fields
+            })))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn signer(server: &MockServer) -> OnePasswordSigner {
+        OnePasswordSigner::new(
+            server.uri(),
+            "connect-token".to_string(),
+            "vault-uuid".to_string(),
+            "item-uuid".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_onepassword_concealed_field() {
+        let keypair = Keypair::new();
+        let server = connect_server(serde_json::json!([
+            { "id": "username", "label": "username", "type": "STRING", "value": "ops" },
+            { "id": "password", "label": "password", "type": "CONCEALED", "value": keypair.to_base58_string() }
+        ]))
+        .await;
+
+        let mut signer = signer(&server);
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), keypair_pubkey(&keypair));
+        assert!(signer.is_available().await);
+        signer.sign_message(b"hello").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_onepassword_labelled_byte_array_field() {
+        let keypair = Keypair::new();
+        let bytes = serde_json::to_string(&keypair.to_bytes().to_vec()).unwrap();
+        let server = connect_server(serde_json::json!([
+            { "id": "a", "label": "notes", "type": "CONCEALED", "value": "not a key" },
+            { "id": "b", "label": "solana key", "type": "CONCEALED", "value": bytes }
+        ]))
+        .await;
+
+        let mut signer = signer(&server).with_field("solana key".to_string());
+        signer.init().await.unwrap();
+        assert_eq!(signer.pubkey(), keypair_pubkey(&keypair));
+    }
+
+    #[tokio::test]
+    async fn test_onepassword_missing_field_and_uninitialized() {
+        let server = connect_server(serde_json::json!([
+            { "id": "username", "label": "username", "type": "STRING", "value": "ops" }
+        ]))
+        .await;
+
+        let mut signer = signer(&server);
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::ConfigError(_))
+        ));
+        assert!(matches!(
+            signer.init().await,
+            Err(SignerError::ConfigError(_))
+        ));
+        assert!(!signer.is_available().await);
+        assert!(!signer.config_debug().contains("connect-token"));
+    }
+}
@@ -0,0 +1,91 @@
+//! Startup checks across a set of signers
+
+use futures::future::join_all;
+
+use crate::error::SignerError;
+use crate::sdk_adapter::Pubkey;
+use crate::traits::SolanaSigner;
+
+/// Validate, warm up, and health check every signer concurrently
+///
+/// Each signer runs [`SolanaSigner::validate_config`], [`SolanaSigner::warm_up`],
+/// and [`SolanaSigner::health_check`] in order, stopping at the first failure.
+/// Signers are checked concurrently with each other.
+///
+/// # Returns
+///
+/// One result per signer, in the same order as `signers`
+pub async fn preflight(signers: &[&dyn SolanaSigner]) -> Vec<(Pubkey, Result<(), SignerError>)> {
+    join_all(signers.iter().map(|signer| async move {
+        let result = async {
+            signer.validate_config()?;
+            signer.warm_up().await?;
+            signer.health_check().await
+        }
+        .await;
+
+        (signer.pubkey(), result)
+    }))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{Signature, Transaction};
+    use crate::traits::SignedTransaction;
+
+    struct MockSigner {
+        pubkey: Pubkey,
+        available: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl SolanaSigner for MockSigner {
+        fn pubkey(&self) -> Pubkey {
+            self.pubkey
+        }
+
+        async fn sign_transaction(
+            &self,
+            _tx: &mut Transaction,
+        ) -> Result<SignedTransaction, SignerError> {
+            unimplemented!()
+        }
+
+        async fn sign_message(&self, _message: &[u8]) -> Result<Signature, SignerError> {
+            unimplemented!()
+        }
+
+        async fn sign_partial_transaction(
+            &self,
+            _tx: &mut Transaction,
+        ) -> Result<SignedTransaction, SignerError> {
+            unimplemented!()
+        }
+
+        async fn is_available(&self) -> bool {
+            self.available
+        }
+    }
+
+    #[tokio::test]
+    async fn test_preflight() {
+        let healthy = MockSigner {
+            pubkey: Pubkey::new_unique(),
+            available: true,
+        };
+        let unhealthy = MockSigner {
+            pubkey: Pubkey::new_unique(),
+            available: false,
+        };
+
+        let results = preflight(&[&healthy, &unhealthy]).await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, healthy.pubkey);
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].0, unhealthy.pubkey);
+        assert!(matches!(results[1].1, Err(SignerError::NotAvailable(_))));
+    }
+}
@@ -0,0 +1,143 @@
+//! Signer for signatures that were produced elsewhere
+//!
+//! [`Presigner`] holds a public key and a signature collected out of band (e.g.
+//! from an offline or air-gapped signer) and attaches it through the
+//! [`SolanaSigner`] interface, so multi-party flows can mix it with live backends.
+
+use async_trait::async_trait;
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerMetadata, SolanaSigner};
+use crate::transaction_util::TransactionUtil;
+
+/// Signer that returns one known signature for one known message
+///
+/// Signing anything other than the message the signature was made over fails with
+/// `SignerError::SigningFailed`, so a stale signature is never attached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Presigner {
+    pubkey: Pubkey,
+    signature: Signature,
+}
+
+impl Presigner {
+    /// Create a signer that replays `signature` made by `pubkey`
+    pub fn new(pubkey: Pubkey, signature: Signature) -> Self {
+        Self { pubkey, signature }
+    }
+
+    /// Get the presigned signature
+    pub fn signature(&self) -> Signature {
+        self.signature
+    }
+
+    fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        if !TransactionUtil::verify_signature_strict(&self.pubkey, message, &self.signature) {
+            return Err(SignerError::SigningFailed(format!(
+                "Presigned signature from {} does not match the message",
+                self.pubkey
+            )));
+        }
+
+        Ok(self.signature)
+    }
+
+    fn sign_and_serialize(&self, tx: &mut Transaction) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&tx.message_data())?;
+
+        TransactionUtil::add_signature_to_transaction(tx, &self.pubkey, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(tx)?,
+            signature,
+            self.pubkey,
+        ))
+    }
+}
+
+#[async_trait]
+impl SolanaSigner for Presigner {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx)
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message)
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx)
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("presigned", self.pubkey, self.latency_class())
+    }
+
+    fn config_debug(&self) -> String {
+        format!("backend=presigned pubkey={}", self.pubkey)
+    }
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_multi_signer_test_transaction;
+
+    #[tokio::test]
+    async fn test_combine_with_live_signer() {
+        let offline = Keypair::new();
+        let online = MemorySigner::new(Keypair::new());
+        let mut tx =
+            create_multi_signer_test_transaction(&[online.pubkey(), keypair_pubkey(&offline)]);
+
+        let presigner = Presigner::new(
+            keypair_pubkey(&offline),
+            keypair_sign_message(&offline, &tx.message_data()),
+        );
+        let signers: [&dyn SolanaSigner; 2] = [&online, &presigner];
+        for signer in signers {
+            signer.sign_partial_transaction(&mut tx).await.unwrap();
+        }
+
+        assert!(tx.verify().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_other_message() {
+        let keypair = Keypair::new();
+        let presigner = Presigner::new(
+            keypair_pubkey(&keypair),
+            keypair_sign_message(&keypair, b"approved"),
+        );
+
+        assert_eq!(
+            presigner.sign_message(b"approved").await.unwrap(),
+            presigner.signature()
+        );
+        assert!(matches!(
+            presigner.sign_message(b"something else").await,
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+}
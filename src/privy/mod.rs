@@ -2,12 +2,20 @@
 
 mod types;
 
+use crate::http_client::{redacted_host, with_deadline, HttpClientConfig};
+use crate::interceptor::{IdentityInterceptor, RequestInterceptor};
 use crate::sdk_adapter::{Pubkey, Signature, Transaction};
-use crate::traits::SignedTransaction;
-use crate::transaction_util::TransactionUtil;
+use crate::traits::{
+    HealthStatus, SignOptions, SignedTransaction, SignerCapabilities, SignerMetadata,
+};
+use crate::transaction_util::{Encoding, TransactionUtil};
 use crate::{error::SignerError, traits::SolanaSigner};
 use base64::{engine::general_purpose::STANDARD, Engine};
+use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
 use types::{SignMessageParams, SignMessageRequest, SignMessageResponse, WalletResponse};
 
 /// Privy-based signer using Privy's wallet API
@@ -19,6 +27,9 @@ pub struct PrivySigner {
     api_base_url: String,
     client: reqwest::Client,
     public_key: Pubkey,
+    interceptor: Arc<dyn RequestInterceptor>,
+    output_encoding: Encoding,
+    http_config: HttpClientConfig,
 }
 
 impl std::fmt::Debug for PrivySigner {
@@ -46,9 +57,44 @@ impl PrivySigner {
             client: reqwest::Client::new(),
             // Set the public key to default to indicate that it's not initialized
             public_key: Pubkey::default(),
+            interceptor: Arc::new(IdentityInterceptor),
+            output_encoding: Encoding::default(),
+            http_config: HttpClientConfig::default(),
         }
     }
 
+    /// Start building a Privy signer with named settings
+    pub fn builder() -> PrivySignerBuilder {
+        PrivySignerBuilder::default()
+    }
+
+    /// Set an interceptor applied to every outbound request
+    pub fn with_request_interceptor(
+        mut self,
+        interceptor: impl RequestInterceptor + 'static,
+    ) -> Self {
+        self.interceptor = Arc::new(interceptor);
+        self
+    }
+
+    /// Set the encoding used for serialized transactions returned by this signer
+    pub fn with_output_encoding(mut self, encoding: Encoding) -> Self {
+        self.output_encoding = encoding;
+        self
+    }
+
+    /// Resolve `domain` to a pinned address instead of using system DNS
+    ///
+    /// The port of the request URL is used when present, so include it in the base
+    /// URL when pointing at a non-standard port. Fails with
+    /// `SignerError::ConfigError` if the signer was built with the builder's
+    /// `client`; set the override on that client instead.
+    pub fn with_resolve(mut self, domain: &str, addr: SocketAddr) -> Result<Self, SignerError> {
+        self.http_config.resolve.push((domain.to_string(), addr));
+        self.client = self.http_config.rebuild("with_resolve")?;
+        Ok(self)
+    }
+
     /// Initialize the signer by fetching the public key
     pub async fn init(&mut self) -> Result<(), SignerError> {
         let pubkey = self.fetch_public_key().await?;
@@ -66,13 +112,13 @@ impl PrivySigner {
     async fn fetch_public_key(&self) -> Result<Pubkey, SignerError> {
         let url = format!("{}/wallets/{}", self.api_base_url, self.wallet_id);
 
-        let response = self
+        let request = self
             .client
             .get(&url)
             .header("Authorization", self.get_privy_auth_header())
-            .header("privy-app-id", &self.app_id)
-            .send()
-            .await?;
+            .header("privy-app-id", &self.app_id);
+
+        let response = self.interceptor.intercept(request).send().await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -94,6 +140,17 @@ impl PrivySigner {
 
         let wallet_info: WalletResponse = response.json().await?;
 
+        // Catch a valid secret paired with the wrong app early, rather than failing on sign.
+        // `owner_id` identifies the owning user or key quorum, so only the app id is compared.
+        if let Some(wallet_app_id) = &wallet_info.app_id {
+            if wallet_app_id != &self.app_id {
+                return Err(SignerError::ConfigError(
+                    "Privy wallet belongs to a different app than the configured app_id"
+                        .to_string(),
+                ));
+            }
+        }
+
         // For Solana wallets, the address is the public key
         Pubkey::from_str(&wallet_info.address).map_err(|_| {
             SignerError::InvalidPublicKey("Invalid public key from Privy API".to_string())
@@ -101,7 +158,11 @@ impl PrivySigner {
     }
 
     /// Sign message bytes using Privy API
-    async fn sign_bytes(&self, serialized: &[u8]) -> Result<Signature, SignerError> {
+    async fn sign_bytes(
+        &self,
+        serialized: &[u8],
+        options: &SignOptions,
+    ) -> Result<Signature, SignerError> {
         let url = format!("{}/wallets/{}/rpc", self.api_base_url, self.wallet_id);
 
         let request = SignMessageRequest {
@@ -112,15 +173,18 @@ impl PrivySigner {
             },
         };
 
-        let response = self
+        let mut request = self
             .client
             .post(&url)
             .header("Authorization", self.get_privy_auth_header())
             .header("privy-app-id", &self.app_id)
             .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .await?;
+            .json(&request);
+        if let Some(key) = &options.idempotency_key {
+            request = request.header("privy-idempotency-key", key);
+        }
+
+        let response = self.interceptor.intercept(request).send().await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -154,18 +218,186 @@ impl PrivySigner {
     async fn sign_and_serialize(
         &self,
         transaction: &mut Transaction,
+        options: &SignOptions,
     ) -> Result<SignedTransaction, SignerError> {
-        let signature = self.sign_bytes(&transaction.message_data()).await?;
+        let signature = self
+            .sign_bytes(&transaction.message_data(), options)
+            .await?;
 
         TransactionUtil::add_signature_to_transaction(transaction, &self.pubkey(), signature)?;
 
-        Ok((
-            TransactionUtil::serialize_transaction(transaction)?,
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction_with_encoding(
+                transaction,
+                self.output_encoding,
+            )?,
             signature,
-        ))
+            self.pubkey(),
+        )
+        .with_encoding(self.output_encoding))
+    }
+
+    /// Partially sign, leaving other parties' slots untouched
+    ///
+    /// Reuses a valid signature already in this wallet's slot without calling
+    /// Privy, and rejects transactions the wallet is not a signer of up front.
+    async fn sign_partial_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+        options: &SignOptions,
+    ) -> Result<SignedTransaction, SignerError> {
+        match TransactionUtil::existing_signature(transaction, &self.public_key)? {
+            Some(signature) => Ok(SignedTransaction::new(
+                TransactionUtil::serialize_transaction_with_encoding(
+                    transaction,
+                    self.output_encoding,
+                )?,
+                signature,
+                self.public_key,
+            )
+            .with_encoding(self.output_encoding)),
+            None => self.sign_and_serialize(transaction, options).await,
+        }
+    }
+
+    /// Sign a transaction, aborting the in-flight request if `token` is cancelled
+    ///
+    /// Returns `SignerError::Other("cancelled")` on cancellation.
+    pub async fn sign_transaction_cancellable(
+        &self,
+        tx: &mut Transaction,
+        token: CancellationToken,
+    ) -> Result<SignedTransaction, SignerError> {
+        let options = SignOptions::default();
+
+        tokio::select! {
+            _ = token.cancelled() => Err(SignerError::Other("cancelled".to_string())),
+            result = self.sign_and_serialize(tx, &options) => result,
+        }
+    }
+
+    /// Sign a transaction within the time remaining until `deadline`
+    ///
+    /// Returns `SignerError::Timeout` immediately if `deadline` has already passed,
+    /// or once it passes while the request is in flight.
+    pub async fn sign_transaction_by_deadline(
+        &self,
+        tx: &mut Transaction,
+        deadline: Instant,
+    ) -> Result<SignedTransaction, SignerError> {
+        with_deadline(
+            deadline,
+            self.sign_and_serialize(tx, &SignOptions::default()),
+        )
+        .await
     }
 }
 
+/// Builder for [`PrivySigner`], created with [`PrivySigner::builder`]
+///
+/// `app_id`, `app_secret` and `wallet_id` are required. The built signer still
+/// needs [`PrivySigner::init`].
+#[derive(Default)]
+pub struct PrivySignerBuilder {
+    app_id: Option<String>,
+    app_secret: Option<String>,
+    wallet_id: Option<String>,
+    api_base_url: Option<String>,
+    output_encoding: Encoding,
+    interceptor: Option<Arc<dyn RequestInterceptor>>,
+    http_config: HttpClientConfig,
+    client: Option<reqwest::Client>,
+}
+
+impl PrivySignerBuilder {
+    /// Privy application ID
+    pub fn app_id(mut self, app_id: impl Into<String>) -> Self {
+        self.app_id = Some(app_id.into());
+        self
+    }
+
+    /// Privy application secret
+    pub fn app_secret(mut self, app_secret: impl Into<String>) -> Self {
+        self.app_secret = Some(app_secret.into());
+        self
+    }
+
+    /// Privy wallet ID
+    pub fn wallet_id(mut self, wallet_id: impl Into<String>) -> Self {
+        self.wallet_id = Some(wallet_id.into());
+        self
+    }
+
+    /// API base URL, `https://api.privy.io/v1` by default
+    pub fn api_base_url(mut self, api_base_url: impl Into<String>) -> Self {
+        self.api_base_url = Some(api_base_url.into());
+        self
+    }
+
+    /// Encoding of serialized transactions returned by the signer
+    pub fn output_encoding(mut self, encoding: Encoding) -> Self {
+        self.output_encoding = encoding;
+        self
+    }
+
+    /// Interceptor applied to every outbound request
+    pub fn request_interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.interceptor = Some(Arc::new(interceptor));
+        self
+    }
+
+    /// Total timeout for each request to the Privy API
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = Some(timeout);
+        self
+    }
+
+    /// Trust an extra CA certificate for the API's TLS certificate
+    pub fn root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.http_config.root_certificates.push(certificate);
+        self
+    }
+
+    /// Use a preconfigured HTTP client
+    ///
+    /// Takes precedence over [`Self::timeout`] and [`Self::root_certificate`], and
+    /// makes [`PrivySigner::with_resolve`] fail instead of replacing this client.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self.http_config.custom_client = true;
+        self
+    }
+
+    /// Build the signer, failing with `SignerError::ConfigError` if a required
+    /// setting is missing
+    pub fn build(self) -> Result<PrivySigner, SignerError> {
+        let mut signer = PrivySigner::new(
+            required(self.app_id, "app_id")?,
+            required(self.app_secret, "app_secret")?,
+            required(self.wallet_id, "wallet_id")?,
+        );
+
+        if let Some(api_base_url) = self.api_base_url {
+            signer.api_base_url = api_base_url;
+        }
+        signer.client = match self.client {
+            Some(client) => client,
+            None => self.http_config.build()?,
+        };
+        signer.http_config = self.http_config;
+        signer.output_encoding = self.output_encoding;
+        if let Some(interceptor) = self.interceptor {
+            signer.interceptor = interceptor;
+        }
+
+        Ok(signer)
+    }
+}
+
+fn required(value: Option<String>, name: &str) -> Result<String, SignerError> {
+    value.ok_or_else(|| SignerError::ConfigError(format!("PrivySignerBuilder: {name} is required")))
+}
+
 #[async_trait::async_trait]
 impl SolanaSigner for PrivySigner {
     fn pubkey(&self) -> Pubkey {
@@ -176,24 +408,92 @@ impl SolanaSigner for PrivySigner {
         &self,
         tx: &mut Transaction,
     ) -> Result<SignedTransaction, SignerError> {
-        self.sign_and_serialize(tx).await
+        self.sign_and_serialize(tx, &SignOptions::default()).await
     }
 
     async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
-        self.sign_bytes(message).await
+        self.sign_bytes(message, &SignOptions::default()).await
     }
 
     async fn sign_partial_transaction(
         &self,
         tx: &mut Transaction,
     ) -> Result<SignedTransaction, SignerError> {
-        self.sign_and_serialize(tx).await
+        self.sign_partial_and_serialize(tx, &SignOptions::default())
+            .await
+    }
+
+    /// Forwards the idempotency key as Privy's `privy-idempotency-key` header, so a
+    /// retried request returns the original signature
+    async fn sign_transaction_with(
+        &self,
+        tx: &mut Transaction,
+        options: SignOptions,
+    ) -> Result<SignedTransaction, SignerError> {
+        options
+            .within_deadline(self.sign_and_serialize(tx, &options))
+            .await
     }
 
     async fn is_available(&self) -> bool {
         // Check if public key is initialized
         self.public_key != Pubkey::default()
     }
+
+    /// Looks the wallet up again and checks it still resolves to the signer's key
+    async fn health(&self) -> HealthStatus {
+        let start = Instant::now();
+
+        if self.public_key == Pubkey::default() {
+            return HealthStatus::unavailable("Privy signer is not initialized", start.elapsed());
+        }
+
+        let reason = match self.fetch_public_key().await {
+            Ok(pubkey) if pubkey == self.public_key => {
+                return HealthStatus::healthy(start.elapsed())
+            }
+            Ok(_) => "Privy wallet address no longer matches the signer's public key".to_string(),
+            Err(SignerError::HttpError(_)) => "Privy unreachable".to_string(),
+            Err(SignerError::RemoteApiError(e)) => format!("Privy wallet lookup failed: {e}"),
+            Err(SignerError::ConfigError(e)) => e,
+            Err(e) => format!("{e:?}"),
+        };
+
+        HealthStatus::unavailable(reason, start.elapsed())
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn output_encoding(&self) -> Encoding {
+        self.output_encoding
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("privy", self.public_key, self.latency_class())
+            .with_key_id(&self.wallet_id)
+            .with_endpoint(&self.api_base_url)
+    }
+
+    fn config_debug(&self) -> String {
+        let host = redacted_host(&self.api_base_url);
+
+        format!(
+            "backend=privy host={host} app_id={} app_secret=[REDACTED] wallet_id={} pubkey={}",
+            self.app_id, self.wallet_id, self.public_key
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        if self.app_id.is_empty() || self.app_secret.is_empty() || self.wallet_id.is_empty() {
+            return Err(SignerError::ConfigError(
+                "Privy app_id, app_secret and wallet_id must not be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -201,6 +501,8 @@ mod tests {
     use super::*;
     use crate::sdk_adapter::{keypair_pubkey, Keypair, Signer};
     use crate::test_util::create_test_transaction;
+    use crate::traits::HealthState;
+    use std::time::Duration;
     use wiremock::{
         matchers::{header, method, path},
         Mock, MockServer, ResponseTemplate,
@@ -221,6 +523,21 @@ mod tests {
         assert_eq!(signer.app_id, "test-app-id");
         assert_eq!(signer.wallet_id, "test-wallet-id");
         assert_eq!(signer.public_key, Pubkey::default());
+        assert!(signer.capabilities().requires_init);
+    }
+
+    #[test]
+    fn test_verify_requires_initialized_pubkey() {
+        let signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        );
+
+        assert!(matches!(
+            signer.verify(b"message", &Signature::default()),
+            Err(SignerError::NotAvailable(_))
+        ));
     }
 
     #[tokio::test]
@@ -258,6 +575,48 @@ mod tests {
         assert_eq!(signer.pubkey(), keypair.pubkey());
     }
 
+    #[tokio::test]
+    async fn test_builder() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+
+        Mock::given(method("GET"))
+            .and(path("/wallets/test-wallet-id"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "test-wallet-id",
+                "address": keypair.pubkey().to_string(),
+                "chain_type": "solana"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = PrivySigner::builder()
+            .app_id("test-app-id")
+            .app_secret("test-app-secret")
+            .wallet_id("test-wallet-id")
+            .api_base_url(mock_server.uri())
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+
+        signer.init().await.unwrap();
+        assert_eq!(signer.pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn test_builder_requires_wallet_id() {
+        let result = PrivySigner::builder()
+            .app_id("test-app-id")
+            .app_secret("test-app-secret")
+            .build();
+
+        match result {
+            Err(SignerError::ConfigError(msg)) => assert!(msg.contains("wallet_id")),
+            other => panic!("Expected ConfigError, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn test_privy_sign_message() {
         let mock_server = MockServer::start().await;
@@ -335,7 +694,11 @@ mod tests {
 
         let result = signer.sign_transaction(&mut tx).await;
         assert!(result.is_ok());
-        let (serialized_tx, returned_sig) = result.unwrap();
+        let SignedTransaction {
+            base64_tx: serialized_tx,
+            signature: returned_sig,
+            ..
+        } = result.unwrap();
 
         // Verify the signature matches
         assert_eq!(returned_sig, signature);
@@ -344,6 +707,30 @@ mod tests {
         assert!(!serialized_tx.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_privy_sign_partial_transaction_not_a_signer() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        );
+        signer.api_base_url = mock_server.uri();
+        signer.public_key = keypair_pubkey(&create_test_keypair());
+        let mut tx = create_test_transaction(&Pubkey::new_unique());
+
+        let result = signer.sign_partial_transaction(&mut tx).await;
+
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+    }
+
     #[tokio::test]
     async fn test_privy_pubkey() {
         let keypair = create_test_keypair();
@@ -357,6 +744,64 @@ mod tests {
         assert_eq!(signer.pubkey(), keypair.pubkey());
     }
 
+    #[tokio::test]
+    async fn test_privy_fetch_public_key_app_mismatch() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+
+        Mock::given(method("GET"))
+            .and(path("/wallets/test-wallet-id"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "test-wallet-id",
+                "address": keypair.pubkey().to_string(),
+                "chain_type": "solana",
+                "app_id": "some-other-app-id"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        );
+        signer.api_base_url = mock_server.uri();
+
+        let result = signer.init().await;
+        assert!(matches!(result, Err(SignerError::ConfigError(_))));
+        assert!(!signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_privy_fetch_public_key_app_match() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+
+        Mock::given(method("GET"))
+            .and(path("/wallets/test-wallet-id"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "test-wallet-id",
+                "address": keypair.pubkey().to_string(),
+                "chain_type": "solana",
+                "app_id": "test-app-id",
+                "owner_id": "did:privy:some-user"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        );
+        signer.api_base_url = mock_server.uri();
+
+        assert!(signer.init().await.is_ok());
+        assert_eq!(signer.pubkey(), keypair.pubkey());
+    }
+
     #[tokio::test]
     async fn test_privy_fetch_public_key_unauthorized() {
         let mock_server = MockServer::start().await;
@@ -469,4 +914,255 @@ mod tests {
         signer.public_key = keypair.pubkey();
         assert!(signer.is_available().await);
     }
+
+    #[tokio::test]
+    async fn test_privy_health() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+
+        Mock::given(method("GET"))
+            .and(path("/wallets/test-wallet-id"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "test-wallet-id",
+                "address": keypair.pubkey().to_string(),
+                "chain_type": "solana"
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/wallets/test-wallet-id"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        );
+        signer.api_base_url = mock_server.uri();
+
+        let health = signer.health().await;
+        assert_eq!(health.state, HealthState::Unavailable);
+        assert_eq!(
+            health.reason.as_deref(),
+            Some("Privy signer is not initialized")
+        );
+
+        signer.public_key = keypair.pubkey();
+        assert_eq!(signer.health().await.state, HealthState::Healthy);
+
+        let health = signer.health().await;
+        assert_eq!(health.state, HealthState::Unavailable);
+        assert_eq!(
+            health.reason.as_deref(),
+            Some("Privy wallet lookup failed: API error 401")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_privy_request_interceptor_adds_header() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+
+        Mock::given(method("GET"))
+            .and(path("/wallets/test-wallet-id"))
+            .and(header("X-Proxy-Envelope", "wrapped"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "test-wallet-id",
+                "address": keypair.pubkey().to_string(),
+                "chain_type": "solana"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        )
+        .with_request_interceptor(|req: reqwest::RequestBuilder| {
+            req.header("X-Proxy-Envelope", "wrapped")
+        });
+        signer.api_base_url = mock_server.uri();
+
+        assert!(signer.init().await.is_ok());
+        assert_eq!(signer.pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn test_privy_latency_class() {
+        let signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        );
+        assert_eq!(signer.latency_class(), crate::traits::LatencyClass::Remote);
+    }
+
+    #[test]
+    fn test_privy_config_debug() {
+        let signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "super-secret-value".to_string(),
+            "test-wallet-id".to_string(),
+        );
+
+        let output = signer.config_debug();
+        assert!(output.contains("backend=privy"));
+        assert!(output.contains("host=api.privy.io"));
+        assert!(output.contains("test-wallet-id"));
+        assert!(!output.contains("super-secret-value"));
+        assert!(!output.contains(&signer.get_privy_auth_header()));
+    }
+
+    #[tokio::test]
+    async fn test_privy_sign_transaction_cancellable() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+
+        Mock::given(method("POST"))
+            .and(path("/wallets/test-wallet-id/rpc"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        );
+        signer.api_base_url = mock_server.uri();
+        signer.public_key = keypair.pubkey();
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            cancel.cancel();
+        });
+
+        let started = Instant::now();
+        let result = signer.sign_transaction_cancellable(&mut tx, token).await;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        match result {
+            Err(SignerError::Other(msg)) => assert_eq!(msg, "cancelled"),
+            other => panic!("Expected cancellation, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_privy_sign_transaction_with_idempotency_key() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let mut tx = create_test_transaction(&keypair_pubkey(&keypair));
+        let signature = keypair.sign_message(&tx.message_data());
+
+        Mock::given(method("POST"))
+            .and(path("/wallets/test-wallet-id/rpc"))
+            .and(header("privy-idempotency-key", "retry-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "method": "signMessage",
+                "data": {
+                    "signature": STANDARD.encode(signature),
+                    "encoding": "base64"
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        );
+        signer.api_base_url = mock_server.uri();
+        signer.public_key = keypair.pubkey();
+
+        let signed = signer
+            .sign_transaction_with(&mut tx, SignOptions::new().with_idempotency_key("retry-1"))
+            .await
+            .unwrap();
+
+        assert_eq!(signed.signature, signature);
+    }
+
+    #[tokio::test]
+    async fn test_privy_sign_transaction_by_deadline_expired() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+
+        Mock::given(method("POST"))
+            .and(path("/wallets/test-wallet-id/rpc"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        );
+        signer.api_base_url = mock_server.uri();
+        signer.public_key = keypair.pubkey();
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        let result = signer
+            .sign_transaction_by_deadline(&mut tx, Instant::now())
+            .await;
+
+        assert!(matches!(result, Err(SignerError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_privy_with_resolve() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+
+        Mock::given(method("GET"))
+            .and(path("/wallets/test-wallet-id"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "test-wallet-id",
+                "address": keypair.pubkey().to_string(),
+                "chain_type": "solana"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = PrivySigner::new(
+            "test-app-id".to_string(),
+            "test-app-secret".to_string(),
+            "test-wallet-id".to_string(),
+        )
+        .with_resolve("privy.internal", *mock_server.address())
+        .unwrap();
+        signer.api_base_url = format!("http://privy.internal:{}", mock_server.address().port());
+
+        assert!(signer.init().await.is_ok());
+        assert_eq!(signer.pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn test_privy_with_resolve_rejects_custom_client() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let signer = PrivySigner::builder()
+            .app_id("test-app-id")
+            .app_secret("test-app-secret")
+            .wallet_id("test-wallet-id")
+            .client(reqwest::Client::new())
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            signer.with_resolve("privy.internal", addr),
+            Err(SignerError::ConfigError(_))
+        ));
+    }
 }
@@ -51,6 +51,8 @@ pub struct WalletResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub owner_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub policy_ids: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_signers: Option<Vec<String>>,
@@ -0,0 +1,358 @@
+//! Record and replay signers for deterministic integration tests
+//!
+//! [`RecordingSigner`] wraps any signer and appends every signing request and its
+//! response to a JSON Lines file. [`ReplaySigner`] loads such a file and serves the
+//! recorded signatures back without contacting the original backend.
+//!
+//! Recordings only ever contain public data: the signer's public key, the signed
+//! message bytes and the resulting signature. Credentials, tokens and backend
+//! configuration are never written.
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{
+    HealthStatus, LatencyClass, SignOptions, SignedTransaction, SignerCapabilities, SignerMetadata,
+    SolanaSigner,
+};
+use crate::transaction_util::{Encoding, TransactionUtil};
+
+/// A single recorded request/response pair
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedSignature {
+    /// Base58-encoded public key of the signer
+    pub pubkey: String,
+    /// Base64-encoded message bytes that were signed
+    pub message: String,
+    /// Base58-encoded signature returned by the signer
+    pub signature: String,
+}
+
+/// Signer wrapper that records every signature it produces to a file
+pub struct RecordingSigner<S> {
+    inner: S,
+    file: Mutex<File>,
+}
+
+impl<S: SolanaSigner> RecordingSigner<S> {
+    /// Wrap `inner`, appending recordings to the file at `path`
+    ///
+    /// The file is created if it does not exist.
+    pub fn new(inner: S, path: impl AsRef<Path>) -> Result<Self, SignerError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path.as_ref())?;
+
+        Ok(Self {
+            inner,
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Get a reference to the wrapped signer
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    fn record(&self, message: &[u8], signature: &Signature) -> Result<(), SignerError> {
+        let entry = RecordedSignature {
+            pubkey: self.inner.pubkey().to_string(),
+            message: STANDARD.encode(message),
+            signature: signature.to_string(),
+        };
+        let mut line = serde_json::to_string(&entry)?;
+        line.push('\n');
+
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| SignerError::Other("recording file lock poisoned".to_string()))?;
+        file.write_all(line.as_bytes())?;
+        file.flush()?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: SolanaSigner> SolanaSigner for RecordingSigner<S> {
+    fn pubkey(&self) -> Pubkey {
+        self.inner.pubkey()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let message = tx.message_data();
+        let signed = self.inner.sign_transaction(tx).await?;
+        self.record(&message, &signed.signature)?;
+        Ok(signed)
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let signature = self.inner.sign_message(message).await?;
+        self.record(message, &signature)?;
+        Ok(signature)
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let message = tx.message_data();
+        let signed = self.inner.sign_partial_transaction(tx).await?;
+        self.record(&message, &signed.signature)?;
+        Ok(signed)
+    }
+
+    async fn sign_transaction_with(
+        &self,
+        tx: &mut Transaction,
+        options: SignOptions,
+    ) -> Result<SignedTransaction, SignerError> {
+        let message = tx.message_data();
+        let signed = self.inner.sign_transaction_with(tx, options).await?;
+        self.record(&message, &signed.signature)?;
+        Ok(signed)
+    }
+
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        self.inner.latency_class()
+    }
+
+    fn output_encoding(&self) -> Encoding {
+        self.inner.output_encoding()
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        self.inner.metadata()
+    }
+
+    fn config_debug(&self) -> String {
+        format!("recording {}", self.inner.config_debug())
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        self.inner.validate_config()
+    }
+
+    async fn warm_up(&self) -> Result<(), SignerError> {
+        self.inner.warm_up().await
+    }
+
+    async fn health_check(&self) -> Result<(), SignerError> {
+        self.inner.health_check().await
+    }
+
+    async fn health(&self) -> HealthStatus {
+        self.inner.health().await
+    }
+}
+
+/// Signer that serves signatures from a recording made by [`RecordingSigner`]
+///
+/// Signing a message that is not in the recording fails with
+/// `SignerError::SigningFailed`.
+#[derive(Debug, Clone)]
+pub struct ReplaySigner {
+    pubkey: Pubkey,
+    signatures: HashMap<Vec<u8>, Signature>,
+}
+
+impl ReplaySigner {
+    /// Load the recording for `pubkey` from the file at `path`
+    ///
+    /// Entries recorded for other signers are ignored.
+    pub fn from_file(path: impl AsRef<Path>, pubkey: Pubkey) -> Result<Self, SignerError> {
+        let contents = std::fs::read_to_string(path.as_ref())?;
+        let mut signatures = HashMap::new();
+
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            let entry: RecordedSignature = serde_json::from_str(line)?;
+            let entry_pubkey = Pubkey::from_str(&entry.pubkey)
+                .map_err(|e| SignerError::InvalidPublicKey(e.to_string()))?;
+            if entry_pubkey != pubkey {
+                continue;
+            }
+
+            let message = STANDARD
+                .decode(&entry.message)
+                .map_err(|e| SignerError::SerializationError(e.to_string()))?;
+            let signature = Signature::from_str(&entry.signature)
+                .map_err(|e| SignerError::SerializationError(e.to_string()))?;
+            signatures.insert(message, signature);
+        }
+
+        Ok(Self { pubkey, signatures })
+    }
+
+    /// Number of recorded messages available for replay
+    pub fn len(&self) -> usize {
+        self.signatures.len()
+    }
+
+    /// Whether the recording has no messages for this signer
+    pub fn is_empty(&self) -> bool {
+        self.signatures.is_empty()
+    }
+
+    fn replay(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.signatures.get(message).copied().ok_or_else(|| {
+            SignerError::SigningFailed("No recorded signature for message".to_string())
+        })
+    }
+}
+
+#[async_trait]
+impl SolanaSigner for ReplaySigner {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.replay(&tx.message_data())?;
+
+        TransactionUtil::add_signature_to_transaction(tx, &self.pubkey, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(tx)?,
+            signature,
+            self.pubkey,
+        ))
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.replay(message)
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_transaction(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("replay", self.pubkey, self.latency_class())
+    }
+
+    fn config_debug(&self) -> String {
+        format!("backend=replay pubkey={}", self.pubkey)
+    }
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::sdk_adapter::{keypair_pubkey, Keypair};
+    use crate::test_util::create_test_transaction;
+
+    fn temp_recording(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "solana-signers-{name}-{}.jsonl",
+            Pubkey::new_unique()
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let path = temp_recording("record");
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let recorder = RecordingSigner::new(MemorySigner::new(keypair), &path).unwrap();
+
+        let unsigned_tx = create_test_transaction(&pubkey);
+        let mut tx = unsigned_tx.clone();
+        let SignedTransaction {
+            base64_tx: recorded_tx,
+            signature: recorded_tx_sig,
+            ..
+        } = recorder.sign_transaction(&mut tx).await.unwrap();
+        let recorded_msg_sig = recorder.sign_message(b"hello").await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let replayer = ReplaySigner::from_file(&path, pubkey);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(contents.lines().count(), 2);
+        let replayer = replayer.unwrap();
+        assert_eq!(replayer.len(), 2);
+
+        let mut tx = unsigned_tx;
+        let SignedTransaction {
+            base64_tx: replayed_tx,
+            signature: replayed_tx_sig,
+            ..
+        } = replayer.sign_transaction(&mut tx).await.unwrap();
+        assert_eq!(replayed_tx, recorded_tx);
+        assert_eq!(replayed_tx_sig, recorded_tx_sig);
+        assert_eq!(
+            replayer.sign_message(b"hello").await.unwrap(),
+            recorded_msg_sig
+        );
+
+        let result = replayer.sign_message(b"not recorded").await;
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_recording_contains_no_secrets() {
+        let path = temp_recording("redacted");
+        let keypair = Keypair::new();
+        let secret = bs58::encode(keypair.to_bytes()).into_string();
+        let recorder = RecordingSigner::new(MemorySigner::new(keypair), &path).unwrap();
+
+        recorder.sign_message(b"hello").await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(!contents.contains(&secret));
+        let entry: RecordedSignature = serde_json::from_str(contents.trim()).unwrap();
+        assert_eq!(entry.pubkey, recorder.pubkey().to_string());
+    }
+
+    #[tokio::test]
+    async fn test_replay_ignores_other_signers() {
+        let path = temp_recording("other");
+        let recorder = RecordingSigner::new(MemorySigner::new(Keypair::new()), &path).unwrap();
+        recorder.sign_message(b"hello").await.unwrap();
+
+        let replayer = ReplaySigner::from_file(&path, Pubkey::new_unique());
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(replayer.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,370 @@
+//! solana-remote-wallet adapter
+//!
+//! Opens hardware wallets through `solana-remote-wallet`, the stack behind the
+//! Solana CLI's `usb://` keypair URIs, so the same strings work here:
+//! `usb://ledger`, `usb://ledger?key=0/0`, `usb://ledger/<pubkey>?full-path=m/44'/501'/1'`.
+//!
+//! The wallet types are not thread-safe, so each signer keeps its device on a
+//! dedicated thread and forwards requests to it. Signing blocks that thread until
+//! the user approves or rejects on the device.
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use solana_derivation_path::DerivationPath;
+use solana_remote_wallet::locator::Locator;
+use solana_remote_wallet::remote_keypair::generate_remote_keypair;
+use solana_remote_wallet::remote_wallet::{
+    maybe_wallet_manager, RemoteWallet, RemoteWalletError, RemoteWalletType,
+};
+use std::sync::mpsc;
+use tokio::sync::oneshot;
+use uriparse::URIReference;
+
+fn remote_wallet_error(err: RemoteWalletError) -> SignerError {
+    match err {
+        RemoteWalletError::NoDeviceFound => {
+            SignerError::NotAvailable("No hardware wallet found".to_string())
+        }
+        RemoteWalletError::Hid(e) => SignerError::NotAvailable(format!("USB HID error: {e}")),
+        RemoteWalletError::UserCancel => {
+            SignerError::SigningFailed("Rejected on the hardware wallet".to_string())
+        }
+        RemoteWalletError::DeviceTypeMismatch
+        | RemoteWalletError::DerivationPathError(_)
+        | RemoteWalletError::LocatorError(_)
+        | RemoteWalletError::InvalidInput(_) => SignerError::ConfigError(err.to_string()),
+        other => SignerError::SigningFailed(format!("Hardware wallet error: {other}")),
+    }
+}
+
+/// Wallet operations run on the device thread
+trait Device {
+    fn pubkey(&self) -> Result<Pubkey, SignerError>;
+    fn sign(&self, message: &[u8]) -> Result<Signature, SignerError>;
+}
+
+struct RemoteWalletDevice {
+    wallet_type: RemoteWalletType,
+    derivation_path: DerivationPath,
+}
+
+impl RemoteWalletDevice {
+    fn open(uri: &str, confirm_key: bool) -> Result<Self, SignerError> {
+        let (locator, derivation_path) = parse_uri(uri)?;
+        let wallet_manager = maybe_wallet_manager()
+            .map_err(remote_wallet_error)?
+            .ok_or_else(|| SignerError::NotAvailable("No hardware wallet found".to_string()))?;
+
+        let keypair = generate_remote_keypair(
+            locator,
+            derivation_path,
+            &wallet_manager,
+            confirm_key,
+            "solana-signers",
+        )
+        .map_err(remote_wallet_error)?;
+
+        Ok(Self {
+            wallet_type: keypair.wallet_type,
+            derivation_path: keypair.derivation_path,
+        })
+    }
+}
+
+impl Device for RemoteWalletDevice {
+    fn pubkey(&self) -> Result<Pubkey, SignerError> {
+        let pubkey = match &self.wallet_type {
+            RemoteWalletType::Ledger(wallet) => wallet.get_pubkey(&self.derivation_path, false),
+        }
+        .map_err(remote_wallet_error)?;
+
+        // solana-remote-wallet 2.x returns SDK v2 types; going through raw bytes keeps
+        // this working when the crate is built with `sdk-v3`
+        Ok(Pubkey::from(pubkey.to_bytes()))
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let signature = match &self.wallet_type {
+            RemoteWalletType::Ledger(wallet) => wallet.sign_message(&self.derivation_path, message),
+        }
+        .map_err(remote_wallet_error)?;
+
+        Signature::try_from(signature.as_ref()).map_err(|_| {
+            SignerError::SigningFailed("Hardware wallet returned a malformed signature".to_string())
+        })
+    }
+}
+
+/// Split a `usb://` keypair URI into its device locator and derivation path
+///
+/// Accepts the same `key=` and `full-path=` queries as the Solana CLI; without
+/// either, the default path `m/44'/501'` is used.
+fn parse_uri(uri: &str) -> Result<(Locator, DerivationPath), SignerError> {
+    let reference = URIReference::try_from(uri)
+        .map_err(|e| SignerError::ConfigError(format!("Invalid keypair URI: {e}")))?;
+    let locator = Locator::new_from_uri(&reference)
+        .map_err(|e| SignerError::ConfigError(format!("Invalid keypair URI: {e}")))?;
+    let derivation_path = DerivationPath::from_uri_any_query(&reference)
+        .map_err(|e| SignerError::ConfigError(format!("Invalid derivation path: {e}")))?
+        .unwrap_or_default();
+
+    Ok((locator, derivation_path))
+}
+
+enum Request {
+    Pubkey(oneshot::Sender<Result<Pubkey, SignerError>>),
+    Sign(Vec<u8>, oneshot::Sender<Result<Signature, SignerError>>),
+}
+
+/// Signer backed by a hardware wallet opened through `solana-remote-wallet`
+#[derive(Debug, Clone)]
+pub struct RemoteWalletSigner {
+    uri: String,
+    public_key: Pubkey,
+    requests: mpsc::Sender<Request>,
+}
+
+impl RemoteWalletSigner {
+    /// Open the hardware wallet addressed by a CLI-style `usb://` keypair URI
+    ///
+    /// Blocks while the device is located and its public key is read. If several
+    /// connected devices match the URI, the user is asked to pick one on the terminal,
+    /// as the Solana CLI does.
+    pub fn new(uri: &str) -> Result<Self, SignerError> {
+        Self::open(uri, false)
+    }
+
+    /// Like [`RemoteWalletSigner::new`], but asks the user to confirm the public key on the device
+    pub fn new_with_confirmation(uri: &str) -> Result<Self, SignerError> {
+        Self::open(uri, true)
+    }
+
+    fn open(uri: &str, confirm_key: bool) -> Result<Self, SignerError> {
+        // Fail on a malformed URI before starting the device thread
+        parse_uri(uri)?;
+
+        let owned_uri = uri.to_string();
+        Self::spawn(uri, move || {
+            RemoteWalletDevice::open(&owned_uri, confirm_key).map(|device| Box::new(device) as _)
+        })
+    }
+
+    /// Start the device thread, open the device on it, and wait for its public key
+    fn spawn<F>(uri: &str, open: F) -> Result<Self, SignerError>
+    where
+        F: FnOnce() -> Result<Box<dyn Device>, SignerError> + Send + 'static,
+    {
+        let (requests, incoming) = mpsc::channel::<Request>();
+        let (ready_tx, ready_rx) = mpsc::sync_channel(1);
+
+        std::thread::Builder::new()
+            .name("solana-remote-wallet".to_string())
+            .spawn(move || {
+                let device = match open().and_then(|device| Ok((device.pubkey()?, device))) {
+                    Ok((pubkey, device)) => {
+                        let _ = ready_tx.send(Ok(pubkey));
+                        device
+                    }
+                    Err(e) => {
+                        let _ = ready_tx.send(Err(e));
+                        return;
+                    }
+                };
+
+                // Runs until every clone of the signer has been dropped
+                for request in incoming {
+                    match request {
+                        Request::Pubkey(reply) => {
+                            let _ = reply.send(device.pubkey());
+                        }
+                        Request::Sign(message, reply) => {
+                            let _ = reply.send(device.sign(&message));
+                        }
+                    }
+                }
+            })
+            .map_err(|e| SignerError::Other(format!("Failed to start device thread: {e}")))?;
+
+        let public_key = ready_rx.recv().map_err(|_| {
+            SignerError::Other("Hardware wallet thread exited during setup".to_string())
+        })??;
+
+        Ok(Self {
+            uri: uri.to_string(),
+            public_key,
+            requests,
+        })
+    }
+
+    async fn call<T>(
+        &self,
+        request: impl FnOnce(oneshot::Sender<Result<T, SignerError>>) -> Request,
+    ) -> Result<T, SignerError> {
+        let (reply, response) = oneshot::channel();
+        let gone = || SignerError::NotAvailable("Hardware wallet thread has stopped".to_string());
+
+        self.requests.send(request(reply)).map_err(|_| gone())?;
+        response.await.map_err(|_| gone())?
+    }
+
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let message = message.to_vec();
+        self.call(|reply| Request::Sign(message, reply)).await
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for RemoteWalletSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Check that the device is still connected and reports the same key
+    /// Signs one at a time so the device prompts for each transaction in order
+    async fn sign_all_transactions(
+        &self,
+        txs: &mut [Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        crate::batch::sign_sequentially(self, txs).await
+    }
+
+    async fn is_available(&self) -> bool {
+        matches!(self.call(Request::Pubkey).await, Ok(pubkey) if pubkey == self.public_key)
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Hardware
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("remote-wallet", self.public_key, self.latency_class())
+            .with_key_id(self.uri.to_string())
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=remote-wallet uri={} pubkey={}",
+            self.uri, self.public_key
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+
+    struct FakeDevice {
+        keypair: Keypair,
+        reject: bool,
+    }
+
+    impl Device for FakeDevice {
+        fn pubkey(&self) -> Result<Pubkey, SignerError> {
+            Ok(keypair_pubkey(&self.keypair))
+        }
+
+        fn sign(&self, message: &[u8]) -> Result<Signature, SignerError> {
+            if self.reject {
+                return Err(remote_wallet_error(RemoteWalletError::UserCancel));
+            }
+            Ok(keypair_sign_message(&self.keypair, message))
+        }
+    }
+
+    fn fake_signer(keypair: Keypair, reject: bool) -> RemoteWalletSigner {
+        RemoteWalletSigner::spawn("usb://ledger?key=0", move || {
+            Ok(Box::new(FakeDevice { keypair, reject }) as _)
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_remote_wallet_sign() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let expected = keypair_sign_message(&keypair, b"hello");
+        let signer = fake_signer(keypair, false);
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert!(signer.is_available().await);
+        assert_eq!(signer.sign_message(b"hello").await.unwrap(), expected);
+
+        let mut tx = create_test_transaction(&pubkey);
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+    }
+
+    #[tokio::test]
+    async fn test_remote_wallet_user_rejects() {
+        let signer = fake_signer(Keypair::new(), true);
+
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_remote_wallet_open_failure() {
+        let result = RemoteWalletSigner::spawn("usb://ledger", || {
+            Err(remote_wallet_error(RemoteWalletError::NoDeviceFound))
+        });
+
+        assert!(matches!(result, Err(SignerError::NotAvailable(_))));
+    }
+
+    #[test]
+    fn test_parse_uri() {
+        let (_, path) = parse_uri("usb://ledger?key=1/0").unwrap();
+        assert_eq!(path, DerivationPath::new_bip44(Some(1), Some(0)));
+
+        let (_, path) = parse_uri("usb://ledger").unwrap();
+        assert_eq!(path, DerivationPath::default());
+
+        assert!(matches!(
+            parse_uri("prompt://"),
+            Err(SignerError::ConfigError(_))
+        ));
+        assert!(matches!(
+            parse_uri("usb://ledger?key=not-a-path"),
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+}
@@ -0,0 +1,118 @@
+//! Sign-then-submit helpers for any [`SolanaSigner`]
+//!
+//! Requires `sdk-v2`: `solana-rpc-client` 2.x shares its transaction types with
+//! the v2 SDK.
+
+use async_trait::async_trait;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{Signature, Transaction};
+use crate::traits::SolanaSigner;
+use crate::transaction_util::TransactionUtil;
+
+/// Extension methods that sign a transaction and submit it over RPC
+///
+/// Implemented for every [`SolanaSigner`], including `dyn SolanaSigner`.
+#[async_trait]
+pub trait SolanaSignerRpcExt: SolanaSigner {
+    /// Sign a transaction and send it, without waiting for confirmation
+    ///
+    /// Every other required signature must already be present; the transaction is
+    /// not sent otherwise.
+    ///
+    /// # Returns
+    ///
+    /// The transaction signature reported by the RPC node
+    async fn sign_and_send_transaction(
+        &self,
+        tx: &mut Transaction,
+        rpc: &RpcClient,
+    ) -> Result<Signature, SignerError> {
+        sign_for_submission(self, tx).await?;
+
+        rpc.send_transaction(tx)
+            .await
+            .map_err(|e| SignerError::RemoteApiError(format!("RPC send failed: {e}")))
+    }
+
+    /// Sign a transaction, send it, and wait until it reaches the client's commitment
+    ///
+    /// # Returns
+    ///
+    /// The transaction signature
+    async fn sign_and_send_and_confirm_transaction(
+        &self,
+        tx: &mut Transaction,
+        rpc: &RpcClient,
+    ) -> Result<Signature, SignerError> {
+        sign_for_submission(self, tx).await?;
+
+        rpc.send_and_confirm_transaction(tx)
+            .await
+            .map_err(|e| SignerError::RemoteApiError(format!("RPC send failed: {e}")))
+    }
+}
+
+impl<T: SolanaSigner + ?Sized> SolanaSignerRpcExt for T {}
+
+/// Sign and check that no required signature is missing
+async fn sign_for_submission<S: SolanaSigner + ?Sized>(
+    signer: &S,
+    tx: &mut Transaction,
+) -> Result<(), SignerError> {
+    signer.sign_transaction(tx).await?;
+
+    let remaining = TransactionUtil::remaining_required_signers(tx);
+    if !remaining.is_empty() {
+        let missing: Vec<String> = remaining.iter().map(ToString::to_string).collect();
+        return Err(SignerError::SigningFailed(format!(
+            "Transaction still needs signatures from {}",
+            missing.join(", ")
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::sdk_adapter::{Keypair, Pubkey};
+    use crate::test_util::{create_multi_signer_test_transaction, create_test_transaction};
+
+    fn unreachable_rpc() -> RpcClient {
+        // Port 9 (discard) is never an RPC node; these tests must not reach the network
+        RpcClient::new("http://127.0.0.1:9".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_refuses_to_send_partially_signed() {
+        let signer = MemorySigner::new(Keypair::new());
+        let other = Pubkey::new_unique();
+        let mut tx = create_multi_signer_test_transaction(&[signer.pubkey(), other]);
+
+        let result = signer
+            .sign_and_send_transaction(&mut tx, &unreachable_rpc())
+            .await;
+
+        match result {
+            Err(SignerError::SigningFailed(msg)) => assert!(msg.contains(&other.to_string())),
+            other => panic!("Expected SigningFailed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signing_error_is_returned_before_sending() {
+        let signer: Box<dyn SolanaSigner> = Box::new(MemorySigner::new(Keypair::new()));
+        let mut tx = create_test_transaction(&Pubkey::new_unique());
+
+        assert!(matches!(
+            signer
+                .sign_and_send_and_confirm_transaction(&mut tx, &unreachable_rpc())
+                .await,
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+}
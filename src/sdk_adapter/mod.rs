@@ -19,5 +19,8 @@ pub use v3::*;
 #[cfg(all(feature = "sdk-v2", feature = "sdk-v3"))]
 compile_error!("Cannot enable both sdk-v2 and sdk-v3 features. Choose one.");
 
+#[cfg(all(feature = "rpc", feature = "sdk-v3"))]
+compile_error!("The rpc feature requires sdk-v2: solana-rpc-client 2.x uses the v2 SDK types.");
+
 #[cfg(not(any(feature = "sdk-v2", feature = "sdk-v3")))]
 compile_error!("Must enable either sdk-v2 or sdk-v3 feature.");
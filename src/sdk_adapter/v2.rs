@@ -5,12 +5,17 @@
 pub use solana_sdk::hash::Hash;
 #[allow(unused_imports)]
 pub use solana_sdk::instruction::{AccountMeta, Instruction};
+pub use solana_sdk::message::compiled_instruction::CompiledInstruction;
+#[allow(unused_imports)]
+pub use solana_sdk::message::v0::{Message as V0Message, MessageAddressTableLookup};
 #[allow(unused_imports)]
 pub use solana_sdk::message::Message;
+#[allow(unused_imports)]
+pub use solana_sdk::message::{AddressLookupTableAccount, MessageHeader, VersionedMessage};
 pub use solana_sdk::pubkey::Pubkey;
 pub use solana_sdk::signature::{Keypair, Signature};
 pub use solana_sdk::signer::Signer;
-pub use solana_sdk::transaction::Transaction;
+pub use solana_sdk::transaction::{Transaction, VersionedTransaction};
 
 /// Parse a keypair from bytes (v2 adapter)
 pub fn keypair_from_bytes(bytes: &[u8]) -> Result<Keypair, String> {
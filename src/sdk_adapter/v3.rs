@@ -5,13 +5,18 @@
 pub use solana_sdk_v3::hash::Hash;
 #[allow(unused_imports)]
 pub use solana_sdk_v3::instruction::{AccountMeta, Instruction};
+pub use solana_sdk_v3::message::compiled_instruction::CompiledInstruction;
+#[allow(unused_imports)]
+pub use solana_sdk_v3::message::v0::{Message as V0Message, MessageAddressTableLookup};
 #[allow(unused_imports)]
 pub use solana_sdk_v3::message::Message;
+#[allow(unused_imports)]
+pub use solana_sdk_v3::message::{AddressLookupTableAccount, MessageHeader, VersionedMessage};
 pub use solana_sdk_v3::pubkey::Pubkey;
 pub use solana_sdk_v3::signature::{Keypair, Signature};
 #[allow(unused_imports)]
 pub use solana_sdk_v3::signer::Signer;
-pub use solana_sdk_v3::transaction::Transaction;
+pub use solana_sdk_v3::transaction::{Transaction, VersionedTransaction};
 
 /// Parse a keypair from bytes (v3 adapter)
 pub fn keypair_from_bytes(bytes: &[u8]) -> Result<Keypair, String> {
@@ -0,0 +1,153 @@
+//! Adapter exposing Solana SDK signers as [`SolanaSigner`]
+//!
+//! Lets keypairs, presigners and `solana-remote-wallet` signers from the SDK flow
+//! through code written against this crate's trait.
+
+use async_trait::async_trait;
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{Pubkey, Signature, Signer, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerMetadata, SolanaSigner};
+use crate::transaction_util::TransactionUtil;
+
+/// [`SolanaSigner`] backed by any Solana SDK `Signer`
+///
+/// SDK signers are synchronous: each signature is produced on the calling task.
+/// Interactive signers (e.g. a Ledger awaiting confirmation) block that thread
+/// until the user responds.
+pub struct SdkSignerAdapter<T> {
+    inner: T,
+    pubkey: Pubkey,
+}
+
+impl<T: Signer> SdkSignerAdapter<T> {
+    /// Wrap an SDK signer, reading its public key once
+    pub fn new(inner: T) -> Result<Self, SignerError> {
+        let pubkey = inner
+            .try_pubkey()
+            .map_err(|e| SignerError::InvalidPublicKey(e.to_string()))?;
+
+        Ok(Self { inner, pubkey })
+    }
+
+    /// Get a reference to the wrapped signer
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwrap the SDK signer
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn sign_and_serialize(&self, tx: &mut Transaction) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&tx.message_data())?;
+
+        TransactionUtil::add_signature_to_transaction(tx, &self.pubkey, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(tx)?,
+            signature,
+            self.pubkey,
+        ))
+    }
+
+    fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.inner
+            .try_sign_message(message)
+            .map_err(|e| SignerError::SigningFailed(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl<T: Signer + Send + Sync> SolanaSigner for SdkSignerAdapter<T> {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx)
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message)
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx)
+    }
+
+    /// Signs one at a time, since interactive signers prompt for each transaction
+    async fn sign_all_transactions(
+        &self,
+        txs: &mut [Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        crate::batch::sign_sequentially(self, txs).await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.inner.try_pubkey().is_ok()
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        if self.inner.is_interactive() {
+            LatencyClass::Hardware
+        } else {
+            LatencyClass::Local
+        }
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("sdk", self.pubkey, self.latency_class())
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=sdk interactive={} pubkey={}",
+            self.inner.is_interactive(),
+            self.pubkey
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::{create_multi_signer_test_transaction, create_test_transaction};
+
+    #[tokio::test]
+    async fn test_sign_with_keypair() {
+        let keypair = Keypair::new();
+        let expected = keypair_sign_message(&keypair, b"hello");
+        let signer = SdkSignerAdapter::new(keypair).unwrap();
+
+        assert_eq!(signer.sign_message(b"hello").await.unwrap(), expected);
+        assert_eq!(signer.latency_class(), LatencyClass::Local);
+        assert!(signer.is_available().await);
+
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let signed = signer.sign_transaction(&mut tx).await.unwrap();
+
+        assert_eq!(signed.signer_pubkey, keypair_pubkey(signer.inner()));
+        assert!(tx.verify().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_partial_sign_through_trait_object() {
+        let signer: Box<dyn SolanaSigner> =
+            Box::new(SdkSignerAdapter::new(Keypair::new()).unwrap());
+        let other = Pubkey::new_unique();
+        let mut tx = create_multi_signer_test_transaction(&[signer.pubkey(), other]);
+
+        let result = signer.sign_partial_detailed(&mut tx).await.unwrap();
+
+        assert_eq!(result.remaining, vec![other]);
+    }
+}
@@ -0,0 +1,259 @@
+//! Cheaply cloneable handle to a signer
+//!
+//! Most backends hold connections, caches or secrets and are not `Clone`.
+//! [`SharedSigner`] puts a signer behind an `Arc` so the same instance can be
+//! handed to many tasks while still being used as a [`SolanaSigner`].
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction, VersionedTransaction};
+#[cfg(feature = "unsafe-debug")]
+use crate::traits::DebugSignResult;
+use crate::traits::{
+    HealthStatus, LatencyClass, PartialSignResult, SignOptions, SignedTransaction,
+    SignerCapabilities, SignerMetadata, SolanaSigner,
+};
+use crate::transaction_util::Encoding;
+use crate::Signer;
+
+/// Shared, cloneable handle to a signer
+///
+/// Clones refer to the same signer, so initialization state, caches and
+/// connection pools are shared. Defaults to the unified [`Signer`] enum; any
+/// signer, including `dyn SolanaSigner`, can be shared.
+pub struct SharedSigner<S: ?Sized = Signer> {
+    inner: Arc<S>,
+}
+
+impl<S> SharedSigner<S> {
+    /// Move a signer behind a shared handle
+    pub fn new(signer: S) -> Self {
+        Self {
+            inner: Arc::new(signer),
+        }
+    }
+}
+
+impl<S: ?Sized> SharedSigner<S> {
+    /// Get the underlying `Arc`
+    pub fn as_arc(&self) -> &Arc<S> {
+        &self.inner
+    }
+}
+
+impl<S: ?Sized> Clone for SharedSigner<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<S: ?Sized> Deref for SharedSigner<S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S> From<S> for SharedSigner<S> {
+    fn from(signer: S) -> Self {
+        Self::new(signer)
+    }
+}
+
+impl<S: ?Sized> From<Arc<S>> for SharedSigner<S> {
+    fn from(inner: Arc<S>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<S: ?Sized> std::fmt::Debug for SharedSigner<S>
+where
+    S: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedSigner").field(&self.inner).finish()
+    }
+}
+
+impl Signer {
+    /// Move this signer behind a cloneable [`SharedSigner`] handle
+    pub fn into_shared(self) -> SharedSigner {
+        SharedSigner::new(self)
+    }
+}
+
+/// Forwards every method, so backend overrides of default methods still apply
+#[async_trait]
+impl<S: SolanaSigner + ?Sized> SolanaSigner for SharedSigner<S> {
+    fn pubkey(&self) -> Pubkey {
+        self.inner.pubkey()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.inner.sign_transaction(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.inner.sign_message(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.inner.sign_partial_transaction(tx).await
+    }
+
+    async fn sign_transaction_with(
+        &self,
+        tx: &mut Transaction,
+        options: SignOptions,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.inner.sign_transaction_with(tx, options).await
+    }
+
+    async fn sign_versioned_transaction(
+        &self,
+        tx: &mut VersionedTransaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.inner.sign_versioned_transaction(tx).await
+    }
+
+    async fn sign_precomputed(
+        &self,
+        message_bytes: &[u8],
+        tx: &mut Transaction,
+        pubkey_slot_hint: Option<usize>,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.inner
+            .sign_precomputed(message_bytes, tx, pubkey_slot_hint)
+            .await
+    }
+
+    async fn sign_partial_detailed(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<PartialSignResult, SignerError> {
+        self.inner.sign_partial_detailed(tx).await
+    }
+
+    #[cfg(feature = "unsafe-debug")]
+    async fn sign_transaction_debug(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<DebugSignResult, SignerError> {
+        self.inner.sign_transaction_debug(tx).await
+    }
+
+    async fn sign_bundle(&self, txs: &mut [Transaction]) -> Result<Vec<String>, SignerError> {
+        self.inner.sign_bundle(txs).await
+    }
+
+    async fn sign_all_transactions(
+        &self,
+        txs: &mut [Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        self.inner.sign_all_transactions(txs).await
+    }
+
+    async fn sign_offchain_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.inner.sign_offchain_message(message).await
+    }
+
+    fn verify(&self, message: &[u8], signature: &Signature) -> Result<bool, SignerError> {
+        self.inner.verify(message, signature)
+    }
+
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        self.inner.latency_class()
+    }
+
+    fn output_encoding(&self) -> Encoding {
+        self.inner.output_encoding()
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn config_debug(&self) -> String {
+        self.inner.config_debug()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        self.inner.metadata()
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        self.inner.validate_config()
+    }
+
+    async fn warm_up(&self) -> Result<(), SignerError> {
+        self.inner.warm_up().await
+    }
+
+    async fn health_check(&self) -> Result<(), SignerError> {
+        self.inner.health_check().await
+    }
+
+    async fn health(&self) -> HealthStatus {
+        self.inner.health().await
+    }
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::sdk_adapter::Keypair;
+
+    #[tokio::test]
+    async fn test_shared_across_tasks() {
+        let signer = Signer::from(MemorySigner::new(Keypair::new())).into_shared();
+        let expected = signer.sign_message(b"hello").await.unwrap();
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let signer = signer.clone();
+                tokio::spawn(async move { signer.sign_message(b"hello").await.unwrap() })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), expected);
+        }
+        assert_eq!(Arc::strong_count(signer.as_arc()), 1);
+    }
+
+    #[tokio::test]
+    async fn test_shared_trait_object() {
+        let memory = MemorySigner::new(Keypair::new());
+        let pubkey = memory.pubkey();
+        let signer: SharedSigner<dyn SolanaSigner> =
+            SharedSigner::from(Arc::new(memory) as Arc<dyn SolanaSigner>);
+
+        assert_eq!(signer.clone().pubkey(), pubkey);
+        assert!(signer.is_available().await);
+    }
+
+    #[test]
+    fn test_memory_signer_clone() {
+        let signer = MemorySigner::new(Keypair::new());
+
+        assert_eq!(signer.clone().pubkey(), signer.pubkey());
+    }
+}
@@ -0,0 +1,444 @@
+//! Squads v4 multisig wrapper
+//!
+//! [`SquadsSigner`] presents a Squads vault as a [`SolanaSigner`]. Transactions handed
+//! to it are written for the vault (the vault as fee payer and signer); instead of
+//! being signed directly, each one is wrapped into a Squads vault transaction, a
+//! proposal for it is opened, and the wrapped member key approves it. Execution
+//! happens later through Squads once the multisig threshold is met.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use async_trait::async_trait;
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{AccountMeta, Instruction, Message, Pubkey, Signature, Transaction};
+use crate::traits::{
+    HealthStatus, LatencyClass, SignedTransaction, SignerCapabilities, SignerMetadata, SolanaSigner,
+};
+
+/// Squads v4 program (`SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf`)
+pub const SQUADS_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    6, 129, 196, 206, 71, 226, 35, 104, 184, 177, 85, 94, 200, 135, 175, 9, 46, 252, 126, 251, 182,
+    108, 163, 245, 47, 191, 104, 212, 172, 156, 183, 168,
+]);
+
+const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array([0; 32]);
+
+// Anchor discriminators: sha256("global:<instruction name>")[..8]
+const VAULT_TRANSACTION_CREATE: [u8; 8] = [48, 250, 78, 168, 208, 226, 218, 211];
+const PROPOSAL_CREATE: [u8; 8] = [220, 60, 73, 224, 30, 108, 79, 159];
+const PROPOSAL_APPROVE: [u8; 8] = [144, 37, 164, 136, 188, 216, 42, 248];
+
+const SEED_PREFIX: &[u8] = b"multisig";
+const SEED_VAULT: &[u8] = b"vault";
+const SEED_TRANSACTION: &[u8] = b"transaction";
+const SEED_PROPOSAL: &[u8] = b"proposal";
+
+/// Signer that proposes and approves Squads vault transactions with a member key
+pub struct SquadsSigner<S> {
+    member: S,
+    multisig: Pubkey,
+    vault_index: u8,
+    vault: Pubkey,
+    transaction_index: AtomicU64,
+}
+
+impl<S: SolanaSigner> SquadsSigner<S> {
+    /// Wrap `member` to act for vault `vault_index` of `multisig`
+    ///
+    /// `transaction_index` is the multisig account's current `transaction_index`;
+    /// each signed transaction uses the next index. The member must hold the
+    /// `Initiate` and `Vote` permissions and pays rent for the new accounts.
+    pub fn new(member: S, multisig: Pubkey, vault_index: u8, transaction_index: u64) -> Self {
+        let (vault, _) = Pubkey::find_program_address(
+            &[SEED_PREFIX, multisig.as_ref(), SEED_VAULT, &[vault_index]],
+            &SQUADS_PROGRAM_ID,
+        );
+
+        Self {
+            member,
+            multisig,
+            vault_index,
+            vault,
+            transaction_index: AtomicU64::new(transaction_index),
+        }
+    }
+
+    /// Get a reference to the wrapped member signer
+    pub fn member(&self) -> &S {
+        &self.member
+    }
+
+    /// Address of the Squads vault this signer acts for
+    pub fn vault_address(&self) -> Pubkey {
+        self.vault
+    }
+
+    /// Index of the most recently proposed vault transaction
+    pub fn transaction_index(&self) -> u64 {
+        self.transaction_index.load(Ordering::SeqCst)
+    }
+
+    fn transaction_address(&self, transaction_index: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                self.multisig.as_ref(),
+                SEED_TRANSACTION,
+                &transaction_index.to_le_bytes(),
+            ],
+            &SQUADS_PROGRAM_ID,
+        )
+        .0
+    }
+
+    fn proposal_address(&self, transaction_index: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                self.multisig.as_ref(),
+                SEED_TRANSACTION,
+                &transaction_index.to_le_bytes(),
+                SEED_PROPOSAL,
+            ],
+            &SQUADS_PROGRAM_ID,
+        )
+        .0
+    }
+
+    /// Build the create, propose and approve instructions for an encoded vault message
+    fn proposal_instructions(
+        &self,
+        transaction_message: &[u8],
+        transaction_index: u64,
+    ) -> Vec<Instruction> {
+        let member = self.member.pubkey();
+        let transaction = self.transaction_address(transaction_index);
+        let proposal = self.proposal_address(transaction_index);
+
+        let mut create_data = VAULT_TRANSACTION_CREATE.to_vec();
+        create_data.push(self.vault_index);
+        // No ephemeral signers
+        create_data.push(0);
+        create_data.extend_from_slice(&(transaction_message.len() as u32).to_le_bytes());
+        create_data.extend_from_slice(transaction_message);
+        // No memo
+        create_data.push(0);
+
+        let mut propose_data = PROPOSAL_CREATE.to_vec();
+        propose_data.extend_from_slice(&transaction_index.to_le_bytes());
+        // Not a draft, so it is immediately open for voting
+        propose_data.push(0);
+
+        let mut approve_data = PROPOSAL_APPROVE.to_vec();
+        approve_data.push(0);
+
+        vec![
+            Instruction {
+                program_id: SQUADS_PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(self.multisig, false),
+                    AccountMeta::new(transaction, false),
+                    AccountMeta::new_readonly(member, true),
+                    AccountMeta::new(member, true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+                data: create_data,
+            },
+            Instruction {
+                program_id: SQUADS_PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new_readonly(self.multisig, false),
+                    AccountMeta::new(proposal, false),
+                    AccountMeta::new_readonly(member, true),
+                    AccountMeta::new(member, true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+                data: propose_data,
+            },
+            Instruction {
+                program_id: SQUADS_PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new_readonly(self.multisig, false),
+                    AccountMeta::new(member, true),
+                    AccountMeta::new(proposal, false),
+                ],
+                data: approve_data,
+            },
+        ]
+    }
+
+    /// Replace `tx` with a member-signed transaction proposing it to the multisig
+    async fn propose(&self, tx: &mut Transaction) -> Result<SignedTransaction, SignerError> {
+        let transaction_message = encode_transaction_message(&tx.message, &self.vault)?;
+        let transaction_index = self.transaction_index.fetch_add(1, Ordering::SeqCst) + 1;
+        let instructions = self.proposal_instructions(&transaction_message, transaction_index);
+
+        let message = Message::new_with_blockhash(
+            &instructions,
+            Some(&self.member.pubkey()),
+            &tx.message.recent_blockhash,
+        );
+        *tx = Transaction::new_unsigned(message);
+
+        self.member.sign_transaction(tx).await
+    }
+}
+
+/// Serialize `message` as a Squads `TransactionMessage`
+///
+/// Squads stores the vault message with `u8` length prefixes (and a `u16` prefix
+/// for instruction data) rather than the compact-u16 of the wire format.
+fn encode_transaction_message(message: &Message, vault: &Pubkey) -> Result<Vec<u8>, SignerError> {
+    let header = &message.header;
+    let num_signers = header.num_required_signatures as usize;
+
+    if num_signers != 1 || message.account_keys.first() != Some(vault) {
+        return Err(SignerError::SigningFailed(
+            "Squads vault transactions must be paid for and signed by the vault only".to_string(),
+        ));
+    }
+
+    let too_large = || {
+        SignerError::SigningFailed("Transaction too large for a Squads vault message".to_string())
+    };
+    let num_writable_signers = num_signers - header.num_readonly_signed_accounts as usize;
+    let num_writable_non_signers =
+        message.account_keys.len() - num_signers - header.num_readonly_unsigned_accounts as usize;
+
+    let mut data = vec![
+        num_signers as u8,
+        num_writable_signers as u8,
+        u8::try_from(num_writable_non_signers).map_err(|_| too_large())?,
+        u8::try_from(message.account_keys.len()).map_err(|_| too_large())?,
+    ];
+    for key in &message.account_keys {
+        data.extend_from_slice(key.as_ref());
+    }
+
+    data.push(u8::try_from(message.instructions.len()).map_err(|_| too_large())?);
+    for instruction in &message.instructions {
+        data.push(instruction.program_id_index);
+        data.push(u8::try_from(instruction.accounts.len()).map_err(|_| too_large())?);
+        data.extend_from_slice(&instruction.accounts);
+        data.extend_from_slice(
+            &u16::try_from(instruction.data.len())
+                .map_err(|_| too_large())?
+                .to_le_bytes(),
+        );
+        data.extend_from_slice(&instruction.data);
+    }
+
+    // Legacy messages have no address table lookups
+    data.push(0);
+
+    Ok(data)
+}
+
+#[async_trait]
+impl<S: SolanaSigner> SolanaSigner for SquadsSigner<S> {
+    /// The vault address, which transactions given to this signer act for
+    fn pubkey(&self) -> Pubkey {
+        self.vault
+    }
+
+    /// Propose `tx` as a vault transaction and approve it with the member key
+    ///
+    /// `tx` is replaced by the member-signed proposal transaction, which reuses its
+    /// recent blockhash. The returned signature is the member's.
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.propose(tx).await
+    }
+
+    async fn sign_message(&self, _message: &[u8]) -> Result<Signature, SignerError> {
+        Err(SignerError::SigningFailed(
+            "Squads vaults cannot sign arbitrary messages".to_string(),
+        ))
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.propose(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.member.is_available().await
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        self.member.latency_class()
+    }
+
+    /// Signing creates a proposal that members approve on-chain, so only legacy
+    /// transactions are supported and no signature is added
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities {
+            supports_partial_sign: false,
+            supports_versioned_tx: false,
+            supports_message_sign: false,
+            supports_batch: false,
+            requires_init: self.member.capabilities().requires_init,
+        }
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("squads", self.pubkey(), self.latency_class())
+            .with_key_id(self.multisig.to_string())
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "squads multisig={} vault_index={} vault={} member=({})",
+            self.multisig,
+            self.vault_index,
+            self.vault,
+            self.member.config_debug()
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        self.member.validate_config()
+    }
+
+    async fn warm_up(&self) -> Result<(), SignerError> {
+        self.member.warm_up().await
+    }
+
+    async fn health_check(&self) -> Result<(), SignerError> {
+        self.member.health_check().await
+    }
+
+    async fn health(&self) -> HealthStatus {
+        self.member.health().await
+    }
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::sdk_adapter::{keypair_pubkey, Keypair};
+    use crate::test_util::{create_multi_signer_test_transaction, create_test_transaction};
+    use crate::transaction_util::TransactionUtil;
+
+    fn create_test_signer(transaction_index: u64) -> (SquadsSigner<MemorySigner>, Pubkey) {
+        let keypair = Keypair::new();
+        let member = keypair_pubkey(&keypair);
+        let signer = SquadsSigner::new(
+            MemorySigner::new(keypair),
+            Pubkey::new_unique(),
+            0,
+            transaction_index,
+        );
+        (signer, member)
+    }
+
+    #[test]
+    fn test_vault_address() {
+        let (signer, _) = create_test_signer(0);
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"multisig", signer.multisig.as_ref(), b"vault", &[0]],
+            &SQUADS_PROGRAM_ID,
+        );
+
+        assert_eq!(signer.vault_address(), expected);
+        assert_eq!(signer.pubkey(), expected);
+        assert_eq!(
+            SQUADS_PROGRAM_ID.to_string(),
+            "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction_proposes_vault_transaction() {
+        let (signer, member) = create_test_signer(41);
+        let vault_tx = create_test_transaction(&signer.pubkey());
+        let vault_message = encode_transaction_message(&vault_tx.message, &signer.vault).unwrap();
+
+        let mut tx = vault_tx.clone();
+        let SignedTransaction {
+            base64_tx: serialized,
+            signature,
+            ..
+        } = signer.sign_transaction(&mut tx).await.unwrap();
+
+        assert!(!serialized.is_empty());
+        assert_eq!(signer.transaction_index(), 42);
+        assert_eq!(tx.message.account_keys[0], member);
+        assert_eq!(tx.signatures, vec![signature]);
+        assert_eq!(
+            tx.message.recent_blockhash,
+            vault_tx.message.recent_blockhash
+        );
+        assert!(TransactionUtil::verify_batch(std::slice::from_ref(&tx))[0].is_ok());
+
+        let instructions = &tx.message.instructions;
+        assert_eq!(instructions.len(), 3);
+        for instruction in instructions {
+            assert_eq!(
+                tx.message.account_keys[instruction.program_id_index as usize],
+                SQUADS_PROGRAM_ID
+            );
+        }
+
+        let create = &instructions[0].data;
+        assert_eq!(create[..8], VAULT_TRANSACTION_CREATE);
+        assert_eq!(create[8..10], [0, 0]);
+        let message_len = u32::from_le_bytes(create[10..14].try_into().unwrap()) as usize;
+        assert_eq!(&create[14..14 + message_len], vault_message.as_slice());
+
+        let propose = &instructions[1].data;
+        assert_eq!(propose[..8], PROPOSAL_CREATE);
+        assert_eq!(propose[8..16], 42u64.to_le_bytes());
+
+        assert_eq!(instructions[2].data, [&PROPOSAL_APPROVE[..], &[0]].concat());
+        let proposal = signer.proposal_address(42);
+        assert!(tx.message.account_keys.contains(&proposal));
+    }
+
+    #[test]
+    fn test_encode_transaction_message() {
+        let (signer, _) = create_test_signer(0);
+        let tx = create_test_transaction(&signer.vault);
+
+        let data = encode_transaction_message(&tx.message, &signer.vault).unwrap();
+
+        // vault + recipient writable, system program readonly
+        assert_eq!(data[..4], [1, 1, 1, 3]);
+        assert_eq!(&data[4..36], signer.vault.as_ref());
+        let instructions = &data[4 + 3 * 32..];
+        assert_eq!(instructions[0], 1);
+        // program index, two accounts, 12 bytes of transfer data
+        assert_eq!(instructions[1..5], [2, 2, 0, 1]);
+        assert_eq!(instructions[5..7], 12u16.to_le_bytes());
+        assert_eq!(*data.last().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_transaction_not_for_vault() {
+        let (signer, _) = create_test_signer(0);
+
+        let mut tx = create_test_transaction(&Pubkey::new_unique());
+        let result = signer.sign_transaction(&mut tx).await;
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+
+        let mut tx = create_multi_signer_test_transaction(&[signer.vault, Pubkey::new_unique()]);
+        let result = signer.sign_transaction(&mut tx).await;
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+        assert_eq!(signer.transaction_index(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sign_message_unsupported() {
+        let (signer, _) = create_test_signer(0);
+        let result = signer.sign_message(b"hello").await;
+
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+        assert!(!signer.capabilities().supports_message_sign);
+    }
+}
@@ -0,0 +1,391 @@
+//! ssh-agent signer integration
+//!
+//! Signs with an Ed25519 key held by a running ssh-agent (OpenSSH, gpg-agent's ssh
+//! support, 1Password, Secretive, ...) over the agent's Unix socket. The private key
+//! never leaves the agent, which may itself keep it on a hardware token.
+
+mod protocol;
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use protocol::Identity;
+use std::path::PathBuf;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Send one request to the agent over `stream` and return the reply type and payload
+async fn exchange<S>(
+    stream: &mut S,
+    message_type: u8,
+    payload: &[u8],
+) -> Result<(u8, Vec<u8>), SignerError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let io_error = |e: std::io::Error| SignerError::NotAvailable(format!("ssh-agent I/O: {e}"));
+
+    stream
+        .write_all(&protocol::encode_message(message_type, payload))
+        .await
+        .map_err(io_error)?;
+
+    let mut length = [0u8; 4];
+    stream.read_exact(&mut length).await.map_err(io_error)?;
+    let length = u32::from_be_bytes(length) as usize;
+    if length == 0 || length > protocol::MAX_MESSAGE_LEN {
+        return Err(SignerError::SerializationError(format!(
+            "ssh-agent reply has invalid length {length}"
+        )));
+    }
+
+    let mut reply = vec![0u8; length];
+    stream.read_exact(&mut reply).await.map_err(io_error)?;
+    let payload = reply.split_off(1);
+
+    Ok((reply[0], payload))
+}
+
+/// Signer backed by an Ed25519 key held by ssh-agent
+#[derive(Debug, Clone)]
+pub struct SshAgentSigner {
+    socket_path: PathBuf,
+    requested_key: Option<Pubkey>,
+    key_blob: Vec<u8>,
+    comment: String,
+    public_key: Pubkey,
+}
+
+impl SshAgentSigner {
+    /// Create a new SshAgentSigner using the agent at `$SSH_AUTH_SOCK` (requires initialization)
+    pub fn new() -> Result<Self, SignerError> {
+        let socket_path = std::env::var_os("SSH_AUTH_SOCK").ok_or_else(|| {
+            SignerError::ConfigError("SSH_AUTH_SOCK is not set; is ssh-agent running?".to_string())
+        })?;
+
+        Ok(Self::with_socket_path(socket_path.into()))
+    }
+
+    /// Create a new SshAgentSigner using the agent listening on `socket_path` (requires initialization)
+    pub fn with_socket_path(socket_path: PathBuf) -> Self {
+        Self {
+            socket_path,
+            requested_key: None,
+            key_blob: Vec::new(),
+            comment: String::new(),
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        }
+    }
+
+    /// Sign with the agent key whose Ed25519 public key is `pubkey`
+    ///
+    /// Without this, the first Ed25519 key the agent lists is used.
+    pub fn with_public_key(mut self, pubkey: Pubkey) -> Self {
+        self.requested_key = Some(pubkey);
+        self
+    }
+
+    /// Initialize the signer by picking the signing key from the agent's identities
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        let identities = self.list_identities().await?;
+        let identity = self.select_identity(&identities)?;
+
+        self.key_blob = identity.key_blob.clone();
+        self.comment = identity.comment.clone();
+        self.public_key = Pubkey::from(identity.public_key.unwrap_or_default());
+
+        Ok(())
+    }
+
+    fn select_identity<'a>(&self, identities: &'a [Identity]) -> Result<&'a Identity, SignerError> {
+        let Some(requested) = self.requested_key else {
+            return identities
+                .iter()
+                .find(|identity| identity.key_type == protocol::KEY_TYPE_ED25519)
+                .ok_or_else(|| {
+                    SignerError::ConfigError("ssh-agent holds no Ed25519 keys".to_string())
+                });
+        };
+
+        let identity = identities
+            .iter()
+            .find(|identity| identity.public_key == Some(requested.to_bytes()))
+            .ok_or_else(|| {
+                SignerError::ConfigError(format!("ssh-agent does not hold the key {requested}"))
+            })?;
+
+        if identity.key_type == protocol::KEY_TYPE_SK_ED25519 {
+            // FIDO keys sign an envelope (application hash, flags, counter, data hash)
+            // rather than the data itself, which Solana cannot verify.
+            return Err(SignerError::ConfigError(format!(
+                "{} keys do not produce plain Ed25519 signatures and cannot sign Solana transactions",
+                protocol::KEY_TYPE_SK_ED25519
+            )));
+        }
+
+        Ok(identity)
+    }
+
+    #[cfg(unix)]
+    async fn request(
+        &self,
+        message_type: u8,
+        payload: &[u8],
+    ) -> Result<(u8, Vec<u8>), SignerError> {
+        let mut stream = tokio::net::UnixStream::connect(&self.socket_path)
+            .await
+            .map_err(|e| {
+                SignerError::NotAvailable(format!(
+                    "Failed to connect to ssh-agent at {}: {e}",
+                    self.socket_path.display()
+                ))
+            })?;
+
+        exchange(&mut stream, message_type, payload).await
+    }
+
+    #[cfg(not(unix))]
+    async fn request(
+        &self,
+        _message_type: u8,
+        _payload: &[u8],
+    ) -> Result<(u8, Vec<u8>), SignerError> {
+        Err(SignerError::NotAvailable(
+            "The ssh-agent signer only supports Unix domain sockets".to_string(),
+        ))
+    }
+
+    async fn list_identities(&self) -> Result<Vec<Identity>, SignerError> {
+        match self
+            .request(protocol::SSH_AGENTC_REQUEST_IDENTITIES, &[])
+            .await?
+        {
+            (protocol::SSH_AGENT_IDENTITIES_ANSWER, payload) => {
+                protocol::parse_identities(&payload)
+            }
+            (message_type, _) => Err(SignerError::RemoteApiError(format!(
+                "ssh-agent answered the identities request with message {message_type}"
+            ))),
+        }
+    }
+
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        if self.key_blob.is_empty() {
+            return Err(SignerError::ConfigError(
+                "SshAgentSigner is not initialized; call init() first".to_string(),
+            ));
+        }
+
+        let request = protocol::sign_request(&self.key_blob, message);
+        match self
+            .request(protocol::SSH_AGENTC_SIGN_REQUEST, &request)
+            .await?
+        {
+            (protocol::SSH_AGENT_SIGN_RESPONSE, payload) => {
+                Ok(Signature::from(protocol::parse_signature(&payload)?))
+            }
+            (protocol::SSH_AGENT_FAILURE, _) => Err(SignerError::SigningFailed(
+                "ssh-agent refused to sign (key removed or confirmation denied)".to_string(),
+            )),
+            (message_type, _) => Err(SignerError::RemoteApiError(format!(
+                "ssh-agent answered the sign request with message {message_type}"
+            ))),
+        }
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for SshAgentSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Check that the agent is reachable and still holds the signing key
+    async fn is_available(&self) -> bool {
+        match self.list_identities().await {
+            Ok(identities) => identities
+                .iter()
+                .any(|identity| identity.key_blob == self.key_blob),
+            Err(_) => false,
+        }
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("ssh-agent", self.public_key, self.latency_class())
+            .with_key_id(&self.comment)
+            .with_endpoint(self.socket_path.display().to_string())
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=ssh-agent socket={} key_comment={} pubkey={}",
+            self.socket_path.display(),
+            self.comment,
+            self.public_key
+        )
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use tokio::net::UnixListener;
+
+    /// Serve a fake agent holding `keypair`, answering every connection
+    fn spawn_agent(keypair: Keypair, extra_identities: Vec<(Vec<u8>, &'static str)>) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "solana-signers-ssh-agent-{}-{}.sock",
+            std::process::id(),
+            Pubkey::new_unique()
+        ));
+        // Socket files outlive the test process; clear one left by an earlier run
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        let key_blob = protocol::ed25519_key_blob(&keypair_pubkey(&keypair).to_bytes());
+
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut length = [0u8; 4];
+                stream.read_exact(&mut length).await.unwrap();
+                let mut request = vec![0u8; u32::from_be_bytes(length) as usize];
+                stream.read_exact(&mut request).await.unwrap();
+
+                let reply = match request[0] {
+                    protocol::SSH_AGENTC_REQUEST_IDENTITIES => {
+                        let mut payload =
+                            (1 + extra_identities.len() as u32).to_be_bytes().to_vec();
+                        for (blob, comment) in &extra_identities {
+                            protocol::put_string(&mut payload, blob);
+                            protocol::put_string(&mut payload, comment.as_bytes());
+                        }
+                        protocol::put_string(&mut payload, &key_blob);
+                        protocol::put_string(&mut payload, b"solana key");
+                        protocol::encode_message(protocol::SSH_AGENT_IDENTITIES_ANSWER, &payload)
+                    }
+                    protocol::SSH_AGENTC_SIGN_REQUEST => {
+                        let mut reader = protocol::Reader::new(&request[1..]);
+                        let blob = reader.string().unwrap().to_vec();
+                        let data = reader.string().unwrap().to_vec();
+                        if blob == key_blob {
+                            let signature = keypair_sign_message(&keypair, &data);
+                            let mut inner = Vec::new();
+                            protocol::put_string(&mut inner, protocol::KEY_TYPE_ED25519.as_bytes());
+                            protocol::put_string(&mut inner, signature.as_ref());
+                            let mut payload = Vec::new();
+                            protocol::put_string(&mut payload, &inner);
+                            protocol::encode_message(protocol::SSH_AGENT_SIGN_RESPONSE, &payload)
+                        } else {
+                            protocol::encode_message(protocol::SSH_AGENT_FAILURE, &[])
+                        }
+                    }
+                    _ => protocol::encode_message(protocol::SSH_AGENT_FAILURE, &[]),
+                };
+                stream.write_all(&reply).await.unwrap();
+            }
+        });
+
+        path
+    }
+
+    fn sk_key_blob(public_key: &[u8; 32]) -> Vec<u8> {
+        let mut blob = Vec::new();
+        protocol::put_string(&mut blob, protocol::KEY_TYPE_SK_ED25519.as_bytes());
+        protocol::put_string(&mut blob, public_key);
+        protocol::put_string(&mut blob, b"ssh:");
+        blob
+    }
+
+    #[tokio::test]
+    async fn test_ssh_agent_sign_message_and_transaction() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let expected = keypair_sign_message(&keypair, b"hello");
+        let path = spawn_agent(keypair, vec![(sk_key_blob(&[5u8; 32]), "yubikey")]);
+
+        let mut signer = SshAgentSigner::with_socket_path(path);
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert_eq!(signer.sign_message(b"hello").await.unwrap(), expected);
+        assert!(signer.is_available().await);
+        assert!(signer.config_debug().contains("key_comment=solana key"));
+
+        let mut tx = create_test_transaction(&pubkey);
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+    }
+
+    #[tokio::test]
+    async fn test_ssh_agent_key_selection() {
+        let keypair = Keypair::new();
+        let path = spawn_agent(keypair, vec![(sk_key_blob(&[5u8; 32]), "yubikey")]);
+
+        let mut missing =
+            SshAgentSigner::with_socket_path(path.clone()).with_public_key(Pubkey::new_unique());
+        assert!(matches!(
+            missing.init().await,
+            Err(SignerError::ConfigError(_))
+        ));
+
+        let mut sk =
+            SshAgentSigner::with_socket_path(path).with_public_key(Pubkey::from([5u8; 32]));
+        let err = sk.init().await.unwrap_err();
+        assert!(err.to_string().contains("sk-ssh-ed25519"));
+    }
+
+    #[tokio::test]
+    async fn test_ssh_agent_unreachable() {
+        let signer = SshAgentSigner::with_socket_path("/nonexistent/agent.sock".into());
+
+        assert!(!signer.is_available().await);
+        assert!(matches!(
+            signer.sign_message(b"hello").await,
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+}
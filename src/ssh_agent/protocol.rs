@@ -0,0 +1,190 @@
+//! Minimal ssh-agent protocol (draft-miller-ssh-agent): framing and the messages we use
+//!
+//! Every message is a 4-byte big-endian length followed by a 1-byte message type and
+//! its payload. Strings are 4-byte length-prefixed byte arrays.
+
+use crate::error::SignerError;
+
+pub const SSH_AGENT_FAILURE: u8 = 5;
+pub const SSH_AGENTC_REQUEST_IDENTITIES: u8 = 11;
+pub const SSH_AGENT_IDENTITIES_ANSWER: u8 = 12;
+pub const SSH_AGENTC_SIGN_REQUEST: u8 = 13;
+pub const SSH_AGENT_SIGN_RESPONSE: u8 = 14;
+
+pub const KEY_TYPE_ED25519: &str = "ssh-ed25519";
+pub const KEY_TYPE_SK_ED25519: &str = "sk-ssh-ed25519@openssh.com";
+
+/// Largest reply we accept from the agent
+pub const MAX_MESSAGE_LEN: usize = 256 * 1024;
+
+/// Key held by the agent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    pub key_blob: Vec<u8>,
+    pub key_type: String,
+    /// Raw public key for Ed25519 keys, `None` for other key types
+    pub public_key: Option<[u8; 32]>,
+    pub comment: String,
+}
+
+pub fn put_string(out: &mut Vec<u8>, value: &[u8]) {
+    out.extend_from_slice(&(value.len() as u32).to_be_bytes());
+    out.extend_from_slice(value);
+}
+
+/// Reader over an agent message payload
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], SignerError> {
+        let value = self
+            .bytes
+            .get(self.pos..self.pos.saturating_add(len))
+            .ok_or_else(|| {
+                SignerError::SerializationError("ssh-agent message truncated".to_string())
+            })?;
+        self.pos += len;
+        Ok(value)
+    }
+
+    pub fn u32(&mut self) -> Result<u32, SignerError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub fn string(&mut self) -> Result<&'a [u8], SignerError> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+}
+
+/// Frame a message for the agent
+pub fn encode_message(message_type: u8, payload: &[u8]) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(5 + payload.len());
+    framed.extend_from_slice(&(payload.len() as u32 + 1).to_be_bytes());
+    framed.push(message_type);
+    framed.extend_from_slice(payload);
+    framed
+}
+
+/// Build an `SSH_AGENTC_SIGN_REQUEST` payload
+pub fn sign_request(key_blob: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(key_blob.len() + data.len() + 12);
+    put_string(&mut payload, key_blob);
+    put_string(&mut payload, data);
+    payload.extend_from_slice(&0u32.to_be_bytes());
+    payload
+}
+
+/// Encode the wire-format public key blob of an Ed25519 key
+#[cfg(test)]
+pub fn ed25519_key_blob(public_key: &[u8; 32]) -> Vec<u8> {
+    let mut blob = Vec::with_capacity(51);
+    put_string(&mut blob, KEY_TYPE_ED25519.as_bytes());
+    put_string(&mut blob, public_key);
+    blob
+}
+
+fn parse_key_blob(key_blob: &[u8]) -> Result<(String, Option<[u8; 32]>), SignerError> {
+    let mut reader = Reader::new(key_blob);
+    let key_type = String::from_utf8_lossy(reader.string()?).into_owned();
+
+    let public_key = match key_type.as_str() {
+        KEY_TYPE_ED25519 | KEY_TYPE_SK_ED25519 => {
+            Some(reader.string()?.try_into().map_err(|_| {
+                SignerError::SerializationError("ssh-agent Ed25519 key is not 32 bytes".to_string())
+            })?)
+        }
+        _ => None,
+    };
+
+    Ok((key_type, public_key))
+}
+
+/// Parse an `SSH_AGENT_IDENTITIES_ANSWER` payload
+pub fn parse_identities(payload: &[u8]) -> Result<Vec<Identity>, SignerError> {
+    let mut reader = Reader::new(payload);
+    let count = reader.u32()?;
+
+    (0..count)
+        .map(|_| {
+            let key_blob = reader.string()?.to_vec();
+            let comment = String::from_utf8_lossy(reader.string()?).into_owned();
+            let (key_type, public_key) = parse_key_blob(&key_blob)?;
+            Ok(Identity {
+                key_blob,
+                key_type,
+                public_key,
+                comment,
+            })
+        })
+        .collect()
+}
+
+/// Parse an `SSH_AGENT_SIGN_RESPONSE` payload into the raw Ed25519 signature
+pub fn parse_signature(payload: &[u8]) -> Result<[u8; 64], SignerError> {
+    let signature = Reader::new(payload).string()?;
+    let mut reader = Reader::new(signature);
+
+    let algorithm = reader.string()?;
+    if algorithm != KEY_TYPE_ED25519.as_bytes() {
+        return Err(SignerError::SigningFailed(format!(
+            "ssh-agent returned a {} signature",
+            String::from_utf8_lossy(algorithm)
+        )));
+    }
+
+    reader
+        .string()?
+        .try_into()
+        .map_err(|_| SignerError::SigningFailed("ssh-agent signature is not 64 bytes".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identities_round_trip() {
+        let mut payload = 2u32.to_be_bytes().to_vec();
+        put_string(&mut payload, &ed25519_key_blob(&[9u8; 32]));
+        put_string(&mut payload, b"deploy key");
+        let mut rsa_blob = Vec::new();
+        put_string(&mut rsa_blob, b"ssh-rsa");
+        put_string(&mut rsa_blob, &[1, 0, 1]);
+        put_string(&mut payload, &rsa_blob);
+        put_string(&mut payload, b"rsa key");
+
+        let identities = parse_identities(&payload).unwrap();
+        assert_eq!(identities.len(), 2);
+        assert_eq!(identities[0].key_type, KEY_TYPE_ED25519);
+        assert_eq!(identities[0].public_key, Some([9u8; 32]));
+        assert_eq!(identities[0].comment, "deploy key");
+        assert_eq!(identities[1].public_key, None);
+    }
+
+    #[test]
+    fn test_truncated_and_foreign_signatures() {
+        assert!(matches!(
+            parse_identities(&[0, 0, 0, 1, 0, 0]),
+            Err(SignerError::SerializationError(_))
+        ));
+
+        let mut signature = Vec::new();
+        put_string(&mut signature, b"rsa-sha2-256");
+        put_string(&mut signature, &[0u8; 64]);
+        let mut payload = Vec::new();
+        put_string(&mut payload, &signature);
+        assert!(matches!(
+            parse_signature(&payload),
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+}
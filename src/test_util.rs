@@ -1,6 +1,14 @@
+//! Helpers for building unsigned test transactions
+//!
+//! Available to downstream crates with the `test-utils` feature.
+
 use std::str::FromStr;
 
-use crate::sdk_adapter::{AccountMeta, Hash, Instruction, Message, Pubkey, Transaction};
+use crate::sdk_adapter::{
+    keypair_pubkey, AccountMeta, CompiledInstruction, Hash, Instruction, Keypair, Message,
+    MessageAddressTableLookup, MessageHeader, Pubkey, Signature, Transaction, V0Message,
+    VersionedMessage, VersionedTransaction,
+};
 
 fn create_transfer_instruction(from: &Pubkey, to: &Pubkey, lamports: u64) -> Instruction {
     Instruction {
@@ -14,11 +22,115 @@ fn create_transfer_instruction(from: &Pubkey, to: &Pubkey, lamports: u64) -> Ins
     }
 }
 
+fn build_test_transaction(payer: &Pubkey, instruction: Instruction) -> Transaction {
+    let message = Message::new(&[instruction], Some(payer));
+    let mut tx = Transaction::new_unsigned(message);
+    tx.message.recent_blockhash = Hash::default();
+    tx
+}
+
+/// Build an unsigned System transfer from `from` to a fresh account
 pub fn create_test_transaction(from: &Pubkey) -> Transaction {
     let to = Pubkey::new_unique();
-    let instruction = create_transfer_instruction(from, &to, 1_000_000);
-    let message = Message::new(&[instruction], Some(from));
+    build_test_transaction(from, create_transfer_instruction(from, &to, 1_000_000))
+}
+
+/// Build an unsigned transaction paid by `signer` containing `instruction`
+///
+/// Use this to test signing flows with your own program's instructions.
+pub fn create_test_transaction_with_instruction(
+    signer: &Keypair,
+    instruction: Instruction,
+) -> Transaction {
+    build_test_transaction(&keypair_pubkey(signer), instruction)
+}
+
+/// Build an unsigned transaction that requires a signature from each of `signers`
+///
+/// The first signer pays the fees.
+pub fn create_multi_signer_test_transaction(signers: &[Pubkey]) -> Transaction {
+    let instruction = Instruction {
+        program_id: Pubkey::new_unique(),
+        accounts: signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true))
+            .collect(),
+        data: vec![],
+    };
+    let message = Message::new(&[instruction], signers.first());
     let mut tx = Transaction::new_unsigned(message);
     tx.message.recent_blockhash = Hash::default();
     tx
 }
+
+/// Build an unsigned v0 transaction whose single instruction loads accounts from `table`
+pub fn create_v0_test_transaction(
+    payer: &Pubkey,
+    table: &Pubkey,
+    writable_indexes: Vec<u8>,
+    readonly_indexes: Vec<u8>,
+) -> VersionedTransaction {
+    let program_id = Pubkey::new_unique();
+    let num_lookup_accounts = writable_indexes.len() + readonly_indexes.len();
+    let message = V0Message {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![*payer, program_id],
+        recent_blockhash: Hash::default(),
+        instructions: vec![CompiledInstruction {
+            program_id_index: 1,
+            accounts: (2..2 + num_lookup_accounts as u8).collect(),
+            data: vec![],
+        }],
+        address_table_lookups: vec![MessageAddressTableLookup {
+            account_key: *table,
+            writable_indexes,
+            readonly_indexes,
+        }],
+    };
+
+    VersionedTransaction {
+        signatures: vec![Signature::default()],
+        message: VersionedMessage::V0(message),
+    }
+}
+
+#[cfg(all(test, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::traits::SolanaSigner;
+    use crate::transaction_util::TransactionUtil;
+
+    #[tokio::test]
+    async fn test_create_test_transaction_with_instruction() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let program_id = Pubkey::new_unique();
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![AccountMeta::new(pubkey, true)],
+            data: vec![3, 1, 4, 1, 5],
+        };
+
+        let mut tx = create_test_transaction_with_instruction(&keypair, instruction);
+        let signer = MemorySigner::new(keypair);
+        let signature = signer.sign_transaction(&mut tx).await.unwrap().signature;
+
+        assert_eq!(tx.message.account_keys[0], pubkey);
+        assert_eq!(tx.message.instructions.len(), 1);
+        assert_eq!(tx.message.instructions[0].data, vec![3, 1, 4, 1, 5]);
+        assert_eq!(
+            tx.message.account_keys[tx.message.instructions[0].program_id_index as usize],
+            program_id
+        );
+        assert!(TransactionUtil::verify_signature(
+            &pubkey,
+            &tx.message_data(),
+            &signature
+        ));
+    }
+}
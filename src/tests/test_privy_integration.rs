@@ -11,7 +11,7 @@ mod tests {
     use super::*;
     use crate::privy::PrivySigner;
     use crate::test_util::create_test_transaction;
-    use crate::traits::SolanaSigner;
+    use crate::traits::{SignedTransaction, SolanaSigner};
     use std::env;
 
     async fn get_signer() -> PrivySigner {
@@ -70,7 +70,11 @@ mod tests {
 
         let original_message = transaction.message_data();
 
-        let (base64_txn, signature) = signer
+        let SignedTransaction {
+            base64_tx: base64_txn,
+            signature,
+            ..
+        } = signer
             .sign_transaction(&mut transaction)
             .await
             .expect("Failed to sign transaction with Privy");
@@ -13,7 +13,7 @@ mod tests {
     use super::*;
     use crate::sdk_adapter::Pubkey;
     use crate::test_util::create_test_transaction;
-    use crate::traits::SolanaSigner;
+    use crate::traits::{SignedTransaction, SolanaSigner};
     use crate::turnkey::TurnkeySigner;
     use std::env;
 
@@ -82,7 +82,11 @@ mod tests {
 
         let original_message = transaction.message_data();
 
-        let (base64_txn, signature) = signer
+        let SignedTransaction {
+            base64_tx: base64_txn,
+            signature,
+            ..
+        } = signer
             .sign_transaction(&mut transaction)
             .await
             .expect("Failed to sign transaction with Turnkey");
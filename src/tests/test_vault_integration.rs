@@ -11,7 +11,7 @@ mod tests {
 
     use super::*;
     use crate::test_util::create_test_transaction;
-    use crate::traits::SolanaSigner;
+    use crate::traits::{SignedTransaction, SolanaSigner};
     use crate::vault::VaultSigner;
     use std::env;
 
@@ -68,7 +68,11 @@ mod tests {
 
         let original_message = transaction.message_data();
 
-        let (base64_txn, signature) = signer
+        let SignedTransaction {
+            base64_tx: base64_txn,
+            signature,
+            ..
+        } = signer
             .sign_transaction(&mut transaction)
             .await
             .expect("Failed to sign transaction with Vault");
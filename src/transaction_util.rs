@@ -1,17 +1,243 @@
 use crate::error::SignerError;
-use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::sdk_adapter::{
+    AddressLookupTableAccount, CompiledInstruction, Hash, MessageHeader, Pubkey, Signature,
+    Transaction, VersionedMessage, VersionedTransaction,
+};
+use crate::traits::{SignedTransaction, SolanaSigner};
 use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+/// The ed25519 group order `L = 2^252 + 27742317777372353535851937790883648493`, little-endian
+const ED25519_GROUP_ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+/// Compute Budget program (`ComputeBudget111111111111111111111111111111`)
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    3, 6, 70, 111, 229, 33, 23, 50, 255, 236, 173, 186, 114, 195, 155, 231, 188, 140, 229, 187,
+    197, 247, 18, 107, 44, 67, 155, 58, 64, 0, 0, 0,
+]);
+
+/// Largest serialized transaction the network accepts (IPv6 MTU minus headers)
+pub const PACKET_DATA_SIZE: usize = 1232;
+
+// Compute Budget instruction discriminators
+const SET_COMPUTE_UNIT_LIMIT: u8 = 2;
+const SET_COMPUTE_UNIT_PRICE: u8 = 3;
+
+/// Text encoding used for serialized transactions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Encoding {
+    /// Standard base64 (the default, accepted by `sendTransaction`)
+    #[default]
+    Base64,
+    /// Base58, as used by some legacy RPC methods and explorers
+    Base58,
+    /// Lowercase hexadecimal
+    Hex,
+}
+
+impl Encoding {
+    /// Encode bytes using this encoding
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Base64 => STANDARD.encode(bytes),
+            Encoding::Base58 => bs58::encode(bytes).into_string(),
+            Encoding::Hex => bytes.iter().map(|b| format!("{b:02x}")).collect(),
+        }
+    }
+
+    /// Decode a string produced by [`Encoding::encode`]
+    pub fn decode(&self, encoded: &str) -> Result<Vec<u8>, SignerError> {
+        let invalid = |e: &dyn std::fmt::Display| {
+            SignerError::SerializationError(format!("Invalid {self:?} encoding: {e}"))
+        };
+
+        match self {
+            Encoding::Base64 => STANDARD.decode(encoded).map_err(|e| invalid(&e)),
+            Encoding::Base58 => bs58::decode(encoded).into_vec().map_err(|e| invalid(&e)),
+            Encoding::Hex => {
+                if !encoded.len().is_multiple_of(2) {
+                    return Err(invalid(&"odd number of digits"));
+                }
+                (0..encoded.len())
+                    .step_by(2)
+                    .map(|i| {
+                        encoded
+                            .get(i..i + 2)
+                            .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                            .ok_or_else(|| invalid(&format!("bad digit at {i}")))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Outcome of checking one required signature slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureStatus {
+    /// The signature verifies against the slot's account key
+    Valid,
+    /// A signature is present but does not verify
+    Invalid,
+    /// The slot is absent or holds `Signature::default()`
+    Missing,
+}
+
+/// A transaction decoded from its wire format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodedTransaction {
+    /// A legacy transaction
+    Legacy(Transaction),
+    /// A versioned transaction with a v0 (or later) message
+    Versioned(VersionedTransaction),
+}
+
+impl DecodedTransaction {
+    /// Get the signatures, one per required signer
+    pub fn signatures(&self) -> &[Signature] {
+        match self {
+            DecodedTransaction::Legacy(tx) => &tx.signatures,
+            DecodedTransaction::Versioned(tx) => &tx.signatures,
+        }
+    }
+
+    /// Get the serialized message, i.e. the bytes each signer signs
+    pub fn message_data(&self) -> Vec<u8> {
+        match self {
+            DecodedTransaction::Legacy(tx) => tx.message_data(),
+            DecodedTransaction::Versioned(tx) => tx.message.serialize(),
+        }
+    }
+
+    /// Re-encode the transaction in its original wire format
+    pub fn encode(&self, encoding: Encoding) -> Result<String, SignerError> {
+        match self {
+            DecodedTransaction::Legacy(tx) => {
+                TransactionUtil::serialize_transaction_with_encoding(tx, encoding)
+            }
+            DecodedTransaction::Versioned(tx) => {
+                Ok(encoding.encode(&bincode::serialize(tx).map_err(|e| {
+                    SignerError::SerializationError(format!("Failed to serialize transaction: {e}"))
+                })?))
+            }
+        }
+    }
+
+    /// Convert into a [`VersionedTransaction`], wrapping legacy messages
+    pub fn into_versioned(self) -> VersionedTransaction {
+        match self {
+            DecodedTransaction::Legacy(tx) => VersionedTransaction::from(tx),
+            DecodedTransaction::Versioned(tx) => tx,
+        }
+    }
+}
+
+/// How unsigned signature slots are encoded when serializing a partially-signed transaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnsignedSlotEncoding {
+    /// Keep an all-zero `Signature::default()` placeholder in every unsigned slot.
+    ///
+    /// This is what both the Rust SDK and `@solana/web3.js`
+    /// (`serialize({ requireAllSignatures: false })`) produce, and the only form
+    /// that passes transaction sanitization once all slots are filled.
+    #[default]
+    ZeroPlaceholder,
+    /// Drop unsigned slots after the last present signature.
+    ///
+    /// The signature count in the encoding then covers only the leading slots that
+    /// are populated. `@solana/web3.js` `Transaction.from` and bincode deserialization
+    /// both accept this and treat the missing slots as unsigned, but RPC nodes will
+    /// reject the transaction until the remaining signatures are appended. Unsigned
+    /// slots *before* a present signature keep their zero placeholder, since
+    /// signatures are positional.
+    OmitTrailing,
+}
 
 pub struct TransactionUtil;
 
 impl TransactionUtil {
     /// Encodes a Transaction to a base64 serialized String
     pub fn serialize_transaction(transaction: &Transaction) -> Result<String, SignerError> {
-        Ok(
-            STANDARD.encode(bincode::serialize(transaction).map_err(|e| {
-                SignerError::SerializationError(format!("Failed to serialize transaction: {e}"))
-            })?),
-        )
+        Self::serialize_transaction_with_encoding(transaction, Encoding::Base64)
+    }
+
+    /// Encodes a Transaction to a serialized String using the given encoding
+    pub fn serialize_transaction_with_encoding(
+        transaction: &Transaction,
+        encoding: Encoding,
+    ) -> Result<String, SignerError> {
+        Ok(encoding.encode(&Self::serialize_transaction_bytes(transaction)?))
+    }
+
+    /// Serialize a Transaction to its raw wire-format bytes
+    pub fn serialize_transaction_bytes(transaction: &Transaction) -> Result<Vec<u8>, SignerError> {
+        bincode::serialize(transaction).map_err(|e| {
+            SignerError::SerializationError(format!("Failed to serialize transaction: {e}"))
+        })
+    }
+
+    /// Decode a bincode-serialized legacy or versioned transaction
+    ///
+    /// The message version prefix decides the variant, so callers do not need to
+    /// know in advance which format a client sent.
+    pub fn deserialize_bytes(bytes: &[u8]) -> Result<DecodedTransaction, SignerError> {
+        let transaction: VersionedTransaction = bincode::deserialize(bytes).map_err(|e| {
+            SignerError::SerializationError(format!("Failed to deserialize transaction: {e}"))
+        })?;
+
+        Ok(match transaction.message {
+            VersionedMessage::Legacy(_) => DecodedTransaction::Legacy(
+                transaction
+                    .into_legacy_transaction()
+                    .expect("legacy message converts to a legacy transaction"),
+            ),
+            VersionedMessage::V0(_) => DecodedTransaction::Versioned(transaction),
+        })
+    }
+
+    /// Decode a base64-encoded legacy or versioned transaction
+    pub fn deserialize_base64(encoded: &str) -> Result<DecodedTransaction, SignerError> {
+        Self::deserialize_with_encoding(encoded, Encoding::Base64)
+    }
+
+    /// Decode a base58-encoded legacy or versioned transaction
+    pub fn deserialize_base58(encoded: &str) -> Result<DecodedTransaction, SignerError> {
+        Self::deserialize_with_encoding(encoded, Encoding::Base58)
+    }
+
+    /// Decode a legacy or versioned transaction encoded with `encoding`
+    pub fn deserialize_with_encoding(
+        encoded: &str,
+        encoding: Encoding,
+    ) -> Result<DecodedTransaction, SignerError> {
+        Self::deserialize_bytes(&encoding.decode(encoded)?)
+    }
+
+    /// Encodes a partially-signed Transaction to a base64 serialized String, encoding
+    /// unsigned signature slots according to `encoding`
+    pub fn serialize_partial_transaction(
+        transaction: &Transaction,
+        encoding: UnsignedSlotEncoding,
+    ) -> Result<String, SignerError> {
+        match encoding {
+            UnsignedSlotEncoding::ZeroPlaceholder => Self::serialize_transaction(transaction),
+            UnsignedSlotEncoding::OmitTrailing => {
+                let signed_len = transaction
+                    .signatures
+                    .iter()
+                    .rposition(|signature| *signature != Signature::default())
+                    .map_or(0, |index| index + 1);
+
+                let mut trimmed = transaction.clone();
+                trimmed.signatures.truncate(signed_len);
+
+                Self::serialize_transaction(&trimmed)
+            }
+        }
     }
 
     /// Get the position of a pubkey in the transaction's signing keypair positions.
@@ -59,4 +285,1210 @@ impl TransactionUtil {
 
         Ok(())
     }
+
+    /// Add a signature to a versioned transaction at the signer's position.
+    ///
+    /// Works for legacy and v0 messages alike: signers are always among the static
+    /// account keys, never loaded from address lookup tables.
+    pub fn add_signature_to_versioned_transaction(
+        transaction: &mut VersionedTransaction,
+        pubkey: &Pubkey,
+        signature: Signature,
+    ) -> Result<(), SignerError> {
+        let num_required_signatures = transaction.message.header().num_required_signatures as usize;
+        let signed_keys = transaction
+            .message
+            .static_account_keys()
+            .get(..num_required_signatures)
+            .ok_or_else(|| {
+                SignerError::SigningFailed(
+                    "Invalid account index: not enough account keys".to_string(),
+                )
+            })?;
+
+        let position = signed_keys
+            .iter()
+            .position(|x| x == pubkey)
+            .ok_or_else(|| {
+                SignerError::SigningFailed(format!(
+                    "Pubkey {pubkey} not found in transaction signers"
+                ))
+            })?;
+
+        if transaction.signatures.len() < num_required_signatures {
+            transaction
+                .signatures
+                .resize(num_required_signatures, Signature::default());
+        }
+
+        transaction.signatures[position] = signature;
+
+        Ok(())
+    }
+
+    /// Encodes a VersionedTransaction to a base64 serialized String
+    pub fn serialize_versioned_transaction(
+        transaction: &VersionedTransaction,
+    ) -> Result<String, SignerError> {
+        Self::serialize_versioned_transaction_with_encoding(transaction, Encoding::Base64)
+    }
+
+    /// Encodes a VersionedTransaction to a serialized String using the given encoding
+    pub fn serialize_versioned_transaction_with_encoding(
+        transaction: &VersionedTransaction,
+        encoding: Encoding,
+    ) -> Result<String, SignerError> {
+        Ok(
+            encoding.encode(&bincode::serialize(transaction).map_err(|e| {
+                SignerError::SerializationError(format!("Failed to serialize transaction: {e}"))
+            })?),
+        )
+    }
+
+    /// Add a signature to the transaction at an explicit signer index.
+    ///
+    /// Useful for hand-built transactions where the caller knows which signer slot
+    /// to fill, e.g. when a pubkey appears at multiple signer indices.
+    pub fn add_signature_at_index(
+        transaction: &mut Transaction,
+        index: usize,
+        signature: Signature,
+    ) -> Result<(), SignerError> {
+        let num_required_signatures = transaction.message.header.num_required_signatures as usize;
+
+        if index >= num_required_signatures {
+            return Err(SignerError::SigningFailed(format!(
+                "Signer index {index} out of range: transaction requires {num_required_signatures} signatures"
+            )));
+        }
+
+        if transaction.signatures.len() < num_required_signatures {
+            transaction
+                .signatures
+                .resize(num_required_signatures, Signature::default());
+        }
+
+        transaction.signatures[index] = signature;
+
+        Ok(())
+    }
+
+    /// Merge the signatures from independently signed copies of a transaction.
+    ///
+    /// Every copy in `others` must have the same message bytes as `base`. Filled
+    /// slots are verified against their signer and copied into `base`; empty slots
+    /// never overwrite a signature. Fails without modifying `base` if a message
+    /// differs, a signature does not verify, a slot has no matching account key, or
+    /// two copies hold different signatures in the same slot.
+    pub fn merge_signatures(
+        base: &mut Transaction,
+        others: &[Transaction],
+    ) -> Result<(), SignerError> {
+        let message_data = base.message_data();
+        let num_required_signatures = base.message.header.num_required_signatures as usize;
+        let mut merged = base.signatures.clone();
+        merged.resize(num_required_signatures, Signature::default());
+
+        for (position, other) in others.iter().enumerate() {
+            if other.message_data() != message_data {
+                return Err(SignerError::SigningFailed(format!(
+                    "Transaction {position} has a different message than the base transaction"
+                )));
+            }
+
+            for (index, signature) in other
+                .signatures
+                .iter()
+                .take(num_required_signatures)
+                .enumerate()
+            {
+                if *signature == Signature::default() {
+                    continue;
+                }
+                let signer = base.message.account_keys.get(index).ok_or_else(|| {
+                    SignerError::SigningFailed(format!(
+                        "Signer index {index} has no account key: transaction has {} account keys",
+                        base.message.account_keys.len()
+                    ))
+                })?;
+                if !Self::verify_signature(signer, &message_data, signature) {
+                    return Err(SignerError::SigningFailed(format!(
+                        "Invalid signature for signer {signer} in transaction {position}"
+                    )));
+                }
+                if merged[index] != Signature::default() && merged[index] != *signature {
+                    return Err(SignerError::SigningFailed(format!(
+                        "Conflicting signatures for signer {signer} in transaction {position}"
+                    )));
+                }
+                merged[index] = *signature;
+            }
+        }
+
+        base.signatures = merged;
+
+        Ok(())
+    }
+
+    /// Move a transaction to a new blockhash and sign it again.
+    ///
+    /// Changing the blockhash changes the message, so every existing signature
+    /// becomes invalid. They are all cleared before `signer` signs, leaving other
+    /// parties' slots empty for them to re-sign instead of carrying stale
+    /// signatures that would fail on-chain.
+    pub async fn refresh_blockhash_and_resign<S: SolanaSigner + ?Sized>(
+        transaction: &mut Transaction,
+        new_blockhash: Hash,
+        signer: &S,
+    ) -> Result<SignedTransaction, SignerError> {
+        let num_required_signatures = transaction.message.header.num_required_signatures as usize;
+
+        transaction.message.recent_blockhash = new_blockhash;
+        transaction.signatures = vec![Signature::default(); num_required_signatures];
+
+        signer.sign_partial_transaction(transaction).await
+    }
+
+    /// Set the compute unit limit and/or price (in micro-lamports per compute unit)
+    ///
+    /// Existing `SetComputeUnitLimit` / `SetComputeUnitPrice` instructions are
+    /// replaced rather than duplicated, which the runtime would reject, and the new
+    /// ones are placed first. If the message changes, all signatures are cleared
+    /// since they no longer match; otherwise the transaction is left untouched, so
+    /// applying the same budget twice is harmless.
+    pub fn with_compute_budget(
+        transaction: &mut Transaction,
+        cu_limit: Option<u32>,
+        cu_price: Option<u64>,
+    ) -> Result<(), SignerError> {
+        let mut budget_data = Vec::new();
+        if let Some(limit) = cu_limit {
+            budget_data.push([&[SET_COMPUTE_UNIT_LIMIT][..], &limit.to_le_bytes()].concat());
+        }
+        if let Some(price) = cu_price {
+            budget_data.push([&[SET_COMPUTE_UNIT_PRICE][..], &price.to_le_bytes()].concat());
+        }
+        if budget_data.is_empty() {
+            return Ok(());
+        }
+
+        let mut message = transaction.message.clone();
+        let program_index = match message
+            .account_keys
+            .iter()
+            .position(|key| *key == COMPUTE_BUDGET_PROGRAM_ID)
+        {
+            Some(index) => index,
+            None => {
+                // Readonly unsigned accounts come last, so appending keeps every
+                // existing index valid
+                message.account_keys.push(COMPUTE_BUDGET_PROGRAM_ID);
+                message.header.num_readonly_unsigned_accounts = message
+                    .header
+                    .num_readonly_unsigned_accounts
+                    .checked_add(1)
+                    .ok_or_else(|| {
+                        SignerError::SigningFailed("Too many readonly accounts".to_string())
+                    })?;
+                message.account_keys.len() - 1
+            }
+        };
+        let program_id_index = u8::try_from(program_index).map_err(|_| {
+            SignerError::SigningFailed(format!(
+                "Transaction has too many accounts ({}) to add the compute budget program",
+                program_index + 1
+            ))
+        })?;
+
+        message.instructions.retain(|instruction| {
+            instruction.program_id_index != program_id_index
+                || !budget_data
+                    .iter()
+                    .any(|data| instruction.data.first() == data.first())
+        });
+        message.instructions.splice(
+            0..0,
+            budget_data.into_iter().map(|data| CompiledInstruction {
+                program_id_index,
+                accounts: vec![],
+                data,
+            }),
+        );
+
+        if message != transaction.message {
+            let num_required_signatures = message.header.num_required_signatures as usize;
+            transaction.message = message;
+            transaction.signatures = vec![Signature::default(); num_required_signatures];
+        }
+
+        Ok(())
+    }
+
+    /// Check that a transaction fits in a single packet once fully signed
+    ///
+    /// Missing signature slots are counted as filled, so the check can run before
+    /// signing. Returns the signed size in bytes, or
+    /// `SignerError::TransactionTooLarge` if it exceeds [`PACKET_DATA_SIZE`].
+    pub fn check_packet_size(transaction: &Transaction) -> Result<usize, SignerError> {
+        let num_required_signatures = transaction.message.header.num_required_signatures as usize;
+        let mut signed = transaction.clone();
+        if signed.signatures.len() < num_required_signatures {
+            signed
+                .signatures
+                .resize(num_required_signatures, Signature::default());
+        }
+
+        Self::check_serialized_size(&Self::serialize_transaction_bytes(&signed)?)
+    }
+
+    /// Check that a versioned transaction fits in a single packet once fully signed
+    pub fn check_versioned_packet_size(
+        transaction: &VersionedTransaction,
+    ) -> Result<usize, SignerError> {
+        let num_required_signatures = transaction.message.header().num_required_signatures as usize;
+        let mut signed = transaction.clone();
+        if signed.signatures.len() < num_required_signatures {
+            signed
+                .signatures
+                .resize(num_required_signatures, Signature::default());
+        }
+
+        let bytes = bincode::serialize(&signed).map_err(|e| {
+            SignerError::SerializationError(format!("Failed to serialize transaction: {e}"))
+        })?;
+        Self::check_serialized_size(&bytes)
+    }
+
+    fn check_serialized_size(bytes: &[u8]) -> Result<usize, SignerError> {
+        if bytes.len() > PACKET_DATA_SIZE {
+            return Err(SignerError::TransactionTooLarge(format!(
+                "{} bytes, limit is {PACKET_DATA_SIZE}",
+                bytes.len()
+            )));
+        }
+
+        Ok(bytes.len())
+    }
+
+    /// Get `pubkey`'s signature from its slot if it is valid for the current message
+    ///
+    /// Fails if `pubkey` is not a required signer, so partial signing can reject a
+    /// transaction before contacting a remote backend.
+    pub fn existing_signature(
+        transaction: &Transaction,
+        pubkey: &Pubkey,
+    ) -> Result<Option<Signature>, SignerError> {
+        let position = Self::get_signing_keypair_position(transaction, pubkey)?;
+
+        Ok(transaction
+            .signatures
+            .get(position)
+            .filter(|signature| {
+                **signature != Signature::default()
+                    && Self::verify_signature(pubkey, &transaction.message_data(), signature)
+            })
+            .copied())
+    }
+
+    /// Check a transaction for structural problems before it is signed
+    ///
+    /// Rejects duplicate account keys, headers inconsistent with the account
+    /// list, instructions referencing missing accounts, and a signature list
+    /// whose length does not match the number of required signers. An empty
+    /// signature list is accepted, since signing allocates the slots. Failures
+    /// are reported as `SignerError::InvalidTransaction`.
+    pub fn sanitize(transaction: &Transaction) -> Result<(), SignerError> {
+        Self::sanitize_parts(
+            &transaction.message.header,
+            &transaction.message.account_keys,
+            &transaction.message.instructions,
+            0,
+            transaction.signatures.len(),
+        )
+    }
+
+    /// Check a versioned transaction for structural problems before it is signed
+    ///
+    /// Same checks as [`Self::sanitize`]. Accounts loaded from lookup tables are
+    /// counted but not resolved; see [`Self::validate_lookups`].
+    pub fn sanitize_versioned(transaction: &VersionedTransaction) -> Result<(), SignerError> {
+        let num_lookup_accounts = transaction
+            .message
+            .address_table_lookups()
+            .unwrap_or_default()
+            .iter()
+            .map(|lookup| lookup.writable_indexes.len() + lookup.readonly_indexes.len())
+            .sum();
+
+        Self::sanitize_parts(
+            transaction.message.header(),
+            transaction.message.static_account_keys(),
+            transaction.message.instructions(),
+            num_lookup_accounts,
+            transaction.signatures.len(),
+        )
+    }
+
+    fn sanitize_parts(
+        header: &MessageHeader,
+        account_keys: &[Pubkey],
+        instructions: &[CompiledInstruction],
+        num_lookup_accounts: usize,
+        num_signatures: usize,
+    ) -> Result<(), SignerError> {
+        let invalid = |reason: String| Err(SignerError::InvalidTransaction(reason));
+        let num_required_signatures = header.num_required_signatures as usize;
+        let num_readonly_signed = header.num_readonly_signed_accounts as usize;
+        let num_readonly_unsigned = header.num_readonly_unsigned_accounts as usize;
+
+        if num_required_signatures == 0 {
+            return invalid("no fee payer: header requires 0 signatures".to_string());
+        }
+        if num_readonly_signed >= num_required_signatures {
+            return invalid(format!(
+                "fee payer is readonly: {num_readonly_signed} of {num_required_signatures} signers are readonly"
+            ));
+        }
+        if num_required_signatures + num_readonly_unsigned > account_keys.len() {
+            return invalid(format!(
+                "header describes {} accounts but the message has {}",
+                num_required_signatures + num_readonly_unsigned,
+                account_keys.len()
+            ));
+        }
+        if num_signatures != 0 && num_signatures != num_required_signatures {
+            return invalid(format!(
+                "{num_signatures} signatures for {num_required_signatures} required signers"
+            ));
+        }
+
+        for (index, key) in account_keys.iter().enumerate() {
+            if account_keys[..index].contains(key) {
+                return invalid(format!("duplicate account key {key}"));
+            }
+        }
+
+        let num_accounts = account_keys.len() + num_lookup_accounts;
+        for (position, instruction) in instructions.iter().enumerate() {
+            let program_index = instruction.program_id_index as usize;
+            // Programs must be static keys, and the fee payer cannot be one
+            if program_index == 0 || program_index >= account_keys.len() {
+                return invalid(format!(
+                    "instruction {position} has invalid program index {program_index}"
+                ));
+            }
+            if let Some(index) = instruction
+                .accounts
+                .iter()
+                .find(|index| **index as usize >= num_accounts)
+            {
+                return invalid(format!(
+                    "instruction {position} references account {index}, message has {num_accounts}"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the required signers whose signature slot is still empty.
+    ///
+    /// A slot is considered empty when it is missing or holds `Signature::default()`.
+    pub fn remaining_required_signers(transaction: &Transaction) -> Vec<Pubkey> {
+        let num_required_signatures = transaction.message.header.num_required_signatures as usize;
+
+        transaction
+            .message
+            .account_keys
+            .iter()
+            .take(num_required_signatures)
+            .enumerate()
+            .filter(|(index, _)| {
+                transaction
+                    .signatures
+                    .get(*index)
+                    .is_none_or(|signature| *signature == Signature::default())
+            })
+            .map(|(_, pubkey)| *pubkey)
+            .collect()
+    }
+
+    /// Check whether every required signature slot is filled.
+    ///
+    /// This only inspects the slots; it does not verify the signatures.
+    pub fn is_fully_signed(transaction: &Transaction) -> bool {
+        Self::remaining_required_signers(transaction).is_empty()
+    }
+
+    /// Get the required signers that none of the `available` signers can satisfy.
+    ///
+    /// Unlike [`Self::remaining_required_signers`], this ignores which slots are
+    /// already filled and reports capability gaps, i.e. external parties that
+    /// must be involved for the transaction to be fully signed.
+    pub fn unmet_signers(transaction: &Transaction, available: &[Pubkey]) -> Vec<Pubkey> {
+        let num_required_signatures = transaction.message.header.num_required_signatures as usize;
+
+        transaction
+            .message
+            .account_keys
+            .iter()
+            .take(num_required_signatures)
+            .filter(|pubkey| !available.contains(pubkey))
+            .copied()
+            .collect()
+    }
+
+    /// Check that every address table lookup in a v0 transaction resolves
+    ///
+    /// Each lookup must reference one of the provided `tables`, and all of its
+    /// writable and readonly indexes must be in range for that table. Otherwise the
+    /// runtime fails to resolve the transaction's accounts after it has been signed.
+    /// Legacy transactions have no lookups and always pass.
+    pub fn validate_lookups(
+        transaction: &VersionedTransaction,
+        tables: &[AddressLookupTableAccount],
+    ) -> Result<(), SignerError> {
+        let Some(lookups) = transaction.message.address_table_lookups() else {
+            return Ok(());
+        };
+
+        for lookup in lookups {
+            let table = tables
+                .iter()
+                .find(|table| table.key == lookup.account_key)
+                .ok_or_else(|| {
+                    SignerError::SigningFailed(format!(
+                        "Address lookup table {} not provided",
+                        lookup.account_key
+                    ))
+                })?;
+
+            let indexes = lookup
+                .writable_indexes
+                .iter()
+                .map(|index| ("writable", index))
+                .chain(
+                    lookup
+                        .readonly_indexes
+                        .iter()
+                        .map(|index| ("readonly", index)),
+                );
+
+            for (kind, &index) in indexes {
+                if index as usize >= table.addresses.len() {
+                    return Err(SignerError::SigningFailed(format!(
+                        "Address lookup table {} {kind} index {index} out of range ({} addresses)",
+                        table.key,
+                        table.addresses.len()
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check every required signature slot against its account key
+    ///
+    /// Returns one `(pubkey, status)` pair per required signer, in signer index
+    /// order. Use it to validate what a remote backend returned before submitting;
+    /// unlike [`Self::verify_batch`], it reports every slot instead of stopping at
+    /// the first failure.
+    pub fn verify_all_signatures(transaction: &Transaction) -> Vec<(Pubkey, SignatureStatus)> {
+        let message = transaction.message_data();
+        let num_required_signatures = transaction.message.header.num_required_signatures as usize;
+
+        transaction
+            .message
+            .account_keys
+            .iter()
+            .take(num_required_signatures)
+            .enumerate()
+            .map(|(index, pubkey)| {
+                let status = match transaction.signatures.get(index) {
+                    None => SignatureStatus::Missing,
+                    Some(signature) if *signature == Signature::default() => {
+                        SignatureStatus::Missing
+                    }
+                    Some(signature) if Self::verify_signature(pubkey, &message, signature) => {
+                        SignatureStatus::Valid
+                    }
+                    Some(_) => SignatureStatus::Invalid,
+                };
+                (*pubkey, status)
+            })
+            .collect()
+    }
+
+    /// Verify an ed25519 signature over a message for the given pubkey
+    pub fn verify_signature(pubkey: &Pubkey, message: &[u8], signature: &Signature) -> bool {
+        signature.verify(&pubkey.to_bytes(), message)
+    }
+
+    /// Verify every required signature of each transaction
+    ///
+    /// Returns one result per transaction, in the same order as `transactions`. A
+    /// transaction fails if any required signer's signature is missing or invalid.
+    /// Signatures are checked one by one; the SDK does not expose ed25519 batch
+    /// verification.
+    pub fn verify_batch(transactions: &[Transaction]) -> Vec<Result<(), SignerError>> {
+        transactions
+            .iter()
+            .map(Self::verify_transaction_signatures)
+            .collect()
+    }
+
+    fn verify_transaction_signatures(transaction: &Transaction) -> Result<(), SignerError> {
+        let message = transaction.message_data();
+        let num_required_signatures = transaction.message.header.num_required_signatures as usize;
+
+        for (index, pubkey) in transaction
+            .message
+            .account_keys
+            .iter()
+            .take(num_required_signatures)
+            .enumerate()
+        {
+            let signature = transaction.signatures.get(index).ok_or_else(|| {
+                SignerError::SigningFailed(format!("Missing signature for {pubkey}"))
+            })?;
+
+            if !Self::verify_signature(pubkey, &message, signature) {
+                return Err(SignerError::SigningFailed(format!(
+                    "Invalid signature for {pubkey} at index {index}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verify an ed25519 signature, additionally rejecting non-canonical encodings.
+    ///
+    /// A signature is `R || S`; it is only canonical when the scalar `S` is reduced
+    /// modulo the group order `L`. Since `[S + L]B == [S]B`, a permissive verifier
+    /// accepts both encodings, which makes signatures malleable.
+    ///
+    /// Note that the Solana runtime already verifies transaction signatures with
+    /// ed25519-dalek's `verify_strict`, which rejects non-canonical `S` as well as
+    /// small-order `R`/`A` points, and so does [`Signature::verify`] in the SDK. This
+    /// helper performs the canonicality check explicitly so callers do not depend on
+    /// the verification rules of a particular dalek version.
+    pub fn verify_signature_strict(pubkey: &Pubkey, message: &[u8], signature: &Signature) -> bool {
+        Self::is_canonical_signature(signature)
+            && Self::verify_signature(pubkey, message, signature)
+    }
+
+    /// Check that the `S` half of a signature is strictly less than the group order
+    fn is_canonical_signature(signature: &Signature) -> bool {
+        let s = &signature.as_ref()[32..];
+
+        for (s_byte, l_byte) in s.iter().zip(ED25519_GROUP_ORDER.iter()).rev() {
+            if s_byte != l_byte {
+                return s_byte < l_byte;
+            }
+        }
+
+        // S == L is not canonical
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::{
+        create_multi_signer_test_transaction, create_test_transaction, create_v0_test_transaction,
+    };
+
+    /// Produce `R || (S + L)`, the non-canonical twin of a valid signature
+    fn make_non_canonical(signature: &Signature) -> Signature {
+        let mut bytes: [u8; 64] = signature.as_ref().try_into().unwrap();
+        let mut carry = 0u16;
+
+        for (byte, l_byte) in bytes[32..].iter_mut().zip(ED25519_GROUP_ORDER.iter()) {
+            let sum = *byte as u16 + *l_byte as u16 + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        }
+
+        Signature::from(bytes)
+    }
+
+    #[test]
+    fn test_add_signature_at_index() {
+        let keypair = Keypair::new();
+        let co_signer = Keypair::new();
+        let mut tx = create_multi_signer_test_transaction(&[
+            keypair_pubkey(&keypair),
+            keypair_pubkey(&co_signer),
+        ]);
+        let signature = keypair_sign_message(&co_signer, &tx.message_data());
+
+        TransactionUtil::add_signature_at_index(&mut tx, 1, signature).unwrap();
+
+        assert_eq!(tx.signatures.len(), 2);
+        assert_eq!(tx.signatures[0], Signature::default());
+        assert_eq!(tx.signatures[1], signature);
+    }
+
+    #[test]
+    fn test_add_signature_at_index_out_of_range() {
+        let keypair = Keypair::new();
+        let mut tx = create_test_transaction(&keypair_pubkey(&keypair));
+        let signature = keypair_sign_message(&keypair, &tx.message_data());
+
+        let result = TransactionUtil::add_signature_at_index(&mut tx, 1, signature);
+
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+    }
+
+    fn create_partially_signed_transaction() -> (Transaction, Signature) {
+        let keypair = Keypair::new();
+        let co_signer = Keypair::new();
+        let mut tx = create_multi_signer_test_transaction(&[
+            keypair_pubkey(&keypair),
+            keypair_pubkey(&co_signer),
+        ]);
+        let signature = keypair_sign_message(&keypair, &tx.message_data());
+        TransactionUtil::add_signature_to_transaction(
+            &mut tx,
+            &keypair_pubkey(&keypair),
+            signature,
+        )
+        .unwrap();
+        (tx, signature)
+    }
+
+    fn decode(serialized: &str) -> Transaction {
+        bincode::deserialize(&STANDARD.decode(serialized).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_serialize_partial_zero_placeholder() {
+        let (tx, signature) = create_partially_signed_transaction();
+
+        let serialized =
+            TransactionUtil::serialize_partial_transaction(&tx, UnsignedSlotEncoding::default())
+                .unwrap();
+        let decoded = decode(&serialized);
+
+        assert_eq!(
+            serialized,
+            TransactionUtil::serialize_transaction(&tx).unwrap()
+        );
+        assert_eq!(decoded.signatures, vec![signature, Signature::default()]);
+        assert_eq!(decoded.message, tx.message);
+    }
+
+    #[test]
+    fn test_serialize_partial_omit_trailing() {
+        let (tx, signature) = create_partially_signed_transaction();
+
+        let serialized =
+            TransactionUtil::serialize_partial_transaction(&tx, UnsignedSlotEncoding::OmitTrailing)
+                .unwrap();
+        let mut decoded = decode(&serialized);
+
+        assert_eq!(decoded.signatures, vec![signature]);
+        assert_eq!(decoded.message, tx.message);
+
+        // The omitted slot is restored as a placeholder once the next party signs
+        let co_signer = decoded.message.account_keys[1];
+        TransactionUtil::add_signature_to_transaction(
+            &mut decoded,
+            &co_signer,
+            Signature::new_unique(),
+        )
+        .unwrap();
+        assert_eq!(decoded.signatures.len(), 2);
+        assert_eq!(decoded.signatures[0], signature);
+    }
+
+    #[test]
+    fn test_unmet_signers() {
+        let signer = keypair_pubkey(&Keypair::new());
+        let fee_payer = keypair_pubkey(&Keypair::new());
+        let external = keypair_pubkey(&Keypair::new());
+        let tx = create_multi_signer_test_transaction(&[fee_payer, signer, external]);
+
+        assert_eq!(
+            TransactionUtil::unmet_signers(&tx, &[fee_payer, signer]),
+            vec![external]
+        );
+        assert!(TransactionUtil::unmet_signers(&tx, &[fee_payer, signer, external]).is_empty());
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let keypair = Keypair::new();
+        let message = b"Hello Solana!";
+        let signature = keypair_sign_message(&keypair, message);
+
+        assert!(TransactionUtil::verify_signature(
+            &keypair_pubkey(&keypair),
+            message,
+            &signature
+        ));
+        assert!(!TransactionUtil::verify_signature(
+            &keypair_pubkey(&keypair),
+            b"tampered",
+            &signature
+        ));
+    }
+
+    #[test]
+    fn test_verify_signature_strict() {
+        let keypair = Keypair::new();
+        let message = b"Hello Solana!";
+        let signature = keypair_sign_message(&keypair, message);
+
+        assert!(TransactionUtil::verify_signature_strict(
+            &keypair_pubkey(&keypair),
+            message,
+            &signature
+        ));
+    }
+
+    #[test]
+    fn test_verify_signature_strict_rejects_non_canonical() {
+        let keypair = Keypair::new();
+        let message = b"Hello Solana!";
+        let signature = keypair_sign_message(&keypair, message);
+        let non_canonical = make_non_canonical(&signature);
+
+        assert!(TransactionUtil::is_canonical_signature(&signature));
+        assert!(!TransactionUtil::is_canonical_signature(&non_canonical));
+        assert!(!TransactionUtil::verify_signature_strict(
+            &keypair_pubkey(&keypair),
+            message,
+            &non_canonical
+        ));
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let mut txs: Vec<Transaction> = (0..32)
+            .map(|_| {
+                let keypair = Keypair::new();
+                let mut tx = create_test_transaction(&keypair_pubkey(&keypair));
+                let signature = keypair_sign_message(&keypair, &tx.message_data());
+                TransactionUtil::add_signature_at_index(&mut tx, 0, signature).unwrap();
+                tx
+            })
+            .collect();
+
+        assert!(TransactionUtil::verify_batch(&txs)
+            .iter()
+            .all(|result| result.is_ok()));
+
+        txs[7].message.recent_blockhash = crate::sdk_adapter::Hash::new_from_array([7u8; 32]);
+        txs[20].signatures[0] = Signature::default();
+
+        let results = TransactionUtil::verify_batch(&txs);
+        assert_eq!(results.len(), txs.len());
+        for (index, result) in results.iter().enumerate() {
+            if index == 7 || index == 20 {
+                assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+            } else {
+                assert!(result.is_ok());
+            }
+        }
+    }
+
+    fn create_lookup_table(num_addresses: usize) -> AddressLookupTableAccount {
+        AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: (0..num_addresses).map(|_| Pubkey::new_unique()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_add_signature_to_versioned_transaction() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let table = create_lookup_table(3);
+        let mut tx = create_v0_test_transaction(&pubkey, &table.key, vec![0], vec![1]);
+        tx.signatures.clear();
+        let signature = keypair_sign_message(&keypair, &tx.message.serialize());
+
+        TransactionUtil::add_signature_to_versioned_transaction(&mut tx, &pubkey, signature)
+            .unwrap();
+        assert_eq!(tx.signatures, vec![signature]);
+
+        let serialized = TransactionUtil::serialize_versioned_transaction(&tx).unwrap();
+        let decoded: VersionedTransaction =
+            bincode::deserialize(&STANDARD.decode(serialized).unwrap()).unwrap();
+        assert_eq!(decoded, tx);
+
+        assert!(TransactionUtil::add_signature_to_versioned_transaction(
+            &mut tx,
+            &Pubkey::new_unique(),
+            signature
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_lookups() {
+        let table = create_lookup_table(3);
+        let tx = create_v0_test_transaction(&Pubkey::new_unique(), &table.key, vec![0, 2], vec![1]);
+
+        assert!(TransactionUtil::validate_lookups(&tx, &[table]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_lookups_writable_index_out_of_range() {
+        let table = create_lookup_table(3);
+        let tx = create_v0_test_transaction(&Pubkey::new_unique(), &table.key, vec![0, 3], vec![]);
+
+        let result = TransactionUtil::validate_lookups(&tx, std::slice::from_ref(&table));
+
+        match result {
+            Err(SignerError::SigningFailed(message)) => {
+                assert!(message.contains(&table.key.to_string()));
+                assert!(message.contains("writable index 3"));
+            }
+            _ => panic!("expected out-of-range lookup to be rejected"),
+        }
+    }
+
+    #[test]
+    fn test_validate_lookups_missing_table() {
+        let table = create_lookup_table(3);
+        let tx = create_v0_test_transaction(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            vec![0],
+            vec![],
+        );
+
+        let result = TransactionUtil::validate_lookups(&tx, &[table]);
+
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+    }
+
+    #[test]
+    fn test_encoding_roundtrip() {
+        let bytes = [0u8, 1, 0x7f, 0xff];
+
+        for encoding in [Encoding::Base64, Encoding::Base58, Encoding::Hex] {
+            assert_eq!(encoding.decode(&encoding.encode(&bytes)).unwrap(), bytes);
+        }
+        assert!(Encoding::Hex.decode("abc").is_err());
+        assert!(Encoding::Hex.decode("zz").is_err());
+        assert!(Encoding::Base58.decode("0OIl").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_legacy_transaction() {
+        let (tx, _) = create_partially_signed_transaction();
+        let encoded = TransactionUtil::serialize_transaction(&tx).unwrap();
+
+        let decoded = TransactionUtil::deserialize_base64(&encoded).unwrap();
+
+        assert_eq!(decoded, DecodedTransaction::Legacy(tx.clone()));
+        assert_eq!(decoded.message_data(), tx.message_data());
+        assert_eq!(decoded.encode(Encoding::Base64).unwrap(), encoded);
+    }
+
+    #[test]
+    fn test_deserialize_versioned_transaction() {
+        let tx = create_v0_test_transaction(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            vec![0],
+            vec![],
+        );
+        let encoded = Encoding::Base58.encode(&bincode::serialize(&tx).unwrap());
+
+        let decoded = TransactionUtil::deserialize_base58(&encoded).unwrap();
+
+        assert_eq!(decoded.signatures(), tx.signatures.as_slice());
+        assert_eq!(decoded.encode(Encoding::Base58).unwrap(), encoded);
+        assert_eq!(decoded.into_versioned(), tx);
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(matches!(
+            TransactionUtil::deserialize_base64("not base64!"),
+            Err(SignerError::SerializationError(_))
+        ));
+        assert!(matches!(
+            TransactionUtil::deserialize_bytes(&[1, 2, 3]),
+            Err(SignerError::SerializationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge_signatures() {
+        let first = Keypair::new();
+        let second = Keypair::new();
+        let unsigned = create_multi_signer_test_transaction(&[
+            keypair_pubkey(&first),
+            keypair_pubkey(&second),
+        ]);
+        let message_data = unsigned.message_data();
+
+        let mut base = unsigned.clone();
+        TransactionUtil::add_signature_at_index(
+            &mut base,
+            0,
+            keypair_sign_message(&first, &message_data),
+        )
+        .unwrap();
+        let mut other = unsigned.clone();
+        TransactionUtil::add_signature_at_index(
+            &mut other,
+            1,
+            keypair_sign_message(&second, &message_data),
+        )
+        .unwrap();
+
+        TransactionUtil::merge_signatures(&mut base, &[other, unsigned]).unwrap();
+
+        assert!(base.verify().is_ok());
+        assert!(TransactionUtil::remaining_required_signers(&base).is_empty());
+    }
+
+    #[test]
+    fn test_merge_signatures_rejects_mismatch() {
+        let (mut base, _) = create_partially_signed_transaction();
+        let original = base.clone();
+
+        let mut different_message = base.clone();
+        different_message.message.recent_blockhash =
+            crate::sdk_adapter::Hash::new_from_array([1; 32]);
+        let result = TransactionUtil::merge_signatures(&mut base, &[different_message]);
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+
+        let mut conflicting = base.clone();
+        conflicting.signatures[0] = Signature::from([7; 64]);
+        let result = TransactionUtil::merge_signatures(&mut base, &[conflicting]);
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+
+        assert_eq!(base, original);
+    }
+
+    #[test]
+    fn test_merge_signatures_rejects_invalid_signature() {
+        let (mut base, _) = create_partially_signed_transaction();
+        let original = base.clone();
+
+        let mut forged = base.clone();
+        forged.signatures[1] = Signature::from([7; 64]);
+        let result = TransactionUtil::merge_signatures(&mut base, &[forged]);
+
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+        assert_eq!(base, original);
+    }
+
+    #[test]
+    fn test_merge_signatures_rejects_missing_account_key() {
+        let (mut base, _) = create_partially_signed_transaction();
+        let num_keys = base.message.account_keys.len();
+        base.message.header.num_required_signatures = num_keys as u8 + 1;
+        base.signatures.resize(num_keys + 1, Signature::default());
+        let original = base.clone();
+
+        let mut other = base.clone();
+        other.signatures[num_keys] = Signature::from([7; 64]);
+        let result = TransactionUtil::merge_signatures(&mut base, &[other]);
+
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+        assert_eq!(base, original);
+    }
+
+    #[test]
+    fn test_is_fully_signed() {
+        let (mut tx, _) = create_partially_signed_transaction();
+        assert!(!TransactionUtil::is_fully_signed(&tx));
+
+        tx.signatures.truncate(1);
+        assert!(!TransactionUtil::is_fully_signed(&tx));
+
+        TransactionUtil::add_signature_at_index(&mut tx, 1, Signature::from([1; 64])).unwrap();
+        assert!(TransactionUtil::is_fully_signed(&tx));
+    }
+
+    #[test]
+    fn test_verify_all_signatures() {
+        let (mut tx, _) = create_partially_signed_transaction();
+        let signer = tx.message.account_keys[0];
+        let co_signer = tx.message.account_keys[1];
+
+        assert_eq!(
+            TransactionUtil::verify_all_signatures(&tx),
+            vec![
+                (signer, SignatureStatus::Valid),
+                (co_signer, SignatureStatus::Missing)
+            ]
+        );
+
+        tx.signatures[1] = Signature::from([1; 64]);
+        assert_eq!(
+            TransactionUtil::verify_all_signatures(&tx)[1],
+            (co_signer, SignatureStatus::Invalid)
+        );
+    }
+
+    #[cfg(feature = "memory")]
+    #[tokio::test]
+    async fn test_refresh_blockhash_and_resign() {
+        use crate::memory::MemorySigner;
+
+        let signer = MemorySigner::new(Keypair::new());
+        let co_signer = Keypair::new();
+        let mut tx =
+            create_multi_signer_test_transaction(&[signer.pubkey(), keypair_pubkey(&co_signer)]);
+        signer.sign_partial_transaction(&mut tx).await.unwrap();
+        let co_signature = keypair_sign_message(&co_signer, &tx.message_data());
+        TransactionUtil::add_signature_at_index(&mut tx, 1, co_signature).unwrap();
+        let new_blockhash = Hash::new_from_array([9; 32]);
+
+        let signed = TransactionUtil::refresh_blockhash_and_resign(&mut tx, new_blockhash, &signer)
+            .await
+            .unwrap();
+
+        assert_eq!(tx.message.recent_blockhash, new_blockhash);
+        assert_eq!(tx.signatures[0], signed.signature);
+        assert_eq!(
+            TransactionUtil::verify_all_signatures(&tx),
+            vec![
+                (signer.pubkey(), SignatureStatus::Valid),
+                (keypair_pubkey(&co_signer), SignatureStatus::Missing)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_compute_budget() {
+        let (mut tx, _) = create_partially_signed_transaction();
+        let num_accounts = tx.message.account_keys.len();
+        let original_instruction = tx.message.instructions[0].clone();
+        let readonly_unsigned = tx.message.header.num_readonly_unsigned_accounts;
+
+        TransactionUtil::with_compute_budget(&mut tx, Some(200_000), Some(5_000)).unwrap();
+
+        assert_eq!(tx.message.account_keys.len(), num_accounts + 1);
+        assert_eq!(
+            tx.message.account_keys[num_accounts],
+            COMPUTE_BUDGET_PROGRAM_ID
+        );
+        assert_eq!(tx.message.instructions.len(), 3);
+        assert_eq!(
+            tx.message.instructions[0].data,
+            [&[2u8][..], &200_000u32.to_le_bytes()].concat()
+        );
+        assert_eq!(
+            tx.message.instructions[1].data,
+            [&[3u8][..], &5_000u64.to_le_bytes()].concat()
+        );
+        assert_eq!(tx.message.instructions[2], original_instruction);
+        assert_eq!(
+            tx.message.header.num_readonly_unsigned_accounts,
+            readonly_unsigned + 1
+        );
+        assert_eq!(TransactionUtil::remaining_required_signers(&tx).len(), 2);
+
+        // Replacing the price keeps the limit and adds no duplicates
+        TransactionUtil::with_compute_budget(&mut tx, None, Some(10_000)).unwrap();
+        assert_eq!(tx.message.instructions.len(), 3);
+        assert_eq!(
+            tx.message.instructions[0].data,
+            [&[3u8][..], &10_000u64.to_le_bytes()].concat()
+        );
+
+        // Re-applying the same budget keeps existing signatures
+        tx.signatures[0] = Signature::from([1; 64]);
+        TransactionUtil::with_compute_budget(&mut tx, None, Some(10_000)).unwrap();
+        assert_eq!(tx.signatures[0], Signature::from([1; 64]));
+    }
+
+    #[test]
+    fn test_check_packet_size() {
+        let mut tx = create_test_transaction(&Pubkey::new_unique());
+        let size = TransactionUtil::serialize_transaction_bytes(&tx)
+            .unwrap()
+            .len();
+
+        assert_eq!(TransactionUtil::check_packet_size(&tx).unwrap(), size);
+
+        // Unfilled slots count as signed
+        tx.signatures.clear();
+        assert_eq!(TransactionUtil::check_packet_size(&tx).unwrap(), size);
+
+        tx.message.instructions[0].data = vec![0; PACKET_DATA_SIZE];
+        assert!(matches!(
+            TransactionUtil::check_packet_size(&tx),
+            Err(SignerError::TransactionTooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_versioned_packet_size() {
+        let mut tx = create_v0_test_transaction(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            vec![0],
+            vec![],
+        );
+        assert!(TransactionUtil::check_versioned_packet_size(&tx).is_ok());
+
+        if let VersionedMessage::V0(message) = &mut tx.message {
+            message.instructions[0].data = vec![0; PACKET_DATA_SIZE];
+        }
+        assert!(matches!(
+            TransactionUtil::check_versioned_packet_size(&tx),
+            Err(SignerError::TransactionTooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn test_sanitize() {
+        let mut tx = create_test_transaction(&Pubkey::new_unique());
+        assert!(TransactionUtil::sanitize(&tx).is_ok());
+
+        tx.signatures.clear();
+        assert!(TransactionUtil::sanitize(&tx).is_ok());
+
+        let cases: [fn(&mut Transaction); 5] = [
+            |tx| tx.message.account_keys[1] = tx.message.account_keys[0],
+            |tx| tx.message.header.num_readonly_signed_accounts = 1,
+            |tx| tx.message.header.num_readonly_unsigned_accounts = 3,
+            |tx| tx.signatures = vec![Signature::default(); 2],
+            |tx| tx.message.instructions[0].accounts.push(9),
+        ];
+        for corrupt in cases {
+            let mut tx = create_test_transaction(&Pubkey::new_unique());
+            corrupt(&mut tx);
+
+            assert!(matches!(
+                TransactionUtil::sanitize(&tx),
+                Err(SignerError::InvalidTransaction(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_sanitize_versioned() {
+        let tx = create_v0_test_transaction(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            vec![0],
+            vec![1],
+        );
+        assert!(TransactionUtil::sanitize_versioned(&tx).is_ok());
+
+        let mut tx = tx;
+        if let VersionedMessage::V0(message) = &mut tx.message {
+            message.instructions[0].accounts.push(4);
+        }
+        assert!(matches!(
+            TransactionUtil::sanitize_versioned(&tx),
+            Err(SignerError::InvalidTransaction(_))
+        ));
+    }
 }
@@ -0,0 +1,619 @@
+//! Trezor hardware wallet signer integration
+//!
+//! Talks to the device through Trezor Bridge (`trezord`), which exposes the USB
+//! transport as a local HTTP API.
+
+mod protocol;
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Default Solana derivation path used by Trezor Suite
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/501'/0'/0'";
+
+/// Default local Trezor Bridge address
+const DEFAULT_BRIDGE_URL: &str = "http://127.0.0.1:21325";
+
+/// Callback returning the PIN to send when the device asks for one
+///
+/// Trezor devices show a scrambled PIN matrix, so the value is the positions the
+/// user picked on that matrix, not the PIN itself.
+pub type PinProvider = Arc<dyn Fn() -> Result<String, SignerError> + Send + Sync>;
+
+#[derive(Deserialize)]
+struct BridgeDevice {
+    path: String,
+}
+
+#[derive(Deserialize)]
+struct BridgeSession {
+    session: String,
+}
+
+/// Trezor-based signer using the device's Solana app through Trezor Bridge
+#[derive(Clone)]
+pub struct TrezorSigner {
+    bridge_url: String,
+    client: reqwest::Client,
+    derivation_path: String,
+    address_n: Vec<u32>,
+    device_path: Option<String>,
+    pin_provider: Option<PinProvider>,
+    public_key: Pubkey,
+}
+
+impl std::fmt::Debug for TrezorSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TrezorSigner")
+            .field("public_key", &self.public_key)
+            .field("derivation_path", &self.derivation_path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TrezorSigner {
+    /// Create a new TrezorSigner (requires initialization)
+    ///
+    /// # Arguments
+    ///
+    /// * `derivation_path` - BIP-32 path of the signing key, e.g. [`DEFAULT_DERIVATION_PATH`]
+    pub fn new(derivation_path: &str) -> Result<Self, SignerError> {
+        Ok(Self {
+            bridge_url: DEFAULT_BRIDGE_URL.to_string(),
+            client: reqwest::Client::new(),
+            derivation_path: derivation_path.to_string(),
+            address_n: protocol::parse_derivation_path(derivation_path)?,
+            device_path: None,
+            pin_provider: None,
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        })
+    }
+
+    /// Use a Trezor Bridge listening somewhere other than `127.0.0.1:21325`
+    pub fn with_bridge_url(mut self, bridge_url: String) -> Self {
+        self.bridge_url = bridge_url;
+        self
+    }
+
+    /// Use the device at this bridge path instead of the first one connected
+    pub fn with_device_path(mut self, device_path: String) -> Self {
+        self.device_path = Some(device_path);
+        self
+    }
+
+    /// Set the callback used to answer the device's PIN requests
+    ///
+    /// Without one, signing on a locked device fails with `SignerError::NotAvailable`.
+    pub fn with_pin_provider(
+        mut self,
+        pin_provider: impl Fn() -> Result<String, SignerError> + Send + Sync + 'static,
+    ) -> Self {
+        self.pin_provider = Some(Arc::new(pin_provider));
+        self
+    }
+
+    /// Initialize the signer by reading the public key at the derivation path
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        let payload = protocol::solana_get_public_key(&self.address_n);
+        let response = self
+            .call(
+                protocol::MESSAGE_SOLANA_GET_PUBLIC_KEY,
+                &payload,
+                protocol::MESSAGE_SOLANA_PUBLIC_KEY,
+            )
+            .await?;
+
+        let public_key = protocol::find_bytes_field(&response, 1)?.ok_or_else(|| {
+            SignerError::InvalidPublicKey("No public key in Trezor response".to_string())
+        })?;
+
+        self.public_key = Pubkey::try_from(public_key)
+            .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid public key: {e}")))?;
+        Ok(())
+    }
+
+    async fn bridge_post(&self, path: &str, body: String) -> Result<String, SignerError> {
+        let url = format!("{}/{}", self.bridge_url, path);
+        let response = self
+            .client
+            .post(&url)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| SignerError::NotAvailable(format!("Trezor Bridge not reachable: {e}")))?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+            #[cfg(feature = "unsafe-debug")]
+            log::error!("Trezor Bridge {path} error - status: {status}, response: {error_text}");
+
+            #[cfg(not(feature = "unsafe-debug"))]
+            log::error!("Trezor Bridge {path} error - status: {status}");
+
+            return Err(SignerError::RemoteApiError(format!(
+                "Trezor Bridge error {status}"
+            )));
+        }
+
+        Ok(response.text().await?)
+    }
+
+    /// List the bridge paths of connected devices
+    async fn enumerate(&self) -> Result<Vec<String>, SignerError> {
+        let body = self.bridge_post("enumerate", String::new()).await?;
+        let devices: Vec<BridgeDevice> = serde_json::from_str(&body)?;
+        Ok(devices.into_iter().map(|device| device.path).collect())
+    }
+
+    /// Pick the configured device, or the first connected one
+    async fn select_device(&self) -> Result<String, SignerError> {
+        let devices = self.enumerate().await?;
+
+        match &self.device_path {
+            Some(path) if devices.contains(path) => Ok(path.clone()),
+            Some(path) => Err(SignerError::NotAvailable(format!(
+                "Trezor device {path} not connected"
+            ))),
+            None => devices
+                .into_iter()
+                .next()
+                .ok_or_else(|| SignerError::NotAvailable("No Trezor device connected".to_string())),
+        }
+    }
+
+    /// Run one request/response exchange on a fresh session
+    ///
+    /// Button and PIN requests raised by the device along the way are answered
+    /// here. Returns the payload of the final `expected` message.
+    async fn call(
+        &self,
+        message_type: u16,
+        payload: &[u8],
+        expected: u16,
+    ) -> Result<Vec<u8>, SignerError> {
+        let device = self.select_device().await?;
+        let body = self
+            .bridge_post(&format!("acquire/{device}/null"), String::new())
+            .await?;
+        let session: BridgeSession = serde_json::from_str(&body)?;
+
+        let result = self
+            .exchange(&session.session, message_type, payload, expected)
+            .await;
+
+        if let Err(e) = self
+            .bridge_post(&format!("release/{}", session.session), String::new())
+            .await
+        {
+            log::warn!("Failed to release Trezor session: {e}");
+        }
+
+        result
+    }
+
+    async fn exchange(
+        &self,
+        session: &str,
+        mut message_type: u16,
+        payload: &[u8],
+        expected: u16,
+    ) -> Result<Vec<u8>, SignerError> {
+        let mut payload = payload.to_vec();
+
+        loop {
+            let request = hex::encode(protocol::encode_message(message_type, &payload));
+            let response = self
+                .bridge_post(&format!("call/{session}"), request)
+                .await?;
+            let framed = hex::decode(response.trim()).map_err(|e| {
+                SignerError::SerializationError(format!("Invalid Trezor Bridge response: {e}"))
+            })?;
+            let (response_type, response_payload) = protocol::decode_message(&framed)?;
+
+            match response_type {
+                t if t == expected => return Ok(response_payload.to_vec()),
+                protocol::MESSAGE_BUTTON_REQUEST => {
+                    message_type = protocol::MESSAGE_BUTTON_ACK;
+                    payload = Vec::new();
+                }
+                protocol::MESSAGE_PIN_MATRIX_REQUEST => {
+                    let pin_provider = self.pin_provider.as_ref().ok_or_else(|| {
+                        SignerError::NotAvailable(
+                            "Trezor is locked and no PIN provider is configured".to_string(),
+                        )
+                    })?;
+                    message_type = protocol::MESSAGE_PIN_MATRIX_ACK;
+                    payload = protocol::pin_matrix_ack(&pin_provider()?);
+                }
+                protocol::MESSAGE_PASSPHRASE_REQUEST => {
+                    return Err(SignerError::ConfigError(
+                        "Trezor passphrase protection is not supported".to_string(),
+                    ));
+                }
+                protocol::MESSAGE_FAILURE => {
+                    let reason = protocol::find_bytes_field(response_payload, 2)?
+                        .map(|message| String::from_utf8_lossy(message).into_owned())
+                        .unwrap_or_else(|| "unknown failure".to_string());
+                    return Err(SignerError::SigningFailed(format!(
+                        "Trezor failure: {reason}"
+                    )));
+                }
+                other => {
+                    return Err(SignerError::RemoteApiError(format!(
+                        "Unexpected Trezor message type {other}"
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Sign message bytes on the device
+    ///
+    /// The Solana app parses the bytes as a transaction message and shows its
+    /// details for confirmation.
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let payload = protocol::solana_sign_tx(&self.address_n, message);
+        let response = self
+            .call(
+                protocol::MESSAGE_SOLANA_SIGN_TX,
+                &payload,
+                protocol::MESSAGE_SOLANA_TX_SIGNATURE,
+            )
+            .await?;
+
+        let signature = protocol::find_bytes_field(&response, 1)?.ok_or_else(|| {
+            SignerError::SigningFailed("No signature in Trezor response".to_string())
+        })?;
+
+        Signature::try_from(signature)
+            .map_err(|_| SignerError::SigningFailed("Invalid signature length".to_string()))
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for TrezorSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        // The Solana app only signs bytes that parse as a transaction message
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Signs one at a time: each request acquires its own device session
+    async fn sign_all_transactions(
+        &self,
+        txs: &mut [Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        crate::batch::sign_sequentially(self, txs).await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.public_key != Pubkey::default() && self.select_device().await.is_ok()
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Hardware
+    }
+
+    /// The device only signs bytes that parse as a transaction message
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities {
+            supports_message_sign: false,
+            ..SignerCapabilities::requiring_init()
+        }
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("trezor", self.public_key, self.latency_class())
+            .with_key_id(&self.derivation_path)
+            .with_endpoint(&self.bridge_url)
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=trezor bridge={} derivation_path={} device={} pubkey={}",
+            self.bridge_url,
+            self.derivation_path,
+            self.device_path.as_deref().unwrap_or("<first>"),
+            self.public_key
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use wiremock::{
+        matchers::{body_string, method, path},
+        Mock, MockBuilder, MockServer, ResponseTemplate,
+    };
+
+    fn bytes_field(field: u8, value: &[u8]) -> Vec<u8> {
+        let mut payload = vec![(field << 3) | 2, value.len() as u8];
+        payload.extend_from_slice(value);
+        payload
+    }
+
+    fn device_response(message_type: u16, payload: &[u8]) -> ResponseTemplate {
+        ResponseTemplate::new(200)
+            .set_body_string(hex::encode(protocol::encode_message(message_type, payload)))
+    }
+
+    async fn mount_session(mock_server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/enumerate"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "path": "1", "session": null, "vendor": 4617, "product": 21441 }
+            ])))
+            .mount(mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/acquire/1/null"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "session": "7"
+            })))
+            .mount(mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/release/7"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(mock_server)
+            .await;
+    }
+
+    fn expect_call(message_type: u16, payload: &[u8]) -> MockBuilder {
+        Mock::given(method("POST"))
+            .and(path("/call/7"))
+            .and(body_string(hex::encode(protocol::encode_message(
+                message_type,
+                payload,
+            ))))
+    }
+
+    async fn mount_public_key(mock_server: &MockServer, signer: &TrezorSigner, pubkey: &Pubkey) {
+        expect_call(
+            protocol::MESSAGE_SOLANA_GET_PUBLIC_KEY,
+            &protocol::solana_get_public_key(&signer.address_n),
+        )
+        .respond_with(device_response(
+            protocol::MESSAGE_SOLANA_PUBLIC_KEY,
+            &bytes_field(1, &pubkey.to_bytes()),
+        ))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+    }
+
+    fn create_test_signer(mock_server: &MockServer) -> TrezorSigner {
+        TrezorSigner::new(DEFAULT_DERIVATION_PATH)
+            .unwrap()
+            .with_bridge_url(mock_server.uri())
+    }
+
+    #[test]
+    fn test_new_invalid_derivation_path() {
+        let result = TrezorSigner::new("44'/501'");
+        assert!(matches!(result, Err(SignerError::ConfigError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_trezor_init() {
+        let mock_server = MockServer::start().await;
+        let pubkey = Pubkey::new_unique();
+        mount_session(&mock_server).await;
+
+        let mut signer = create_test_signer(&mock_server);
+        mount_public_key(&mock_server, &signer, &pubkey).await;
+        assert!(!signer.is_available().await);
+
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert!(signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_trezor_sign_transaction_with_button_confirmation() {
+        let mock_server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        mount_session(&mock_server).await;
+
+        let mut signer = create_test_signer(&mock_server);
+        mount_public_key(&mock_server, &signer, &pubkey).await;
+        signer.init().await.unwrap();
+
+        let mut tx = create_test_transaction(&pubkey);
+        let message = tx.message_data();
+        let signature = keypair_sign_message(&keypair, &message);
+
+        expect_call(
+            protocol::MESSAGE_SOLANA_SIGN_TX,
+            &protocol::solana_sign_tx(&signer.address_n, &message),
+        )
+        .respond_with(device_response(protocol::MESSAGE_BUTTON_REQUEST, &[]))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+        expect_call(protocol::MESSAGE_BUTTON_ACK, &[])
+            .respond_with(device_response(
+                protocol::MESSAGE_SOLANA_TX_SIGNATURE,
+                &bytes_field(1, signature.as_ref()),
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let SignedTransaction {
+            base64_tx: serialized,
+            signature: result,
+            ..
+        } = signer.sign_transaction(&mut tx).await.unwrap();
+
+        assert_eq!(result, signature);
+        assert_eq!(tx.signatures[0], signature);
+        assert!(!serialized.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_trezor_pin_request() {
+        let mock_server = MockServer::start().await;
+        let pubkey = Pubkey::new_unique();
+        mount_session(&mock_server).await;
+
+        let prompts = Arc::new(AtomicUsize::new(0));
+        let counter = prompts.clone();
+        let mut signer = create_test_signer(&mock_server).with_pin_provider(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok("1379".to_string())
+        });
+
+        expect_call(
+            protocol::MESSAGE_SOLANA_GET_PUBLIC_KEY,
+            &protocol::solana_get_public_key(&signer.address_n),
+        )
+        .respond_with(device_response(protocol::MESSAGE_PIN_MATRIX_REQUEST, &[]))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+        expect_call(
+            protocol::MESSAGE_PIN_MATRIX_ACK,
+            &protocol::pin_matrix_ack("1379"),
+        )
+        .respond_with(device_response(
+            protocol::MESSAGE_SOLANA_PUBLIC_KEY,
+            &bytes_field(1, &pubkey.to_bytes()),
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert_eq!(prompts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_trezor_pin_request_without_provider() {
+        let mock_server = MockServer::start().await;
+        mount_session(&mock_server).await;
+
+        let mut signer = create_test_signer(&mock_server);
+
+        expect_call(
+            protocol::MESSAGE_SOLANA_GET_PUBLIC_KEY,
+            &protocol::solana_get_public_key(&signer.address_n),
+        )
+        .respond_with(device_response(protocol::MESSAGE_PIN_MATRIX_REQUEST, &[]))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+        let result = signer.init().await;
+        assert!(matches!(result, Err(SignerError::NotAvailable(_))));
+    }
+
+    #[tokio::test]
+    async fn test_trezor_failure_response() {
+        let mock_server = MockServer::start().await;
+        mount_session(&mock_server).await;
+
+        let signer = create_test_signer(&mock_server);
+
+        expect_call(
+            protocol::MESSAGE_SOLANA_SIGN_TX,
+            &protocol::solana_sign_tx(&signer.address_n, b"test"),
+        )
+        .respond_with(device_response(
+            protocol::MESSAGE_FAILURE,
+            &bytes_field(2, b"Action cancelled by user"),
+        ))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+        let result = signer.sign_message(b"test").await;
+        assert!(matches!(result, Err(SignerError::SigningFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_trezor_device_not_connected() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/enumerate"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = create_test_signer(&mock_server);
+        let result = signer.init().await;
+
+        assert!(matches!(result, Err(SignerError::NotAvailable(_))));
+    }
+
+    #[test]
+    fn test_trezor_latency_class() {
+        let signer = TrezorSigner::new(DEFAULT_DERIVATION_PATH).unwrap();
+        assert_eq!(signer.latency_class(), LatencyClass::Hardware);
+    }
+
+    #[test]
+    fn test_trezor_capabilities() {
+        let capabilities = TrezorSigner::new(DEFAULT_DERIVATION_PATH)
+            .unwrap()
+            .capabilities();
+
+        assert!(!capabilities.supports_message_sign);
+        assert!(capabilities.requires_init);
+    }
+}
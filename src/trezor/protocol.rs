@@ -0,0 +1,207 @@
+//! Minimal Trezor wire protocol: message framing and the protobuf fields we use
+//!
+//! Messages are framed as a 2-byte big-endian message type, a 4-byte big-endian
+//! payload length and the protobuf-encoded payload.
+
+use crate::error::SignerError;
+
+pub const MESSAGE_FAILURE: u16 = 3;
+pub const MESSAGE_PIN_MATRIX_REQUEST: u16 = 18;
+pub const MESSAGE_PIN_MATRIX_ACK: u16 = 19;
+pub const MESSAGE_BUTTON_REQUEST: u16 = 26;
+pub const MESSAGE_BUTTON_ACK: u16 = 27;
+pub const MESSAGE_PASSPHRASE_REQUEST: u16 = 41;
+pub const MESSAGE_SOLANA_GET_PUBLIC_KEY: u16 = 900;
+pub const MESSAGE_SOLANA_PUBLIC_KEY: u16 = 901;
+pub const MESSAGE_SOLANA_SIGN_TX: u16 = 904;
+pub const MESSAGE_SOLANA_TX_SIGNATURE: u16 = 905;
+
+/// Bit set on hardened BIP-32 path components
+pub const HARDENED: u32 = 0x8000_0000;
+
+/// Frame a message for the device
+pub fn encode_message(message_type: u16, payload: &[u8]) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(6 + payload.len());
+    framed.extend_from_slice(&message_type.to_be_bytes());
+    framed.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    framed.extend_from_slice(payload);
+    framed
+}
+
+/// Split a framed device message into its type and payload
+pub fn decode_message(framed: &[u8]) -> Result<(u16, &[u8]), SignerError> {
+    if framed.len() < 6 {
+        return Err(SignerError::SerializationError(
+            "Trezor message too short".to_string(),
+        ));
+    }
+
+    let message_type = u16::from_be_bytes([framed[0], framed[1]]);
+    let length = u32::from_be_bytes([framed[2], framed[3], framed[4], framed[5]]) as usize;
+    let payload = framed
+        .get(6..6 + length)
+        .ok_or_else(|| SignerError::SerializationError("Trezor message truncated".to_string()))?;
+
+    Ok((message_type, payload))
+}
+
+fn encode_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn decode_varint(bytes: &[u8], pos: &mut usize) -> Result<u64, SignerError> {
+    let mut value = 0u64;
+
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes.get(*pos).ok_or_else(|| {
+            SignerError::SerializationError("Truncated protobuf varint".to_string())
+        })?;
+        *pos += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(SignerError::SerializationError(
+        "Invalid protobuf varint".to_string(),
+    ))
+}
+
+fn encode_uint32_field(field: u32, value: u32, out: &mut Vec<u8>) {
+    encode_varint((field << 3) as u64, out);
+    encode_varint(value as u64, out);
+}
+
+fn encode_bytes_field(field: u32, value: &[u8], out: &mut Vec<u8>) {
+    encode_varint(((field << 3) | 2) as u64, out);
+    encode_varint(value.len() as u64, out);
+    out.extend_from_slice(value);
+}
+
+/// Find the first length-delimited field with the given number
+pub fn find_bytes_field(payload: &[u8], field: u32) -> Result<Option<&[u8]>, SignerError> {
+    let mut pos = 0;
+
+    while pos < payload.len() {
+        let key = decode_varint(payload, &mut pos)?;
+        let (number, wire_type) = ((key >> 3) as u32, key & 0x7);
+
+        match wire_type {
+            0 => {
+                decode_varint(payload, &mut pos)?;
+            }
+            1 => pos += 8,
+            2 => {
+                let length = decode_varint(payload, &mut pos)? as usize;
+                let value = payload.get(pos..pos + length).ok_or_else(|| {
+                    SignerError::SerializationError("Truncated protobuf field".to_string())
+                })?;
+                if number == field {
+                    return Ok(Some(value));
+                }
+                pos += length;
+            }
+            5 => pos += 4,
+            _ => {
+                return Err(SignerError::SerializationError(format!(
+                    "Unsupported protobuf wire type {wire_type}"
+                )))
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// `SolanaGetPublicKey { address_n = 1; show_display = 2 }`
+pub fn solana_get_public_key(address_n: &[u32]) -> Vec<u8> {
+    let mut payload = Vec::new();
+    for component in address_n {
+        encode_uint32_field(1, *component, &mut payload);
+    }
+    payload
+}
+
+/// `SolanaSignTx { address_n = 1; serialized_tx = 2 }`
+pub fn solana_sign_tx(address_n: &[u32], serialized_message: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::new();
+    for component in address_n {
+        encode_uint32_field(1, *component, &mut payload);
+    }
+    encode_bytes_field(2, serialized_message, &mut payload);
+    payload
+}
+
+/// `PinMatrixAck { pin = 1 }`
+pub fn pin_matrix_ack(pin: &str) -> Vec<u8> {
+    let mut payload = Vec::new();
+    encode_bytes_field(1, pin.as_bytes(), &mut payload);
+    payload
+}
+
+/// Parse a BIP-32 derivation path such as `m/44'/501'/0'/0'`
+pub fn parse_derivation_path(path: &str) -> Result<Vec<u32>, SignerError> {
+    let invalid = || SignerError::ConfigError(format!("Invalid derivation path: {path}"));
+
+    let mut components = path.split('/');
+    if components.next() != Some("m") {
+        return Err(invalid());
+    }
+
+    components
+        .map(|component| {
+            let (index, hardened) = match component.strip_suffix(['\'', 'h']) {
+                Some(index) => (index, true),
+                None => (component, false),
+            };
+            let index: u32 = index.parse().map_err(|_| invalid())?;
+            if index >= HARDENED {
+                return Err(invalid());
+            }
+            Ok(if hardened { index | HARDENED } else { index })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_derivation_path() {
+        assert_eq!(
+            parse_derivation_path("m/44'/501'/0'/0'").unwrap(),
+            vec![44 | HARDENED, 501 | HARDENED, HARDENED, HARDENED]
+        );
+        assert_eq!(
+            parse_derivation_path("m/44h/501h/2").unwrap(),
+            vec![44 | HARDENED, 501 | HARDENED, 2]
+        );
+        assert!(parse_derivation_path("44'/501'").is_err());
+        assert!(parse_derivation_path("m/abc").is_err());
+    }
+
+    #[test]
+    fn test_message_roundtrip() {
+        let payload = solana_sign_tx(&[44 | HARDENED], b"message");
+        let framed = encode_message(MESSAGE_SOLANA_SIGN_TX, &payload);
+
+        let (message_type, decoded) = decode_message(&framed).unwrap();
+
+        assert_eq!(message_type, MESSAGE_SOLANA_SIGN_TX);
+        assert_eq!(decoded, payload.as_slice());
+        assert_eq!(find_bytes_field(decoded, 2).unwrap(), Some(&b"message"[..]));
+        assert_eq!(find_bytes_field(decoded, 3).unwrap(), None);
+    }
+
+    #[test]
+    fn test_decode_message_truncated() {
+        let framed = encode_message(MESSAGE_SOLANA_PUBLIC_KEY, &[1, 2, 3]);
+        assert!(decode_message(&framed[..7]).is_err());
+    }
+}
@@ -2,14 +2,105 @@
 
 mod types;
 
+use crate::hedging::{hedged_check, Hedging};
+use crate::http_client::{redacted_host, with_deadline, HttpClientConfig, CONTEXT_HEADER};
+use crate::interceptor::{IdentityInterceptor, RequestInterceptor};
 use crate::sdk_adapter::{Pubkey, Signature, Transaction};
 pub use crate::traits::SignedTransaction;
-use crate::{error::SignerError, traits::SolanaSigner, transaction_util::TransactionUtil};
+use crate::traits::{HealthStatus, SignOptions, SignerMetadata};
+use crate::{
+    error::SignerError,
+    traits::SolanaSigner,
+    transaction_util::{Encoding, TransactionUtil},
+};
 use base64::Engine;
 use p256::ecdsa::signature::Signer as P256Signer;
+use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
 use types::{ActivityResponse, SignParameters, SignRequest, WhoAmIRequest};
 
+/// Turnkey API host for the US region (the default)
+pub const TURNKEY_US_API_URL: &str = "https://api.turnkey.com";
+
+/// How long a successful availability probe (and its stamp) is reused by default
+const DEFAULT_AVAILABILITY_CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// Recent availability probe state, shared between clones of a signer
+#[derive(Default)]
+struct AvailabilityCache {
+    last_success: Option<Instant>,
+    stamp: Option<(Instant, String)>,
+}
+
+/// Turnkey regional API endpoint
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TurnkeyRegion {
+    /// `api.turnkey.com`
+    #[default]
+    Us,
+    /// EU-hosted API endpoint
+    ///
+    /// Turnkey does not document a fixed host for it, so its base URL must be set
+    /// with [`TurnkeySigner::with_region_base_url`] or
+    /// [`TurnkeySignerBuilder::region_base_url`].
+    Eu,
+}
+
+impl TurnkeyRegion {
+    /// All known regions
+    pub const ALL: [TurnkeyRegion; 2] = [TurnkeyRegion::Us, TurnkeyRegion::Eu];
+
+    /// Documented base URL of the API for this region, if there is one
+    pub fn base_url(&self) -> Option<&'static str> {
+        match self {
+            TurnkeyRegion::Us => Some(TURNKEY_US_API_URL),
+            TurnkeyRegion::Eu => None,
+        }
+    }
+}
+
+/// Encoding used to send raw payloads to Turnkey's `sign_raw_payload` activity
+///
+/// Turnkey signs the bytes it decodes from the payload, so whichever encoding is
+/// chosen must round-trip the exact message bytes. Turnkey does not offer a base64
+/// payload encoding for raw payloads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TurnkeyPayloadEncoding {
+    /// Hex-encode the payload (`PAYLOAD_ENCODING_HEXADECIMAL`). Works for any bytes.
+    #[default]
+    Hexadecimal,
+    /// Send the payload as-is (`PAYLOAD_ENCODING_TEXT_UTF8`). Only valid for messages
+    /// that are UTF-8 text, such as human-readable off-chain messages.
+    TextUtf8,
+}
+
+impl TurnkeyPayloadEncoding {
+    /// Encode the message for the `payload` field, checking that it round-trips
+    fn encode(&self, message: &[u8]) -> Result<String, SignerError> {
+        match self {
+            TurnkeyPayloadEncoding::Hexadecimal => Ok(hex::encode(message)),
+            TurnkeyPayloadEncoding::TextUtf8 => std::str::from_utf8(message)
+                .map(str::to_string)
+                .map_err(|_| {
+                    SignerError::SerializationError(
+                        "Message is not valid UTF-8 and cannot be sent as text payload".to_string(),
+                    )
+                }),
+        }
+    }
+
+    /// The value of the `encoding` field Turnkey expects
+    fn as_str(&self) -> &'static str {
+        match self {
+            TurnkeyPayloadEncoding::Hexadecimal => "PAYLOAD_ENCODING_HEXADECIMAL",
+            TurnkeyPayloadEncoding::TextUtf8 => "PAYLOAD_ENCODING_TEXT_UTF8",
+        }
+    }
+}
+
 /// Turnkey-based signer using Turnkey's API
 #[derive(Clone)]
 pub struct TurnkeySigner {
@@ -20,6 +111,14 @@ pub struct TurnkeySigner {
     public_key: Pubkey,
     api_base_url: String,
     client: reqwest::Client,
+    interceptor: Arc<dyn RequestInterceptor>,
+    output_encoding: Encoding,
+    payload_encoding: TurnkeyPayloadEncoding,
+    hedging: Option<Hedging>,
+    region_base_urls: Vec<(TurnkeyRegion, String)>,
+    http_config: HttpClientConfig,
+    availability_cache: Arc<Mutex<AvailabilityCache>>,
+    availability_cache_ttl: Duration,
 }
 
 impl std::fmt::Debug for TurnkeySigner {
@@ -56,14 +155,157 @@ impl TurnkeySigner {
             organization_id,
             private_key_id,
             public_key: pubkey,
-            api_base_url: "https://api.turnkey.com".to_string(),
+            api_base_url: TURNKEY_US_API_URL.to_string(),
             client: reqwest::Client::new(),
+            interceptor: Arc::new(IdentityInterceptor),
+            output_encoding: Encoding::default(),
+            payload_encoding: TurnkeyPayloadEncoding::default(),
+            hedging: None,
+            region_base_urls: Vec::new(),
+            http_config: HttpClientConfig::default(),
+            availability_cache: Arc::new(Mutex::new(AvailabilityCache::default())),
+            availability_cache_ttl: DEFAULT_AVAILABILITY_CACHE_TTL,
         })
     }
 
+    /// Start building a Turnkey signer with named settings
+    pub fn builder() -> TurnkeySignerBuilder {
+        TurnkeySignerBuilder::default()
+    }
+
+    /// Set an interceptor applied to every outbound request
+    pub fn with_request_interceptor(
+        mut self,
+        interceptor: impl RequestInterceptor + 'static,
+    ) -> Self {
+        self.interceptor = Arc::new(interceptor);
+        self
+    }
+
+    /// Set the encoding used for serialized transactions returned by this signer
+    pub fn with_output_encoding(mut self, encoding: Encoding) -> Self {
+        self.output_encoding = encoding;
+        self
+    }
+
+    /// Send requests to the API endpoint of the given region
+    ///
+    /// Fails with `SignerError::ConfigError` for a region that has no documented
+    /// host and no base URL set with [`Self::with_region_base_url`].
+    pub fn with_region(mut self, region: TurnkeyRegion) -> Result<Self, SignerError> {
+        self.api_base_url = self.region_base_url(region)?;
+        Ok(self)
+    }
+
+    /// Use `base_url` for `region` whenever it is selected, by [`Self::with_region`]
+    /// or [`Self::with_fastest_region`]
+    pub fn with_region_base_url(mut self, region: TurnkeyRegion, base_url: String) -> Self {
+        self.region_base_urls.retain(|(r, _)| *r != region);
+        self.region_base_urls.push((region, base_url));
+        self
+    }
+
+    fn region_base_url(&self, region: TurnkeyRegion) -> Result<String, SignerError> {
+        self.region_base_urls
+            .iter()
+            .find(|(r, _)| *r == region)
+            .map(|(_, url)| url.clone())
+            .or_else(|| region.base_url().map(str::to_string))
+            .ok_or_else(|| {
+                SignerError::ConfigError(format!(
+                    "No base URL for Turnkey region {region:?}; set one with with_region_base_url"
+                ))
+            })
+    }
+
+    /// Probe the given regions and use the first one to answer `whoami` successfully
+    ///
+    /// All regions are probed concurrently, so the first successful response is
+    /// also the lowest-latency one. If no region answers, the current endpoint is
+    /// kept. Regions without a base URL are skipped. This makes one authenticated
+    /// request per region, so it is opt-in.
+    pub async fn with_fastest_region(self, regions: &[TurnkeyRegion]) -> Self {
+        let candidates = regions
+            .iter()
+            .filter_map(|region| self.region_base_url(*region).ok())
+            .collect();
+        self.with_fastest_base_url(candidates).await
+    }
+
+    async fn with_fastest_base_url(mut self, candidates: Vec<String>) -> Self {
+        if let Some(base_url) = self.fastest_base_url(candidates).await {
+            self.api_base_url = base_url;
+        }
+        self
+    }
+
+    async fn fastest_base_url(&self, candidates: Vec<String>) -> Option<String> {
+        let probes = candidates.into_iter().map(|base_url| {
+            Box::pin(async move {
+                if self.check_availability(base_url.clone()).await {
+                    Ok(base_url)
+                } else {
+                    Err(())
+                }
+            })
+        });
+
+        futures::future::select_ok(probes)
+            .await
+            .ok()
+            .map(|(base_url, _)| base_url)
+    }
+
+    /// Set the encoding used to send payloads to Turnkey
+    pub fn with_payload_encoding(mut self, encoding: TurnkeyPayloadEncoding) -> Self {
+        self.payload_encoding = encoding;
+        self
+    }
+
+    /// Resolve `domain` to a pinned address instead of using system DNS
+    ///
+    /// The port of the request URL is used when present, so include it in the base
+    /// URL when pointing at a non-standard port. Fails with
+    /// `SignerError::ConfigError` if the signer was built with the builder's
+    /// `client`; set the override on that client instead.
+    pub fn with_resolve(mut self, domain: &str, addr: SocketAddr) -> Result<Self, SignerError> {
+        self.http_config.resolve.push((domain.to_string(), addr));
+        self.client = self.http_config.rebuild("with_resolve")?;
+        Ok(self)
+    }
+
+    /// Hedge availability checks against a secondary Turnkey endpoint
+    ///
+    /// If the primary endpoint has not answered after `delay`, the same `whoami`
+    /// check is sent to `secondary_base_url` and the first successful response wins.
+    /// Signing requests are never hedged, to avoid creating duplicate activities.
+    pub fn with_hedging(mut self, delay: Duration, secondary_base_url: String) -> Self {
+        self.hedging = Some(Hedging {
+            delay,
+            secondary_base_url,
+        });
+        self
+    }
+
+    /// Set how long a successful `is_available` probe is reused
+    ///
+    /// Within this window `is_available` returns `true` without a network call, and
+    /// probes reuse the same `whoami` stamp instead of computing a new P-256
+    /// signature. Failed probes are never cached. Defaults to 5 seconds; pass
+    /// `Duration::ZERO` to probe on every call. Signing requests are always stamped
+    /// individually.
+    pub fn with_availability_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.availability_cache_ttl = ttl;
+        self
+    }
+
     /// Sign message bytes using Turnkey API and return just the signature
-    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
-        let hex_message = hex::encode(message);
+    async fn sign_bytes(
+        &self,
+        message: &[u8],
+        options: &SignOptions,
+    ) -> Result<Signature, SignerError> {
+        let payload = self.payload_encoding.encode(message)?;
 
         let request = SignRequest {
             activity_type: "ACTIVITY_TYPE_SIGN_RAW_PAYLOAD_V2".to_string(),
@@ -71,8 +313,8 @@ impl TurnkeySigner {
             organization_id: self.organization_id.clone(),
             parameters: SignParameters {
                 sign_with: self.private_key_id.clone(),
-                payload: hex_message,
-                encoding: "PAYLOAD_ENCODING_HEXADECIMAL".to_string(),
+                payload,
+                encoding: self.payload_encoding.as_str().to_string(),
                 hash_function: "HASH_FUNCTION_NOT_APPLICABLE".to_string(),
             },
         };
@@ -81,14 +323,17 @@ impl TurnkeySigner {
         let stamp = self.create_stamp(&body)?;
 
         let url = format!("{}/public/v1/submit/sign_raw_payload", self.api_base_url);
-        let response = self
+        let mut request = self
             .client
             .post(&url)
             .header("Content-Type", "application/json")
             .header("X-Stamp", stamp)
-            .body(body)
-            .send()
-            .await?;
+            .body(body);
+        if let Some(context) = &options.context {
+            request = request.header(CONTEXT_HEADER, context);
+        }
+
+        let response = self.interceptor.intercept(request).send().await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -155,19 +400,84 @@ impl TurnkeySigner {
     async fn sign_and_serialize(
         &self,
         transaction: &mut Transaction,
+        options: &SignOptions,
     ) -> Result<SignedTransaction, SignerError> {
-        let signature = self.sign_bytes(&transaction.message_data()).await?;
+        let signature = self
+            .sign_bytes(&transaction.message_data(), options)
+            .await?;
 
         TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
 
-        Ok((
-            TransactionUtil::serialize_transaction(transaction)?,
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction_with_encoding(
+                transaction,
+                self.output_encoding,
+            )?,
             signature,
-        ))
+            self.public_key,
+        )
+        .with_encoding(self.output_encoding))
     }
 
-    /// Create X-Stamp header for Turnkey API authentication
-    fn create_stamp(&self, message: &str) -> Result<String, SignerError> {
+    /// Like [`Self::sign_and_serialize`], but for one signer among several
+    ///
+    /// Fails before creating an activity if the key is not a required signer,
+    /// and skips the activity when its slot already holds a valid signature.
+    async fn sign_partial_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        match TransactionUtil::existing_signature(transaction, &self.public_key)? {
+            Some(signature) => Ok(SignedTransaction::new(
+                TransactionUtil::serialize_transaction_with_encoding(
+                    transaction,
+                    self.output_encoding,
+                )?,
+                signature,
+                self.public_key,
+            )
+            .with_encoding(self.output_encoding)),
+            None => {
+                self.sign_and_serialize(transaction, &SignOptions::default())
+                    .await
+            }
+        }
+    }
+
+    /// Sign a transaction, aborting the in-flight request if `token` is cancelled
+    ///
+    /// Returns `SignerError::Other("cancelled")` on cancellation.
+    pub async fn sign_transaction_cancellable(
+        &self,
+        tx: &mut Transaction,
+        token: CancellationToken,
+    ) -> Result<SignedTransaction, SignerError> {
+        let options = SignOptions::default();
+
+        tokio::select! {
+            _ = token.cancelled() => Err(SignerError::Other("cancelled".to_string())),
+            result = self.sign_and_serialize(tx, &options) => result,
+        }
+    }
+
+    /// Sign a transaction within the time remaining until `deadline`
+    ///
+    /// Returns `SignerError::Timeout` immediately if `deadline` has already passed,
+    /// or once it passes while the request is in flight.
+    pub async fn sign_transaction_by_deadline(
+        &self,
+        tx: &mut Transaction,
+        deadline: Instant,
+    ) -> Result<SignedTransaction, SignerError> {
+        with_deadline(
+            deadline,
+            self.sign_and_serialize(tx, &SignOptions::default()),
+        )
+        .await
+    }
+
+    /// Parse the hex-encoded API private key into a P256 signing key
+    fn api_signing_key(&self) -> Result<p256::ecdsa::SigningKey, SignerError> {
         let private_key_bytes = hex::decode(&self.api_private_key).map_err(|e| {
             SignerError::InvalidPrivateKey(format!("Failed to decode private key: {e}"))
         })?;
@@ -176,8 +486,13 @@ impl TurnkeySigner {
             SignerError::InvalidPrivateKey("Invalid private key length".to_string())
         })?;
 
-        let signing_key = p256::ecdsa::SigningKey::from_slice(&private_key_array)
-            .map_err(|e| SignerError::InvalidPrivateKey(format!("Invalid signing key: {e}")))?;
+        p256::ecdsa::SigningKey::from_slice(&private_key_array)
+            .map_err(|e| SignerError::InvalidPrivateKey(format!("Invalid signing key: {e}")))
+    }
+
+    /// Create X-Stamp header for Turnkey API authentication
+    fn create_stamp(&self, message: &str) -> Result<String, SignerError> {
+        let signing_key = self.api_signing_key()?;
 
         let signature: p256::ecdsa::Signature = signing_key.sign(message.as_bytes());
         let signature_der = signature.to_der().to_bytes();
@@ -194,39 +509,253 @@ impl TurnkeySigner {
         Ok(base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json_stamp.as_bytes()))
     }
 
+    /// Stamp for the `whoami` probe, reused within the availability cache window
+    ///
+    /// The probe body only contains the organization ID, so the stamp is the same
+    /// for every probe and does not need to be recomputed.
+    fn probe_stamp(&self, body: &str) -> Result<String, SignerError> {
+        let ttl = self.availability_cache_ttl;
+        if ttl.is_zero() {
+            return self.create_stamp(body);
+        }
+
+        if let Ok(cache) = self.availability_cache.lock() {
+            if let Some((created_at, stamp)) = &cache.stamp {
+                if created_at.elapsed() < ttl {
+                    return Ok(stamp.clone());
+                }
+            }
+        }
+
+        let stamp = self.create_stamp(body)?;
+        if let Ok(mut cache) = self.availability_cache.lock() {
+            cache.stamp = Some((Instant::now(), stamp.clone()));
+        }
+
+        Ok(stamp)
+    }
+
+    /// Whether a successful availability probe happened within the cache window
+    fn recently_available(&self) -> bool {
+        self.availability_cache
+            .lock()
+            .ok()
+            .and_then(|cache| cache.last_success)
+            .is_some_and(|at| at.elapsed() < self.availability_cache_ttl)
+    }
+
     /// Check if Turnkey API is available and credentials are valid
-    async fn check_availability(&self) -> bool {
+    async fn check_availability(&self, api_base_url: String) -> bool {
+        self.whoami(&api_base_url).await.is_ok()
+    }
+
+    /// Call `whoami` on `api_base_url`, returning why it failed
+    async fn whoami(&self, api_base_url: &str) -> Result<(), String> {
         let request = WhoAmIRequest {
             organization_id: self.organization_id.clone(),
         };
 
-        let body = match serde_json::to_string(&request) {
-            Ok(b) => b,
-            Err(_) => return false,
-        };
-
-        let stamp = match self.create_stamp(&body) {
-            Ok(s) => s,
-            Err(_) => return false,
-        };
+        let body = serde_json::to_string(&request)
+            .map_err(|_| "Failed to serialize whoami request".to_string())?;
+        let stamp = self
+            .probe_stamp(&body)
+            .map_err(|e| format!("Failed to stamp whoami request: {e:?}"))?;
 
-        let url = format!("{}/public/v1/query/whoami", self.api_base_url);
-        let response = self
+        let url = format!("{}/public/v1/query/whoami", api_base_url);
+        let request = self
             .client
             .post(&url)
             .header("Content-Type", "application/json")
             .header("X-Stamp", stamp)
-            .body(body)
-            .send()
-            .await;
+            .body(body);
+
+        match self.interceptor.intercept(request).send().await {
+            Ok(resp) if resp.status().is_success() => Ok(()),
+            Ok(resp) => Err(format!(
+                "Turnkey whoami failed with status {}",
+                resp.status()
+            )),
+            Err(e) => Err(format!("Turnkey unreachable: {e}")),
+        }
+    }
+}
+
+/// Builder for [`TurnkeySigner`], created with [`TurnkeySigner::builder`]
+///
+/// `api_public_key`, `api_private_key`, `organization_id`, `private_key_id` and
+/// `public_key` are required.
+#[derive(Default)]
+pub struct TurnkeySignerBuilder {
+    api_public_key: Option<String>,
+    api_private_key: Option<String>,
+    organization_id: Option<String>,
+    private_key_id: Option<String>,
+    public_key: Option<String>,
+    api_base_url: Option<String>,
+    region: Option<TurnkeyRegion>,
+    region_base_urls: Vec<(TurnkeyRegion, String)>,
+    output_encoding: Encoding,
+    payload_encoding: TurnkeyPayloadEncoding,
+    interceptor: Option<Arc<dyn RequestInterceptor>>,
+    http_config: HttpClientConfig,
+    client: Option<reqwest::Client>,
+    hedging: Option<Hedging>,
+    availability_cache_ttl: Option<Duration>,
+}
+
+impl TurnkeySignerBuilder {
+    /// Turnkey API public key
+    pub fn api_public_key(mut self, api_public_key: impl Into<String>) -> Self {
+        self.api_public_key = Some(api_public_key.into());
+        self
+    }
+
+    /// Turnkey API private key (hex-encoded)
+    pub fn api_private_key(mut self, api_private_key: impl Into<String>) -> Self {
+        self.api_private_key = Some(api_private_key.into());
+        self
+    }
+
+    /// Turnkey organization ID
+    pub fn organization_id(mut self, organization_id: impl Into<String>) -> Self {
+        self.organization_id = Some(organization_id.into());
+        self
+    }
+
+    /// Turnkey private key ID
+    pub fn private_key_id(mut self, private_key_id: impl Into<String>) -> Self {
+        self.private_key_id = Some(private_key_id.into());
+        self
+    }
+
+    /// Solana public key (base58-encoded)
+    pub fn public_key(mut self, public_key: impl Into<String>) -> Self {
+        self.public_key = Some(public_key.into());
+        self
+    }
+
+    /// API base URL, the default region's endpoint by default
+    ///
+    /// Takes precedence over [`Self::region`].
+    pub fn api_base_url(mut self, api_base_url: impl Into<String>) -> Self {
+        self.api_base_url = Some(api_base_url.into());
+        self
+    }
+
+    /// Use the API endpoint of `region`, resolved like [`TurnkeySigner::with_region`]
+    pub fn region(mut self, region: TurnkeyRegion) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Base URL to use for `region`, see [`TurnkeySigner::with_region_base_url`]
+    pub fn region_base_url(mut self, region: TurnkeyRegion, base_url: impl Into<String>) -> Self {
+        self.region_base_urls.retain(|(r, _)| *r != region);
+        self.region_base_urls.push((region, base_url.into()));
+        self
+    }
+
+    /// Hedge availability checks against a secondary endpoint, see
+    /// [`TurnkeySigner::with_hedging`]
+    pub fn hedging(mut self, delay: Duration, secondary_base_url: impl Into<String>) -> Self {
+        self.hedging = Some(Hedging {
+            delay,
+            secondary_base_url: secondary_base_url.into(),
+        });
+        self
+    }
+
+    /// How long a successful availability probe is reused, see
+    /// [`TurnkeySigner::with_availability_cache_ttl`]
+    pub fn availability_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.availability_cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Encoding of the payload sent to Turnkey for signing
+    pub fn payload_encoding(mut self, encoding: TurnkeyPayloadEncoding) -> Self {
+        self.payload_encoding = encoding;
+        self
+    }
+
+    /// Encoding of serialized transactions returned by the signer
+    pub fn output_encoding(mut self, encoding: Encoding) -> Self {
+        self.output_encoding = encoding;
+        self
+    }
+
+    /// Interceptor applied to every outbound request
+    pub fn request_interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.interceptor = Some(Arc::new(interceptor));
+        self
+    }
+
+    /// Total timeout for each request to the Turnkey API
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = Some(timeout);
+        self
+    }
+
+    /// Trust an extra CA certificate for the API's TLS certificate
+    pub fn root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.http_config.root_certificates.push(certificate);
+        self
+    }
+
+    /// Use a preconfigured HTTP client
+    ///
+    /// Takes precedence over [`Self::timeout`] and [`Self::root_certificate`], and
+    /// makes [`TurnkeySigner::with_resolve`] fail instead of replacing this client.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self.http_config.custom_client = true;
+        self
+    }
 
-        match response {
-            Ok(resp) => resp.status().is_success(),
-            Err(_) => false,
+    /// Build the signer, failing with `SignerError::ConfigError` if a required
+    /// setting is missing
+    pub fn build(self) -> Result<TurnkeySigner, SignerError> {
+        let mut signer = TurnkeySigner::new(
+            required(self.api_public_key, "api_public_key")?,
+            required(self.api_private_key, "api_private_key")?,
+            required(self.organization_id, "organization_id")?,
+            required(self.private_key_id, "private_key_id")?,
+            required(self.public_key, "public_key")?,
+        )?;
+
+        for (region, base_url) in self.region_base_urls {
+            signer = signer.with_region_base_url(region, base_url);
+        }
+        match (self.api_base_url, self.region) {
+            (Some(api_base_url), _) => signer.api_base_url = api_base_url,
+            (None, Some(region)) => signer = signer.with_region(region)?,
+            (None, None) => {}
+        }
+        signer.hedging = self.hedging;
+        if let Some(ttl) = self.availability_cache_ttl {
+            signer.availability_cache_ttl = ttl;
+        }
+        signer.client = match self.client {
+            Some(client) => client,
+            None => self.http_config.build()?,
+        };
+        signer.http_config = self.http_config;
+        signer.output_encoding = self.output_encoding;
+        signer.payload_encoding = self.payload_encoding;
+        if let Some(interceptor) = self.interceptor {
+            signer.interceptor = interceptor;
         }
+
+        Ok(signer)
     }
 }
 
+fn required(value: Option<String>, name: &str) -> Result<String, SignerError> {
+    value.ok_or_else(|| {
+        SignerError::ConfigError(format!("TurnkeySignerBuilder: {name} is required"))
+    })
+}
+
 #[async_trait::async_trait]
 impl SolanaSigner for TurnkeySigner {
     fn pubkey(&self) -> Pubkey {
@@ -237,23 +766,94 @@ impl SolanaSigner for TurnkeySigner {
         &self,
         tx: &mut Transaction,
     ) -> Result<SignedTransaction, SignerError> {
-        self.sign_and_serialize(tx).await
+        self.sign_and_serialize(tx, &SignOptions::default()).await
     }
 
     async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
-        self.sign_bytes(message).await
+        self.sign_bytes(message, &SignOptions::default()).await
     }
 
     async fn sign_partial_transaction(
         &self,
         tx: &mut Transaction,
     ) -> Result<SignedTransaction, SignerError> {
-        self.sign_and_serialize(tx).await
+        self.sign_partial_and_serialize(tx).await
+    }
+
+    async fn sign_transaction_with(
+        &self,
+        tx: &mut Transaction,
+        options: SignOptions,
+    ) -> Result<SignedTransaction, SignerError> {
+        options
+            .within_deadline(self.sign_and_serialize(tx, &options))
+            .await
     }
 
     async fn is_available(&self) -> bool {
+        if self.recently_available() {
+            return true;
+        }
+
         // Verify Turnkey API is reachable and credentials are valid
-        self.check_availability().await
+        let available = hedged_check(self.hedging.as_ref(), &self.api_base_url, |api_base_url| {
+            self.check_availability(api_base_url)
+        })
+        .await;
+
+        if available {
+            if let Ok(mut cache) = self.availability_cache.lock() {
+                cache.last_success = Some(Instant::now());
+            }
+        }
+
+        available
+    }
+
+    /// Calls `whoami` on the primary endpoint, reporting `Degraded` when only the
+    /// hedging secondary answers
+    async fn health(&self) -> HealthStatus {
+        let start = Instant::now();
+
+        let result = match (self.whoami(&self.api_base_url).await, &self.hedging) {
+            (Err(reason), Some(hedging)) => match self.whoami(&hedging.secondary_base_url).await {
+                Ok(()) => Ok(Some(format!(
+                    "{reason}; secondary {} is usable",
+                    hedging.secondary_base_url
+                ))),
+                Err(_) => Err(reason),
+            },
+            (result, _) => result.map(|()| None),
+        };
+
+        match result {
+            Ok(None) => HealthStatus::healthy(start.elapsed()),
+            Ok(Some(reason)) => HealthStatus::degraded(reason, start.elapsed()),
+            Err(reason) => HealthStatus::unavailable(reason, start.elapsed()),
+        }
+    }
+
+    fn output_encoding(&self) -> Encoding {
+        self.output_encoding
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("turnkey", self.public_key, self.latency_class())
+            .with_key_id(&self.private_key_id)
+            .with_endpoint(&self.api_base_url)
+    }
+
+    fn config_debug(&self) -> String {
+        let host = redacted_host(&self.api_base_url);
+
+        format!(
+            "backend=turnkey host={host} organization_id={} private_key_id={} api_public_key={} api_private_key=[REDACTED] pubkey={}",
+            self.organization_id, self.private_key_id, self.api_public_key, self.public_key
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        self.api_signing_key().map(|_| ())
     }
 }
 
@@ -262,8 +862,9 @@ mod tests {
     use super::*;
     use crate::sdk_adapter::{keypair_pubkey, Keypair, Signer};
     use crate::test_util::create_test_transaction;
+    use crate::traits::HealthState;
     use wiremock::{
-        matchers::{header, method, path},
+        matchers::{body_partial_json, header, method, path},
         Mock, MockServer, ResponseTemplate,
     };
 
@@ -429,7 +1030,11 @@ mod tests {
 
         let result = signer.sign_transaction(&mut tx).await;
         assert!(result.is_ok());
-        let (serialized_tx, returned_sig) = result.unwrap();
+        let SignedTransaction {
+            base64_tx: serialized_tx,
+            signature: returned_sig,
+            ..
+        } = result.unwrap();
 
         // Verify the signature matches
         assert_eq!(returned_sig, signature);
@@ -603,6 +1208,73 @@ mod tests {
         assert!(!signer.is_available().await);
     }
 
+    #[tokio::test]
+    async fn test_turnkey_health() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap();
+        signer.api_base_url = mock_server.uri();
+
+        let health = signer.health().await;
+        assert_eq!(health.state, HealthState::Unavailable);
+        assert_eq!(
+            health.reason.as_deref(),
+            Some("Turnkey whoami failed with status 401 Unauthorized")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_health_degraded_to_secondary() {
+        let primary = MockServer::start().await;
+        let secondary = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&primary)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&secondary)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap()
+        .with_hedging(Duration::from_millis(50), secondary.uri());
+        signer.api_base_url = primary.uri();
+
+        let health = signer.health().await;
+        assert_eq!(health.state, HealthState::Degraded);
+        assert!(health.reason.unwrap().contains("secondary"));
+    }
+
     #[tokio::test]
     async fn test_turnkey_create_stamp() {
         let (api_public_key, api_private_key) = create_test_api_keys();
@@ -674,4 +1346,652 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), SignerError::SigningFailed(_)));
     }
+
+    #[tokio::test]
+    async fn test_turnkey_request_interceptor_adds_header() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .and(header("X-Proxy-Envelope", "wrapped"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "organizationId": "test-org-id"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap()
+        .with_request_interceptor(|req: reqwest::RequestBuilder| {
+            req.header("X-Proxy-Envelope", "wrapped")
+        });
+        signer.api_base_url = mock_server.uri();
+
+        assert!(signer.is_available().await);
+    }
+
+    #[test]
+    fn test_builder() {
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        let signer = TurnkeySigner::builder()
+            .api_public_key(api_public_key)
+            .api_private_key(api_private_key)
+            .organization_id("test-org-id")
+            .private_key_id("test-key-id")
+            .public_key(keypair.pubkey().to_string())
+            .region_base_url(TurnkeyRegion::Eu, "https://eu.example.com")
+            .region(TurnkeyRegion::Eu)
+            .hedging(Duration::from_millis(50), "https://secondary.example.com")
+            .availability_cache_ttl(Duration::ZERO)
+            .build()
+            .unwrap();
+
+        assert_eq!(signer.api_base_url, "https://eu.example.com");
+        assert_eq!(signer.pubkey(), keypair.pubkey());
+        assert_eq!(
+            signer
+                .hedging
+                .as_ref()
+                .map(|h| h.secondary_base_url.as_str()),
+            Some("https://secondary.example.com")
+        );
+        assert_eq!(signer.availability_cache_ttl, Duration::ZERO);
+
+        let result = TurnkeySigner::builder()
+            .api_public_key("test-api-public-key")
+            .api_private_key("test-api-private-key")
+            .organization_id("test-org-id")
+            .private_key_id("test-key-id")
+            .public_key(keypair.pubkey().to_string())
+            .region(TurnkeyRegion::Eu)
+            .build();
+        assert!(matches!(result, Err(SignerError::ConfigError(_))));
+
+        let result = TurnkeySigner::builder()
+            .api_public_key("test-api-public-key")
+            .build();
+        assert!(matches!(result, Err(SignerError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_turnkey_validate_config() {
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        let signer = TurnkeySigner::new(
+            api_public_key.clone(),
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap();
+        assert!(signer.validate_config().is_ok());
+
+        let signer = TurnkeySigner::new(
+            api_public_key,
+            "not-hex".to_string(),
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap();
+        assert!(matches!(
+            signer.validate_config(),
+            Err(SignerError::InvalidPrivateKey(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_sign_message_text_payload() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        let message = "Sign in to example.com";
+        let signature = keypair.sign_message(message.as_bytes());
+        let sig_bytes = signature.as_ref();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/submit/sign_raw_payload"))
+            .and(body_partial_json(serde_json::json!({
+                "parameters": {
+                    "payload": message,
+                    "encoding": "PAYLOAD_ENCODING_TEXT_UTF8"
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "activity": {
+                    "result": {
+                        "signRawPayloadResult": {
+                            "r": hex::encode(&sig_bytes[0..32]),
+                            "s": hex::encode(&sig_bytes[32..64])
+                        }
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap()
+        .with_payload_encoding(TurnkeyPayloadEncoding::TextUtf8);
+        signer.api_base_url = mock_server.uri();
+
+        let result = signer.sign_message(message.as_bytes()).await.unwrap();
+        assert_eq!(result, signature);
+        assert!(result.verify(&keypair.pubkey().to_bytes(), message.as_bytes()));
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_text_payload_rejects_binary_message() {
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        let signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap()
+        .with_payload_encoding(TurnkeyPayloadEncoding::TextUtf8);
+
+        let result = signer.sign_message(&[0xff, 0xfe, 0x00]).await;
+        assert!(matches!(result, Err(SignerError::SerializationError(_))));
+    }
+
+    #[test]
+    fn test_turnkey_latency_class() {
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        let signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap();
+        assert_eq!(signer.latency_class(), crate::traits::LatencyClass::Remote);
+    }
+
+    #[test]
+    fn test_turnkey_config_debug() {
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        let signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key.clone(),
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap();
+
+        let output = signer.config_debug();
+        assert!(output.contains("backend=turnkey"));
+        assert!(output.contains("host=api.turnkey.com"));
+        assert!(output.contains("test-key-id"));
+        assert!(!output.contains(&api_private_key));
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_sign_transaction_cancellable() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/submit/sign_raw_payload"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap();
+        signer.api_base_url = mock_server.uri();
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            cancel.cancel();
+        });
+
+        let started = Instant::now();
+        let result = signer.sign_transaction_cancellable(&mut tx, token).await;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        match result {
+            Err(SignerError::Other(msg)) => assert_eq!(msg, "cancelled"),
+            other => panic!("Expected cancellation, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_sign_transaction_with_options() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+        let mut tx = create_test_transaction(&keypair_pubkey(&keypair));
+        let signature = keypair.sign_message(&tx.message_data());
+        let sig_bytes = signature.as_ref();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/submit/sign_raw_payload"))
+            .and(header(CONTEXT_HEADER, "request-42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "activity": {
+                    "result": {
+                        "signRawPayloadResult": {
+                            "r": hex::encode(&sig_bytes[0..32]),
+                            "s": hex::encode(&sig_bytes[32..64])
+                        }
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap();
+        signer.api_base_url = mock_server.uri();
+
+        let signed = signer
+            .sign_transaction_with(
+                &mut tx.clone(),
+                SignOptions::new().with_context("request-42"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(signed.signature, signature);
+
+        let result = signer
+            .sign_transaction_with(&mut tx, SignOptions::new().with_deadline(Instant::now()))
+            .await;
+        assert!(matches!(result, Err(SignerError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_sign_transaction_by_deadline_expired() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/submit/sign_raw_payload"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap();
+        signer.api_base_url = mock_server.uri();
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        let result = signer
+            .sign_transaction_by_deadline(&mut tx, Instant::now())
+            .await;
+
+        assert!(matches!(result, Err(SignerError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_is_available_hedged() {
+        let primary = MockServer::start().await;
+        let secondary = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+            .mount(&primary)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&secondary)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap()
+        .with_hedging(Duration::from_millis(50), secondary.uri());
+        signer.api_base_url = primary.uri();
+
+        let started = Instant::now();
+        assert!(signer.is_available().await);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_with_resolve() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        let message = b"test message";
+        let signature = keypair.sign_message(message);
+        let sig_bytes = signature.as_ref();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/submit/sign_raw_payload"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "activity": {
+                    "result": {
+                        "signRawPayloadResult": {
+                            "r": hex::encode(&sig_bytes[0..32]),
+                            "s": hex::encode(&sig_bytes[32..64])
+                        }
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap()
+        .with_resolve("turnkey.internal", *mock_server.address())
+        .unwrap();
+        signer.api_base_url = format!("http://turnkey.internal:{}", mock_server.address().port());
+
+        assert_eq!(signer.sign_message(message).await.unwrap(), signature);
+    }
+
+    #[test]
+    fn test_turnkey_with_resolve_rejects_custom_client() {
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+
+        let signer = TurnkeySigner::builder()
+            .api_public_key(api_public_key)
+            .api_private_key(api_private_key)
+            .organization_id("test-org-id")
+            .private_key_id("test-key-id")
+            .public_key(keypair.pubkey().to_string())
+            .client(reqwest::Client::new())
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            signer.with_resolve("turnkey.internal", addr),
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_turnkey_region() {
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        let signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap();
+        assert_eq!(signer.api_base_url, "https://api.turnkey.com");
+
+        assert!(matches!(
+            signer.clone().with_region(TurnkeyRegion::Eu),
+            Err(SignerError::ConfigError(_))
+        ));
+
+        let signer = signer
+            .with_region_base_url(TurnkeyRegion::Eu, "https://eu.example.com".to_string())
+            .with_region(TurnkeyRegion::Eu)
+            .unwrap();
+        assert_eq!(signer.api_base_url, "https://eu.example.com");
+        assert!(signer.config_debug().contains("host=eu.example.com"));
+
+        let signer = signer.with_region(TurnkeyRegion::Us).unwrap();
+        assert_eq!(signer.api_base_url, TURNKEY_US_API_URL);
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_region_routes_requests() {
+        let us = MockServer::start().await;
+        let eu = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        let message = b"test message";
+        let signature = keypair.sign_message(message);
+        let sig_bytes = signature.as_ref();
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&us)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/submit/sign_raw_payload"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "activity": {
+                    "result": {
+                        "signRawPayloadResult": {
+                            "r": hex::encode(&sig_bytes[0..32]),
+                            "s": hex::encode(&sig_bytes[32..64])
+                        }
+                    }
+                }
+            })))
+            .expect(2)
+            .mount(&eu)
+            .await;
+
+        let signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap()
+        .with_region_base_url(TurnkeyRegion::Us, us.uri())
+        .with_region_base_url(TurnkeyRegion::Eu, eu.uri())
+        .with_region(TurnkeyRegion::Eu)
+        .unwrap();
+
+        assert_eq!(signer.sign_message(message).await.unwrap(), signature);
+
+        let signer = TurnkeySigner::builder()
+            .api_public_key(signer.api_public_key.clone())
+            .api_private_key(signer.api_private_key.clone())
+            .organization_id("test-org-id")
+            .private_key_id("test-key-id")
+            .public_key(keypair.pubkey().to_string())
+            .region(TurnkeyRegion::Eu)
+            .region_base_url(TurnkeyRegion::Eu, eu.uri())
+            .build()
+            .unwrap();
+
+        assert_eq!(signer.sign_message(message).await.unwrap(), signature);
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_fastest_region() {
+        let slow = MockServer::start().await;
+        let fast = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+            .mount(&slow)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&fast)
+            .await;
+
+        let signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap()
+        .with_fastest_base_url(vec![slow.uri(), fast.uri()])
+        .await;
+
+        assert_eq!(signer.api_base_url, fast.uri());
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_is_available_cached() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap()
+        .with_availability_cache_ttl(Duration::from_secs(60));
+        signer.api_base_url = mock_server.uri();
+
+        for _ in 0..3 {
+            assert!(signer.is_available().await);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_is_available_cache_disabled() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap()
+        .with_availability_cache_ttl(Duration::ZERO);
+        signer.api_base_url = mock_server.uri();
+
+        assert!(signer.is_available().await);
+        assert!(signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_turnkey_unavailable_not_cached() {
+        let mock_server = MockServer::start().await;
+        let keypair = create_test_keypair();
+        let (api_public_key, api_private_key) = create_test_api_keys();
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/public/v1/query/whoami"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut signer = TurnkeySigner::new(
+            api_public_key,
+            api_private_key,
+            "test-org-id".to_string(),
+            "test-key-id".to_string(),
+            keypair.pubkey().to_string(),
+        )
+        .unwrap();
+        signer.api_base_url = mock_server.uri();
+
+        assert!(!signer.is_available().await);
+        assert!(signer.is_available().await);
+    }
 }
@@ -0,0 +1,134 @@
+//! Helpers for modifying transactions before signing
+
+use std::str::FromStr;
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{CompiledInstruction, Pubkey, Signature, Transaction};
+
+/// SPL Memo program (v2) address
+pub const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+/// Prepend an SPL Memo instruction to a transaction and clear its signatures
+///
+/// The memo program is added as a read-only, non-signer account, so existing
+/// account indices are preserved. Because the message changes, any existing
+/// signatures are invalidated and reset to placeholders; sign the transaction
+/// afterwards, e.g. with [`SolanaSigner::sign_transaction`].
+///
+/// [`SolanaSigner::sign_transaction`]: crate::traits::SolanaSigner::sign_transaction
+pub fn with_memo(tx: &mut Transaction, memo: &str) -> Result<(), SignerError> {
+    let memo_program = Pubkey::from_str(MEMO_PROGRAM_ID)
+        .map_err(|e| SignerError::ConfigError(format!("Invalid memo program id: {e}")))?;
+
+    // Work on a copy so a failure leaves the caller's transaction untouched
+    let mut message = tx.message.clone();
+
+    let program_index = match message.account_keys.iter().position(|k| *k == memo_program) {
+        Some(index) => index,
+        None => {
+            // Read-only unsigned accounts live at the end of the account list
+            message.account_keys.push(memo_program);
+            message.header.num_readonly_unsigned_accounts = message
+                .header
+                .num_readonly_unsigned_accounts
+                .checked_add(1)
+                .ok_or_else(|| {
+                    SignerError::SerializationError(
+                        "Too many read-only accounts to add a memo".to_string(),
+                    )
+                })?;
+            message.account_keys.len() - 1
+        }
+    };
+
+    let program_id_index = u8::try_from(program_index).map_err(|_| {
+        SignerError::SerializationError("Too many accounts to add a memo".to_string())
+    })?;
+
+    message.instructions.insert(
+        0,
+        CompiledInstruction {
+            program_id_index,
+            accounts: vec![],
+            data: memo.as_bytes().to_vec(),
+        },
+    );
+
+    let num_required_signatures = message.header.num_required_signatures as usize;
+    tx.message = message;
+    tx.signatures = vec![Signature::default(); num_required_signatures];
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::create_test_transaction;
+
+    #[test]
+    fn test_with_memo() {
+        let payer = Pubkey::new_unique();
+        let mut tx = create_test_transaction(&payer);
+        tx.signatures = vec![Signature::new_unique()];
+        let original_instructions = tx.message.instructions.clone();
+
+        with_memo(&mut tx, "invoice #42").unwrap();
+
+        let memo_ix = &tx.message.instructions[0];
+        assert_eq!(
+            tx.message.account_keys[memo_ix.program_id_index as usize],
+            Pubkey::from_str(MEMO_PROGRAM_ID).unwrap()
+        );
+        assert_eq!(memo_ix.data, b"invoice #42");
+        assert_eq!(&tx.message.instructions[1..], &original_instructions[..]);
+        assert_eq!(tx.signatures, vec![Signature::default()]);
+    }
+
+    #[test]
+    fn test_with_memo_too_many_accounts() {
+        let mut tx = create_test_transaction(&Pubkey::new_unique());
+        tx.signatures = vec![Signature::new_unique()];
+        let extra = 256 - tx.message.account_keys.len();
+        tx.message
+            .account_keys
+            .extend((0..extra).map(|_| Pubkey::new_unique()));
+        tx.message.header.num_readonly_unsigned_accounts += extra as u8;
+        let original = tx.clone();
+
+        let result = with_memo(&mut tx, "invoice #42");
+
+        assert!(matches!(result, Err(SignerError::SerializationError(_))));
+        assert_eq!(tx, original);
+    }
+
+    #[cfg(all(feature = "memory", feature = "integration-tests"))]
+    #[tokio::test]
+    async fn test_with_memo_simulates() {
+        use crate::sdk_adapter::Keypair;
+        use crate::tests::litesvm_util::{
+            get_latest_blockhash, simulate_transaction, start_litesvm,
+        };
+        use crate::traits::SolanaSigner;
+        use crate::MemorySigner;
+
+        let signer = MemorySigner::new(Keypair::new());
+        let payer = signer.pubkey();
+
+        let lite_svm = start_litesvm(&payer)
+            .await
+            .expect("Failed to start LiteSVM");
+
+        let mut tx = create_test_transaction(&payer);
+        tx.message.recent_blockhash = get_latest_blockhash(&lite_svm)
+            .await
+            .expect("Failed to get latest blockhash");
+
+        with_memo(&mut tx, "hello from solana-signers").unwrap();
+        signer.sign_transaction(&mut tx).await.unwrap();
+
+        simulate_transaction(&lite_svm, &tx)
+            .await
+            .expect("Failed to simulate transaction");
+    }
+}
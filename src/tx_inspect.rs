@@ -0,0 +1,429 @@
+//! Structured view of what a transaction does
+//!
+//! [`inspect_message`] resolves a legacy or v0 message's accounts and decodes the
+//! instructions policy and audit checks care about: System Program lamport
+//! transfers and SPL Token / Token-2022 transfers. Everything else is reported
+//! only through the program IDs and accounts it touches.
+
+use crate::error::SignerError;
+use crate::sdk_adapter::{
+    AddressLookupTableAccount, CompiledInstruction, Pubkey, Transaction, VersionedMessage,
+    VersionedTransaction,
+};
+
+/// System Program (`11111111111111111111111111111111`)
+pub const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array([0; 32]);
+
+/// SPL Token program (`TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+pub const TOKEN_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237,
+    95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+]);
+
+/// SPL Token-2022 program (`TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108, 205, 218, 182, 26, 252, 77,
+    131, 185, 13, 39, 254, 189, 249, 40, 216, 161, 139, 252,
+]);
+
+// System Program instruction discriminators (u32, little-endian)
+const SYSTEM_CREATE_ACCOUNT: u32 = 0;
+const SYSTEM_TRANSFER: u32 = 2;
+const SYSTEM_TRANSFER_WITH_SEED: u32 = 11;
+
+// SPL Token instruction discriminators
+const TOKEN_TRANSFER: u8 = 3;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+
+/// Lamports moved by a System Program instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LamportTransfer {
+    /// Instruction index within the message
+    pub instruction_index: usize,
+    /// Funding account
+    pub from: Pubkey,
+    /// Receiving account
+    pub to: Pubkey,
+    /// Amount in lamports
+    pub lamports: u64,
+}
+
+/// Tokens moved by an SPL Token or Token-2022 `Transfer` / `TransferChecked`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenTransfer {
+    /// Instruction index within the message
+    pub instruction_index: usize,
+    /// Token program that executes the transfer
+    pub program_id: Pubkey,
+    /// Source token account
+    pub source: Pubkey,
+    /// Destination token account
+    pub destination: Pubkey,
+    /// Owner or delegate authorizing the transfer
+    pub authority: Pubkey,
+    /// Mint, only known for `TransferChecked`
+    pub mint: Option<Pubkey>,
+    /// Amount in base units
+    pub amount: u64,
+    /// Mint decimals, only known for `TransferChecked`
+    pub decimals: Option<u8>,
+}
+
+/// Accounts, programs and transfers of a transaction message
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionSummary {
+    /// Account paying the fees
+    pub fee_payer: Option<Pubkey>,
+    /// Accounts that must sign, in signer index order
+    pub signers: Vec<Pubkey>,
+    /// Accounts the message requests write access to, including lookup accounts
+    pub writable_accounts: Vec<Pubkey>,
+    /// Programs invoked by top-level instructions, deduplicated in order of use
+    pub program_ids: Vec<Pubkey>,
+    /// Decoded System Program transfers
+    pub lamport_transfers: Vec<LamportTransfer>,
+    /// Decoded SPL Token and Token-2022 transfers
+    pub token_transfers: Vec<TokenTransfer>,
+}
+
+impl TransactionSummary {
+    /// Total lamports moved out of `account` by System Program transfers
+    pub fn lamports_sent_by(&self, account: &Pubkey) -> u64 {
+        self.lamport_transfers
+            .iter()
+            .filter(|transfer| transfer.from == *account)
+            .map(|transfer| transfer.lamports)
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Whether any top-level instruction invokes `program_id`
+    pub fn invokes(&self, program_id: &Pubkey) -> bool {
+        self.program_ids.contains(program_id)
+    }
+}
+
+/// Inspect a legacy transaction
+pub fn inspect_transaction(transaction: &Transaction) -> Result<TransactionSummary, SignerError> {
+    inspect_message(&VersionedMessage::Legacy(transaction.message.clone()), &[])
+}
+
+/// Inspect a versioned transaction, resolving lookup accounts from `tables`
+pub fn inspect_versioned_transaction(
+    transaction: &VersionedTransaction,
+    tables: &[AddressLookupTableAccount],
+) -> Result<TransactionSummary, SignerError> {
+    inspect_message(&transaction.message, tables)
+}
+
+/// Inspect a legacy or v0 message
+///
+/// Accounts loaded through address lookup tables are resolved from `tables`, so
+/// every table a v0 message references must be provided. Fails with
+/// `SignerError::SerializationError` if the message references an account it
+/// does not contain.
+pub fn inspect_message(
+    message: &VersionedMessage,
+    tables: &[AddressLookupTableAccount],
+) -> Result<TransactionSummary, SignerError> {
+    let static_keys = message.static_account_keys();
+    let header = message.header();
+    let num_signers = header.num_required_signatures as usize;
+    let num_writable_signers =
+        num_signers.saturating_sub(header.num_readonly_signed_accounts as usize);
+    let num_writable_unsigned = static_keys
+        .len()
+        .saturating_sub(num_signers)
+        .saturating_sub(header.num_readonly_unsigned_accounts as usize);
+
+    let mut writable_accounts: Vec<Pubkey> = static_keys
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| {
+            *index < num_writable_signers
+                || (*index >= num_signers && *index < num_signers + num_writable_unsigned)
+        })
+        .map(|(_, key)| *key)
+        .collect();
+
+    let (lookup_writable, lookup_readonly) = resolve_lookups(message, tables)?;
+    writable_accounts.extend_from_slice(&lookup_writable);
+
+    let account_keys: Vec<Pubkey> = static_keys
+        .iter()
+        .chain(&lookup_writable)
+        .chain(&lookup_readonly)
+        .copied()
+        .collect();
+
+    let mut summary = TransactionSummary {
+        fee_payer: static_keys.first().copied(),
+        signers: static_keys.iter().take(num_signers).copied().collect(),
+        writable_accounts,
+        ..TransactionSummary::default()
+    };
+
+    for (instruction_index, instruction) in message.instructions().iter().enumerate() {
+        let account = |position: usize| -> Result<Pubkey, SignerError> {
+            instruction
+                .accounts
+                .get(position)
+                .and_then(|index| account_keys.get(*index as usize))
+                .copied()
+                .ok_or_else(|| {
+                    SignerError::SerializationError(format!(
+                        "Instruction {instruction_index} references a missing account"
+                    ))
+                })
+        };
+        let program_id = account_keys
+            .get(instruction.program_id_index as usize)
+            .copied()
+            .ok_or_else(|| {
+                SignerError::SerializationError(format!(
+                    "Instruction {instruction_index} references a missing program"
+                ))
+            })?;
+
+        if !summary.program_ids.contains(&program_id) {
+            summary.program_ids.push(program_id);
+        }
+
+        if program_id == SYSTEM_PROGRAM_ID {
+            if let Some(transfer) = decode_system_transfer(instruction, &account)? {
+                summary.lamport_transfers.push(LamportTransfer {
+                    instruction_index,
+                    ..transfer
+                });
+            }
+        } else if program_id == TOKEN_PROGRAM_ID || program_id == TOKEN_2022_PROGRAM_ID {
+            if let Some(transfer) = decode_token_transfer(instruction, &account)? {
+                summary.token_transfers.push(TokenTransfer {
+                    instruction_index,
+                    program_id,
+                    ..transfer
+                });
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Look up the writable and readonly addresses a v0 message loads from tables
+fn resolve_lookups(
+    message: &VersionedMessage,
+    tables: &[AddressLookupTableAccount],
+) -> Result<(Vec<Pubkey>, Vec<Pubkey>), SignerError> {
+    let mut writable = Vec::new();
+    let mut readonly = Vec::new();
+
+    for lookup in message.address_table_lookups().unwrap_or_default() {
+        let table = tables
+            .iter()
+            .find(|table| table.key == lookup.account_key)
+            .ok_or_else(|| {
+                SignerError::SerializationError(format!(
+                    "Address lookup table {} not provided",
+                    lookup.account_key
+                ))
+            })?;
+        let address = |index: &u8| {
+            table
+                .addresses
+                .get(*index as usize)
+                .copied()
+                .ok_or_else(|| {
+                    SignerError::SerializationError(format!(
+                        "Address lookup table {} index {index} out of range",
+                        table.key
+                    ))
+                })
+        };
+
+        for index in &lookup.writable_indexes {
+            writable.push(address(index)?);
+        }
+        for index in &lookup.readonly_indexes {
+            readonly.push(address(index)?);
+        }
+    }
+
+    Ok((writable, readonly))
+}
+
+fn decode_system_transfer(
+    instruction: &CompiledInstruction,
+    account: &dyn Fn(usize) -> Result<Pubkey, SignerError>,
+) -> Result<Option<LamportTransfer>, SignerError> {
+    let data = &instruction.data;
+    let (Some(discriminator), Some(lamports)) = (read_u32(data, 0), read_u64(data, 4)) else {
+        return Ok(None);
+    };
+
+    let (from, to) = match discriminator {
+        SYSTEM_CREATE_ACCOUNT | SYSTEM_TRANSFER => (account(0)?, account(1)?),
+        SYSTEM_TRANSFER_WITH_SEED => (account(0)?, account(2)?),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(LamportTransfer {
+        instruction_index: 0,
+        from,
+        to,
+        lamports,
+    }))
+}
+
+fn decode_token_transfer(
+    instruction: &CompiledInstruction,
+    account: &dyn Fn(usize) -> Result<Pubkey, SignerError>,
+) -> Result<Option<TokenTransfer>, SignerError> {
+    let data = &instruction.data;
+    let Some(amount) = read_u64(data, 1) else {
+        return Ok(None);
+    };
+
+    let transfer = match data[0] {
+        TOKEN_TRANSFER => TokenTransfer {
+            instruction_index: 0,
+            program_id: TOKEN_PROGRAM_ID,
+            source: account(0)?,
+            destination: account(1)?,
+            authority: account(2)?,
+            mint: None,
+            amount,
+            decimals: None,
+        },
+        TOKEN_TRANSFER_CHECKED => TokenTransfer {
+            instruction_index: 0,
+            program_id: TOKEN_PROGRAM_ID,
+            source: account(0)?,
+            destination: account(2)?,
+            authority: account(3)?,
+            mint: Some(account(1)?),
+            amount,
+            decimals: data.get(9).copied(),
+        },
+        _ => return Ok(None),
+    };
+
+    Ok(Some(transfer))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{AccountMeta, Instruction, Message};
+    use crate::test_util::{create_test_transaction, create_v0_test_transaction};
+
+    fn token_transfer_checked(
+        source: &Pubkey,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let mut data = vec![TOKEN_TRANSFER_CHECKED];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.push(6);
+
+        Instruction {
+            program_id: TOKEN_2022_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*source, false),
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(*destination, false),
+                AccountMeta::new_readonly(*authority, true),
+            ],
+            data,
+        }
+    }
+
+    #[test]
+    fn test_inspect_lamport_transfer() {
+        let payer = Pubkey::new_unique();
+        let tx = create_test_transaction(&payer);
+        let recipient = tx.message.account_keys[1];
+
+        let summary = inspect_transaction(&tx).unwrap();
+
+        assert_eq!(summary.fee_payer, Some(payer));
+        assert_eq!(summary.signers, vec![payer]);
+        assert_eq!(summary.writable_accounts, vec![payer, recipient]);
+        assert_eq!(summary.program_ids, vec![SYSTEM_PROGRAM_ID]);
+        assert_eq!(
+            summary.lamport_transfers,
+            vec![LamportTransfer {
+                instruction_index: 0,
+                from: payer,
+                to: recipient,
+                lamports: 1_000_000,
+            }]
+        );
+        assert_eq!(summary.lamports_sent_by(&payer), 1_000_000);
+    }
+
+    #[test]
+    fn test_inspect_token_transfer() {
+        let [owner, source, mint, destination] = [(); 4].map(|_| Pubkey::new_unique());
+        let message = Message::new(
+            &[token_transfer_checked(
+                &source,
+                &mint,
+                &destination,
+                &owner,
+                2_500_000,
+            )],
+            Some(&owner),
+        );
+
+        let summary = inspect_transaction(&Transaction::new_unsigned(message)).unwrap();
+
+        assert!(summary.invokes(&TOKEN_2022_PROGRAM_ID));
+        assert!(summary.lamport_transfers.is_empty());
+        assert_eq!(
+            summary.token_transfers,
+            vec![TokenTransfer {
+                instruction_index: 0,
+                program_id: TOKEN_2022_PROGRAM_ID,
+                source,
+                destination,
+                authority: owner,
+                mint: Some(mint),
+                amount: 2_500_000,
+                decimals: Some(6),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_inspect_v0_lookups() {
+        let payer = Pubkey::new_unique();
+        let table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        };
+        let tx = create_v0_test_transaction(&payer, &table.key, vec![1], vec![0]);
+
+        let summary = inspect_versioned_transaction(&tx, std::slice::from_ref(&table)).unwrap();
+
+        assert_eq!(summary.signers, vec![payer]);
+        assert_eq!(summary.writable_accounts, vec![payer, table.addresses[1]]);
+        assert!(matches!(
+            inspect_versioned_transaction(&tx, &[]),
+            Err(SignerError::SerializationError(_))
+        ));
+    }
+}
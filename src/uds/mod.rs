@@ -0,0 +1,314 @@
+//! Unix-domain-socket signer integration
+//!
+//! Sends length-prefixed sign requests to a sidecar process over a local Unix
+//! socket, so the key can live in a separate process (or container) from the
+//! application. [`UdsSignerServer`] is the matching server side and can expose any
+//! other signer in this crate. The frame layout is documented in `protocol.rs`.
+
+mod protocol;
+mod server;
+
+pub use server::UdsSignerServer;
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use std::path::PathBuf;
+
+/// Signer backed by a sidecar signing process on a Unix socket
+#[derive(Debug, Clone)]
+pub struct UdsSigner {
+    socket_path: PathBuf,
+    public_key: Pubkey,
+}
+
+impl UdsSigner {
+    /// Create a new UdsSigner for the sidecar listening on `socket_path` (requires initialization)
+    pub fn new(socket_path: PathBuf) -> Self {
+        Self {
+            socket_path,
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        }
+    }
+
+    /// Initialize the signer by asking the sidecar for its public key
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        self.public_key = self.fetch_pubkey().await?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    async fn request(&self, operation: u8, body: &[u8]) -> Result<Vec<u8>, SignerError> {
+        let io_error = |e: std::io::Error| {
+            SignerError::NotAvailable(format!("UDS signer at {}: {e}", self.socket_path.display()))
+        };
+
+        let mut stream = tokio::net::UnixStream::connect(&self.socket_path)
+            .await
+            .map_err(io_error)?;
+        protocol::write_frame(&mut stream, operation, body)
+            .await
+            .map_err(io_error)?;
+
+        let (status, body) = protocol::read_frame(&mut stream).await?.ok_or_else(|| {
+            SignerError::NotAvailable("UDS signer closed the connection".to_string())
+        })?;
+
+        if status != protocol::STATUS_OK {
+            return Err(protocol::error_for(
+                status,
+                String::from_utf8_lossy(&body).into_owned(),
+            ));
+        }
+
+        Ok(body)
+    }
+
+    #[cfg(not(unix))]
+    async fn request(&self, _operation: u8, _body: &[u8]) -> Result<Vec<u8>, SignerError> {
+        Err(SignerError::NotAvailable(
+            "The UDS signer only supports Unix domain sockets".to_string(),
+        ))
+    }
+
+    async fn fetch_pubkey(&self) -> Result<Pubkey, SignerError> {
+        let body = self.request(protocol::OP_PUBKEY, &[]).await?;
+        let bytes: [u8; 32] = body.try_into().map_err(|_| {
+            SignerError::InvalidPublicKey("UDS signer returned a malformed public key".to_string())
+        })?;
+
+        Ok(Pubkey::from(bytes))
+    }
+
+    /// Send a sign request and check the returned signature over `message`
+    async fn request_signature(
+        &self,
+        operation: u8,
+        body: &[u8],
+        message: &[u8],
+    ) -> Result<Signature, SignerError> {
+        let body = self.request(operation, body).await?;
+        let bytes: [u8; 64] = body.try_into().map_err(|_| {
+            SignerError::SigningFailed("UDS signer returned a malformed signature".to_string())
+        })?;
+        let signature = Signature::from(bytes);
+
+        if !TransactionUtil::verify_signature(&self.public_key, message, &signature) {
+            return Err(SignerError::SigningFailed(
+                "UDS signer returned a signature that does not verify".to_string(),
+            ));
+        }
+
+        Ok(signature)
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+        operation: u8,
+    ) -> Result<SignedTransaction, SignerError> {
+        let wire = bincode::serialize(transaction).map_err(|e| {
+            SignerError::SerializationError(format!("Failed to serialize transaction: {e}"))
+        })?;
+        let signature = self
+            .request_signature(operation, &wire, &transaction.message_data())
+            .await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for UdsSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx, protocol::OP_SIGN_TRANSACTION)
+            .await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.request_signature(protocol::OP_SIGN_MESSAGE, message, message)
+            .await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx, protocol::OP_SIGN_PARTIAL_TRANSACTION)
+            .await
+    }
+
+    /// Check that the sidecar answers and still reports the same key
+    async fn is_available(&self) -> bool {
+        matches!(self.fetch_pubkey().await, Ok(pubkey) if pubkey == self.public_key)
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("uds", self.public_key, self.latency_class())
+            .with_endpoint(self.socket_path.display().to_string())
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=uds socket={} pubkey={}",
+            self.socket_path.display(),
+            self.public_key
+        )
+    }
+}
+
+#[cfg(all(test, unix, feature = "memory"))]
+mod tests {
+    use super::*;
+    use crate::memory::MemorySigner;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+
+    /// Refuses partial signing, standing in for a sidecar's policy
+    struct NoPartialSigning(MemorySigner);
+
+    #[async_trait::async_trait]
+    impl SolanaSigner for NoPartialSigning {
+        fn pubkey(&self) -> Pubkey {
+            self.0.pubkey()
+        }
+
+        async fn sign_transaction(
+            &self,
+            tx: &mut Transaction,
+        ) -> Result<SignedTransaction, SignerError> {
+            self.0.sign_transaction(tx).await
+        }
+
+        async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+            self.0.sign_message(message).await
+        }
+
+        async fn sign_partial_transaction(
+            &self,
+            _tx: &mut Transaction,
+        ) -> Result<SignedTransaction, SignerError> {
+            Err(SignerError::SigningFailed(
+                "partial signing disabled".to_string(),
+            ))
+        }
+
+        async fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    fn spawn_server(keypair: &Keypair) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "solana-signers-uds-{}-{}.sock",
+            std::process::id(),
+            Pubkey::new_unique()
+        ));
+        let signer = MemorySigner::from_bytes(&keypair.to_bytes()).unwrap();
+        let server = UdsSignerServer::new(NoPartialSigning(signer));
+
+        let socket = path.clone();
+        tokio::spawn(async move { server.bind_and_serve(socket).await });
+
+        path
+    }
+
+    async fn connected(path: PathBuf) -> UdsSigner {
+        let mut signer = UdsSigner::new(path);
+        // The server binds on its own task; retry until the socket exists
+        for _ in 0..50 {
+            if signer.init().await.is_ok() {
+                return signer;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("UDS test server did not start");
+    }
+
+    #[tokio::test]
+    async fn test_uds_sign_message_and_transaction() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let signer = connected(spawn_server(&keypair)).await;
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert!(signer.is_available().await);
+        assert_eq!(
+            signer.sign_message(b"hello").await.unwrap(),
+            keypair_sign_message(&keypair, b"hello")
+        );
+
+        let mut tx = create_test_transaction(&pubkey);
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+    }
+
+    #[tokio::test]
+    async fn test_uds_server_refusal() {
+        let keypair = Keypair::new();
+        let signer = connected(spawn_server(&keypair)).await;
+
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let err = signer.sign_partial_transaction(&mut tx).await.unwrap_err();
+        assert!(
+            matches!(err, SignerError::SigningFailed(message) if message == "partial signing disabled")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_uds_server_multiple_requests_per_connection() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let server = UdsSignerServer::new(MemorySigner::from_bytes(&keypair.to_bytes()).unwrap());
+        let (mut client, stream) = tokio::io::duplex(4096);
+        tokio::spawn(async move { server.serve_connection(stream).await });
+
+        protocol::write_frame(&mut client, protocol::OP_PUBKEY, &[])
+            .await
+            .unwrap();
+        protocol::write_frame(&mut client, 99, &[]).await.unwrap();
+
+        let (status, body) = protocol::read_frame(&mut client).await.unwrap().unwrap();
+        assert_eq!(
+            (status, body),
+            (protocol::STATUS_OK, pubkey.to_bytes().to_vec())
+        );
+
+        let (status, _) = protocol::read_frame(&mut client).await.unwrap().unwrap();
+        assert_eq!(status, protocol::STATUS_BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_uds_unreachable() {
+        let mut signer = UdsSigner::new("/nonexistent/signer.sock".into());
+
+        assert!(!signer.is_available().await);
+        assert!(matches!(
+            signer.init().await,
+            Err(SignerError::NotAvailable(_))
+        ));
+    }
+}
@@ -0,0 +1,163 @@
+//! Framing and messages shared by the UDS signer client and server
+//!
+//! Every frame is a 4-byte big-endian length followed by that many bytes. A request
+//! frame starts with a 1-byte operation; a response frame starts with a 1-byte
+//! status, followed by the result on success or a UTF-8 error message otherwise.
+//!
+//! | Operation | Request body | Success body |
+//! |-----------|--------------|--------------|
+//! | `OP_PUBKEY` | empty | 32-byte public key |
+//! | `OP_SIGN_MESSAGE` | message bytes | 64-byte signature |
+//! | `OP_SIGN_TRANSACTION` | bincode wire transaction | 64-byte signature |
+//! | `OP_SIGN_PARTIAL_TRANSACTION` | bincode wire transaction | 64-byte signature |
+
+use crate::error::SignerError;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+pub const OP_PUBKEY: u8 = 1;
+pub const OP_SIGN_MESSAGE: u8 = 2;
+pub const OP_SIGN_TRANSACTION: u8 = 3;
+pub const OP_SIGN_PARTIAL_TRANSACTION: u8 = 4;
+
+pub const STATUS_OK: u8 = 0;
+pub const STATUS_REFUSED: u8 = 1;
+pub const STATUS_UNAVAILABLE: u8 = 2;
+pub const STATUS_BAD_REQUEST: u8 = 3;
+pub const STATUS_ERROR: u8 = 4;
+
+/// Largest frame either side accepts
+pub const MAX_FRAME_LEN: usize = 64 * 1024;
+
+/// Write `kind` followed by `body` as one frame
+pub async fn write_frame<W>(writer: &mut W, kind: u8, body: &[u8]) -> std::io::Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut frame = Vec::with_capacity(5 + body.len());
+    frame.extend_from_slice(&(body.len() as u32 + 1).to_be_bytes());
+    frame.push(kind);
+    frame.extend_from_slice(body);
+    writer.write_all(&frame).await?;
+    writer.flush().await
+}
+
+/// Read one frame and split it into its leading kind byte and body
+///
+/// Returns `Ok(None)` if the peer closed the connection between frames.
+pub async fn read_frame<R>(reader: &mut R) -> Result<Option<(u8, Vec<u8>)>, SignerError>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut length = [0u8; 4];
+    match reader.read_exact(&mut length).await {
+        Ok(_) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+
+    let length = u32::from_be_bytes(length) as usize;
+    if length == 0 || length > MAX_FRAME_LEN {
+        return Err(SignerError::SerializationError(format!(
+            "UDS signer frame has invalid length {length}"
+        )));
+    }
+
+    let mut frame = vec![0u8; length];
+    reader.read_exact(&mut frame).await?;
+    let body = frame.split_off(1);
+
+    Ok(Some((frame[0], body)))
+}
+
+/// Response status reported for `error`
+pub fn status_for(error: &SignerError) -> u8 {
+    match error {
+        SignerError::SigningFailed(_) => STATUS_REFUSED,
+        SignerError::NotAvailable(_) | SignerError::Timeout(_) => STATUS_UNAVAILABLE,
+        SignerError::SerializationError(_)
+        | SignerError::ConfigError(_)
+        | SignerError::InvalidTransaction(_)
+        | SignerError::TransactionTooLarge(_) => STATUS_BAD_REQUEST,
+        _ => STATUS_ERROR,
+    }
+}
+
+/// Message sent with a non-OK response, without the error kind's prefix
+pub fn message_for(error: &SignerError) -> &str {
+    match error {
+        SignerError::InvalidPrivateKey(message)
+        | SignerError::InvalidPublicKey(message)
+        | SignerError::SigningFailed(message)
+        | SignerError::RemoteApiError(message)
+        | SignerError::HttpError(message)
+        | SignerError::SerializationError(message)
+        | SignerError::ConfigError(message)
+        | SignerError::NotAvailable(message)
+        | SignerError::Timeout(message)
+        | SignerError::InvalidTransaction(message)
+        | SignerError::TransactionTooLarge(message)
+        | SignerError::IoError(message)
+        | SignerError::Other(message) => message,
+    }
+}
+
+/// Error for a non-OK response `status` carrying `message`
+pub fn error_for(status: u8, message: String) -> SignerError {
+    match status {
+        STATUS_REFUSED => SignerError::SigningFailed(message),
+        STATUS_UNAVAILABLE => SignerError::NotAvailable(message),
+        STATUS_BAD_REQUEST => SignerError::SerializationError(message),
+        _ => SignerError::RemoteApiError(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_frame_roundtrip() {
+        let (mut client, mut server) = tokio::io::duplex(1024);
+
+        write_frame(&mut client, OP_SIGN_MESSAGE, b"hello")
+            .await
+            .unwrap();
+        drop(client);
+
+        assert_eq!(
+            read_frame(&mut server).await.unwrap(),
+            Some((OP_SIGN_MESSAGE, b"hello".to_vec()))
+        );
+        assert_eq!(read_frame(&mut server).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_frame_rejects_oversized_length() {
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        client
+            .write_all(&(MAX_FRAME_LEN as u32 + 1).to_be_bytes())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            read_frame(&mut server).await,
+            Err(SignerError::SerializationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_status_roundtrip() {
+        let refused = SignerError::SigningFailed("policy".to_string());
+        assert!(matches!(
+            error_for(status_for(&refused), message_for(&refused).to_string()),
+            SignerError::SigningFailed(message) if message == "policy"
+        ));
+        assert!(matches!(
+            error_for(
+                status_for(&SignerError::Other("x".to_string())),
+                String::new()
+            ),
+            SignerError::RemoteApiError(_)
+        ));
+    }
+}
@@ -0,0 +1,120 @@
+//! Server side of the UDS signer protocol, for sidecar signer processes
+
+use super::protocol;
+use crate::error::SignerError;
+use crate::sdk_adapter::Transaction;
+use crate::traits::SolanaSigner;
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncWrite};
+
+/// Serves any [`SolanaSigner`] to [`UdsSigner`](super::UdsSigner) clients
+///
+/// Each connection may carry any number of requests, answered in order. Policy
+/// checks belong in the wrapped signer: whatever it refuses is reported to the
+/// client as a refusal.
+pub struct UdsSignerServer<S> {
+    signer: Arc<S>,
+}
+
+impl<S> Clone for UdsSignerServer<S> {
+    fn clone(&self) -> Self {
+        Self {
+            signer: self.signer.clone(),
+        }
+    }
+}
+
+impl<S: SolanaSigner + 'static> UdsSignerServer<S> {
+    /// Create a server that signs with `signer`
+    pub fn new(signer: S) -> Self {
+        Self {
+            signer: Arc::new(signer),
+        }
+    }
+
+    /// Create a server sharing an existing `signer`
+    pub fn from_arc(signer: Arc<S>) -> Self {
+        Self { signer }
+    }
+
+    /// Bind a Unix socket at `path`, replacing a stale socket file, and serve it forever
+    ///
+    /// Access control is left to the socket's file permissions, so place it in a
+    /// directory only the intended clients can reach.
+    #[cfg(unix)]
+    pub async fn bind_and_serve(
+        self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), SignerError> {
+        let path = path.as_ref();
+        match std::fs::remove_file(path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        self.serve(tokio::net::UnixListener::bind(path)?).await
+    }
+
+    /// Accept connections on `listener` and serve each on its own task
+    #[cfg(unix)]
+    pub async fn serve(self, listener: tokio::net::UnixListener) -> Result<(), SignerError> {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let server = self.clone();
+            tokio::spawn(async move {
+                if let Err(e) = server.serve_connection(stream).await {
+                    log::warn!("UDS signer connection closed with error: {e}");
+                }
+            });
+        }
+    }
+
+    /// Answer requests on one connection until the client closes it
+    pub async fn serve_connection<T>(&self, mut stream: T) -> Result<(), SignerError>
+    where
+        T: AsyncRead + AsyncWrite + Unpin,
+    {
+        while let Some((operation, body)) = protocol::read_frame(&mut stream).await? {
+            match self.handle(operation, &body).await {
+                Ok(result) => {
+                    protocol::write_frame(&mut stream, protocol::STATUS_OK, &result).await?
+                }
+                Err(e) => {
+                    protocol::write_frame(
+                        &mut stream,
+                        protocol::status_for(&e),
+                        protocol::message_for(&e).as_bytes(),
+                    )
+                    .await?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle(&self, operation: u8, body: &[u8]) -> Result<Vec<u8>, SignerError> {
+        match operation {
+            protocol::OP_PUBKEY => Ok(self.signer.pubkey().to_bytes().to_vec()),
+            protocol::OP_SIGN_MESSAGE => {
+                let signature = self.signer.sign_message(body).await?;
+                Ok(signature.as_ref().to_vec())
+            }
+            protocol::OP_SIGN_TRANSACTION | protocol::OP_SIGN_PARTIAL_TRANSACTION => {
+                let mut tx: Transaction = bincode::deserialize(body).map_err(|e| {
+                    SignerError::SerializationError(format!("Invalid transaction: {e}"))
+                })?;
+                let signed = if operation == protocol::OP_SIGN_TRANSACTION {
+                    self.signer.sign_transaction(&mut tx).await?
+                } else {
+                    self.signer.sign_partial_transaction(&mut tx).await?
+                };
+                Ok(signed.signature_bytes().to_vec())
+            }
+            other => Err(SignerError::SerializationError(format!(
+                "Unknown operation {other}"
+            ))),
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Ways for [`super::VaultSigner`] to obtain its Vault token
+//!
+//! A static token is sent as-is. A token file, such as a Vault Agent sink, is
+//! read again whenever its modification time changes, so tokens rotated by the
+//! agent are picked up without a restart. With Kubernetes auth the pod's
+//! service-account JWT is exchanged for a Vault token at `auth/<mount>/login`;
+//! the token is cached and the login repeated shortly before its lease runs
+//! out. The JWT is read again on every login, so rotated projected tokens are
+//! picked up.
+
+use crate::error::SignerError;
+use crate::interceptor::RequestInterceptor;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Where Kubernetes mounts the service-account token in every pod
+pub const DEFAULT_SERVICE_ACCOUNT_TOKEN_PATH: &str =
+    "/var/run/secrets/kubernetes.io/serviceaccount/token";
+
+/// Log in again this long before the token lease expires
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// How a [`super::VaultSigner`] authenticates to Vault
+#[derive(Clone)]
+pub enum VaultAuth {
+    /// A token issued out of band
+    Token(String),
+    /// A file holding the token, e.g. a Vault Agent `file` sink
+    TokenFile(PathBuf),
+    /// Kubernetes service-account login
+    Kubernetes(KubernetesAuth),
+}
+
+impl std::fmt::Debug for VaultAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Token(_) => f.write_str("Token([REDACTED])"),
+            Self::TokenFile(path) => f.debug_tuple("TokenFile").field(path).finish(),
+            Self::Kubernetes(auth) => f.debug_tuple("Kubernetes").field(auth).finish(),
+        }
+    }
+}
+
+impl From<KubernetesAuth> for VaultAuth {
+    fn from(auth: KubernetesAuth) -> Self {
+        Self::Kubernetes(auth)
+    }
+}
+
+/// Settings for Vault's Kubernetes auth method
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KubernetesAuth {
+    role: String,
+    mount: String,
+    jwt_path: PathBuf,
+}
+
+impl KubernetesAuth {
+    /// Log in as `role` on the `kubernetes` auth mount with the pod's own
+    /// service-account token
+    pub fn new(role: impl Into<String>) -> Self {
+        Self {
+            role: role.into(),
+            mount: "kubernetes".to_string(),
+            jwt_path: PathBuf::from(DEFAULT_SERVICE_ACCOUNT_TOKEN_PATH),
+        }
+    }
+
+    /// Use the auth method mounted at `mount` instead of `kubernetes`
+    pub fn with_mount(mut self, mount: impl Into<String>) -> Self {
+        self.mount = mount.into().trim_matches('/').to_string();
+        self
+    }
+
+    /// Read the JWT from `path`, e.g. a projected token with a Vault audience
+    pub fn with_jwt_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.jwt_path = path.into();
+        self
+    }
+
+    /// Get the Vault role
+    pub fn role(&self) -> &str {
+        &self.role
+    }
+}
+
+#[derive(Serialize)]
+struct LoginRequest<'a> {
+    role: &'a str,
+    jwt: &'a str,
+}
+
+#[derive(Deserialize)]
+struct LoginResponse {
+    auth: LoginAuth,
+}
+
+#[derive(Deserialize)]
+struct LoginAuth {
+    client_token: String,
+    /// Seconds; zero means the token does not expire
+    lease_duration: u64,
+}
+
+struct CachedToken {
+    token: String,
+    expires_at: Option<Instant>,
+    /// Modification time of the token file the token was read from
+    modified: Option<SystemTime>,
+}
+
+/// Hands out the current Vault token for a [`VaultAuth`]
+pub(super) struct TokenProvider {
+    auth: VaultAuth,
+    cached: tokio::sync::Mutex<Option<CachedToken>>,
+}
+
+impl TokenProvider {
+    pub(super) fn new(auth: VaultAuth) -> Self {
+        Self {
+            auth,
+            cached: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    pub(super) fn auth(&self) -> &VaultAuth {
+        &self.auth
+    }
+
+    /// Return a usable token, logging in first if there is none or it is about to expire
+    pub(super) async fn token(
+        &self,
+        client: &Client,
+        vault_addr: &str,
+        interceptor: &dyn RequestInterceptor,
+    ) -> Result<String, SignerError> {
+        let kubernetes = match &self.auth {
+            VaultAuth::Token(token) => return Ok(token.clone()),
+            VaultAuth::TokenFile(path) => return self.file_token(path).await,
+            VaultAuth::Kubernetes(kubernetes) => kubernetes,
+        };
+
+        let mut cached = self.cached.lock().await;
+        if let Some(cached) = cached.as_ref() {
+            let fresh = cached
+                .expires_at
+                .is_none_or(|expires_at| Instant::now() + TOKEN_EXPIRY_MARGIN < expires_at);
+            if fresh {
+                return Ok(cached.token.clone());
+            }
+        }
+
+        let login = Self::login(kubernetes, client, vault_addr, interceptor).await?;
+        let token = login.client_token;
+        *cached = Some(CachedToken {
+            token: token.clone(),
+            expires_at: (login.lease_duration > 0)
+                .then(|| Instant::now() + Duration::from_secs(login.lease_duration)),
+            modified: None,
+        });
+
+        Ok(token)
+    }
+
+    /// Return the token in `path`, reading the file again only if it was modified
+    ///
+    /// A rewrite that keeps the same modification time is still picked up once
+    /// Vault rejects the old token and the cache is invalidated.
+    async fn file_token(&self, path: &Path) -> Result<String, SignerError> {
+        let io_error = |e: std::io::Error| SignerError::IoError(format!("{}: {e}", path.display()));
+        let modified = tokio::fs::metadata(path)
+            .await
+            .and_then(|metadata| metadata.modified())
+            .map_err(io_error)?;
+
+        let mut cached = self.cached.lock().await;
+        if let Some(cached) = cached
+            .as_ref()
+            .filter(|cached| cached.modified == Some(modified))
+        {
+            return Ok(cached.token.clone());
+        }
+
+        let token = tokio::fs::read_to_string(path).await.map_err(io_error)?;
+        let token = token.trim();
+        if token.is_empty() {
+            // Vault Agent has not written a token yet
+            return Err(SignerError::NotAvailable(format!(
+                "Vault token file {} is empty",
+                path.display()
+            )));
+        }
+
+        *cached = Some(CachedToken {
+            token: token.to_string(),
+            expires_at: None,
+            modified: Some(modified),
+        });
+
+        Ok(token.to_string())
+    }
+
+    /// Drop a cached token that Vault rejected, so the next request logs in or
+    /// reads the token file again
+    pub(super) async fn invalidate(&self) {
+        *self.cached.lock().await = None;
+    }
+
+    async fn login(
+        kubernetes: &KubernetesAuth,
+        client: &Client,
+        vault_addr: &str,
+        interceptor: &dyn RequestInterceptor,
+    ) -> Result<LoginAuth, SignerError> {
+        let jwt = tokio::fs::read_to_string(&kubernetes.jwt_path)
+            .await
+            .map_err(|e| SignerError::IoError(format!("{}: {e}", kubernetes.jwt_path.display())))?;
+
+        let url = format!("{vault_addr}/v1/auth/{}/login", kubernetes.mount);
+        let request = client.post(&url).json(&LoginRequest {
+            role: &kubernetes.role,
+            jwt: jwt.trim(),
+        });
+
+        let response = interceptor.intercept(request).send().await.map_err(|e| {
+            SignerError::RemoteApiError(format!("Failed to send login request to Vault: {e}"))
+        })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            log::error!(
+                "Vault Kubernetes login failed - status: {status}, role: {}",
+                kubernetes.role
+            );
+
+            return Err(SignerError::RemoteApiError(format!(
+                "Vault Kubernetes login failed with status {status}"
+            )));
+        }
+
+        let login: LoginResponse = response.json().await.map_err(|_| {
+            SignerError::SerializationError("Failed to parse Vault login response".to_string())
+        })?;
+
+        Ok(login.auth)
+    }
+}
@@ -0,0 +1,458 @@
+//! Vault KV v2 keypair signer
+//!
+//! Reads a keypair stored in a KV v2 secret and signs locally, for deployments that
+//! keep Solana keys in KV rather than in a transit Ed25519 key. The secret field may
+//! hold a base58 keypair or a `[0, 1, ...]` byte array.
+//!
+//! Response bodies and decoded key bytes are zeroized once parsed; the keypair itself
+//! is wiped by ed25519-dalek when the signer is dropped.
+
+use super::VaultSigner;
+use crate::http_client::redacted_host;
+use crate::interceptor::{IdentityInterceptor, RequestInterceptor};
+use crate::memory::MemorySigner;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::{error::SignerError, traits::SolanaSigner};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+use zeroize::Zeroizing;
+
+/// Default KV v2 mount path
+pub const DEFAULT_KV_MOUNT: &str = "secret";
+
+/// Default secret field holding the keypair
+pub const DEFAULT_KV_FIELD: &str = "keypair";
+
+#[derive(Deserialize)]
+struct KvResponse {
+    data: KvData,
+}
+
+#[derive(Deserialize)]
+struct KvData {
+    /// `null` when the requested version has been deleted or destroyed
+    data: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// Signer whose keypair is read from a Vault KV v2 secret
+pub struct VaultKvSigner {
+    client: reqwest::Client,
+    interceptor: Arc<dyn RequestInterceptor>,
+    vault_addr: String,
+    token: String,
+    mount: String,
+    path: String,
+    field: String,
+    version: Option<u64>,
+    expected_pubkey: Option<Pubkey>,
+    signer: Box<OnceCell<MemorySigner>>,
+}
+
+impl std::fmt::Debug for VaultKvSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultKvSigner")
+            .field("mount", &self.mount)
+            .field("path", &self.path)
+            .field("pubkey", &self.pubkey())
+            .finish_non_exhaustive()
+    }
+}
+
+impl VaultKvSigner {
+    /// Create a new VaultKvSigner (requires initialization or an expected public key)
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_addr` - Vault server address (e.g., "https://vault.example.com")
+    /// * `token` - Vault token with read access to the secret
+    /// * `path` - Secret path within the KV mount (e.g., "solana/fee-payer")
+    pub fn new(vault_addr: String, token: String, path: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            interceptor: Arc::new(IdentityInterceptor),
+            vault_addr: vault_addr.trim_end_matches('/').to_string(),
+            token,
+            mount: DEFAULT_KV_MOUNT.to_string(),
+            path: path.trim_matches('/').to_string(),
+            field: DEFAULT_KV_FIELD.to_string(),
+            version: None,
+            expected_pubkey: None,
+            signer: Box::default(),
+        }
+    }
+
+    /// Read from the KV v2 engine mounted at `mount` (default `secret`)
+    pub fn with_mount(mut self, mount: String) -> Self {
+        self.mount = mount.trim_matches('/').to_string();
+        self
+    }
+
+    /// Read the keypair from `field` of the secret (default `keypair`)
+    pub fn with_field(mut self, field: String) -> Self {
+        self.field = field;
+        self
+    }
+
+    /// Pin a secret version instead of reading the latest one
+    pub fn with_version(mut self, version: u64) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Defer reading the secret until the first signature, reporting `pubkey` until then
+    ///
+    /// The loaded keypair must match `pubkey`, which also guards against the secret
+    /// being replaced with a different key.
+    pub fn with_expected_pubkey(mut self, pubkey: Pubkey) -> Self {
+        self.expected_pubkey = Some(pubkey);
+        self
+    }
+
+    /// Set an interceptor applied to every outbound request
+    pub fn with_request_interceptor(
+        mut self,
+        interceptor: impl RequestInterceptor + 'static,
+    ) -> Self {
+        self.interceptor = Arc::new(interceptor);
+        self
+    }
+
+    /// Initialize the signer by reading the keypair from Vault now
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        self.signer.get_or_try_init(|| self.load()).await?;
+        Ok(())
+    }
+
+    async fn loaded(&self) -> Result<&MemorySigner, SignerError> {
+        if self.signer.get().is_none() && self.expected_pubkey.is_none() {
+            // Without an expected key, pubkey() would have reported the default key
+            // to callers building transactions before this point
+            return Err(SignerError::ConfigError(
+                "VaultKvSigner is not initialized; call init() or set an expected pubkey"
+                    .to_string(),
+            ));
+        }
+
+        self.signer.get_or_try_init(|| self.load()).await
+    }
+
+    /// Fetch and decode the keypair, checking it against the expected public key
+    async fn load(&self) -> Result<MemorySigner, SignerError> {
+        let mut url = format!("{}/v1/{}/data/{}", self.vault_addr, self.mount, self.path);
+        if let Some(version) = self.version {
+            url.push_str(&format!("?version={version}"));
+        }
+
+        let request = self.client.get(&url).header("X-Vault-Token", &self.token);
+        let response = self.interceptor.intercept(request).send().await?;
+
+        let status = response.status();
+        let body = Zeroizing::new(
+            response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to read response".to_string()),
+        );
+
+        if !status.is_success() {
+            #[cfg(feature = "unsafe-debug")]
+            log::error!(
+                "Vault KV read error - status: {status}, response: {}",
+                *body
+            );
+
+            #[cfg(not(feature = "unsafe-debug"))]
+            log::error!("Vault KV read error - status: {status}");
+
+            if let Some(err) = VaultSigner::unavailable_error(&body) {
+                return Err(err);
+            }
+
+            return Err(SignerError::RemoteApiError(format!(
+                "Vault API error {status}"
+            )));
+        }
+
+        let response: KvResponse = serde_json::from_str(&body).map_err(|_| {
+            SignerError::SerializationError("Failed to parse Vault KV response".to_string())
+        })?;
+        let mut data = response.data.data.ok_or_else(|| {
+            SignerError::ConfigError(format!(
+                "Vault secret {}/{} version has been deleted",
+                self.mount, self.path
+            ))
+        })?;
+        let value = data.remove(&self.field).ok_or_else(|| {
+            SignerError::ConfigError(format!(
+                "Vault secret {}/{} has no field '{}'",
+                self.mount, self.path, self.field
+            ))
+        })?;
+
+        let signer = MemorySigner::from_bytes(&Self::decode_keypair(value)?)?;
+
+        if let Some(expected) = self.expected_pubkey {
+            if signer.pubkey() != expected {
+                return Err(SignerError::ConfigError(format!(
+                    "Vault secret {}/{} holds {}, expected {expected}",
+                    self.mount,
+                    self.path,
+                    signer.pubkey()
+                )));
+            }
+        }
+
+        Ok(signer)
+    }
+
+    fn decode_keypair(value: serde_json::Value) -> Result<Zeroizing<Vec<u8>>, SignerError> {
+        let invalid = || {
+            SignerError::InvalidPrivateKey(
+                "Vault secret field is not a base58 keypair or byte array".to_string(),
+            )
+        };
+
+        match value {
+            serde_json::Value::String(secret) => {
+                let secret = Zeroizing::new(secret);
+                let secret = secret.trim();
+                let bytes = if secret.starts_with('[') {
+                    serde_json::from_str::<Vec<u8>>(secret).map_err(|_| invalid())?
+                } else {
+                    bs58::decode(secret).into_vec().map_err(|_| invalid())?
+                };
+                Ok(Zeroizing::new(bytes))
+            }
+            value @ serde_json::Value::Array(_) => serde_json::from_value::<Vec<u8>>(value)
+                .map(Zeroizing::new)
+                .map_err(|_| invalid()),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for VaultKvSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.signer
+            .get()
+            .map(|signer| signer.pubkey())
+            .or(self.expected_pubkey)
+            .unwrap_or_default()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.loaded().await?.sign_transaction(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.loaded().await?.sign_message(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.loaded().await?.sign_partial_transaction(tx).await
+    }
+
+    /// Available once the keypair is loaded, or if it can be loaded now
+    async fn is_available(&self) -> bool {
+        self.loaded().await.is_ok()
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        // Only the first signature waits on Vault
+        LatencyClass::Local
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("vault-kv", self.pubkey(), self.latency_class())
+            .with_key_id(format!("{}/{}#{}", self.mount, self.path, self.field))
+            .with_endpoint(&self.vault_addr)
+    }
+
+    fn config_debug(&self) -> String {
+        let host = redacted_host(&self.vault_addr);
+
+        format!(
+            "backend=vault-kv host={host} mount={} path={} field={} loaded={} token=[REDACTED] pubkey={}",
+            self.mount,
+            self.path,
+            self.field,
+            self.signer.initialized(),
+            self.pubkey()
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        reqwest::Url::parse(&self.vault_addr)
+            .map_err(|e| SignerError::ConfigError(format!("Invalid Vault address: {e}")))?;
+
+        if self.path.is_empty() {
+            return Err(SignerError::ConfigError(
+                "Vault KV secret path must not be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn mock_secret(server: &MockServer, data: serde_json::Value) {
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/solana/payer"))
+            .and(header("X-Vault-Token", "test-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "data": data, "metadata": { "version": 3 } }
+            })))
+            .mount(server)
+            .await;
+    }
+
+    fn signer(server: &MockServer) -> VaultKvSigner {
+        VaultKvSigner::new(
+            server.uri(),
+            "test-token".to_string(),
+            "/solana/payer".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_vault_kv_init_and_sign() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        mock_secret(
+            &server,
+            serde_json::json!({ "keypair": keypair.to_base58_string() }),
+        )
+        .await;
+
+        let mut signer = signer(&server);
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), keypair_pubkey(&keypair));
+        assert_eq!(
+            signer.sign_message(b"hello").await.unwrap(),
+            keypair_sign_message(&keypair, b"hello")
+        );
+
+        let mut tx = create_test_transaction(&signer.pubkey());
+        signer.sign_transaction(&mut tx).await.unwrap();
+        assert!(tx.is_signed());
+    }
+
+    #[tokio::test]
+    async fn test_vault_kv_lazy_load() {
+        let server = MockServer::start().await;
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        mock_secret(
+            &server,
+            serde_json::json!({ "key": keypair.to_bytes().to_vec() }),
+        )
+        .await;
+
+        let uninitialized = signer(&server).with_field("key".to_string());
+        assert!(matches!(
+            uninitialized.sign_message(b"hello").await,
+            Err(SignerError::ConfigError(_))
+        ));
+        assert!(server.received_requests().await.unwrap().is_empty());
+
+        let lazy = signer(&server)
+            .with_field("key".to_string())
+            .with_expected_pubkey(pubkey);
+        assert_eq!(lazy.pubkey(), pubkey);
+        assert!(lazy.config_debug().contains("loaded=false"));
+
+        lazy.sign_message(b"hello").await.unwrap();
+        lazy.sign_message(b"again").await.unwrap();
+        assert!(lazy.config_debug().contains("loaded=true"));
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_vault_kv_expected_pubkey_mismatch() {
+        let server = MockServer::start().await;
+        mock_secret(
+            &server,
+            serde_json::json!({ "keypair": Keypair::new().to_base58_string() }),
+        )
+        .await;
+
+        let signer = signer(&server).with_expected_pubkey(Pubkey::new_unique());
+        let err = signer.sign_message(b"hello").await.unwrap_err();
+        assert!(matches!(err, SignerError::ConfigError(_)));
+        assert!(!signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_vault_kv_version_and_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/kv/data/solana/payer"))
+            .and(query_param("version", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "data": null, "metadata": { "version": 2 } }
+            })))
+            .mount(&server)
+            .await;
+
+        let mut deleted = signer(&server).with_mount("kv".to_string()).with_version(2);
+        assert!(matches!(
+            deleted.init().await,
+            Err(SignerError::ConfigError(_))
+        ));
+
+        let mut missing = signer(&server);
+        assert!(matches!(
+            missing.init().await,
+            Err(SignerError::RemoteApiError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_vault_kv_missing_field() {
+        let server = MockServer::start().await;
+        mock_secret(&server, serde_json::json!({ "other": "value" })).await;
+
+        let mut signer = signer(&server);
+        let err = signer.init().await.unwrap_err();
+        assert!(err.to_string().contains("no field 'keypair'"));
+    }
+
+    #[test]
+    fn test_vault_kv_config_debug_redacts_token() {
+        let signer = VaultKvSigner::new(
+            "https://vault.example.com".to_string(),
+            "s.secret-token".to_string(),
+            "solana/payer".to_string(),
+        );
+
+        let output = signer.config_debug();
+        assert!(output.contains("host=vault.example.com"));
+        assert!(output.contains("path=solana/payer"));
+        assert!(!output.contains("s.secret-token"));
+    }
+}
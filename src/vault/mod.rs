@@ -1,21 +1,66 @@
 //! HashiCorp Vault signer integration
 
+mod auth;
+#[cfg(feature = "vault-kv")]
+mod kv;
+
+pub use auth::{KubernetesAuth, VaultAuth, DEFAULT_SERVICE_ACCOUNT_TOKEN_PATH};
+#[cfg(feature = "vault-kv")]
+pub use kv::VaultKvSigner;
+
+use auth::TokenProvider;
+
+use crate::hedging::{hedged_check, Hedging};
+use crate::http_client::{redacted_host, with_deadline, HttpClientConfig, CONTEXT_HEADER};
+use crate::interceptor::{IdentityInterceptor, RequestInterceptor};
 use crate::sdk_adapter::{Pubkey, Signature, Transaction};
-use crate::traits::SignedTransaction;
-use crate::{error::SignerError, traits::SolanaSigner, transaction_util::TransactionUtil};
+use crate::traits::{
+    HealthStatus, SignOptions, SignedTransaction, SignerCapabilities, SignerMetadata,
+};
+use crate::{
+    error::SignerError,
+    traits::SolanaSigner,
+    transaction_util::{Encoding, TransactionUtil},
+};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use reqwest::Client;
 use serde_json::json;
+use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+/// Path the transit secrets engine is mounted at unless configured otherwise
+const DEFAULT_TRANSIT_MOUNT: &str = "transit";
+
+/// Server implementation behind the Vault address
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VaultFlavor {
+    /// HashiCorp Vault
+    #[default]
+    HashiCorp,
+    /// OpenBao, the Linux Foundation fork of Vault
+    ///
+    /// Gates availability on `sys/health` so sealed or uninitialized nodes are
+    /// reported as unavailable before the key is read.
+    OpenBao,
+}
 
 /// Vault-based signer using HashiCorp Vault transit engine
 #[derive(Clone)]
 pub struct VaultSigner {
     client: Arc<Client>,
     vault_addr: String,
-    token: String,
+    auth: Arc<TokenProvider>,
+    transit_mount: String,
     key_name: String,
     pubkey: Pubkey,
+    interceptor: Arc<dyn RequestInterceptor>,
+    output_encoding: Encoding,
+    hedging: Option<Hedging>,
+    http_config: HttpClientConfig,
+    flavor: VaultFlavor,
 }
 
 impl std::fmt::Debug for VaultSigner {
@@ -41,9 +86,42 @@ impl VaultSigner {
         key_name: String,
         pubkey: String,
     ) -> Result<Self, SignerError> {
-        let client = Client::new();
+        Self::new_with_auth(vault_addr, VaultAuth::Token(token), key_name, pubkey)
+    }
+
+    /// Creates a new Vault signer that authenticates with `auth`
+    ///
+    /// With [`VaultAuth::Kubernetes`] no request is made here; the first login
+    /// happens on the first request to Vault.
+    pub fn new_with_auth(
+        vault_addr: String,
+        auth: VaultAuth,
+        key_name: String,
+        pubkey: String,
+    ) -> Result<Self, SignerError> {
+        let pubkey = Self::parse_pubkey(&pubkey)?;
+
+        Ok(Self::from_parts(vault_addr, auth, key_name, pubkey))
+    }
 
-        let pubkey = Pubkey::try_from(
+    fn from_parts(vault_addr: String, auth: VaultAuth, key_name: String, pubkey: Pubkey) -> Self {
+        Self {
+            client: Arc::new(Client::new()),
+            vault_addr,
+            auth: Arc::new(TokenProvider::new(auth)),
+            transit_mount: DEFAULT_TRANSIT_MOUNT.to_string(),
+            key_name,
+            pubkey,
+            interceptor: Arc::new(IdentityInterceptor),
+            output_encoding: Encoding::default(),
+            hedging: None,
+            http_config: HttpClientConfig::default(),
+            flavor: VaultFlavor::default(),
+        }
+    }
+
+    fn parse_pubkey(pubkey: &str) -> Result<Pubkey, SignerError> {
+        Pubkey::try_from(
             bs58::decode(pubkey)
                 .into_vec()
                 .map_err(|e| {
@@ -53,29 +131,239 @@ impl VaultSigner {
                 })?
                 .as_slice(),
         )
-        .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid public key bytes: {e}")))?;
+        .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid public key bytes: {e}")))
+    }
 
-        Ok(Self {
-            client: Arc::new(client),
-            vault_addr,
-            token,
-            key_name,
-            pubkey,
-        })
+    /// Start building a Vault signer with named settings
+    pub fn builder() -> VaultSignerBuilder {
+        VaultSignerBuilder::default()
+    }
+
+    /// Talk to `flavor` instead of HashiCorp Vault
+    pub fn with_flavor(mut self, flavor: VaultFlavor) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// Use the transit engine mounted at `mount` instead of `transit`
+    pub fn with_transit_mount(mut self, mount: impl Into<String>) -> Self {
+        self.transit_mount = mount.into().trim_matches('/').to_string();
+        self
+    }
+
+    fn key_url(&self, vault_addr: &str) -> String {
+        format!(
+            "{vault_addr}/v1/{}/keys/{}",
+            self.transit_mount, self.key_name
+        )
+    }
+
+    /// Read the public key of the transit key from Vault
+    ///
+    /// Uses the latest key version, which is also the one transit signs with.
+    /// Fails with `SignerError::ConfigError` if the key is not Ed25519.
+    pub async fn fetch_pubkey(&self) -> Result<Pubkey, SignerError> {
+        let request = self
+            .authorize(self.client.get(self.key_url(&self.vault_addr)))
+            .await?;
+
+        let response = self
+            .interceptor
+            .intercept(request)
+            .send()
+            .await
+            .map_err(|e| {
+                SignerError::RemoteApiError(format!("Failed to send request to Vault: {e}"))
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            log::error!("Vault key lookup error - status: {status}");
+
+            return Err(SignerError::RemoteApiError(format!(
+                "Vault key lookup failed with status {status}"
+            )));
+        }
+
+        let result: serde_json::Value = response.json().await.map_err(|_| {
+            SignerError::SerializationError("Failed to parse Vault key response".to_string())
+        })?;
+        let data = &result["data"];
+
+        let key_type = data["type"].as_str().unwrap_or_default();
+        if key_type != "ed25519" {
+            return Err(SignerError::ConfigError(format!(
+                "Vault transit key {} is {key_type}, expected ed25519",
+                self.key_name
+            )));
+        }
+
+        let version = data["latest_version"].as_u64().ok_or_else(|| {
+            SignerError::RemoteApiError("No latest_version in Vault key response".to_string())
+        })?;
+        let public_key = data["keys"][version.to_string()]["public_key"]
+            .as_str()
+            .ok_or_else(|| {
+                SignerError::RemoteApiError(format!(
+                    "No public key for version {version} in Vault key response"
+                ))
+            })?;
+
+        let bytes = STANDARD.decode(public_key).map_err(|e| {
+            SignerError::InvalidPublicKey(format!("Failed to decode public key: {e}"))
+        })?;
+
+        Pubkey::try_from(bytes.as_slice())
+            .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid public key bytes: {e}")))
+    }
+
+    /// Attach the current token
+    ///
+    /// Login-based auth always logs in against the primary address, also for
+    /// hedged checks, since tokens are valid across the cluster.
+    async fn authorize(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::RequestBuilder, SignerError> {
+        let token = self
+            .auth
+            .token(&self.client, &self.vault_addr, self.interceptor.as_ref())
+            .await?;
+
+        Ok(request.header("X-Vault-Token", token))
     }
 
-    async fn sign_bytes(&self, serialized: &[u8]) -> Result<Signature, SignerError> {
-        let url = format!("{}/v1/transit/sign/{}", self.vault_addr, self.key_name);
+    /// Set an interceptor applied to every outbound request
+    pub fn with_request_interceptor(
+        mut self,
+        interceptor: impl RequestInterceptor + 'static,
+    ) -> Self {
+        self.interceptor = Arc::new(interceptor);
+        self
+    }
+
+    /// Set the encoding used for serialized transactions returned by this signer
+    pub fn with_output_encoding(mut self, encoding: Encoding) -> Self {
+        self.output_encoding = encoding;
+        self
+    }
 
+    async fn sign_bytes(
+        &self,
+        serialized: &[u8],
+        options: &SignOptions,
+    ) -> Result<Signature, SignerError> {
         let payload = json!({
             "input": STANDARD.encode(serialized)
         });
 
+        let result = self.transit_sign(&payload, options).await?;
+
+        let signature = result["data"]["signature"].as_str().ok_or_else(|| {
+            SignerError::RemoteApiError("No signature in Vault response".to_string())
+        })?;
+
+        Self::parse_signature(signature)
+    }
+
+    /// Sign several payloads with one transit `batch_input` request
+    ///
+    /// Returns one result per payload, in order. Vault reports per-item failures
+    /// in `batch_results`; a failure of the request itself is repeated for every
+    /// payload.
+    async fn sign_bytes_batch(
+        &self,
+        payloads: &[Vec<u8>],
+        options: &SignOptions,
+    ) -> Vec<Result<Signature, SignerError>> {
+        if payloads.is_empty() {
+            return Vec::new();
+        }
+
+        let payload = json!({
+            "batch_input": payloads
+                .iter()
+                .map(|payload| json!({ "input": STANDARD.encode(payload) }))
+                .collect::<Vec<_>>()
+        });
+
+        let results = match self.transit_sign(&payload, options).await {
+            Ok(result) => result["data"]["batch_results"].as_array().cloned(),
+            Err(e) => {
+                return payloads
+                    .iter()
+                    .map(|_| Err(Self::batch_failure(&e)))
+                    .collect()
+            }
+        };
+        let Some(results) = results.filter(|results| results.len() == payloads.len()) else {
+            return payloads
+                .iter()
+                .map(|_| {
+                    Err(SignerError::RemoteApiError(
+                        "Vault batch response does not match the request".to_string(),
+                    ))
+                })
+                .collect();
+        };
+
+        results
+            .iter()
+            .map(|result| match result["signature"].as_str() {
+                Some(signature) => Self::parse_signature(signature),
+                None => Err(SignerError::SigningFailed(format!(
+                    "Vault batch item failed: {}",
+                    result["error"].as_str().unwrap_or("no signature returned")
+                ))),
+            })
+            .collect()
+    }
+
+    /// Rebuild the error of a failed batch request for one of its payloads
+    fn batch_failure(error: &SignerError) -> SignerError {
+        match error {
+            SignerError::NotAvailable(msg) => SignerError::NotAvailable(msg.clone()),
+            SignerError::SerializationError(msg) => SignerError::SerializationError(msg.clone()),
+            SignerError::IoError(msg) => SignerError::IoError(msg.clone()),
+            SignerError::RemoteApiError(msg) => SignerError::RemoteApiError(msg.clone()),
+            other => SignerError::RemoteApiError(format!("Vault batch request failed: {other}")),
+        }
+    }
+
+    /// Sign several messages with one request to Vault
+    ///
+    /// # Returns
+    ///
+    /// One result per message, in the same order as `messages`
+    pub async fn sign_many_messages(
+        &self,
+        messages: &[&[u8]],
+    ) -> Vec<Result<Signature, SignerError>> {
+        let payloads: Vec<Vec<u8>> = messages.iter().map(|message| message.to_vec()).collect();
+
+        self.sign_bytes_batch(&payloads, &SignOptions::default())
+            .await
+    }
+
+    /// POST `payload` to the transit sign endpoint and return the parsed response
+    async fn transit_sign(
+        &self,
+        payload: &serde_json::Value,
+        options: &SignOptions,
+    ) -> Result<serde_json::Value, SignerError> {
+        let url = format!(
+            "{}/v1/{}/sign/{}",
+            self.vault_addr, self.transit_mount, self.key_name
+        );
+
+        let mut request = self.authorize(self.client.post(&url).json(payload)).await?;
+        if let Some(context) = &options.context {
+            request = request.header(CONTEXT_HEADER, context);
+        }
+
         let response = self
-            .client
-            .post(&url)
-            .header("X-Vault-Token", &self.token)
-            .json(&payload)
+            .interceptor
+            .intercept(request)
             .send()
             .await
             .map_err(|e| {
@@ -84,6 +372,10 @@ impl VaultSigner {
 
         if !response.status().is_success() {
             let status = response.status();
+            if status == reqwest::StatusCode::FORBIDDEN {
+                // The login token may have been revoked; log in again next time
+                self.auth.invalidate().await;
+            }
 
             let error_text = response
                 .text()
@@ -96,24 +388,24 @@ impl VaultSigner {
             #[cfg(not(feature = "unsafe-debug"))]
             log::error!("Vault API error - status: {status}");
 
+            if let Some(err) = Self::unavailable_error(&error_text) {
+                return Err(err);
+            }
+
             return Err(SignerError::RemoteApiError(format!(
                 "Vault API error {}",
                 status
             )));
         }
 
-        let result: serde_json::Value = response.json().await.map_err(|_| {
+        response.json().await.map_err(|_| {
             SignerError::SerializationError("Failed to parse Vault response".to_string())
-        })?;
-
-        let signature_b64 = result["data"]["signature"].as_str().ok_or_else(|| {
-            SignerError::RemoteApiError("No signature in Vault response".to_string())
-        })?;
+        })
+    }
 
-        // Remove the version prefix (e.g., "vault:v1:") if present
-        let signature_b64 = signature_b64
-            .strip_prefix("vault:v1:")
-            .unwrap_or(signature_b64);
+    /// Decode a transit signature, with or without its version prefix
+    fn parse_signature(signature: &str) -> Result<Signature, SignerError> {
+        let signature_b64 = Self::strip_version_prefix(signature);
 
         let sig_bytes = STANDARD.decode(signature_b64).map_err(|_| {
             SignerError::SerializationError("Failed to decode signature".to_string())
@@ -123,21 +415,456 @@ impl VaultSigner {
             .map_err(|_| SignerError::SigningFailed("Invalid signature format".to_string()))
     }
 
+    /// Resolve `domain` to a pinned address instead of using system DNS
+    ///
+    /// The port of the request URL is used when present, so include it in the base
+    /// URL when pointing at a non-standard port. Fails with
+    /// `SignerError::ConfigError` if the signer was built with the builder's
+    /// `client`; set the override on that client instead.
+    pub fn with_resolve(mut self, domain: &str, addr: SocketAddr) -> Result<Self, SignerError> {
+        self.http_config.resolve.push((domain.to_string(), addr));
+        self.client = Arc::new(self.http_config.rebuild("with_resolve")?);
+        Ok(self)
+    }
+
+    /// Hedge availability checks against a secondary Vault address
+    ///
+    /// If the primary address has not answered after `delay`, the same check is sent
+    /// to `secondary_base_url` and the first successful response wins. Signing
+    /// requests are never hedged.
+    pub fn with_hedging(mut self, delay: Duration, secondary_base_url: String) -> Self {
+        self.hedging = Some(Hedging {
+            delay,
+            secondary_base_url,
+        });
+        self
+    }
+
+    /// Remove the `vault:v<N>:` version prefix if present
+    fn strip_version_prefix(signature: &str) -> &str {
+        let Some((prefix, rest)) = signature.split_once(':') else {
+            return signature;
+        };
+        if prefix != "vault" {
+            return signature;
+        }
+
+        match rest.split_once(':') {
+            Some((version, signature))
+                if version.len() > 1
+                    && version.starts_with('v')
+                    && version[1..].bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                signature
+            }
+            _ => signature,
+        }
+    }
+
+    /// Check if we can read the key metadata at the given Vault address
+    ///
+    /// For OpenBao, `sys/health` must first report an unsealed, initialized node
+    /// (standbys are accepted, since they forward requests to the active node).
+    async fn check_availability(&self, vault_addr: String) -> bool {
+        if self.flavor == VaultFlavor::OpenBao {
+            let url = format!("{vault_addr}/v1/sys/health?standbyok=true&perfstandbyok=true");
+            let healthy = match self
+                .interceptor
+                .intercept(self.client.get(&url))
+                .send()
+                .await
+            {
+                Ok(resp) => resp.status().is_success(),
+                Err(_) => false,
+            };
+            if !healthy {
+                return false;
+            }
+        }
+
+        let url = self.key_url(&vault_addr);
+
+        let Ok(request) = self.authorize(self.client.get(&url)).await else {
+            return false;
+        };
+
+        let response = self.interceptor.intercept(request).send().await;
+
+        match response {
+            Ok(resp) => resp.status().is_success(),
+            Err(_) => false,
+        }
+    }
+
+    /// Check one Vault node for [`SolanaSigner::health`]
+    ///
+    /// Returns `Ok(None)` if healthy, `Ok(Some(reason))` if it can sign but is
+    /// degraded, and `Err(reason)` if it cannot sign.
+    async fn node_health(&self, vault_addr: &str) -> Result<Option<String>, String> {
+        // sys/health is unauthenticated; unexpected statuses (e.g. from a proxy) fall
+        // through to the key lookup
+        let url = format!("{vault_addr}/v1/sys/health");
+        let degraded = match self
+            .interceptor
+            .intercept(self.client.get(&url))
+            .send()
+            .await
+        {
+            Ok(resp) => match resp.status().as_u16() {
+                429 => Some("Vault node is a standby".to_string()),
+                473 => Some("Vault node is a performance standby".to_string()),
+                472 => return Err("Vault node is a disaster recovery secondary".to_string()),
+                501 => return Err("Vault is not initialized".to_string()),
+                503 => return Err("Vault is sealed".to_string()),
+                _ => None,
+            },
+            Err(e) => return Err(format!("Vault unreachable: {e}")),
+        };
+
+        let url = self.key_url(vault_addr);
+        let request = self
+            .authorize(self.client.get(&url))
+            .await
+            .map_err(|e| format!("Vault authentication failed: {e}"))?;
+
+        match self.interceptor.intercept(request).send().await {
+            Ok(resp) if resp.status().is_success() => Ok(degraded),
+            Ok(resp) => Err(format!("Key lookup failed with status {}", resp.status())),
+            Err(e) => Err(format!("Vault unreachable: {e}")),
+        }
+    }
+
+    /// Sign a transaction, aborting the in-flight request if `token` is cancelled
+    ///
+    /// Returns `SignerError::Other("cancelled")` on cancellation.
+    pub async fn sign_transaction_cancellable(
+        &self,
+        tx: &mut Transaction,
+        token: CancellationToken,
+    ) -> Result<SignedTransaction, SignerError> {
+        let options = SignOptions::default();
+
+        tokio::select! {
+            _ = token.cancelled() => Err(SignerError::Other("cancelled".to_string())),
+            result = self.sign_and_serialize(tx, &options) => result,
+        }
+    }
+
+    /// Sign a transaction within the time remaining until `deadline`
+    ///
+    /// Returns `SignerError::Timeout` immediately if `deadline` has already passed,
+    /// or once it passes while the request is in flight.
+    pub async fn sign_transaction_by_deadline(
+        &self,
+        tx: &mut Transaction,
+        deadline: Instant,
+    ) -> Result<SignedTransaction, SignerError> {
+        with_deadline(
+            deadline,
+            self.sign_and_serialize(tx, &SignOptions::default()),
+        )
+        .await
+    }
+
+    /// Map Vault's sealed/standby error bodies to `SignerError::NotAvailable`
+    ///
+    /// Vault reports these conditions as `{"errors": ["..."]}`, typically with a 503
+    /// (sealed) or 429/500 (standby) status.
+    fn unavailable_error(error_text: &str) -> Option<SignerError> {
+        let body: serde_json::Value = serde_json::from_str(error_text).ok()?;
+        let errors = body["errors"].as_array()?;
+
+        let mentions = |needle: &str| {
+            errors
+                .iter()
+                .filter_map(|e| e.as_str())
+                .any(|e| e.to_lowercase().contains(needle))
+        };
+
+        if mentions("sealed") {
+            Some(SignerError::NotAvailable("vault sealed".to_string()))
+        } else if mentions("standby") {
+            Some(SignerError::NotAvailable("vault standby".to_string()))
+        } else {
+            None
+        }
+    }
+
     async fn sign_and_serialize(
         &self,
         transaction: &mut Transaction,
+        options: &SignOptions,
     ) -> Result<SignedTransaction, SignerError> {
-        let signature = self.sign_bytes(&transaction.message_data()).await?;
+        let signature = self
+            .sign_bytes(&transaction.message_data(), options)
+            .await?;
 
         TransactionUtil::add_signature_to_transaction(transaction, &self.pubkey, signature)?;
 
-        Ok((
-            TransactionUtil::serialize_transaction(transaction)?,
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction_with_encoding(
+                transaction,
+                self.output_encoding,
+            )?,
             signature,
-        ))
+            self.pubkey,
+        )
+        .with_encoding(self.output_encoding))
+    }
+
+    /// Sign this signer's slot of a multi-party transaction
+    ///
+    /// The pubkey is checked against the required signers before calling Vault,
+    /// and a valid signature already in the slot is reused instead of signing
+    /// again. Other slots are left as they are and may stay empty.
+    async fn sign_partial_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+        options: &SignOptions,
+    ) -> Result<SignedTransaction, SignerError> {
+        match TransactionUtil::existing_signature(transaction, &self.pubkey)? {
+            Some(signature) => Ok(SignedTransaction::new(
+                TransactionUtil::serialize_transaction_with_encoding(
+                    transaction,
+                    self.output_encoding,
+                )?,
+                signature,
+                self.pubkey,
+            )
+            .with_encoding(self.output_encoding)),
+            None => self.sign_and_serialize(transaction, options).await,
+        }
+    }
+}
+
+/// Builder for [`VaultSigner`], created with [`VaultSigner::builder`]
+///
+/// `vault_addr`, `key_name` and one of `token`, `token_file` or `kubernetes_auth`
+/// are required.
+/// [`Self::build`] also requires `pubkey`; [`Self::connect`] reads it from Vault.
+#[derive(Default)]
+pub struct VaultSignerBuilder {
+    vault_addr: Option<String>,
+    auth: Option<VaultAuth>,
+    transit_mount: Option<String>,
+    key_name: Option<String>,
+    pubkey: Option<String>,
+    flavor: VaultFlavor,
+    output_encoding: Encoding,
+    interceptor: Option<Arc<dyn RequestInterceptor>>,
+    http_config: HttpClientConfig,
+    ca_bundle: Option<Vec<u8>>,
+    client_certificate: Option<(Vec<u8>, Vec<u8>)>,
+    client: Option<Client>,
+}
+
+impl VaultSignerBuilder {
+    /// Vault server address (e.g., "https://vault.example.com")
+    pub fn vault_addr(mut self, vault_addr: impl Into<String>) -> Self {
+        self.vault_addr = Some(vault_addr.into());
+        self
+    }
+
+    /// Vault authentication token
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.auth = Some(VaultAuth::Token(token.into()));
+        self
+    }
+
+    /// Read the token from `path`, e.g. a Vault Agent sink, instead of a fixed token
+    ///
+    /// The file is read again whenever it changes.
+    pub fn token_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.auth = Some(VaultAuth::TokenFile(path.into()));
+        self
+    }
+
+    /// Log in with the pod's Kubernetes service account instead of a fixed token
+    pub fn kubernetes_auth(mut self, auth: KubernetesAuth) -> Self {
+        self.auth = Some(VaultAuth::Kubernetes(auth));
+        self
+    }
+
+    /// Vault key name in the transit engine
+    pub fn key_name(mut self, key_name: impl Into<String>) -> Self {
+        self.key_name = Some(key_name.into());
+        self
+    }
+
+    /// Path the transit engine is mounted at, `transit` by default
+    pub fn transit_mount(mut self, mount: impl Into<String>) -> Self {
+        self.transit_mount = Some(mount.into());
+        self
+    }
+
+    /// Base58-encoded public key of the transit key
+    pub fn pubkey(mut self, pubkey: impl Into<String>) -> Self {
+        self.pubkey = Some(pubkey.into());
+        self
+    }
+
+    /// Server implementation, HashiCorp Vault by default
+    pub fn flavor(mut self, flavor: VaultFlavor) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// Encoding of serialized transactions returned by the signer
+    pub fn output_encoding(mut self, encoding: Encoding) -> Self {
+        self.output_encoding = encoding;
+        self
+    }
+
+    /// Interceptor applied to every outbound request
+    pub fn request_interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.interceptor = Some(Arc::new(interceptor));
+        self
+    }
+
+    /// Total timeout for each request to Vault
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = Some(timeout);
+        self
+    }
+
+    /// Trust an extra CA certificate for Vault's TLS certificate
+    pub fn root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.http_config.root_certificates.push(certificate);
+        self
+    }
+
+    /// Trust every certificate in a PEM bundle, e.g. the private CA that issued
+    /// Vault's certificate (what `VAULT_CACERT` points to)
+    ///
+    /// The bundle is parsed by [`Self::build`], which fails with
+    /// `SignerError::ConfigError` if it is invalid.
+    pub fn ca_bundle(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.ca_bundle = Some(pem.into());
+        self
+    }
+
+    /// Present a client certificate for Vault's TLS cert auth or an mTLS proxy
+    ///
+    /// # Arguments
+    ///
+    /// * `certificate_pem` - PEM-encoded client certificate (chain)
+    /// * `private_key_pem` - PEM-encoded PKCS#8 private key for the certificate
+    pub fn client_certificate(
+        mut self,
+        certificate_pem: impl Into<Vec<u8>>,
+        private_key_pem: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.client_certificate = Some((certificate_pem.into(), private_key_pem.into()));
+        self
+    }
+
+    /// Skip verification of Vault's TLS certificate
+    ///
+    /// For local development against a self-signed dev server only: anyone on
+    /// the network path can impersonate Vault and see the token.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.http_config.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Use a preconfigured HTTP client
+    ///
+    /// Takes precedence over [`Self::timeout`] and the TLS settings, and makes
+    /// [`VaultSigner::with_resolve`] fail instead of replacing this client.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self.http_config.custom_client = true;
+        self
+    }
+
+    /// Build the signer, failing with `SignerError::ConfigError` if a required
+    /// setting is missing
+    pub fn build(mut self) -> Result<VaultSigner, SignerError> {
+        let pubkey = VaultSigner::parse_pubkey(&required(self.pubkey.take(), "pubkey")?)?;
+
+        self.assemble(pubkey)
+    }
+
+    /// Build the signer and read its public key from the transit key
+    ///
+    /// If [`Self::pubkey`] was also set, fails with `SignerError::ConfigError`
+    /// when it does not match the key in Vault.
+    pub async fn connect(mut self) -> Result<VaultSigner, SignerError> {
+        let expected = self
+            .pubkey
+            .take()
+            .map(|pubkey| VaultSigner::parse_pubkey(&pubkey))
+            .transpose()?;
+
+        let mut signer = self.assemble(Pubkey::default())?;
+        let pubkey = signer.fetch_pubkey().await?;
+        if let Some(expected) = expected.filter(|expected| *expected != pubkey) {
+            return Err(SignerError::ConfigError(format!(
+                "Configured pubkey {expected} does not match Vault transit key {pubkey}"
+            )));
+        }
+        signer.pubkey = pubkey;
+
+        Ok(signer)
+    }
+
+    fn assemble(self, pubkey: Pubkey) -> Result<VaultSigner, SignerError> {
+        let auth = self.auth.ok_or_else(|| {
+            SignerError::ConfigError(
+                "VaultSignerBuilder: token, token_file or kubernetes_auth is required".to_string(),
+            )
+        })?;
+        let mut signer = VaultSigner::from_parts(
+            required(self.vault_addr, "vault_addr")?,
+            auth,
+            required(self.key_name, "key_name")?,
+            pubkey,
+        );
+        if let Some(mount) = self.transit_mount {
+            signer = signer.with_transit_mount(mount);
+        }
+
+        let mut http_config = self.http_config;
+        if let Some(pem) = &self.ca_bundle {
+            let certificates = reqwest::Certificate::from_pem_bundle(pem)
+                .map_err(|e| SignerError::ConfigError(format!("Invalid CA bundle: {e}")))?;
+            if certificates.is_empty() {
+                return Err(SignerError::ConfigError(
+                    "Invalid CA bundle: no certificates found".to_string(),
+                ));
+            }
+            http_config.root_certificates.extend(certificates);
+        }
+        if let Some((certificate_pem, private_key_pem)) = &self.client_certificate {
+            let identity = reqwest::Identity::from_pkcs8_pem(certificate_pem, private_key_pem)
+                .map_err(|e| {
+                    SignerError::ConfigError(format!("Invalid client certificate: {e}"))
+                })?;
+            http_config.identity = Some(identity);
+        }
+        if http_config.accept_invalid_certs {
+            log::warn!("Vault TLS certificate verification is disabled");
+        }
+
+        signer.client = Arc::new(match self.client {
+            Some(client) => client,
+            None => http_config.build()?,
+        });
+        signer.http_config = http_config;
+        signer.flavor = self.flavor;
+        signer.output_encoding = self.output_encoding;
+        if let Some(interceptor) = self.interceptor {
+            signer.interceptor = interceptor;
+        }
+
+        Ok(signer)
     }
 }
 
+fn required(value: Option<String>, name: &str) -> Result<String, SignerError> {
+    value.ok_or_else(|| SignerError::ConfigError(format!("VaultSignerBuilder: {name} is required")))
+}
+
 #[async_trait::async_trait]
 impl SolanaSigner for VaultSigner {
     fn pubkey(&self) -> Pubkey {
@@ -148,41 +875,166 @@ impl SolanaSigner for VaultSigner {
         &self,
         tx: &mut Transaction,
     ) -> Result<SignedTransaction, SignerError> {
-        self.sign_and_serialize(tx).await
+        self.sign_and_serialize(tx, &SignOptions::default()).await
     }
 
     async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
-        self.sign_bytes(message).await
+        self.sign_bytes(message, &SignOptions::default()).await
     }
 
     async fn sign_partial_transaction(
         &self,
         tx: &mut Transaction,
     ) -> Result<SignedTransaction, SignerError> {
-        self.sign_and_serialize(tx).await
+        self.sign_partial_and_serialize(tx, &SignOptions::default())
+            .await
     }
 
-    async fn is_available(&self) -> bool {
-        // Check if we can read the key metadata as a health check
-        let url = format!("{}/v1/transit/keys/{}", self.vault_addr, self.key_name);
+    /// Forwards the caller context in the `X-Signer-Context` header; add it to
+    /// Vault's audited request headers to record it in the audit log
+    async fn sign_transaction_with(
+        &self,
+        tx: &mut Transaction,
+        options: SignOptions,
+    ) -> Result<SignedTransaction, SignerError> {
+        options
+            .within_deadline(self.sign_and_serialize(tx, &options))
+            .await
+    }
 
-        let response = self
-            .client
-            .get(&url)
-            .header("X-Vault-Token", &self.token)
-            .send()
+    /// Signs every transaction with a single transit `batch_input` request
+    async fn sign_all_transactions(
+        &self,
+        txs: &mut [Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        let messages: Vec<Vec<u8>> = txs.iter().map(|tx| tx.message_data()).collect();
+        let signatures = self
+            .sign_bytes_batch(&messages, &SignOptions::default())
             .await;
 
-        match response {
-            Ok(resp) => resp.status().is_success(),
-            Err(_) => false,
+        txs.iter_mut()
+            .zip(signatures)
+            .map(|(tx, signature)| {
+                let signature = signature?;
+                TransactionUtil::add_signature_to_transaction(tx, &self.pubkey, signature)?;
+
+                Ok(SignedTransaction::new(
+                    TransactionUtil::serialize_transaction_with_encoding(tx, self.output_encoding)?,
+                    signature,
+                    self.pubkey,
+                )
+                .with_encoding(self.output_encoding))
+            })
+            .collect()
+    }
+
+    async fn is_available(&self) -> bool {
+        // Check if we can read the key metadata as a health check
+        hedged_check(self.hedging.as_ref(), &self.vault_addr, |vault_addr| {
+            self.check_availability(vault_addr)
+        })
+        .await
+    }
+
+    /// Reports standby nodes as degraded and sealed or uninitialized nodes as
+    /// unavailable. With hedging, a failed primary whose secondary is usable is
+    /// reported as degraded.
+    async fn health(&self) -> HealthStatus {
+        let start = Instant::now();
+
+        let result = match (self.node_health(&self.vault_addr).await, &self.hedging) {
+            (Err(reason), Some(hedging)) => {
+                match self.node_health(&hedging.secondary_base_url).await {
+                    Ok(_) => Ok(Some(format!(
+                        "{reason}; secondary {} is usable",
+                        hedging.secondary_base_url
+                    ))),
+                    Err(_) => Err(reason),
+                }
+            }
+            (result, _) => result,
+        };
+
+        match result {
+            Ok(None) => HealthStatus::healthy(start.elapsed()),
+            Ok(Some(reason)) => HealthStatus::degraded(reason, start.elapsed()),
+            Err(reason) => HealthStatus::unavailable(reason, start.elapsed()),
+        }
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities {
+            supports_batch: true,
+            ..SignerCapabilities::default()
+        }
+    }
+
+    fn output_encoding(&self) -> Encoding {
+        self.output_encoding
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        let backend = match self.flavor {
+            VaultFlavor::HashiCorp => "vault",
+            VaultFlavor::OpenBao => "openbao",
+        };
+
+        SignerMetadata::new(backend, self.pubkey, self.latency_class())
+            .with_key_id(&self.key_name)
+            .with_endpoint(&self.vault_addr)
+    }
+
+    fn config_debug(&self) -> String {
+        let host = redacted_host(&self.vault_addr);
+
+        let backend = match self.flavor {
+            VaultFlavor::HashiCorp => "vault",
+            VaultFlavor::OpenBao => "openbao",
+        };
+
+        let auth = match self.auth.auth() {
+            VaultAuth::Token(_) => "token=[REDACTED]".to_string(),
+            VaultAuth::TokenFile(path) => format!("token_file={}", path.display()),
+            VaultAuth::Kubernetes(kubernetes) => {
+                format!("auth=kubernetes role={}", kubernetes.role())
+            }
+        };
+
+        let tls = if self.http_config.accept_invalid_certs {
+            " tls_verify=false"
+        } else {
+            ""
+        };
+
+        format!(
+            "backend={backend} host={host} key_name={} {auth} pubkey={}{tls}",
+            self.key_name, self.pubkey
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        reqwest::Url::parse(&self.vault_addr)
+            .map_err(|e| SignerError::ConfigError(format!("Invalid Vault address: {e}")))?;
+
+        if self.key_name.is_empty() {
+            return Err(SignerError::ConfigError(
+                "Vault key name must not be empty".to_string(),
+            ));
         }
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::create_test_transaction;
+    use crate::traits::HealthState;
+    use wiremock::{
+        matchers::{body_json, header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
 
     const TEST_VAULT_ADDR: &str = "http://127.0.0.1:8200";
     const TEST_VAULT_TOKEN: &str = "test-token";
@@ -235,4 +1087,975 @@ mod tests {
         assert!(debug_str.contains("VaultSigner"));
         assert!(debug_str.contains("pubkey"));
     }
+
+    #[test]
+    fn test_latency_class() {
+        assert_eq!(
+            create_test_signer().latency_class(),
+            crate::traits::LatencyClass::Remote
+        );
+    }
+
+    #[test]
+    fn test_config_debug() {
+        let output = create_test_signer().config_debug();
+        assert!(output.contains("backend=vault"));
+        assert!(output.contains("host=127.0.0.1"));
+        assert!(output.contains(TEST_KEY_NAME));
+        assert!(output.contains(TEST_PUBKEY));
+        assert!(!output.contains(TEST_VAULT_TOKEN));
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = create_test_signer().metadata();
+
+        assert_eq!(metadata.backend, "vault");
+        assert_eq!(metadata.pubkey.to_string(), TEST_PUBKEY);
+        assert_eq!(metadata.key_id.as_deref(), Some(TEST_KEY_NAME));
+        assert_eq!(metadata.endpoint.as_deref(), Some(TEST_VAULT_ADDR));
+
+        let metadata = create_test_signer()
+            .with_flavor(VaultFlavor::OpenBao)
+            .metadata();
+        assert_eq!(metadata.backend, "openbao");
+    }
+
+    #[test]
+    fn test_validate_config() {
+        assert!(create_test_signer().validate_config().is_ok());
+
+        let signer = VaultSigner::new(
+            "not a url".to_string(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap();
+        assert!(matches!(
+            signer.validate_config(),
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_request_interceptor_adds_header() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/transit/keys/test-key"))
+            .and(header("X-Proxy-Envelope", "wrapped"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap()
+        .with_request_interceptor(|req: reqwest::RequestBuilder| {
+            req.header("X-Proxy-Envelope", "wrapped")
+        });
+
+        assert!(signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_sign_vault_sealed() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "errors": ["Vault is sealed"]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap();
+
+        let result = signer.sign_message(b"test").await;
+        match result {
+            Err(SignerError::NotAvailable(msg)) => assert_eq!(msg, "vault sealed"),
+            other => panic!("Expected NotAvailable, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_vault_standby() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+                "errors": ["node is in standby mode"]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap();
+
+        let result = signer.sign_message(b"test").await;
+        match result {
+            Err(SignerError::NotAvailable(msg)) => assert_eq!(msg, "vault standby"),
+            other => panic!("Expected NotAvailable, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_vault_generic_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "errors": ["permission denied"]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap();
+
+        let result = signer.sign_message(b"test").await;
+        assert!(matches!(result, Err(SignerError::RemoteApiError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction_cancellable() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap();
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            cancel.cancel();
+        });
+
+        let started = Instant::now();
+        let result = signer.sign_transaction_cancellable(&mut tx, token).await;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        match result {
+            Err(SignerError::Other(msg)) => assert_eq!(msg, "cancelled"),
+            other => panic!("Expected cancellation, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction_by_deadline_expired() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap();
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        let result = signer
+            .sign_transaction_by_deadline(&mut tx, Instant::now())
+            .await;
+
+        assert!(matches!(result, Err(SignerError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction_with_forwards_context() {
+        let mock_server = MockServer::start().await;
+        let signature = Signature::from([7u8; 64]);
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .and(header(CONTEXT_HEADER, "request-42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "signature": format!("vault:v1:{}", STANDARD.encode(signature))
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap();
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        let signed = signer
+            .sign_transaction_with(
+                &mut tx,
+                SignOptions::new()
+                    .with_context("request-42")
+                    .with_timeout(Duration::from_secs(5)),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(signed.signature, signature);
+
+        let result = signer
+            .sign_transaction_with(&mut tx, SignOptions::new().with_deadline(Instant::now()))
+            .await;
+        assert!(matches!(result, Err(SignerError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction_by_deadline_exceeded() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap();
+        let mut tx = create_test_transaction(&signer.pubkey());
+
+        let started = Instant::now();
+        let result = signer
+            .sign_transaction_by_deadline(&mut tx, started + Duration::from_millis(50))
+            .await;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(matches!(result, Err(SignerError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_is_available_hedged() {
+        let primary = MockServer::start().await;
+        let secondary = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/transit/keys/test-key"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+            .mount(&primary)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/transit/keys/test-key"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&secondary)
+            .await;
+
+        let signer = VaultSigner::new(
+            primary.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap()
+        .with_hedging(Duration::from_millis(50), secondary.uri());
+
+        let started = Instant::now();
+        assert!(signer.is_available().await);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    async fn mount_health(server: &MockServer, health_status: u16, key_status: u16) {
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/health"))
+            .respond_with(ResponseTemplate::new(health_status))
+            .mount(server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/transit/keys/test-key"))
+            .respond_with(ResponseTemplate::new(key_status))
+            .mount(server)
+            .await;
+    }
+
+    fn create_signer_for(vault_addr: String) -> VaultSigner {
+        VaultSigner::new(
+            vault_addr,
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_health() {
+        let cases = [
+            (200, 200, HealthState::Healthy, None),
+            (429, 200, HealthState::Degraded, Some("standby")),
+            (473, 200, HealthState::Degraded, Some("performance standby")),
+            (503, 200, HealthState::Unavailable, Some("sealed")),
+            (501, 200, HealthState::Unavailable, Some("not initialized")),
+            (200, 403, HealthState::Unavailable, Some("403")),
+        ];
+
+        for (health_status, key_status, state, reason) in cases {
+            let mock_server = MockServer::start().await;
+            mount_health(&mock_server, health_status, key_status).await;
+
+            let health = create_signer_for(mock_server.uri()).health().await;
+
+            assert_eq!(health.state, state, "sys/health {health_status}");
+            match reason {
+                Some(reason) => assert!(health.reason.unwrap().contains(reason)),
+                None => assert_eq!(health.reason, None),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_health_degraded_when_secondary_usable() {
+        let primary = MockServer::start().await;
+        let secondary = MockServer::start().await;
+        mount_health(&primary, 503, 200).await;
+        mount_health(&secondary, 200, 200).await;
+
+        let health = create_signer_for(primary.uri())
+            .with_hedging(Duration::from_millis(50), secondary.uri())
+            .health()
+            .await;
+
+        assert_eq!(health.state, HealthState::Degraded);
+        assert!(health.is_available());
+        assert!(health.reason.unwrap().contains("sealed"));
+    }
+
+    #[tokio::test]
+    async fn test_builder_timeout() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::builder()
+            .vault_addr(mock_server.uri())
+            .token(TEST_VAULT_TOKEN)
+            .key_name(TEST_KEY_NAME)
+            .pubkey(TEST_PUBKEY)
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let started = Instant::now();
+        let result = signer.sign_message(b"test").await;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(matches!(result, Err(SignerError::RemoteApiError(_))));
+    }
+
+    #[test]
+    fn test_builder_requires_pubkey() {
+        let result = VaultSigner::builder()
+            .vault_addr(TEST_VAULT_ADDR)
+            .token(TEST_VAULT_TOKEN)
+            .key_name(TEST_KEY_NAME)
+            .build();
+
+        match result {
+            Err(SignerError::ConfigError(msg)) => assert!(msg.contains("pubkey")),
+            other => panic!("Expected ConfigError, got {other:?}"),
+        }
+    }
+
+    fn tls_builder() -> VaultSignerBuilder {
+        VaultSigner::builder()
+            .vault_addr(TEST_VAULT_ADDR)
+            .token(TEST_VAULT_TOKEN)
+            .key_name(TEST_KEY_NAME)
+            .pubkey(TEST_PUBKEY)
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_tls_material() {
+        let result = tls_builder().ca_bundle("not a certificate").build();
+        match result {
+            Err(SignerError::ConfigError(msg)) => assert!(msg.contains("CA bundle")),
+            other => panic!("Expected ConfigError, got {other:?}"),
+        }
+
+        let result = tls_builder()
+            .client_certificate("not a certificate", "not a key")
+            .build();
+        match result {
+            Err(SignerError::ConfigError(msg)) => assert!(msg.contains("client certificate")),
+            other => panic!("Expected ConfigError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_builder_insecure_tls_is_reported() {
+        assert!(!tls_builder()
+            .build()
+            .unwrap()
+            .config_debug()
+            .contains("tls_verify"));
+
+        let signer = tls_builder()
+            .danger_accept_invalid_certs(true)
+            .build()
+            .unwrap();
+        assert!(signer.config_debug().ends_with("tls_verify=false"));
+    }
+
+    #[tokio::test]
+    async fn test_with_resolve() {
+        let mock_server = MockServer::start().await;
+        let signature = Signature::from([7u8; 64]);
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "signature": format!("vault:v1:{}", STANDARD.encode(signature))
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            format!("http://vault.internal:{}", mock_server.address().port()),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap()
+        .with_resolve("vault.internal", *mock_server.address())
+        .unwrap();
+
+        assert_eq!(signer.sign_message(b"test").await.unwrap(), signature);
+    }
+
+    #[test]
+    fn test_with_resolve_rejects_custom_client() {
+        let addr: SocketAddr = "127.0.0.1:8200".parse().unwrap();
+        let signer = tls_builder().client(Client::new()).build().unwrap();
+
+        assert!(matches!(
+            signer.with_resolve("vault.internal", addr),
+            Err(SignerError::ConfigError(_))
+        ));
+    }
+
+    #[test]
+    fn test_strip_version_prefix() {
+        assert_eq!(VaultSigner::strip_version_prefix("vault:v1:c2ln"), "c2ln");
+        assert_eq!(VaultSigner::strip_version_prefix("vault:v12:c2ln"), "c2ln");
+        assert_eq!(VaultSigner::strip_version_prefix("c2ln"), "c2ln");
+        assert_eq!(
+            VaultSigner::strip_version_prefix("vault:vx:c2ln"),
+            "vault:vx:c2ln"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_openbao_sign_uses_vault_token_header() {
+        let mock_server = MockServer::start().await;
+        let signature = Signature::from([7u8; 64]);
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .and(header("X-Vault-Token", TEST_VAULT_TOKEN))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "signature": format!("vault:v2:{}", STANDARD.encode(signature))
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap()
+        .with_flavor(VaultFlavor::OpenBao);
+
+        assert_eq!(signer.sign_message(b"test").await.unwrap(), signature);
+        assert!(signer.config_debug().contains("backend=openbao"));
+    }
+
+    #[tokio::test]
+    async fn test_openbao_is_available_checks_health() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/health"))
+            .and(query_param("standbyok", "true"))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "initialized": true,
+                "sealed": true,
+                "standby": true
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/transit/keys/test-key"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap()
+        .with_flavor(VaultFlavor::OpenBao);
+
+        assert!(!signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_sign_partial_transaction_keeps_other_signatures() {
+        let mock_server = MockServer::start().await;
+        let keypair = crate::sdk_adapter::Keypair::new();
+        let co_signer = crate::sdk_adapter::Keypair::new();
+        let pubkey = crate::sdk_adapter::keypair_pubkey(&keypair);
+        let mut tx = crate::test_util::create_multi_signer_test_transaction(&[
+            crate::sdk_adapter::keypair_pubkey(&co_signer),
+            pubkey,
+        ]);
+        let message = tx.message_data();
+        let co_signature = crate::sdk_adapter::keypair_sign_message(&co_signer, &message);
+        let signature = crate::sdk_adapter::keypair_sign_message(&keypair, &message);
+        TransactionUtil::add_signature_at_index(&mut tx, 0, co_signature).unwrap();
+
+        // A second partial sign reuses the signature already in the slot
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "signature": format!("vault:v1:{}", STANDARD.encode(signature))
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            pubkey.to_string(),
+        )
+        .unwrap();
+
+        for _ in 0..2 {
+            let signed = signer.sign_partial_transaction(&mut tx).await.unwrap();
+            assert_eq!(signed.signature, signature);
+        }
+        assert_eq!(tx.signatures, vec![co_signature, signature]);
+        assert!(tx.verify().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_sign_versioned_transaction() {
+        let mock_server = MockServer::start().await;
+        let keypair = crate::sdk_adapter::Keypair::new();
+        let pubkey = crate::sdk_adapter::keypair_pubkey(&keypair);
+        let mut tx = crate::test_util::create_v0_test_transaction(
+            &pubkey,
+            &Pubkey::new_unique(),
+            vec![0],
+            vec![],
+        );
+        let message = tx.message.serialize();
+        let signature = crate::sdk_adapter::keypair_sign_message(&keypair, &message);
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .and(body_json(
+                serde_json::json!({ "input": STANDARD.encode(&message) }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "signature": format!("vault:v1:{}", STANDARD.encode(signature))
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            pubkey.to_string(),
+        )
+        .unwrap();
+
+        let returned = signer
+            .sign_versioned_transaction(&mut tx)
+            .await
+            .unwrap()
+            .signature;
+        assert_eq!(returned, signature);
+        assert_eq!(tx.signatures[0], signature);
+    }
+
+    async fn mount_transit_key(server: &MockServer, key_path: &str, key_type: &str) -> Pubkey {
+        let pubkey = Pubkey::new_unique();
+        Mock::given(method("GET"))
+            .and(path(key_path))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "type": key_type,
+                    "latest_version": 2,
+                    "keys": {
+                        "1": { "public_key": STANDARD.encode(Pubkey::new_unique()) },
+                        "2": { "public_key": STANDARD.encode(pubkey) }
+                    }
+                }
+            })))
+            .mount(server)
+            .await;
+        pubkey
+    }
+
+    #[tokio::test]
+    async fn test_builder_connect_fetches_pubkey() {
+        let mock_server = MockServer::start().await;
+        let pubkey =
+            mount_transit_key(&mock_server, "/v1/solana-transit/keys/test-key", "ed25519").await;
+
+        let builder = VaultSigner::builder()
+            .vault_addr(mock_server.uri())
+            .token(TEST_VAULT_TOKEN)
+            .transit_mount("solana-transit")
+            .key_name(TEST_KEY_NAME);
+
+        let signer = builder.connect().await.unwrap();
+        assert_eq!(signer.pubkey(), pubkey);
+        assert!(signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_builder_connect_rejects_mismatch() {
+        let mock_server = MockServer::start().await;
+        mount_transit_key(&mock_server, "/v1/transit/keys/test-key", "ed25519").await;
+
+        let result = VaultSigner::builder()
+            .vault_addr(mock_server.uri())
+            .token(TEST_VAULT_TOKEN)
+            .key_name(TEST_KEY_NAME)
+            .pubkey(TEST_PUBKEY)
+            .connect()
+            .await;
+
+        match result {
+            Err(SignerError::ConfigError(msg)) => assert!(msg.contains(TEST_PUBKEY)),
+            other => panic!("Expected ConfigError, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pubkey_rejects_non_ed25519() {
+        let mock_server = MockServer::start().await;
+        mount_transit_key(&mock_server, "/v1/transit/keys/test-key", "ecdsa-p256").await;
+
+        let result = create_signer_for(mock_server.uri()).fetch_pubkey().await;
+        assert!(matches!(result, Err(SignerError::ConfigError(_))));
+    }
+
+    fn write_service_account_token(jwt: &str) -> std::path::PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("solana-signers-k8s-{}", Pubkey::new_unique()));
+        std::fs::write(&path, format!("{jwt}\n")).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_kubernetes_auth_logs_in_once() {
+        let mock_server = MockServer::start().await;
+        let signature = Signature::from([7u8; 64]);
+        let jwt_path = write_service_account_token("service-account-jwt");
+
+        Mock::given(method("POST"))
+            .and(path("/v1/auth/k8s-prod/login"))
+            .and(body_json(
+                serde_json::json!({ "role": "signer", "jwt": "service-account-jwt" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "auth": { "client_token": "login-token", "lease_duration": 3600 }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .and(header("X-Vault-Token", "login-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "signature": format!("vault:v1:{}", STANDARD.encode(signature))
+                }
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::builder()
+            .vault_addr(mock_server.uri())
+            .kubernetes_auth(
+                KubernetesAuth::new("signer")
+                    .with_mount("k8s-prod")
+                    .with_jwt_path(&jwt_path),
+            )
+            .key_name(TEST_KEY_NAME)
+            .pubkey(TEST_PUBKEY)
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            assert_eq!(signer.sign_message(b"test").await.unwrap(), signature);
+        }
+        assert!(signer
+            .config_debug()
+            .contains("auth=kubernetes role=signer"));
+        std::fs::remove_file(&jwt_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_kubernetes_auth_logs_in_again_after_forbidden() {
+        let mock_server = MockServer::start().await;
+        let jwt_path = write_service_account_token("service-account-jwt");
+
+        // Zero lease means the token never expires, so only the 403 forces a new login
+        Mock::given(method("POST"))
+            .and(path("/v1/auth/kubernetes/login"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "auth": { "client_token": "login-token", "lease_duration": 0 }
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "errors": ["permission denied"]
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new_with_auth(
+            mock_server.uri(),
+            KubernetesAuth::new("signer")
+                .with_jwt_path(&jwt_path)
+                .into(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap();
+
+        for _ in 0..2 {
+            let result = signer.sign_message(b"test").await;
+            assert!(matches!(result, Err(SignerError::RemoteApiError(_))));
+        }
+        std::fs::remove_file(&jwt_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_token_file_picks_up_rotation() {
+        let mock_server = MockServer::start().await;
+        let signature = Signature::from([7u8; 64]);
+        let token_path = std::env::temp_dir().join(format!(
+            "solana-signers-vault-token-{}",
+            Pubkey::new_unique()
+        ));
+        std::fs::write(&token_path, "first-token\n").unwrap();
+
+        for token in ["first-token", "second-token"] {
+            Mock::given(method("POST"))
+                .and(path("/v1/transit/sign/test-key"))
+                .and(header("X-Vault-Token", token))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "data": {
+                        "signature": format!("vault:v1:{}", STANDARD.encode(signature))
+                    }
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let signer = VaultSigner::builder()
+            .vault_addr(mock_server.uri())
+            .token_file(&token_path)
+            .key_name(TEST_KEY_NAME)
+            .pubkey(TEST_PUBKEY)
+            .build()
+            .unwrap();
+        assert!(signer.config_debug().contains("token_file="));
+
+        signer.sign_message(b"test").await.unwrap();
+
+        // Vault Agent rewrites the sink with a new modification time
+        std::fs::write(&token_path, "second-token\n").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&token_path)
+            .unwrap()
+            .set_modified(std::time::SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+
+        signer.sign_message(b"test").await.unwrap();
+        std::fs::remove_file(&token_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_kubernetes_auth_missing_jwt() {
+        let signer = VaultSigner::new_with_auth(
+            TEST_VAULT_ADDR.to_string(),
+            KubernetesAuth::new("signer")
+                .with_jwt_path("/nonexistent/serviceaccount/token")
+                .into(),
+            TEST_KEY_NAME.to_string(),
+            TEST_PUBKEY.to_string(),
+        )
+        .unwrap();
+
+        let result = signer.sign_message(b"test").await;
+        assert!(matches!(result, Err(SignerError::IoError(_))));
+        assert!(!signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_sign_all_transactions_in_one_batch() {
+        let mock_server = MockServer::start().await;
+        let keypair = crate::sdk_adapter::Keypair::new();
+        let pubkey = crate::sdk_adapter::keypair_pubkey(&keypair);
+        let mut txs: Vec<_> = (0..3).map(|_| create_test_transaction(&pubkey)).collect();
+        let signatures: Vec<_> = txs
+            .iter()
+            .map(|tx| crate::sdk_adapter::keypair_sign_message(&keypair, &tx.message_data()))
+            .collect();
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .and(body_json(serde_json::json!({
+                "batch_input": txs
+                    .iter()
+                    .map(|tx| serde_json::json!({ "input": STANDARD.encode(tx.message_data()) }))
+                    .collect::<Vec<_>>()
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "batch_results": [
+                        { "signature": format!("vault:v1:{}", STANDARD.encode(signatures[0])) },
+                        { "error": "failed to sign input" },
+                        { "signature": format!("vault:v1:{}", STANDARD.encode(signatures[2])) }
+                    ]
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = VaultSigner::new(
+            mock_server.uri(),
+            TEST_VAULT_TOKEN.to_string(),
+            TEST_KEY_NAME.to_string(),
+            pubkey.to_string(),
+        )
+        .unwrap();
+        assert!(signer.capabilities().supports_batch);
+
+        let results = signer.sign_all_transactions(&mut txs).await;
+
+        assert_eq!(results[0].as_ref().unwrap().signature, signatures[0]);
+        assert!(matches!(results[1], Err(SignerError::SigningFailed(_))));
+        assert_eq!(results[2].as_ref().unwrap().signature, signatures[2]);
+        assert!(txs[0].verify().is_ok());
+        assert!(txs[2].verify().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_sign_many_messages_request_failure() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/sign/test-key"))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "errors": ["Vault is sealed"]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let signer = create_signer_for(mock_server.uri());
+        let results = signer.sign_many_messages(&[b"first", b"second"]).await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|result| matches!(result, Err(SignerError::NotAvailable(_)))));
+        assert!(signer.sign_many_messages(&[]).await.is_empty());
+    }
 }
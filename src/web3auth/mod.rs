@@ -0,0 +1,448 @@
+//! Web3Auth signer integration
+//!
+//! Reconstructs a Web3Auth user's key server-side from the Torus network, given the
+//! user's id token and verifier, and then signs locally. Solana keys are derived the
+//! way Web3Auth's Solana provider does it: the 32-byte secp256k1 key is used as the
+//! Ed25519 seed.
+//!
+//! Only keys without an additional metadata nonce (single factor auth keys on
+//! networks that return the final key from the shares alone) are supported.
+
+mod torus;
+
+use crate::memory::MemorySigner;
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::{error::SignerError, traits::SolanaSigner};
+use futures::future::join_all;
+use k256::SecretKey;
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::Arc;
+use torus::{
+    CommitmentParams, JsonRpcRequest, JsonRpcResponse, KeyAssignment, NodeSignature, ShareParams,
+    ShareRequestItem, ShareResult,
+};
+
+/// Web3Auth signer reconstructing the user key from the Torus network
+#[derive(Clone)]
+pub struct Web3AuthSigner {
+    verifier: String,
+    verifier_id: String,
+    id_token: String,
+    node_urls: Vec<String>,
+    client: reqwest::Client,
+    signer: Option<Arc<MemorySigner>>,
+}
+
+impl std::fmt::Debug for Web3AuthSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Web3AuthSigner")
+            .field("public_key", &self.pubkey())
+            .field("verifier", &self.verifier)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Web3AuthSigner {
+    /// Create a new Web3AuthSigner (requires initialization)
+    ///
+    /// # Arguments
+    ///
+    /// * `verifier` - Name of the Web3Auth verifier the id token was issued for
+    /// * `verifier_id` - The user's identifier for that verifier, e.g. their email
+    /// * `id_token` - JWT from the verifier's identity provider
+    /// * `node_urls` - JSON-RPC endpoints of the Torus nodes for the Web3Auth network
+    pub fn new(
+        verifier: String,
+        verifier_id: String,
+        id_token: String,
+        node_urls: Vec<String>,
+    ) -> Self {
+        Self {
+            verifier,
+            verifier_id,
+            id_token,
+            node_urls,
+            client: reqwest::Client::new(),
+            signer: None,
+        }
+    }
+
+    /// Initialize the signer by reconstructing the user key from the Torus nodes
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        let session_key = SecretKey::random(&mut rand::thread_rng());
+        self.init_with_session_key(&session_key).await
+    }
+
+    async fn init_with_session_key(&mut self, session_key: &SecretKey) -> Result<(), SignerError> {
+        let secret = self.reconstruct(session_key).await?;
+
+        let seed: [u8; 32] = secret.to_bytes().into();
+        let signer =
+            MemorySigner::from_ed25519_signing_key(ed25519_dalek::SigningKey::from_bytes(&seed))?;
+        self.signer = Some(Arc::new(signer));
+        Ok(())
+    }
+
+    /// Make a JSON-RPC call to one Torus node
+    async fn rpc<P: Serialize, R: DeserializeOwned>(
+        &self,
+        url: &str,
+        method: &str,
+        params: P,
+    ) -> Result<R, SignerError> {
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0",
+            method,
+            id: 10,
+            params,
+        };
+
+        let response = self.client.post(url).json(&request).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            log::error!("Torus node {method} error - status: {status}");
+            return Err(SignerError::RemoteApiError(format!("API error {status}")));
+        }
+
+        let response: JsonRpcResponse<R> = response.json().await?;
+        match (response.result, response.error) {
+            (Some(result), _) => Ok(result),
+            (None, Some(_error)) => {
+                #[cfg(feature = "unsafe-debug")]
+                log::error!("Torus node {method} error: {}", _error.message);
+
+                #[cfg(not(feature = "unsafe-debug"))]
+                log::error!("Torus node {method} error");
+
+                Err(SignerError::RemoteApiError(format!(
+                    "Torus node rejected {method}"
+                )))
+            }
+            (None, None) => Err(SignerError::SerializationError(
+                "Empty Torus node response".to_string(),
+            )),
+        }
+    }
+
+    /// Commit to the id token and collect enough node shares to rebuild the key
+    async fn reconstruct(&self, session_key: &SecretKey) -> Result<k256::Scalar, SignerError> {
+        if self.node_urls.is_empty() {
+            return Err(SignerError::ConfigError(
+                "Web3Auth requires at least one Torus node URL".to_string(),
+            ));
+        }
+
+        let (temppubx, temppuby) = torus::public_key_coordinates(&session_key.public_key());
+        let token_commitment = torus::token_commitment(&self.id_token);
+
+        let commitments = join_all(self.node_urls.iter().map(|url| {
+            self.rpc::<_, NodeSignature>(
+                url,
+                "CommitmentRequest",
+                CommitmentParams {
+                    messageprefix: torus::COMMITMENT_MESSAGE_PREFIX,
+                    tokencommitment: token_commitment.clone(),
+                    temppubx: temppubx.clone(),
+                    temppuby: temppuby.clone(),
+                    verifieridentifier: &self.verifier,
+                },
+            )
+        }))
+        .await;
+        let signatures: Vec<NodeSignature> = commitments.into_iter().flatten().collect();
+
+        if signatures.len() < torus::commitment_threshold(self.node_urls.len()) {
+            return Err(SignerError::RemoteApiError(format!(
+                "Only {} of {} Torus nodes accepted the token commitment",
+                signatures.len(),
+                self.node_urls.len()
+            )));
+        }
+
+        let responses = join_all(self.node_urls.iter().map(|url| {
+            self.rpc::<_, ShareResult>(
+                url,
+                "ShareRequest",
+                ShareParams {
+                    encrypted: "yes",
+                    item: vec![ShareRequestItem {
+                        verifieridentifier: &self.verifier,
+                        verifier_id: &self.verifier_id,
+                        idtoken: &self.id_token,
+                        nodesignatures: &signatures,
+                    }],
+                },
+            )
+        }))
+        .await;
+
+        let assignments: Vec<KeyAssignment> = responses
+            .into_iter()
+            .flatten()
+            .filter_map(|result| result.keys.into_iter().next())
+            .collect();
+        let shares: Vec<_> = assignments
+            .iter()
+            .filter_map(|assignment| torus::decrypt_share(session_key, assignment).ok())
+            .collect();
+
+        let threshold = torus::share_threshold(self.node_urls.len());
+        if shares.len() < threshold {
+            return Err(SignerError::RemoteApiError(format!(
+                "Only {} of {} Torus nodes returned a valid share",
+                shares.len(),
+                self.node_urls.len()
+            )));
+        }
+
+        let secret = torus::interpolate(&shares[..threshold])?;
+        torus::check_public_key(&secret, &assignments[0].public_key)?;
+        Ok(secret)
+    }
+
+    fn signer(&self) -> Result<&MemorySigner, SignerError> {
+        self.signer
+            .as_deref()
+            .ok_or_else(|| SignerError::NotAvailable("Web3Auth signer not initialized".to_string()))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for Web3AuthSigner {
+    fn pubkey(&self) -> Pubkey {
+        // Default indicates that the signer is not initialized
+        self.signer
+            .as_ref()
+            .map(|signer| signer.pubkey())
+            .unwrap_or_default()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.signer()?.sign_transaction(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.signer()?.sign_message(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.signer()?.sign_partial_transaction(tx).await
+    }
+
+    async fn is_available(&self) -> bool {
+        self.signer.is_some()
+    }
+
+    /// Signing happens in-process once the key has been reconstructed
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Local
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        SignerMetadata::new("web3auth", self.pubkey(), self.latency_class())
+            .with_key_id(format!("{}/{}", self.verifier, self.verifier_id))
+    }
+
+    fn config_debug(&self) -> String {
+        format!(
+            "backend=web3auth verifier={} verifier_id={} nodes={} id_token=[REDACTED] pubkey={}",
+            self.verifier,
+            self.verifier_id,
+            self.node_urls.len(),
+            self.pubkey()
+        )
+    }
+
+    fn validate_config(&self) -> Result<(), SignerError> {
+        if self.node_urls.is_empty() {
+            return Err(SignerError::ConfigError(
+                "Web3Auth requires at least one Torus node URL".to_string(),
+            ));
+        }
+
+        if self.id_token.is_empty() {
+            return Err(SignerError::ConfigError(
+                "Web3Auth id_token must not be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::create_test_transaction;
+    use k256::Scalar;
+    use wiremock::{
+        matchers::{body_partial_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const TEST_VERIFIER: &str = "test-verifier";
+    const TEST_ID_TOKEN: &str = "header.payload.signature";
+
+    fn rpc_result(result: serde_json::Value) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 10,
+            "result": result
+        }))
+    }
+
+    /// Mount three nodes holding shares of `secret` on a degree-1 polynomial
+    async fn mount_nodes(
+        mock_server: &MockServer,
+        session_key: &SecretKey,
+        secret: Scalar,
+        failing_node: Option<u64>,
+    ) -> Vec<String> {
+        let public_key =
+            k256::PublicKey::from_secret_scalar(&k256::NonZeroScalar::new(secret).unwrap());
+        let slope = Scalar::from(99u64);
+
+        for index in 1u64..=3 {
+            let node_path = format!("/node{index}");
+
+            Mock::given(method("POST"))
+                .and(path(node_path.clone()))
+                .and(body_partial_json(serde_json::json!({
+                    "method": "CommitmentRequest",
+                    "params": {
+                        "tokencommitment": torus::token_commitment(TEST_ID_TOKEN),
+                        "verifieridentifier": TEST_VERIFIER
+                    }
+                })))
+                .respond_with(rpc_result(serde_json::json!({
+                    "signature": format!("sig{index}"),
+                    "data": "commitment",
+                    "nodepubx": "01",
+                    "nodepuby": "02"
+                })))
+                .mount(mock_server)
+                .await;
+
+            let share = secret + slope * Scalar::from(index);
+            let response = if failing_node == Some(index) {
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": 10,
+                    "error": { "code": -32602, "message": "invalid token" }
+                }))
+            } else {
+                rpc_result(serde_json::json!({
+                    "keys": [torus::test_support::encrypt_share(
+                        &session_key.public_key(),
+                        index,
+                        &share,
+                        &public_key,
+                    )]
+                }))
+            };
+
+            Mock::given(method("POST"))
+                .and(path(node_path))
+                .and(body_partial_json(serde_json::json!({
+                    "method": "ShareRequest",
+                    "params": {
+                        "encrypted": "yes",
+                        "item": [{ "idtoken": TEST_ID_TOKEN, "verifier_id": "user@example.com" }]
+                    }
+                })))
+                .respond_with(response)
+                .mount(mock_server)
+                .await;
+        }
+
+        (1..=3)
+            .map(|index| format!("{}/node{index}", mock_server.uri()))
+            .collect()
+    }
+
+    fn create_test_signer(node_urls: Vec<String>) -> Web3AuthSigner {
+        Web3AuthSigner::new(
+            TEST_VERIFIER.to_string(),
+            "user@example.com".to_string(),
+            TEST_ID_TOKEN.to_string(),
+            node_urls,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_web3auth_init_and_sign() {
+        let mock_server = MockServer::start().await;
+        let session_key = SecretKey::random(&mut rand::thread_rng());
+        let secret = Scalar::from(123_456_789u64);
+        let node_urls = mount_nodes(&mock_server, &session_key, secret, Some(2)).await;
+
+        let mut signer = create_test_signer(node_urls);
+        assert!(!signer.is_available().await);
+
+        signer.init_with_session_key(&session_key).await.unwrap();
+        assert!(signer.is_available().await);
+
+        let seed: [u8; 32] = secret.to_bytes().into();
+        let expected =
+            MemorySigner::from_ed25519_signing_key(ed25519_dalek::SigningKey::from_bytes(&seed))
+                .unwrap();
+        assert_eq!(signer.pubkey(), expected.pubkey());
+
+        let mut tx = create_test_transaction(&signer.pubkey());
+        let signature = signer.sign_transaction(&mut tx).await.unwrap().signature;
+        assert_eq!(
+            signature,
+            expected.sign_message(&tx.message_data()).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_web3auth_init_not_enough_shares() {
+        let mock_server = MockServer::start().await;
+        let session_key = SecretKey::random(&mut rand::thread_rng());
+        let node_urls = mount_nodes(&mock_server, &session_key, Scalar::from(5u64), Some(1)).await;
+
+        // Shares were encrypted for a different session key, so none decrypt
+        let mut signer = create_test_signer(node_urls);
+        let result = signer.init().await;
+
+        assert!(matches!(result, Err(SignerError::RemoteApiError(_))));
+        assert!(!signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_web3auth_sign_before_init() {
+        let signer = create_test_signer(vec!["http://localhost:1".to_string()]);
+        let result = signer.sign_message(b"test").await;
+
+        assert!(matches!(result, Err(SignerError::NotAvailable(_))));
+        assert_eq!(signer.pubkey(), Pubkey::default());
+    }
+
+    #[test]
+    fn test_web3auth_config() {
+        let signer = create_test_signer(vec![]);
+        assert!(matches!(
+            signer.validate_config(),
+            Err(SignerError::ConfigError(_))
+        ));
+
+        let signer = create_test_signer(vec!["https://node-1.example.com/jrpc".to_string()]);
+        assert!(signer.validate_config().is_ok());
+        let output = signer.config_debug();
+        assert!(output.contains("backend=web3auth"));
+        assert!(!output.contains(TEST_ID_TOKEN));
+    }
+}
@@ -0,0 +1,321 @@
+//! Torus network key retrieval used by Web3Auth
+//!
+//! A user key is split across the Torus nodes with Shamir secret sharing. Each node
+//! hands out its share, encrypted to a per-session key, once the user's id token has
+//! been committed to and verified. The key is the polynomial's value at zero.
+
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+use hmac::{Hmac, Mac};
+use k256::elliptic_curve::point::AffineCoordinates;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::PrimeField;
+use k256::{ProjectivePoint, PublicKey, Scalar, SecretKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512};
+use sha3::Keccak256;
+
+use crate::error::SignerError;
+
+/// Message prefix nodes expect on commitment requests
+pub const COMMITMENT_MESSAGE_PREFIX: &str = "mug00";
+
+#[derive(Serialize)]
+pub struct JsonRpcRequest<'a, P> {
+    pub jsonrpc: &'static str,
+    pub method: &'a str,
+    pub id: u32,
+    pub params: P,
+}
+
+#[derive(Deserialize)]
+pub struct JsonRpcResponse<R> {
+    pub result: Option<R>,
+    pub error: Option<JsonRpcError>,
+}
+
+#[derive(Deserialize)]
+pub struct JsonRpcError {
+    pub message: String,
+}
+
+#[derive(Serialize)]
+pub struct CommitmentParams<'a> {
+    pub messageprefix: &'static str,
+    pub tokencommitment: String,
+    pub temppubx: String,
+    pub temppuby: String,
+    pub verifieridentifier: &'a str,
+}
+
+/// A node's signed acknowledgement of the token commitment
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NodeSignature {
+    pub signature: String,
+    pub data: String,
+    pub nodepubx: String,
+    pub nodepuby: String,
+}
+
+#[derive(Serialize)]
+pub struct ShareParams<'a> {
+    pub encrypted: &'static str,
+    pub item: Vec<ShareRequestItem<'a>>,
+}
+
+#[derive(Serialize)]
+pub struct ShareRequestItem<'a> {
+    pub verifieridentifier: &'a str,
+    pub verifier_id: &'a str,
+    pub idtoken: &'a str,
+    pub nodesignatures: &'a [NodeSignature],
+}
+
+#[derive(Deserialize)]
+pub struct ShareResult {
+    pub keys: Vec<KeyAssignment>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct KeyAssignment {
+    /// Hex-encoded x coordinate of this node's share
+    pub index: String,
+    pub public_key: NodePublicKey,
+    /// Base64 of the hex-encoded ciphertext
+    pub share: String,
+    pub metadata: EncryptionMetadata,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NodePublicKey {
+    pub x: String,
+    pub y: String,
+}
+
+/// ECIES parameters of an encrypted share (hex-encoded)
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptionMetadata {
+    pub iv: String,
+    pub ephem_public_key: String,
+    pub mac: String,
+}
+
+/// Commitments needed before shares are requested
+pub fn commitment_threshold(nodes: usize) -> usize {
+    (nodes / 4) * 3 + 1
+}
+
+/// Shares needed to reconstruct the key
+pub fn share_threshold(nodes: usize) -> usize {
+    nodes / 2 + 1
+}
+
+/// Hex-encoded keccak256 of the id token, without `0x`
+pub fn token_commitment(id_token: &str) -> String {
+    hex::encode(Keccak256::digest(id_token.as_bytes()))
+}
+
+/// Uncompressed x and y coordinates of a public key, hex-encoded
+pub fn public_key_coordinates(public_key: &PublicKey) -> (String, String) {
+    let point = public_key.to_encoded_point(false);
+    // Both coordinates are present on an uncompressed, non-identity point
+    (
+        hex::encode(point.x().expect("uncompressed point")),
+        hex::encode(point.y().expect("uncompressed point")),
+    )
+}
+
+fn decode_hex(value: &str, what: &str) -> Result<Vec<u8>, SignerError> {
+    hex::decode(value.trim_start_matches("0x"))
+        .map_err(|e| SignerError::SerializationError(format!("Invalid {what}: {e}")))
+}
+
+fn parse_scalar(value: &str, what: &str) -> Result<Scalar, SignerError> {
+    let hex_value = format!("{:0>64}", value.trim_start_matches("0x"));
+    let bytes: [u8; 32] = decode_hex(&hex_value, what)?
+        .try_into()
+        .map_err(|_| SignerError::SerializationError(format!("Invalid {what} length")))?;
+
+    Option::from(Scalar::from_repr(bytes.into()))
+        .ok_or_else(|| SignerError::SerializationError(format!("{what} out of range")))
+}
+
+/// Decrypt one node's share with the session key
+///
+/// Shares use the `eccrypto` ECIES scheme: SHA-512 of the ECDH x coordinate gives
+/// the AES-256-CBC key and the HMAC-SHA256 key.
+pub fn decrypt_share(
+    session_key: &SecretKey,
+    assignment: &KeyAssignment,
+) -> Result<(Scalar, Scalar), SignerError> {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    let ciphertext_hex = STANDARD
+        .decode(&assignment.share)
+        .map_err(|e| SignerError::SerializationError(format!("Failed to decode share: {e}")))?;
+    let ciphertext = decode_hex(&String::from_utf8_lossy(&ciphertext_hex), "share")?;
+    let iv = decode_hex(&assignment.metadata.iv, "share iv")?;
+    let ephemeral = decode_hex(&assignment.metadata.ephem_public_key, "ephemeral key")?;
+    let mac = decode_hex(&assignment.metadata.mac, "share mac")?;
+
+    let ephemeral_key = PublicKey::from_sec1_bytes(&ephemeral)
+        .map_err(|_| SignerError::SerializationError("Invalid ephemeral key".to_string()))?;
+    let shared = (ephemeral_key.to_projective() * *session_key.to_nonzero_scalar()).to_affine();
+    let hash = Sha512::digest(shared.x());
+    let (encryption_key, mac_key) = hash.split_at(32);
+
+    let mut hmac = Hmac::<Sha256>::new_from_slice(mac_key)
+        .map_err(|e| SignerError::Other(format!("HMAC init failed: {e}")))?;
+    hmac.update(&iv);
+    hmac.update(&ephemeral);
+    hmac.update(&ciphertext);
+    hmac.verify_slice(&mac)
+        .map_err(|_| SignerError::SigningFailed("Torus share MAC mismatch".to_string()))?;
+
+    let plaintext = cbc::Decryptor::<aes::Aes256>::new_from_slices(encryption_key, &iv)
+        .map_err(|e| SignerError::SerializationError(format!("Invalid share iv: {e}")))?
+        .decrypt_padded_vec_mut::<Pkcs7>(&ciphertext)
+        .map_err(|_| SignerError::SigningFailed("Failed to decrypt Torus share".to_string()))?;
+
+    let index = parse_scalar(&assignment.index, "share index")?;
+    let share = parse_scalar(&String::from_utf8_lossy(&plaintext), "share")?;
+    Ok((index, share))
+}
+
+/// Interpolate the shared polynomial at zero
+pub fn interpolate(shares: &[(Scalar, Scalar)]) -> Result<Scalar, SignerError> {
+    let mut secret = Scalar::ZERO;
+
+    for (i, (x_i, y_i)) in shares.iter().enumerate() {
+        let mut numerator = Scalar::ONE;
+        let mut denominator = Scalar::ONE;
+        for (j, (x_j, _)) in shares.iter().enumerate() {
+            if i != j {
+                numerator *= x_j;
+                denominator *= *x_j - x_i;
+            }
+        }
+
+        let inverse = Option::<Scalar>::from(denominator.invert())
+            .ok_or_else(|| SignerError::SigningFailed("Duplicate Torus share index".to_string()))?;
+        secret += *y_i * numerator * inverse;
+    }
+
+    Ok(secret)
+}
+
+/// Check that `secret` matches the public key reported by the nodes
+pub fn check_public_key(secret: &Scalar, expected: &NodePublicKey) -> Result<(), SignerError> {
+    let public_key = PublicKey::from_affine((ProjectivePoint::GENERATOR * secret).to_affine())
+        .map_err(|_| {
+            SignerError::SigningFailed("Reconstructed Web3Auth key is zero".to_string())
+        })?;
+    let normalize = |value: &str| format!("{:0>64}", value.trim_start_matches("0x").to_lowercase());
+
+    if public_key_coordinates(&public_key) != (normalize(&expected.x), normalize(&expected.y)) {
+        return Err(SignerError::SigningFailed(
+            "Reconstructed Web3Auth key does not match the node public key".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod test_support {
+    use super::*;
+    use aes::cipher::BlockEncryptMut;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    /// Encrypt `share` for `recipient` the way a Torus node does
+    pub fn encrypt_share(
+        recipient: &PublicKey,
+        index: u64,
+        share: &Scalar,
+        public_key: &PublicKey,
+    ) -> serde_json::Value {
+        let ephemeral = SecretKey::random(&mut rand::thread_rng());
+        let ephemeral_public = ephemeral.public_key().to_encoded_point(false);
+        let shared = (recipient.to_projective() * *ephemeral.to_nonzero_scalar()).to_affine();
+        let hash = Sha512::digest(shared.x());
+        let iv = [3u8; 16];
+
+        let plaintext = hex::encode(share.to_repr());
+        let ciphertext = cbc::Encryptor::<aes::Aes256>::new_from_slices(&hash[..32], &iv)
+            .unwrap()
+            .encrypt_padded_vec_mut::<Pkcs7>(plaintext.as_bytes());
+
+        let mut hmac = Hmac::<Sha256>::new_from_slice(&hash[32..]).unwrap();
+        hmac.update(&iv);
+        hmac.update(ephemeral_public.as_bytes());
+        hmac.update(&ciphertext);
+
+        let (x, y) = public_key_coordinates(public_key);
+        serde_json::json!({
+            "Index": format!("{index:x}"),
+            "PublicKey": { "X": x, "Y": y },
+            "Threshold": 1,
+            "Share": STANDARD.encode(hex::encode(&ciphertext)),
+            "Metadata": {
+                "iv": hex::encode(iv),
+                "ephemPublicKey": hex::encode(ephemeral_public.as_bytes()),
+                "mac": hex::encode(hmac.finalize().into_bytes()),
+                "mode": "AES256"
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolate_recovers_secret() {
+        let secret = Scalar::from(42u64);
+        let slope = Scalar::from(7u64);
+        let shares: Vec<(Scalar, Scalar)> = [1u64, 2, 3]
+            .iter()
+            .map(|x| (Scalar::from(*x), secret + slope * Scalar::from(*x)))
+            .collect();
+
+        assert_eq!(interpolate(&shares[..2]).unwrap(), secret);
+        assert_eq!(interpolate(&shares[1..]).unwrap(), secret);
+        assert!(interpolate(&[shares[0], shares[0]]).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_share_round_trip() {
+        let session_key = SecretKey::random(&mut rand::thread_rng());
+        let share = Scalar::from(1234u64);
+        let public_key = SecretKey::random(&mut rand::thread_rng()).public_key();
+
+        let assignment: KeyAssignment = serde_json::from_value(test_support::encrypt_share(
+            &session_key.public_key(),
+            5,
+            &share,
+            &public_key,
+        ))
+        .unwrap();
+
+        let (index, decrypted) = decrypt_share(&session_key, &assignment).unwrap();
+        assert_eq!(index, Scalar::from(5u64));
+        assert_eq!(decrypted, share);
+
+        let other_key = SecretKey::random(&mut rand::thread_rng());
+        assert!(matches!(
+            decrypt_share(&other_key, &assignment),
+            Err(SignerError::SigningFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_thresholds() {
+        assert_eq!(commitment_threshold(5), 4);
+        assert_eq!(share_threshold(5), 3);
+        assert_eq!(commitment_threshold(1), 1);
+        assert_eq!(share_threshold(1), 1);
+    }
+}
@@ -0,0 +1,281 @@
+//! ISO 7816 APDUs for the YubiKey PIV and OpenPGP applets
+
+use super::SmartCard;
+use crate::error::SignerError;
+
+const PIV_AID: [u8; 5] = [0xa0, 0x00, 0x00, 0x03, 0x08];
+const OPENPGP_AID: [u8; 6] = [0xd2, 0x76, 0x00, 0x01, 0x24, 0x01];
+
+/// PIV algorithm identifier for Ed25519 keys (YubiKey firmware 5.7+)
+pub const PIV_ALGORITHM_ED25519: u8 = 0xe0;
+
+/// Build a command APDU, switching to extended length for long payloads
+pub fn command(ins: u8, p1: u8, p2: u8, data: &[u8], expect_response: bool) -> Vec<u8> {
+    let mut apdu = vec![0x00, ins, p1, p2];
+
+    if data.len() <= 255 {
+        if !data.is_empty() {
+            apdu.push(data.len() as u8);
+            apdu.extend_from_slice(data);
+        }
+        if expect_response {
+            apdu.push(0x00);
+        }
+    } else {
+        apdu.push(0x00);
+        apdu.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        apdu.extend_from_slice(data);
+        if expect_response {
+            apdu.extend_from_slice(&[0x00, 0x00]);
+        }
+    }
+
+    apdu
+}
+
+/// Send an APDU, collect chained responses and map error status words
+pub fn transmit(card: &mut dyn SmartCard, apdu: &[u8]) -> Result<Vec<u8>, SignerError> {
+    let mut data = Vec::new();
+    let mut response = card.transmit(apdu)?;
+
+    loop {
+        if response.len() < 2 {
+            return Err(SignerError::SigningFailed(
+                "Malformed YubiKey response".to_string(),
+            ));
+        }
+        let (body, sw) = response.split_at(response.len() - 2);
+        data.extend_from_slice(body);
+
+        match (sw[0], sw[1]) {
+            (0x90, 0x00) => return Ok(data),
+            // More data available
+            (0x61, remaining) => response = card.transmit(&[0x00, 0xc0, 0x00, 0x00, remaining])?,
+            (0x63, retries) if retries & 0xf0 == 0xc0 => {
+                return Err(SignerError::ConfigError(format!(
+                    "YubiKey PIN incorrect, {} attempts remaining",
+                    retries & 0x0f
+                )))
+            }
+            (0x69, 0x83) => {
+                return Err(SignerError::ConfigError(
+                    "YubiKey PIN is blocked".to_string(),
+                ))
+            }
+            (0x69, 0x82) => {
+                return Err(SignerError::SigningFailed(
+                    "YubiKey security status not satisfied (PIN or touch required)".to_string(),
+                ))
+            }
+            (0x6a, 0x82) | (0x6a, 0x88) => {
+                return Err(SignerError::ConfigError(
+                    "No key found in the selected YubiKey slot".to_string(),
+                ))
+            }
+            (sw1, sw2) => {
+                return Err(SignerError::SigningFailed(format!(
+                    "YubiKey returned status {sw1:02X}{sw2:02X}"
+                )))
+            }
+        }
+    }
+}
+
+/// Encode a BER-TLV length
+fn encode_length(length: usize, out: &mut Vec<u8>) {
+    if length < 0x80 {
+        out.push(length as u8);
+    } else if length <= 0xff {
+        out.extend_from_slice(&[0x81, length as u8]);
+    } else {
+        out.push(0x82);
+        out.extend_from_slice(&(length as u16).to_be_bytes());
+    }
+}
+
+fn tlv(tag: u8, value: &[u8], out: &mut Vec<u8>) {
+    out.push(tag);
+    encode_length(value.len(), out);
+    out.extend_from_slice(value);
+}
+
+/// Find the value of `tag` among the top-level BER-TLV objects in `data`
+pub fn find_tlv<'a>(data: &'a [u8], tag: &[u8]) -> Option<&'a [u8]> {
+    let mut pos = 0;
+
+    while pos < data.len() {
+        let tag_start = pos;
+        // Multi-byte tags have the low five bits of the first byte set
+        if data[pos] & 0x1f == 0x1f {
+            pos += 1;
+            while *data.get(pos)? & 0x80 != 0 {
+                pos += 1;
+            }
+        }
+        pos += 1;
+        let current_tag = data.get(tag_start..pos)?;
+
+        let length = match *data.get(pos)? {
+            0x81 => {
+                pos += 2;
+                *data.get(pos - 1)? as usize
+            }
+            0x82 => {
+                pos += 3;
+                u16::from_be_bytes([*data.get(pos - 2)?, *data.get(pos - 1)?]) as usize
+            }
+            length if length < 0x80 => {
+                pos += 1;
+                length as usize
+            }
+            _ => return None,
+        };
+
+        let value = data.get(pos..pos + length)?;
+        if current_tag == tag {
+            return Some(value);
+        }
+        pos += length;
+    }
+
+    None
+}
+
+pub fn select_piv(card: &mut dyn SmartCard) -> Result<(), SignerError> {
+    transmit(card, &command(0xa4, 0x04, 0x00, &PIV_AID, false)).map(|_| ())
+}
+
+pub fn select_openpgp(card: &mut dyn SmartCard) -> Result<(), SignerError> {
+    transmit(card, &command(0xa4, 0x04, 0x00, &OPENPGP_AID, false)).map(|_| ())
+}
+
+/// VERIFY the PIV application PIN (padded to 8 bytes with 0xFF)
+pub fn verify_piv_pin(card: &mut dyn SmartCard, pin: &str) -> Result<(), SignerError> {
+    if !(6..=8).contains(&pin.len()) {
+        return Err(SignerError::ConfigError(
+            "YubiKey PIV PIN must be 6 to 8 characters".to_string(),
+        ));
+    }
+
+    let mut padded = [0xff; 8];
+    padded[..pin.len()].copy_from_slice(pin.as_bytes());
+    transmit(card, &command(0x20, 0x00, 0x80, &padded, false)).map(|_| ())
+}
+
+/// VERIFY PW1 for the OpenPGP signing key
+pub fn verify_openpgp_pin(card: &mut dyn SmartCard, pin: &str) -> Result<(), SignerError> {
+    transmit(card, &command(0x20, 0x00, 0x81, pin.as_bytes(), false)).map(|_| ())
+}
+
+/// Key metadata from the Yubico PIV GET METADATA extension
+pub struct PivMetadata {
+    pub algorithm: u8,
+    pub touch_policy: u8,
+    pub public_key: Vec<u8>,
+}
+
+pub fn piv_metadata(card: &mut dyn SmartCard, slot: u8) -> Result<PivMetadata, SignerError> {
+    let response = transmit(card, &command(0xf7, 0x00, slot, &[], true))?;
+    let malformed = || SignerError::SerializationError("Malformed PIV key metadata".to_string());
+
+    let algorithm = *find_tlv(&response, &[0x01])
+        .and_then(|value| value.first())
+        .ok_or_else(malformed)?;
+    let touch_policy = find_tlv(&response, &[0x02])
+        .and_then(|policy| policy.get(1).copied())
+        .unwrap_or(0);
+    let public_key = find_tlv(&response, &[0x04])
+        .and_then(|key| find_tlv(key, &[0x86]))
+        .ok_or_else(malformed)?;
+
+    Ok(PivMetadata {
+        algorithm,
+        touch_policy,
+        public_key: public_key.to_vec(),
+    })
+}
+
+/// GENERAL AUTHENTICATE with the Ed25519 key in `slot`
+pub fn piv_sign(
+    card: &mut dyn SmartCard,
+    slot: u8,
+    message: &[u8],
+) -> Result<Vec<u8>, SignerError> {
+    let mut template = Vec::new();
+    tlv(0x82, &[], &mut template);
+    tlv(0x81, message, &mut template);
+    let mut data = Vec::new();
+    tlv(0x7c, &template, &mut data);
+
+    let response = transmit(
+        card,
+        &command(0x87, PIV_ALGORITHM_ED25519, slot, &data, true),
+    )?;
+
+    find_tlv(&response, &[0x7c])
+        .and_then(|template| find_tlv(template, &[0x82]))
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| SignerError::SigningFailed("No signature in YubiKey response".to_string()))
+}
+
+/// Read the public key of the OpenPGP signature key
+pub fn openpgp_public_key(card: &mut dyn SmartCard) -> Result<Vec<u8>, SignerError> {
+    let response = transmit(card, &command(0x47, 0x81, 0x00, &[0xb6, 0x00], true))?;
+
+    find_tlv(&response, &[0x7f, 0x49])
+        .and_then(|key| find_tlv(key, &[0x86]))
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| SignerError::SerializationError("Malformed OpenPGP public key".to_string()))
+}
+
+/// Whether the OpenPGP signature key requires touch (the UIF-SIG data object)
+pub fn openpgp_touch_required(card: &mut dyn SmartCard) -> Result<bool, SignerError> {
+    let response = transmit(card, &command(0xca, 0x00, 0xd6, &[], true))?;
+    Ok(response.first().is_some_and(|uif| *uif != 0x00))
+}
+
+/// PSO: COMPUTE DIGITAL SIGNATURE over raw data (EdDSA signs the message itself)
+pub fn openpgp_sign(card: &mut dyn SmartCard, message: &[u8]) -> Result<Vec<u8>, SignerError> {
+    transmit(card, &command(0x2a, 0x9e, 0x9a, message, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_short_and_extended() {
+        assert_eq!(
+            command(0x20, 0x00, 0x80, &[1, 2], false),
+            vec![0x00, 0x20, 0x00, 0x80, 0x02, 1, 2]
+        );
+        assert_eq!(
+            command(0xf7, 0x00, 0x9c, &[], true),
+            vec![0x00, 0xf7, 0x00, 0x9c, 0x00]
+        );
+
+        let data = vec![7u8; 300];
+        let apdu = command(0x87, 0xe0, 0x9c, &data, true);
+        assert_eq!(&apdu[..7], &[0x00, 0x87, 0xe0, 0x9c, 0x00, 0x01, 0x2c]);
+        assert_eq!(&apdu[apdu.len() - 2..], &[0x00, 0x00]);
+        assert_eq!(apdu.len(), 7 + 300 + 2);
+    }
+
+    #[test]
+    fn test_find_tlv() {
+        let mut key = Vec::new();
+        tlv(0x86, &[9u8; 32], &mut key);
+        let mut data = vec![0x7f, 0x49];
+        encode_length(key.len(), &mut data);
+        data.extend_from_slice(&key);
+
+        let key = find_tlv(&data, &[0x7f, 0x49]).unwrap();
+        assert_eq!(find_tlv(key, &[0x86]).unwrap(), &[9u8; 32]);
+        assert!(find_tlv(&data, &[0x86]).is_none());
+
+        let mut long = Vec::new();
+        tlv(0x81, &[1u8; 300], &mut long);
+        assert_eq!(&long[..4], &[0x81, 0x82, 0x01, 0x2c]);
+        assert_eq!(find_tlv(&long, &[0x81]).unwrap().len(), 300);
+    }
+}
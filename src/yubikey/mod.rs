@@ -0,0 +1,713 @@
+//! YubiKey signer integration
+//!
+//! Signs with an Ed25519 key resident on a YubiKey, either in a PIV slot
+//! (firmware 5.7+) or as the OpenPGP signature key. The device is reached through
+//! the system PC/SC service (`pcscd` on Linux).
+
+mod apdu;
+
+use crate::sdk_adapter::{Pubkey, Signature, Transaction};
+use crate::traits::{LatencyClass, SignedTransaction, SignerCapabilities, SignerMetadata};
+use crate::transaction_util::TransactionUtil;
+use crate::{error::SignerError, traits::SolanaSigner};
+use std::sync::Arc;
+
+/// Callback invoked right before the device waits for a touch
+pub type TouchNotifier = Arc<dyn Fn() + Send + Sync>;
+
+/// PIV slot holding the signing key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PivSlot {
+    /// Slot 9a
+    Authentication,
+    /// Slot 9c
+    Signature,
+    /// Slot 9d
+    KeyManagement,
+    /// Slot 9e
+    CardAuthentication,
+}
+
+impl PivSlot {
+    /// Slot identifier used in PIV commands
+    pub fn id(self) -> u8 {
+        match self {
+            PivSlot::Authentication => 0x9a,
+            PivSlot::Signature => 0x9c,
+            PivSlot::KeyManagement => 0x9d,
+            PivSlot::CardAuthentication => 0x9e,
+        }
+    }
+}
+
+/// YubiKey applet holding the signing key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YubiKeyApplet {
+    /// PIV key in the given slot
+    Piv(PivSlot),
+    /// OpenPGP signature key
+    OpenPgp,
+}
+
+impl Default for YubiKeyApplet {
+    fn default() -> Self {
+        YubiKeyApplet::Piv(PivSlot::Signature)
+    }
+}
+
+/// A connected smart card exchanging raw APDUs
+trait SmartCard {
+    fn transmit(&mut self, apdu: &[u8]) -> Result<Vec<u8>, SignerError>;
+}
+
+/// Opens a connection to the YubiKey
+trait CardConnector: Send + Sync {
+    fn connect(&self) -> Result<Box<dyn SmartCard>, SignerError>;
+}
+
+/// Connects through PC/SC to the first reader whose name matches
+struct PcscConnector {
+    reader: Option<String>,
+}
+
+struct PcscCard {
+    card: pcsc::Card,
+}
+
+impl SmartCard for PcscCard {
+    fn transmit(&mut self, apdu: &[u8]) -> Result<Vec<u8>, SignerError> {
+        let mut buffer = vec![0u8; pcsc::MAX_BUFFER_SIZE_EXTENDED];
+        let response = self
+            .card
+            .transmit(apdu, &mut buffer)
+            .map_err(|e| SignerError::NotAvailable(format!("YubiKey transmit failed: {e}")))?;
+        Ok(response.to_vec())
+    }
+}
+
+impl CardConnector for PcscConnector {
+    fn connect(&self) -> Result<Box<dyn SmartCard>, SignerError> {
+        let not_available =
+            |e: pcsc::Error| SignerError::NotAvailable(format!("PC/SC unavailable: {e}"));
+
+        let context = pcsc::Context::establish(pcsc::Scope::User).map_err(not_available)?;
+        let pattern = self.reader.as_deref().unwrap_or("yubico").to_lowercase();
+        let reader = context
+            .list_readers_owned()
+            .map_err(not_available)?
+            .into_iter()
+            .find(|reader| reader.to_string_lossy().to_lowercase().contains(&pattern))
+            .ok_or_else(|| SignerError::NotAvailable("No YubiKey connected".to_string()))?;
+
+        let card = context
+            .connect(&reader, pcsc::ShareMode::Shared, pcsc::Protocols::ANY)
+            .map_err(not_available)?;
+        Ok(Box::new(PcscCard { card }))
+    }
+}
+
+/// Public key and touch requirement of the configured key
+struct KeyInfo {
+    public_key: Vec<u8>,
+    touch_required: bool,
+}
+
+fn select(card: &mut dyn SmartCard, applet: YubiKeyApplet) -> Result<(), SignerError> {
+    match applet {
+        YubiKeyApplet::Piv(_) => apdu::select_piv(card),
+        YubiKeyApplet::OpenPgp => apdu::select_openpgp(card),
+    }
+}
+
+fn read_key_info(card: &mut dyn SmartCard, applet: YubiKeyApplet) -> Result<KeyInfo, SignerError> {
+    select(card, applet)?;
+
+    match applet {
+        YubiKeyApplet::Piv(slot) => {
+            let metadata = apdu::piv_metadata(card, slot.id())?;
+            if metadata.algorithm != apdu::PIV_ALGORITHM_ED25519 {
+                return Err(SignerError::ConfigError(format!(
+                    "YubiKey PIV slot {:02x} does not hold an Ed25519 key",
+                    slot.id()
+                )));
+            }
+            Ok(KeyInfo {
+                public_key: metadata.public_key,
+                // 0x01 is "never"; 0x00 means the slot default, which is also never
+                touch_required: metadata.touch_policy > 0x01,
+            })
+        }
+        YubiKeyApplet::OpenPgp => Ok(KeyInfo {
+            public_key: apdu::openpgp_public_key(card)?,
+            touch_required: apdu::openpgp_touch_required(card)?,
+        }),
+    }
+}
+
+fn sign_on_card(
+    card: &mut dyn SmartCard,
+    applet: YubiKeyApplet,
+    pin: Option<&str>,
+    message: &[u8],
+) -> Result<Vec<u8>, SignerError> {
+    select(card, applet)?;
+
+    match applet {
+        YubiKeyApplet::Piv(slot) => {
+            if let Some(pin) = pin {
+                apdu::verify_piv_pin(card, pin)?;
+            }
+            apdu::piv_sign(card, slot.id(), message)
+        }
+        YubiKeyApplet::OpenPgp => {
+            if let Some(pin) = pin {
+                apdu::verify_openpgp_pin(card, pin)?;
+            }
+            apdu::openpgp_sign(card, message)
+        }
+    }
+}
+
+/// YubiKey-based signer using an Ed25519 key in the PIV or OpenPGP applet
+#[derive(Clone)]
+pub struct YubiKeySigner {
+    connector: Arc<dyn CardConnector>,
+    reader: Option<String>,
+    applet: YubiKeyApplet,
+    pin: Option<String>,
+    touch_notifier: Option<TouchNotifier>,
+    touch_required: bool,
+    public_key: Pubkey,
+}
+
+impl std::fmt::Debug for YubiKeySigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YubiKeySigner")
+            .field("public_key", &self.public_key)
+            .field("applet", &self.applet)
+            .finish_non_exhaustive()
+    }
+}
+
+impl YubiKeySigner {
+    /// Create a new YubiKeySigner (requires initialization)
+    ///
+    /// # Arguments
+    ///
+    /// * `applet` - Applet and slot holding the Ed25519 key
+    pub fn new(applet: YubiKeyApplet) -> Self {
+        Self {
+            connector: Arc::new(PcscConnector { reader: None }),
+            reader: None,
+            applet,
+            pin: None,
+            touch_notifier: None,
+            touch_required: false,
+            // Set the public key to default to indicate that it's not initialized
+            public_key: Pubkey::default(),
+        }
+    }
+
+    /// Use the PC/SC reader whose name contains `reader` instead of the first YubiKey
+    pub fn with_reader(mut self, reader: String) -> Self {
+        self.connector = Arc::new(PcscConnector {
+            reader: Some(reader.clone()),
+        });
+        self.reader = Some(reader);
+        self
+    }
+
+    /// Set the PIN verified before each signature
+    ///
+    /// For PIV this is the application PIN; for OpenPGP it is PW1. Without one,
+    /// signing only succeeds if the key's PIN policy allows it.
+    pub fn with_pin(mut self, pin: String) -> Self {
+        self.pin = Some(pin);
+        self
+    }
+
+    /// Set a callback invoked when the key's touch policy requires a touch
+    ///
+    /// Useful to prompt the user, since signing blocks until the YubiKey is touched.
+    pub fn with_touch_notifier(mut self, notifier: impl Fn() + Send + Sync + 'static) -> Self {
+        self.touch_notifier = Some(Arc::new(notifier));
+        self
+    }
+
+    /// Initialize the signer by reading the public key and touch policy from the device
+    pub async fn init(&mut self) -> Result<(), SignerError> {
+        let info = self.key_info().await?;
+
+        self.public_key = Pubkey::try_from(info.public_key.as_slice())
+            .map_err(|e| SignerError::InvalidPublicKey(format!("Invalid public key: {e}")))?;
+        self.touch_required = info.touch_required;
+        Ok(())
+    }
+
+    /// Run a card session on the blocking thread pool
+    async fn with_card<T: Send + 'static>(
+        &self,
+        session: impl FnOnce(&mut dyn SmartCard) -> Result<T, SignerError> + Send + 'static,
+    ) -> Result<T, SignerError> {
+        let connector = self.connector.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut card = connector.connect()?;
+            session(card.as_mut())
+        })
+        .await
+        .map_err(|e| SignerError::Other(format!("YubiKey task failed: {e}")))?
+    }
+
+    async fn key_info(&self) -> Result<KeyInfo, SignerError> {
+        let applet = self.applet;
+        self.with_card(move |card| read_key_info(card, applet))
+            .await
+    }
+
+    async fn sign_bytes(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        if self.touch_required {
+            if let Some(notifier) = &self.touch_notifier {
+                notifier();
+            }
+        }
+
+        let applet = self.applet;
+        let pin = self.pin.clone();
+        let message = message.to_vec();
+        let signature = self
+            .with_card(move |card| sign_on_card(card, applet, pin.as_deref(), &message))
+            .await?;
+
+        Signature::try_from(signature.as_slice())
+            .map_err(|_| SignerError::SigningFailed("Invalid signature length".to_string()))
+    }
+
+    async fn sign_and_serialize(
+        &self,
+        transaction: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        let signature = self.sign_bytes(&transaction.message_data()).await?;
+
+        TransactionUtil::add_signature_to_transaction(transaction, &self.public_key, signature)?;
+
+        Ok(SignedTransaction::new(
+            TransactionUtil::serialize_transaction(transaction)?,
+            signature,
+            self.public_key,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SolanaSigner for YubiKeySigner {
+    fn pubkey(&self) -> Pubkey {
+        self.public_key
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_bytes(message).await
+    }
+
+    async fn sign_partial_transaction(
+        &self,
+        tx: &mut Transaction,
+    ) -> Result<SignedTransaction, SignerError> {
+        self.sign_and_serialize(tx).await
+    }
+
+    /// Check that the YubiKey is connected and the slot still holds this key
+    /// Signs one at a time, since the PIV applet runs a single operation at once
+    async fn sign_all_transactions(
+        &self,
+        txs: &mut [Transaction],
+    ) -> Vec<Result<SignedTransaction, SignerError>> {
+        crate::batch::sign_sequentially(self, txs).await
+    }
+
+    async fn is_available(&self) -> bool {
+        if self.public_key == Pubkey::default() {
+            return false;
+        }
+
+        match self.key_info().await {
+            Ok(info) => info.public_key == self.public_key.to_bytes(),
+            Err(_) => false,
+        }
+    }
+
+    fn latency_class(&self) -> LatencyClass {
+        LatencyClass::Hardware
+    }
+
+    fn capabilities(&self) -> SignerCapabilities {
+        SignerCapabilities::requiring_init()
+    }
+
+    fn metadata(&self) -> SignerMetadata {
+        let metadata = SignerMetadata::new("yubikey", self.pubkey(), self.latency_class());
+
+        match &self.reader {
+            Some(reader) => metadata.with_endpoint(reader),
+            None => metadata,
+        }
+    }
+
+    fn config_debug(&self) -> String {
+        let applet = match self.applet {
+            YubiKeyApplet::Piv(slot) => format!("piv slot={:02x}", slot.id()),
+            YubiKeyApplet::OpenPgp => "openpgp".to_string(),
+        };
+        format!(
+            "backend=yubikey applet={} reader={} pin={} pubkey={}",
+            applet,
+            self.reader.as_deref().unwrap_or("<first>"),
+            if self.pin.is_some() {
+                "<set>"
+            } else {
+                "<unset>"
+            },
+            self.public_key
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdk_adapter::{keypair_pubkey, keypair_sign_message, Keypair};
+    use crate::test_util::create_test_transaction;
+    use std::collections::VecDeque;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    const OK: [u8; 2] = [0x90, 0x00];
+
+    /// Expected APDUs paired with the responses to return
+    type Script = Arc<Mutex<VecDeque<(Vec<u8>, Vec<u8>)>>>;
+
+    /// Card that checks each APDU against a script and replies in order
+    struct ScriptedCard {
+        script: Script,
+    }
+
+    impl SmartCard for ScriptedCard {
+        fn transmit(&mut self, apdu: &[u8]) -> Result<Vec<u8>, SignerError> {
+            let (expected, response) = self
+                .script
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("unexpected APDU");
+            assert_eq!(apdu, expected.as_slice());
+            Ok(response)
+        }
+    }
+
+    #[derive(Default)]
+    struct ScriptedConnector {
+        script: Script,
+        disconnected: bool,
+    }
+
+    impl ScriptedConnector {
+        fn expect(&self, apdu: Vec<u8>, response: Vec<u8>) {
+            self.script.lock().unwrap().push_back((apdu, response));
+        }
+
+        fn is_done(&self) -> bool {
+            self.script.lock().unwrap().is_empty()
+        }
+    }
+
+    impl CardConnector for ScriptedConnector {
+        fn connect(&self) -> Result<Box<dyn SmartCard>, SignerError> {
+            if self.disconnected {
+                return Err(SignerError::NotAvailable(
+                    "No YubiKey connected".to_string(),
+                ));
+            }
+            Ok(Box::new(ScriptedCard {
+                script: self.script.clone(),
+            }))
+        }
+    }
+
+    fn ok(data: &[u8]) -> Vec<u8> {
+        [data, &OK].concat()
+    }
+
+    fn tlv(tag: &[u8], value: &[u8]) -> Vec<u8> {
+        assert!(value.len() < 0x80);
+        [tag, &[value.len() as u8], value].concat()
+    }
+
+    fn expect_piv_metadata(
+        connector: &ScriptedConnector,
+        algorithm: u8,
+        touch_policy: u8,
+        pubkey: &Pubkey,
+    ) {
+        connector.expect(
+            apdu::command(0xa4, 0x04, 0x00, &[0xa0, 0x00, 0x00, 0x03, 0x08], false),
+            ok(&[]),
+        );
+        let metadata = [
+            tlv(&[0x01], &[algorithm]),
+            tlv(&[0x02], &[0x02, touch_policy]),
+            tlv(&[0x04], &tlv(&[0x86], &pubkey.to_bytes())),
+        ]
+        .concat();
+        connector.expect(apdu::command(0xf7, 0x00, 0x9c, &[], true), ok(&metadata));
+    }
+
+    fn piv_sign_apdu(message: &[u8]) -> Vec<u8> {
+        let mut template = vec![0x82, 0x00, 0x81];
+        if message.len() > 0xff {
+            template.push(0x82);
+            template.extend_from_slice(&(message.len() as u16).to_be_bytes());
+        } else if message.len() >= 0x80 {
+            template.extend_from_slice(&[0x81, message.len() as u8]);
+        } else {
+            template.push(message.len() as u8);
+        }
+        template.extend_from_slice(message);
+
+        let mut data = vec![0x7c];
+        if template.len() > 0xff {
+            data.push(0x82);
+            data.extend_from_slice(&(template.len() as u16).to_be_bytes());
+        } else if template.len() >= 0x80 {
+            data.extend_from_slice(&[0x81, template.len() as u8]);
+        } else {
+            data.push(template.len() as u8);
+        }
+        data.extend_from_slice(&template);
+
+        apdu::command(0x87, 0xe0, 0x9c, &data, true)
+    }
+
+    fn piv_signature_response(signature: &Signature) -> Vec<u8> {
+        ok(&tlv(&[0x7c], &tlv(&[0x82], signature.as_ref())))
+    }
+
+    fn create_test_signer(
+        connector: Arc<ScriptedConnector>,
+        applet: YubiKeyApplet,
+    ) -> YubiKeySigner {
+        let mut signer = YubiKeySigner::new(applet);
+        signer.connector = connector;
+        signer
+    }
+
+    #[tokio::test]
+    async fn test_init_piv() {
+        let connector = Arc::new(ScriptedConnector::default());
+        let pubkey = Pubkey::new_unique();
+        expect_piv_metadata(&connector, apdu::PIV_ALGORITHM_ED25519, 0x01, &pubkey);
+
+        let mut signer = create_test_signer(connector.clone(), YubiKeyApplet::default());
+        signer.init().await.unwrap();
+
+        assert_eq!(signer.pubkey(), pubkey);
+        assert!(!signer.touch_required);
+        assert!(connector.is_done());
+    }
+
+    #[tokio::test]
+    async fn test_init_piv_rejects_non_ed25519_key() {
+        let connector = Arc::new(ScriptedConnector::default());
+        // 0x11 is ECC P-256
+        expect_piv_metadata(&connector, 0x11, 0x01, &Pubkey::new_unique());
+
+        let mut signer = create_test_signer(connector, YubiKeyApplet::default());
+        let result = signer.init().await;
+
+        assert!(matches!(result, Err(SignerError::ConfigError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction_piv_with_pin_and_touch() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let connector = Arc::new(ScriptedConnector::default());
+        // Touch policy "always"
+        expect_piv_metadata(&connector, apdu::PIV_ALGORITHM_ED25519, 0x02, &pubkey);
+
+        let touches = Arc::new(AtomicUsize::new(0));
+        let counter = touches.clone();
+        let mut signer = create_test_signer(connector.clone(), YubiKeyApplet::default())
+            .with_pin("123456".to_string())
+            .with_touch_notifier(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        signer.init().await.unwrap();
+
+        let mut tx = create_test_transaction(&pubkey);
+        let message = tx.message_data();
+        let expected_signature = keypair_sign_message(&keypair, &message);
+
+        connector.expect(
+            apdu::command(0xa4, 0x04, 0x00, &[0xa0, 0x00, 0x00, 0x03, 0x08], false),
+            ok(&[]),
+        );
+        connector.expect(
+            apdu::command(
+                0x20,
+                0x00,
+                0x80,
+                &[b'1', b'2', b'3', b'4', b'5', b'6', 0xff, 0xff],
+                false,
+            ),
+            ok(&[]),
+        );
+        connector.expect(
+            piv_sign_apdu(&message),
+            piv_signature_response(&expected_signature),
+        );
+
+        let SignedTransaction {
+            base64_tx: serialized,
+            signature,
+            ..
+        } = signer.sign_transaction(&mut tx).await.unwrap();
+
+        assert_eq!(signature, expected_signature);
+        assert!(!serialized.is_empty());
+        assert_eq!(tx.signatures[0], expected_signature);
+        assert_eq!(touches.load(Ordering::SeqCst), 1);
+        assert!(connector.is_done());
+    }
+
+    #[tokio::test]
+    async fn test_sign_message_wrong_pin() {
+        let connector = Arc::new(ScriptedConnector::default());
+        let pubkey = Pubkey::new_unique();
+        expect_piv_metadata(&connector, apdu::PIV_ALGORITHM_ED25519, 0x01, &pubkey);
+
+        let mut signer = create_test_signer(connector.clone(), YubiKeyApplet::default())
+            .with_pin("000000".to_string());
+        signer.init().await.unwrap();
+
+        connector.expect(
+            apdu::command(0xa4, 0x04, 0x00, &[0xa0, 0x00, 0x00, 0x03, 0x08], false),
+            ok(&[]),
+        );
+        connector.expect(
+            apdu::command(
+                0x20,
+                0x00,
+                0x80,
+                &[b'0', b'0', b'0', b'0', b'0', b'0', 0xff, 0xff],
+                false,
+            ),
+            vec![0x63, 0xc2],
+        );
+
+        let result = signer.sign_message(b"hello").await;
+
+        match result {
+            Err(SignerError::ConfigError(message)) => assert!(message.contains("2 attempts")),
+            other => panic!("expected ConfigError, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_openpgp_init_and_sign_with_chained_response() {
+        let keypair = Keypair::new();
+        let pubkey = keypair_pubkey(&keypair);
+        let connector = Arc::new(ScriptedConnector::default());
+        let select = apdu::command(
+            0xa4,
+            0x04,
+            0x00,
+            &[0xd2, 0x76, 0x00, 0x01, 0x24, 0x01],
+            false,
+        );
+
+        connector.expect(select.clone(), ok(&[]));
+        connector.expect(
+            apdu::command(0x47, 0x81, 0x00, &[0xb6, 0x00], true),
+            ok(&tlv(&[0x7f, 0x49], &tlv(&[0x86], &pubkey.to_bytes()))),
+        );
+        connector.expect(
+            apdu::command(0xca, 0x00, 0xd6, &[], true),
+            ok(&[0x00, 0x20]),
+        );
+
+        let mut signer = create_test_signer(connector.clone(), YubiKeyApplet::OpenPgp)
+            .with_pin("123456".to_string());
+        signer.init().await.unwrap();
+        assert_eq!(signer.pubkey(), pubkey);
+        assert!(!signer.touch_required);
+
+        let expected_signature = keypair_sign_message(&keypair, b"hello");
+        let (first, rest) = expected_signature.as_ref().split_at(40);
+
+        connector.expect(select, ok(&[]));
+        connector.expect(apdu::command(0x20, 0x00, 0x81, b"123456", false), ok(&[]));
+        connector.expect(
+            apdu::command(0x2a, 0x9e, 0x9a, b"hello", true),
+            [first, &[0x61, rest.len() as u8]].concat(),
+        );
+        connector.expect(vec![0x00, 0xc0, 0x00, 0x00, rest.len() as u8], ok(rest));
+
+        let signature = signer.sign_message(b"hello").await.unwrap();
+
+        assert_eq!(signature, expected_signature);
+        assert!(connector.is_done());
+    }
+
+    #[tokio::test]
+    async fn test_is_available() {
+        let pubkey = Pubkey::new_unique();
+        let connector = Arc::new(ScriptedConnector::default());
+        expect_piv_metadata(&connector, apdu::PIV_ALGORITHM_ED25519, 0x01, &pubkey);
+        expect_piv_metadata(&connector, apdu::PIV_ALGORITHM_ED25519, 0x01, &pubkey);
+        // A different key now sits in the slot
+        expect_piv_metadata(
+            &connector,
+            apdu::PIV_ALGORITHM_ED25519,
+            0x01,
+            &Pubkey::new_unique(),
+        );
+
+        let mut signer = create_test_signer(connector, YubiKeyApplet::default());
+        assert!(!signer.is_available().await);
+
+        signer.init().await.unwrap();
+        assert!(signer.is_available().await);
+        assert!(!signer.is_available().await);
+    }
+
+    #[tokio::test]
+    async fn test_is_available_disconnected() {
+        let connector = Arc::new(ScriptedConnector {
+            disconnected: true,
+            ..Default::default()
+        });
+        let mut signer = create_test_signer(connector, YubiKeyApplet::default());
+        signer.public_key = Pubkey::new_unique();
+
+        assert!(!signer.is_available().await);
+    }
+
+    #[test]
+    fn test_config_debug_hides_pin() {
+        let signer = YubiKeySigner::new(YubiKeyApplet::Piv(PivSlot::Authentication))
+            .with_pin("123456".to_string());
+
+        let debug = signer.config_debug();
+        assert!(debug.contains("piv slot=9a"));
+        assert!(debug.contains("pin=<set>"));
+        assert!(!debug.contains("123456"));
+        assert!(!format!("{signer:?}").contains("123456"));
+    }
+}